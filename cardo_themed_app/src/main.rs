@@ -7,7 +7,6 @@
 
 mod app;
 mod pages;
-mod theme;
 
 use leptos::prelude::*;
 
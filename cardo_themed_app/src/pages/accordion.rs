@@ -1,7 +1,7 @@
 use cardo_ui::accordion::AccordionType;
 use leptos::prelude::*;
 
-use crate::theme::accordion::*;
+use cardo_ui_themes::accordion::*;
 
 #[component]
 pub fn AccordionPage() -> impl IntoView {
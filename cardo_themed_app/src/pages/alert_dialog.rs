@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 
-use crate::theme::alert_dialog::*;
-use crate::theme::button::*;
+use cardo_ui_themes::alert_dialog::*;
+use cardo_ui_themes::button::*;
 
 #[component]
 pub fn AlertDialogPage() -> impl IntoView {
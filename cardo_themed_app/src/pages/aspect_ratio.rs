@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::aspect_ratio::*;
+use cardo_ui_themes::aspect_ratio::*;
 
 #[component]
 pub fn AspectRatioPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::avatar::ThemedAvatar;
+use cardo_ui_themes::avatar::ThemedAvatar;
 
 #[component]
 pub fn AvatarPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::badge::*;
+use cardo_ui_themes::badge::*;
 
 #[component]
 pub fn BadgesPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
+use cardo_ui_themes::button::*;
 
 #[component]
 fn ButtonRow(label: &'static str, variant: ButtonVariant) -> impl IntoView {
@@ -1,7 +1,7 @@
 use cardo_ui::calendar::NaiveDate;
 use leptos::prelude::*;
 
-use crate::theme::calendar::*;
+use cardo_ui_themes::calendar::*;
 
 #[component]
 pub fn CalendarPage() -> impl IntoView {
@@ -1,8 +1,8 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::card::*;
-use crate::theme::input::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::card::*;
+use cardo_ui_themes::input::*;
 
 #[component]
 pub fn CardPage() -> impl IntoView {
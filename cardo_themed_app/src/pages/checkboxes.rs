@@ -1,7 +1,7 @@
 use cardo_ui::checkbox::CheckedState;
 use leptos::prelude::*;
 
-use crate::theme::checkbox::*;
+use cardo_ui_themes::checkbox::*;
 
 #[component]
 pub fn CheckboxesPage() -> impl IntoView {
@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::collapsible::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::collapsible::*;
 
 #[component]
 pub fn CollapsiblePage() -> impl IntoView {
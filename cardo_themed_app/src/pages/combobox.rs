@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::combobox::*;
+use cardo_ui_themes::combobox::*;
 
 const FRAMEWORKS: &[(&str, &str)] = &[
     ("react", "React"),
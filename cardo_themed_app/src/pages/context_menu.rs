@@ -1,7 +1,7 @@
 use cardo_ui::context_menu::CheckedState;
 use leptos::prelude::*;
 
-use crate::theme::context_menu::*;
+use cardo_ui_themes::context_menu::*;
 
 #[component]
 pub fn ContextMenuPage() -> impl IntoView {
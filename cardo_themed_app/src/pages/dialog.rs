@@ -1,8 +1,8 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::dialog::*;
-use crate::theme::input::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::dialog::*;
+use cardo_ui_themes::input::*;
 
 #[component]
 pub fn DialogPage() -> impl IntoView {
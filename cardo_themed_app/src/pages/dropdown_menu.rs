@@ -1,8 +1,8 @@
 use cardo_ui::dropdown_menu::CheckedState;
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::dropdown_menu::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::dropdown_menu::*;
 
 #[component]
 pub fn DropdownMenuPage() -> impl IntoView {
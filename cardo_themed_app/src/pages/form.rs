@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 
-use crate::theme::button::Button;
-use crate::theme::form::*;
+use cardo_ui_themes::button::Button;
+use cardo_ui_themes::form::*;
 
 #[component]
 pub fn FormPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::hover_card::*;
+use cardo_ui_themes::hover_card::*;
 
 #[component]
 pub fn HoverCardPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::input::*;
+use cardo_ui_themes::input::*;
 
 #[component]
 pub fn InputPage() -> impl IntoView {
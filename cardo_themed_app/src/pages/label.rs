@@ -1,8 +1,8 @@
 use leptos::prelude::*;
 
-use crate::theme::checkbox::ThemedCheckbox;
-use crate::theme::input::*;
-use crate::theme::label::ThemedLabel;
+use cardo_ui_themes::checkbox::ThemedCheckbox;
+use cardo_ui_themes::input::*;
+use cardo_ui_themes::label::ThemedLabel;
 
 #[component]
 pub fn LabelPage() -> impl IntoView {
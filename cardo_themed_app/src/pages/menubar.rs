@@ -1,7 +1,7 @@
 use cardo_ui::menubar::CheckedState;
 use leptos::prelude::*;
 
-use crate::theme::menubar::*;
+use cardo_ui_themes::menubar::*;
 
 #[component]
 pub fn MenubarPage() -> impl IntoView {
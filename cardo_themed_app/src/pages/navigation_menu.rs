@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::navigation_menu::*;
+use cardo_ui_themes::navigation_menu::*;
 
 #[component]
 pub fn NavigationMenuPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::one_time_password_field::*;
+use cardo_ui_themes::one_time_password_field::*;
 
 #[component]
 pub fn OneTimePasswordFieldPage() -> impl IntoView {
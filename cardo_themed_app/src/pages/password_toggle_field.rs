@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::password_toggle_field::*;
+use cardo_ui_themes::password_toggle_field::*;
 
 #[component]
 pub fn PasswordToggleFieldPage() -> impl IntoView {
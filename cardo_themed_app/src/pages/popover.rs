@@ -1,8 +1,8 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::input::*;
-use crate::theme::popover::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::input::*;
+use cardo_ui_themes::popover::*;
 
 #[component]
 pub fn PopoverPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::progress::ThemedProgress;
+use cardo_ui_themes::progress::ThemedProgress;
 
 #[component]
 pub fn ProgressPage() -> impl IntoView {
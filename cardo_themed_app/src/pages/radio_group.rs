@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 
-use crate::theme::label::ThemedLabel;
-use crate::theme::radio_group::*;
+use cardo_ui_themes::label::ThemedLabel;
+use cardo_ui_themes::radio_group::*;
 
 #[component]
 pub fn RadioGroupPage() -> impl IntoView {
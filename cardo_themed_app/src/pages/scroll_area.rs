@@ -1,7 +1,7 @@
 use cardo_ui::scroll_area::ScrollAreaType;
 use leptos::prelude::*;
 
-use crate::theme::scroll_area::*;
+use cardo_ui_themes::scroll_area::*;
 
 #[component]
 pub fn ScrollAreaPage() -> impl IntoView {
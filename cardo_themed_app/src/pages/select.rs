@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::select::*;
+use cardo_ui_themes::select::*;
 
 #[component]
 pub fn SelectPage() -> impl IntoView {
@@ -1,7 +1,7 @@
 use cardo_ui::separator::Orientation;
 use leptos::prelude::*;
 
-use crate::theme::separator::ThemedSeparator;
+use cardo_ui_themes::separator::ThemedSeparator;
 
 #[component]
 pub fn SeparatorPage() -> impl IntoView {
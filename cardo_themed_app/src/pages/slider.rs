@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::slider::ThemedSlider;
+use cardo_ui_themes::slider::ThemedSlider;
 
 type SliderValues = Vec<f64>;
 
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::switch::*;
+use cardo_ui_themes::switch::*;
 
 #[component]
 pub fn SwitchesPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::tabs::*;
+use cardo_ui_themes::tabs::*;
 
 #[component]
 pub fn TabsPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::textarea::*;
+use cardo_ui_themes::textarea::*;
 
 #[component]
 pub fn TextareaPage() -> impl IntoView {
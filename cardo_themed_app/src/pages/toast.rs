@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::toast::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::toast::*;
 
 #[component]
 pub fn ToastPage() -> impl IntoView {
@@ -1,7 +1,7 @@
 use cardo_ui::toggle_group::ToggleGroupType;
 use leptos::prelude::*;
 
-use crate::theme::toggle_group::*;
+use cardo_ui_themes::toggle_group::*;
 
 #[component]
 pub fn ToggleGroupPage() -> impl IntoView {
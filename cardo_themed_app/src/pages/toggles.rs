@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::toggle::*;
+use cardo_ui_themes::toggle::*;
 
 #[component]
 pub fn TogglesPage() -> impl IntoView {
@@ -1,6 +1,6 @@
 use leptos::prelude::*;
 
-use crate::theme::toolbar::*;
+use cardo_ui_themes::toolbar::*;
 
 #[component]
 pub fn ToolbarPage() -> impl IntoView {
@@ -1,7 +1,7 @@
 use leptos::prelude::*;
 
-use crate::theme::button::*;
-use crate::theme::tooltip::*;
+use cardo_ui_themes::button::*;
+use cardo_ui_themes::tooltip::*;
 
 #[component]
 pub fn TooltipPage() -> impl IntoView {
@@ -1,2 +0,0 @@
-// AccessibleIcon is purely behavioral — no styling needed.
-// The page imports `AccessibleIcon` from `cardo_ui` directly.
@@ -1,38 +0,0 @@
-pub mod accessible_icon;
-pub mod accordion;
-pub mod alert_dialog;
-pub mod aspect_ratio;
-pub mod avatar;
-pub mod badge;
-pub mod button;
-pub mod calendar;
-pub mod card;
-pub mod checkbox;
-pub mod collapsible;
-pub mod combobox;
-pub mod context_menu;
-pub mod dialog;
-pub mod dropdown_menu;
-pub mod form;
-pub mod hover_card;
-pub mod input;
-pub mod label;
-pub mod menubar;
-pub mod navigation_menu;
-pub mod one_time_password_field;
-pub mod password_toggle_field;
-pub mod popover;
-pub mod progress;
-pub mod radio_group;
-pub mod scroll_area;
-pub mod select;
-pub mod separator;
-pub mod slider;
-pub mod switch;
-pub mod tabs;
-pub mod textarea;
-pub mod toast;
-pub mod toggle;
-pub mod toggle_group;
-pub mod toolbar;
-pub mod tooltip;
@@ -0,0 +1,502 @@
+//! Parsed color values and conversions between color spaces.
+
+use std::{fmt, str::FromStr};
+
+use crate::space::{
+    Gamut, format_hex, format_p3, oklab_to_oklch, oklch_to_rgb_in_gamut, parse, rgb_to_oklab,
+};
+
+/// Gamma-encoded RGB components and alpha, each from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub alpha: f64,
+}
+
+/// OKLCH components: lightness from 0 to 1, chroma from 0 (about 0.4 at most
+/// for displayable colors), hue in degrees, and alpha from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+    pub alpha: f64,
+}
+
+/// HSL components of an sRGB color: hue in degrees, saturation, lightness and
+/// alpha from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+    pub alpha: f64,
+}
+
+/// HSV components of an sRGB color: hue in degrees, saturation, value
+/// (brightness) and alpha from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+    pub alpha: f64,
+}
+
+/// A color in sRGB, Display P3, OKLCH, HSL or HSV.
+///
+/// Parses the formats used by the scales (`#rgb`, `#rrggbb`, `#rrggbbaa`,
+/// `color(display-p3 r g b[ / a])`) as well as `oklch(l c h[ / a])` and
+/// `hsl(h s l[ / a])`, and formats back to the same CSS syntax. HSV has no CSS
+/// syntax, so it's formatted as hex.
+///
+/// HSL and HSV keep their hue for grays and, for HSV, the saturation of
+/// black, which color pickers need while the color is edited.
+///
+/// ```
+/// use cardo_ui_colors::Color;
+///
+/// let blue: Color = "#0090ff".parse().unwrap();
+/// let oklch = blue.to_oklch();
+///
+/// assert!((oklch.l - 0.649).abs() < 0.001);
+/// assert_eq!(Color::Oklch(oklch).to_hex(), "#0090ff");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Color {
+    Srgb(Rgb),
+    DisplayP3(Rgb),
+    Oklch(Oklch),
+    Hsl(Hsl),
+    Hsv(Hsv),
+}
+
+impl Color {
+    /// Parses a CSS color. See [`Color`] for the supported formats.
+    pub fn parse(value: &str) -> Result<Self, ParseColorError> {
+        let error = || ParseColorError {
+            value: value.into(),
+        };
+
+        if let Some(inner) = value.trim().strip_prefix("oklch(") {
+            let ([l, c, h], alpha) = parse_components(inner).ok_or_else(error)?;
+            return Ok(Color::Oklch(Oklch { l, c, h, alpha }));
+        }
+        if let Some(inner) = value.trim().strip_prefix("hsl(") {
+            let ([h, s, l], alpha) = parse_components(inner).ok_or_else(error)?;
+            return Ok(Color::Hsl(Hsl { h, s, l, alpha }));
+        }
+
+        let (gamut, [r, g, b], alpha) = parse(value).ok_or_else(error)?;
+        let rgb = Rgb { r, g, b, alpha };
+        Ok(match gamut {
+            Gamut::Srgb => Color::Srgb(rgb),
+            Gamut::P3 => Color::DisplayP3(rgb),
+        })
+    }
+
+    pub fn alpha(&self) -> f64 {
+        match self {
+            Color::Srgb(rgb) | Color::DisplayP3(rgb) => rgb.alpha,
+            Color::Oklch(oklch) => oklch.alpha,
+            Color::Hsl(hsl) => hsl.alpha,
+            Color::Hsv(hsv) => hsv.alpha,
+        }
+    }
+
+    /// Converts to OKLCH.
+    pub fn to_oklch(&self) -> Oklch {
+        let (gamut, rgb) = match self {
+            Color::Srgb(rgb) => (Gamut::Srgb, *rgb),
+            Color::DisplayP3(rgb) => (Gamut::P3, *rgb),
+            Color::Oklch(oklch) => return *oklch,
+            Color::Hsl(hsl) => (Gamut::Srgb, hsl.to_rgb()),
+            Color::Hsv(hsv) => (Gamut::Srgb, hsv.to_rgb()),
+        };
+        let [l, c, h] = oklab_to_oklch(rgb_to_oklab(gamut, [rgb.r, rgb.g, rgb.b]));
+        Oklch {
+            l,
+            c,
+            h,
+            alpha: rgb.alpha,
+        }
+    }
+
+    /// Converts to sRGB, reducing chroma if the color is outside the sRGB gamut.
+    pub fn to_srgb(&self) -> Rgb {
+        match self {
+            Color::Srgb(rgb) => *rgb,
+            Color::Hsl(hsl) => hsl.to_rgb(),
+            Color::Hsv(hsv) => hsv.to_rgb(),
+            _ => self.to_gamut(Gamut::Srgb),
+        }
+    }
+
+    /// Converts to Display P3, reducing chroma if the color is outside the P3
+    /// gamut.
+    pub fn to_display_p3(&self) -> Rgb {
+        match self {
+            Color::DisplayP3(rgb) => *rgb,
+            _ => self.to_gamut(Gamut::P3),
+        }
+    }
+
+    /// Converts to HSL, reducing chroma if the color is outside the sRGB gamut.
+    pub fn to_hsl(&self) -> Hsl {
+        match self {
+            Color::Hsl(hsl) => *hsl,
+            Color::Hsv(hsv) => hsv.to_hsl(),
+            _ => self.to_srgb().to_hsl(),
+        }
+    }
+
+    /// Converts to HSV, reducing chroma if the color is outside the sRGB gamut.
+    pub fn to_hsv(&self) -> Hsv {
+        match self {
+            Color::Hsv(hsv) => *hsv,
+            Color::Hsl(hsl) => hsl.to_hsv(),
+            _ => self.to_srgb().to_hsv(),
+        }
+    }
+
+    /// Formats as `#rrggbb`, or `#rrggbbaa` when translucent, converting to sRGB.
+    pub fn to_hex(&self) -> String {
+        let rgb = self.to_srgb();
+        format_hex([rgb.r, rgb.g, rgb.b], rgb.alpha)
+    }
+
+    fn to_gamut(self, gamut: Gamut) -> Rgb {
+        let oklch = self.to_oklch();
+        let [r, g, b] = oklch_to_rgb_in_gamut(gamut, [oklch.l, oklch.c, oklch.h]);
+        Rgb {
+            r,
+            g,
+            b,
+            alpha: oklch.alpha,
+        }
+    }
+}
+
+impl Rgb {
+    /// Converts to HSL. Grays get a hue of 0.
+    pub fn to_hsl(&self) -> Hsl {
+        self.to_hsv().to_hsl()
+    }
+
+    /// Converts to HSV. Grays get a hue of 0.
+    pub fn to_hsv(&self) -> Hsv {
+        let Rgb { r, g, b, alpha } = *self;
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        let h = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        let s = if max == 0.0 { 0.0 } else { delta / max };
+        Hsv {
+            h,
+            s,
+            v: max,
+            alpha,
+        }
+    }
+}
+
+impl Hsl {
+    pub fn to_rgb(&self) -> Rgb {
+        self.to_hsv().to_rgb()
+    }
+
+    /// Converts to HSV, keeping the hue.
+    pub fn to_hsv(&self) -> Hsv {
+        let v = self.l + self.s * self.l.min(1.0 - self.l);
+        let s = if v == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - self.l / v)
+        };
+        Hsv {
+            h: self.h,
+            s,
+            v,
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl Hsv {
+    pub fn to_rgb(&self) -> Rgb {
+        let chroma = self.v * self.s;
+        let sector = self.h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = self.v - chroma;
+        Rgb {
+            r: r + m,
+            g: g + m,
+            b: b + m,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Converts to HSL, keeping the hue.
+    pub fn to_hsl(&self) -> Hsl {
+        let l = self.v * (1.0 - self.s / 2.0);
+        let s = if l == 0.0 || l == 1.0 {
+            0.0
+        } else {
+            (self.v - l) / l.min(1.0 - l)
+        };
+        Hsl {
+            h: self.h,
+            s,
+            l,
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::parse(s)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Srgb(rgb) => f.write_str(&format_hex([rgb.r, rgb.g, rgb.b], rgb.alpha)),
+            Color::DisplayP3(rgb) => f.write_str(&format_p3([rgb.r, rgb.g, rgb.b], rgb.alpha)),
+            Color::Oklch(oklch) => {
+                write!(
+                    f,
+                    "oklch({} {} {}",
+                    round(oklch.l, 3),
+                    round(oklch.c, 3),
+                    round(oklch.h, 1)
+                )?;
+                if oklch.alpha < 1.0 {
+                    write!(f, " / {}", round(oklch.alpha, 3))?;
+                }
+                f.write_str(")")
+            }
+            Color::Hsl(hsl) => {
+                write!(
+                    f,
+                    "hsl({} {}% {}%",
+                    round(hsl.h, 1),
+                    round(hsl.s * 100.0, 1),
+                    round(hsl.l * 100.0, 1)
+                )?;
+                if hsl.alpha < 1.0 {
+                    write!(f, " / {}", round(hsl.alpha, 3))?;
+                }
+                f.write_str(")")
+            }
+            Color::Hsv(_) => f.write_str(&self.to_hex()),
+        }
+    }
+}
+
+/// Error returned when a string is not a supported color.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseColorError {
+    value: String,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid color `{}`", self.value)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Parses `c1 c2 c3[ / alpha])`, the inside of a color function such as `oklch(`.
+fn parse_components(inner: &str) -> Option<([f64; 3], f64)> {
+    let inner = inner.strip_suffix(')')?;
+    let (components, alpha) = match inner.split_once('/') {
+        Some((components, alpha)) => (components, parse_number(alpha)?),
+        None => (inner, 1.0),
+    };
+    let components: Vec<f64> = components
+        .split_whitespace()
+        .map(parse_number)
+        .collect::<Option<_>>()?;
+    components
+        .try_into()
+        .ok()
+        .map(|components| (components, alpha))
+}
+
+/// Parses a number or a percentage (as a fraction of 1).
+fn parse_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    match value.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok().map(|p| p / 100.0),
+        None => value.parse().ok(),
+    }
+}
+
+fn round(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 0.002
+    }
+
+    #[test]
+    fn parses_scale_formats() {
+        assert_eq!(
+            Color::parse("#0090ff80"),
+            Ok(Color::Srgb(Rgb {
+                r: 0.0,
+                g: 144.0 / 255.0,
+                b: 1.0,
+                alpha: 128.0 / 255.0,
+            }))
+        );
+        assert_eq!(
+            Color::parse("color(display-p3 0.024 0.514 1 / 0.016)"),
+            Ok(Color::DisplayP3(Rgb {
+                r: 0.024,
+                g: 0.514,
+                b: 1.0,
+                alpha: 0.016,
+            }))
+        );
+        assert_eq!(
+            Color::parse("oklch(65% 0.19 251.8)").map(|color| color.to_oklch().l),
+            Ok(0.65)
+        );
+        assert_eq!(
+            Color::parse("hsl(120 100% 25%)"),
+            Ok(Color::Hsl(Hsl {
+                h: 120.0,
+                s: 1.0,
+                l: 0.25,
+                alpha: 1.0,
+            }))
+        );
+        assert!(Color::parse("blue").is_err());
+        assert!(Color::parse("oklch(0.5 0.1)").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for value in [
+            "#0090ff",
+            "#00000003",
+            "color(display-p3 0.024 0.514 1 / 0.016)",
+            "oklch(0.649 0.193 251.8)",
+            "hsl(206.1 100% 50% / 0.5)",
+        ] {
+            assert_eq!(Color::parse(value).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn shipped_p3_values_share_lightness_with_srgb_values() {
+        // P3 steps may be more saturated, but keep the lightness of their sRGB twins.
+        for name in ScaleName::ALL {
+            for step in 1..=12 {
+                let srgb = name.light().color(step).to_oklch();
+                let p3 = name.light_p3().color(step).to_oklch();
+                assert!((srgb.l - p3.l).abs() < 0.025, "{name} step {step}");
+            }
+        }
+    }
+
+    #[test]
+    fn converts_between_spaces() {
+        let blue = Color::parse(BLUE[8]).unwrap();
+        let p3 = Color::DisplayP3(blue.to_display_p3());
+        let back = p3.to_srgb();
+
+        assert!(close(back.r, 0.0) && close(back.g, 144.0 / 255.0) && close(back.b, 1.0));
+        assert!(close(blue.to_oklch().h, p3.to_oklch().h));
+    }
+
+    #[test]
+    fn out_of_gamut_colors_are_mapped() {
+        let vivid = Color::Oklch(Oklch {
+            l: 0.7,
+            c: 0.35,
+            h: 150.0,
+            alpha: 1.0,
+        });
+        let srgb = vivid.to_srgb();
+        let p3 = vivid.to_display_p3();
+
+        for channel in [srgb.r, srgb.g, srgb.b, p3.r, p3.g, p3.b] {
+            assert!((0.0..=1.0).contains(&channel));
+        }
+        assert!(
+            Color::DisplayP3(p3).to_oklch().c > Color::Srgb(srgb).to_oklch().c,
+            "P3 should keep more chroma than sRGB"
+        );
+    }
+
+    #[test]
+    fn converts_hsl_and_hsv() {
+        let green = Color::Hsl(Hsl {
+            h: 120.0,
+            s: 1.0,
+            l: 0.25,
+            alpha: 1.0,
+        });
+        assert_eq!(green.to_hex(), "#008000");
+        assert_eq!(
+            green.to_hsv(),
+            Hsv {
+                h: 120.0,
+                s: 1.0,
+                v: 0.5,
+                alpha: 1.0,
+            }
+        );
+
+        for hex in ["#ff0000", "#123456", "#c8c8c8", "#000000", "#010203"] {
+            let color = Color::parse(hex).unwrap();
+            assert_eq!(Color::Hsv(color.to_hsv()).to_hex(), hex);
+            assert_eq!(Color::Hsl(color.to_hsl()).to_hex(), hex);
+        }
+        assert_eq!(Color::parse("#0000ff").unwrap().to_hsv().h, 240.0);
+    }
+
+    #[test]
+    fn hsl_and_hsv_keep_the_hue_of_grays() {
+        let gray = Hsv {
+            h: 200.0,
+            s: 0.0,
+            v: 0.5,
+            alpha: 1.0,
+        };
+        assert_eq!(gray.to_hsl().h, 200.0);
+        assert_eq!(Color::Hsv(gray).to_hsl().to_hsv().h, 200.0);
+        assert_eq!(Color::Hsv(gray).to_string(), "#808080");
+    }
+}
@@ -0,0 +1,228 @@
+//! Contrast checking between colors.
+//!
+//! Colors are given as the scale strings (`#rrggbb[aa]` or
+//! `color(display-p3 …)`). Translucent text is blended over the background
+//! before measuring; the background's own alpha is ignored.
+
+use crate::space::{Gamut, oklab_to_rgb, parse, rgb_to_oklab, to_linear};
+
+/// Minimum WCAG 2 ratio for normal text (level AA).
+pub const WCAG_AA: f64 = 4.5;
+
+/// Minimum WCAG 2 ratio for large text (level AA).
+pub const WCAG_AA_LARGE: f64 = 3.0;
+
+/// Minimum WCAG 2 ratio for normal text (level AAA).
+pub const WCAG_AAA: f64 = 7.0;
+
+/// WCAG 2 contrast ratio between `foreground` and `background`, from `1.0` to
+/// `21.0`. Returns `None` if either color can't be parsed.
+pub fn wcag_contrast(foreground: &str, background: &str) -> Option<f64> {
+    let (foreground, background) = blend(foreground, background)?;
+    let foreground = luminance(foreground);
+    let background = luminance(background);
+
+    let (lighter, darker) = if foreground > background {
+        (foreground, background)
+    } else {
+        (background, foreground)
+    };
+    Some((lighter + 0.05) / (darker + 0.05))
+}
+
+/// APCA lightness contrast (Lc, APCA-W3 0.0.98G) of `text` on `background`.
+///
+/// Positive for dark text on a light background and negative for light text on
+/// a dark background; compare the absolute value against thresholds such as
+/// Lc 60 for body text and Lc 90 for preferred body text. Returns `None` if
+/// either color can't be parsed.
+pub fn apca_contrast(text: &str, background: &str) -> Option<f64> {
+    const BLACK_THRESHOLD: f64 = 0.022;
+    const BLACK_CLAMP: f64 = 1.414;
+    const DELTA_Y_MIN: f64 = 0.0005;
+    const SCALE: f64 = 1.14;
+    const LOW_CLIP: f64 = 0.1;
+    const LOW_OFFSET: f64 = 0.027;
+
+    let (text, background) = blend(text, background)?;
+    let soft_clamp = |y: f64| {
+        if y > BLACK_THRESHOLD {
+            y
+        } else {
+            y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+        }
+    };
+    let text = soft_clamp(apca_luminance(text));
+    let background = soft_clamp(apca_luminance(background));
+
+    if (background - text).abs() < DELTA_Y_MIN {
+        return Some(0.0);
+    }
+
+    let lc = if background > text {
+        let sapc = (background.powf(0.56) - text.powf(0.57)) * SCALE;
+        if sapc < LOW_CLIP {
+            0.0
+        } else {
+            sapc - LOW_OFFSET
+        }
+    } else {
+        let sapc = (background.powf(0.65) - text.powf(0.62)) * SCALE;
+        if sapc > -LOW_CLIP {
+            0.0
+        } else {
+            sapc + LOW_OFFSET
+        }
+    };
+    Some(lc * 100.0)
+}
+
+/// Contrast of one text step on one background step, returned by
+/// [`text_contrast`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextContrast {
+    /// Text step, `11` or `12`.
+    pub text_step: usize,
+    /// Background step, `1` to `3`.
+    pub background_step: usize,
+    /// WCAG 2 contrast ratio.
+    pub wcag: f64,
+    /// APCA lightness contrast (Lc).
+    pub apca: f64,
+}
+
+/// Measures the text steps (11 and 12) of `scale` on its app and subtle
+/// backgrounds (steps 1 to 3).
+pub fn text_contrast<S: AsRef<str>>(scale: &[S; 12]) -> Option<Vec<TextContrast>> {
+    let mut results = Vec::with_capacity(6);
+    for text_step in [11, 12] {
+        for background_step in 1..=3 {
+            let text = scale[text_step - 1].as_ref();
+            let background = scale[background_step - 1].as_ref();
+            results.push(TextContrast {
+                text_step,
+                background_step,
+                wcag: wcag_contrast(text, background)?,
+                apca: apca_contrast(text, background)?,
+            });
+        }
+    }
+    Some(results)
+}
+
+/// A parsed color with gamma-encoded components.
+type Parsed = (Gamut, [f64; 3]);
+
+/// Parses both colors, blending a translucent foreground over the background.
+fn blend(foreground: &str, background: &str) -> Option<(Parsed, Parsed)> {
+    let (background_gamut, background_rgb, _) = parse(background)?;
+    let (foreground_gamut, foreground_rgb, alpha) = parse(foreground)?;
+
+    let foreground = if alpha < 1.0 {
+        // Blend in the background's color space.
+        let foreground_rgb = if foreground_gamut == background_gamut {
+            foreground_rgb
+        } else {
+            oklab_to_rgb(
+                background_gamut,
+                rgb_to_oklab(foreground_gamut, foreground_rgb),
+            )
+        };
+        let rgb = [0, 1, 2].map(|i| foreground_rgb[i] * alpha + background_rgb[i] * (1.0 - alpha));
+        (background_gamut, rgb)
+    } else {
+        (foreground_gamut, foreground_rgb)
+    };
+
+    Some((foreground, (background_gamut, background_rgb)))
+}
+
+/// Relative luminance with the piecewise sRGB transfer function.
+fn luminance((gamut, rgb): Parsed) -> f64 {
+    let coefficients = match gamut {
+        Gamut::Srgb => [0.2126, 0.7152, 0.0722],
+        Gamut::P3 => P3_COEFFICIENTS,
+    };
+    let [r, g, b] = rgb.map(to_linear);
+    coefficients[0] * r + coefficients[1] * g + coefficients[2] * b
+}
+
+/// Screen luminance as estimated by APCA, with a simple 2.4 exponent.
+fn apca_luminance((gamut, rgb): Parsed) -> f64 {
+    let coefficients = match gamut {
+        Gamut::Srgb => [0.212_672_9, 0.715_152_2, 0.072_175_0],
+        Gamut::P3 => P3_COEFFICIENTS,
+    };
+    let [r, g, b] = rgb.map(|c| c.max(0.0).powf(2.4));
+    coefficients[0] * r + coefficients[1] * g + coefficients[2] * b
+}
+
+const P3_COEFFICIENTS: [f64; 3] = [0.228_974_6, 0.691_738_5, 0.079_286_9];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ScaleName;
+    use crate::generate::{GenerateOptions, generate_scale};
+
+    /// Minimums met by the text steps of every shipped scale.
+    fn assert_text_contrast<S: AsRef<str>>(name: &str, scale: &[S; 12]) {
+        for result in text_contrast(scale).unwrap() {
+            let (wcag, apca) = match result.text_step {
+                11 => (WCAG_AA_LARGE, 55.0),
+                _ => (WCAG_AAA, 80.0),
+            };
+            assert!(result.wcag >= wcag, "{name}: {result:?}");
+            assert!(result.apca.abs() >= apca, "{name}: {result:?}");
+        }
+    }
+
+    #[test]
+    fn wcag_contrast_matches_reference_values() {
+        assert_eq!(wcag_contrast("#000000", "#ffffff"), Some(21.0));
+        assert_eq!(wcag_contrast("#777", "#777"), Some(1.0));
+        let ratio = wcag_contrast("#767676", "#ffffff").unwrap();
+        assert!((ratio - 4.54).abs() < 0.01, "{ratio}");
+    }
+
+    #[test]
+    fn apca_contrast_matches_reference_values() {
+        let black_on_white = apca_contrast("#000000", "#ffffff").unwrap();
+        assert!((black_on_white - 106.04).abs() < 0.1, "{black_on_white}");
+        let white_on_black = apca_contrast("#ffffff", "#000000").unwrap();
+        assert!((white_on_black + 107.88).abs() < 0.1, "{white_on_black}");
+        let gray_on_white = apca_contrast("#888888", "#ffffff").unwrap();
+        assert!((gray_on_white - 63.06).abs() < 0.1, "{gray_on_white}");
+    }
+
+    #[test]
+    fn translucent_text_is_blended_over_background() {
+        assert_eq!(
+            wcag_contrast("#00000000", "#ffffff"),
+            wcag_contrast("#ffffff", "#ffffff")
+        );
+    }
+
+    #[test]
+    fn unparsable_colors_are_rejected() {
+        assert_eq!(wcag_contrast("blue", "#ffffff"), None);
+        assert_eq!(apca_contrast("#fff", "rgb(0 0 0)"), None);
+    }
+
+    #[test]
+    fn shipped_text_steps_are_readable_on_backgrounds() {
+        for name in ScaleName::ALL {
+            assert_text_contrast(name.as_str(), name.light().as_array());
+            assert_text_contrast(name.as_str(), name.dark().as_array());
+        }
+    }
+
+    #[test]
+    fn generated_text_steps_are_readable_on_backgrounds() {
+        for seed in ["#0090ff", "#ff5a1f", "#7b61ff", "#2f9e44", "#ffd60a"] {
+            let scale = generate_scale(seed, &GenerateOptions::default()).unwrap();
+            assert_text_contrast(seed, &scale.light);
+            assert_text_contrast(seed, &scale.dark);
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! CSS custom property generation.
+//!
+//! [`css`] turns the color scales into a stylesheet of custom properties, in
+//! the same shape as the `@radix-ui/colors` CSS files:
+//!
+//! - `--blue-1` … `--blue-12` and `--blue-a1` … `--blue-a12` for each scale,
+//!   under a light and a dark selector.
+//! - Display P3 overrides inside
+//!   `@supports (color: color(display-p3 1 1 1))`, so browsers without P3
+//!   support fall back to the sRGB values.
+//! - Semantic aliases such as `--accent-9` or `--gray-a3`, declared next to
+//!   the scales so they follow the light/dark switch.
+//!
+//! [`tailwind_colors_json`] exports the same names as a Tailwind `colors`
+//! config object whose values reference the custom properties.
+//!
+//! ```
+//! use cardo_ui_colors::{
+//!     ScaleName,
+//!     css::{CssOptions, ScaleAlias, css},
+//! };
+//!
+//! let blue = ScaleName::Blue;
+//! let options = CssOptions {
+//!     scales: vec![blue],
+//!     aliases: vec![ScaleAlias::new("accent", blue)],
+//!     ..Default::default()
+//! };
+//! let stylesheet = css(&options);
+//!
+//! assert!(stylesheet.contains("--blue-9: #0090ff;"));
+//! assert!(stylesheet.contains("--accent-9: var(--blue-9);"));
+//! ```
+
+use std::fmt::Write;
+
+use crate::*;
+
+const P3_SUPPORTS: &str = "@supports (color: color(display-p3 1 1 1))";
+const P3_MEDIA: &str = "@media (color-gamut: p3)";
+
+/// An alpha-only overlay scale that is the same in light and dark mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OverlayScale {
+    /// Name used in custom properties, e.g. `black` for `--black-a1`.
+    pub name: &'static str,
+    pub alpha: [&'static str; 12],
+    pub p3_alpha: [&'static str; 12],
+}
+
+/// The black and white overlay scales.
+pub static OVERLAY_SCALES: [OverlayScale; 2] = [
+    OverlayScale {
+        name: "black",
+        alpha: BLACK_A,
+        p3_alpha: BLACK_P3_A,
+    },
+    OverlayScale {
+        name: "white",
+        alpha: WHITE_A,
+        p3_alpha: WHITE_P3_A,
+    },
+];
+
+/// A semantic name for a scale, e.g. `accent` for `blue`, which emits
+/// `--accent-1: var(--blue-1);` and so on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScaleAlias {
+    pub name: String,
+    pub scale: ScaleName,
+}
+
+impl ScaleAlias {
+    pub fn new(name: impl Into<String>, scale: ScaleName) -> Self {
+        Self {
+            name: name.into(),
+            scale,
+        }
+    }
+}
+
+/// Options for [`css`] and [`tailwind_colors_json`].
+#[derive(Clone, Debug)]
+pub struct CssOptions {
+    /// Scales to emit. Defaults to [`ScaleName::ALL`].
+    pub scales: Vec<ScaleName>,
+    /// Semantic aliases. An alias named like its own scale is skipped, since
+    /// it would reference itself.
+    pub aliases: Vec<ScaleAlias>,
+    /// Whether to emit the black and white overlay scales. Defaults to `true`.
+    pub overlays: bool,
+    /// Whether to emit the alpha steps (`--blue-a1` …). Defaults to `true`.
+    pub alpha: bool,
+    /// Whether to emit Display P3 overrides. Defaults to `true`.
+    pub p3: bool,
+    /// Selector for light values. Defaults to `:root, .light, .light-theme`.
+    pub light_selector: String,
+    /// Selector for dark values. Defaults to `.dark, .dark-theme`.
+    pub dark_selector: String,
+}
+
+impl Default for CssOptions {
+    fn default() -> Self {
+        Self {
+            scales: ScaleName::ALL.to_vec(),
+            aliases: Vec::new(),
+            overlays: true,
+            alpha: true,
+            p3: true,
+            light_selector: ":root, .light, .light-theme".into(),
+            dark_selector: ".dark, .dark-theme".into(),
+        }
+    }
+}
+
+impl CssOptions {
+    fn aliases(&self) -> impl Iterator<Item = &ScaleAlias> {
+        self.aliases
+            .iter()
+            .filter(|alias| alias.name != alias.scale.as_str())
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Mode {
+    Light,
+    Dark,
+}
+
+/// Generates a stylesheet of color custom properties.
+pub fn css(options: &CssOptions) -> String {
+    let mut output = String::new();
+
+    for mode in [Mode::Light, Mode::Dark] {
+        let mut block = String::new();
+        for scale in &options.scales {
+            push_scale(&mut block, options, *scale, mode, false);
+        }
+        for alias in options.aliases() {
+            push_alias(&mut block, options, alias);
+        }
+        push_rule(&mut output, selector(options, mode), &block, 0);
+    }
+
+    if options.overlays {
+        let mut block = String::new();
+        for overlay in &OVERLAY_SCALES {
+            push_steps(&mut block, overlay.name, "a", &overlay.alpha);
+        }
+        push_rule(&mut output, ":root", &block, 0);
+    }
+
+    if options.p3 {
+        let mut p3 = String::new();
+        for mode in [Mode::Light, Mode::Dark] {
+            let mut block = String::new();
+            for scale in &options.scales {
+                push_scale(&mut block, options, *scale, mode, true);
+            }
+            push_rule(&mut p3, selector(options, mode), &block, 4);
+        }
+        if options.overlays {
+            let mut block = String::new();
+            for overlay in &OVERLAY_SCALES {
+                push_steps(&mut block, overlay.name, "a", &overlay.p3_alpha);
+            }
+            push_rule(&mut p3, ":root", &block, 4);
+        }
+
+        if !p3.is_empty() {
+            let _ = write!(output, "{P3_SUPPORTS} {{\n  {P3_MEDIA} {{\n{p3}  }}\n}}\n");
+        }
+    }
+
+    output
+}
+
+/// Generates a Tailwind `colors` config object, as JSON, mapping every scale,
+/// alias and overlay to its custom property, e.g. `blue.9` to `var(--blue-9)`.
+pub fn tailwind_colors_json(options: &CssOptions) -> String {
+    let prefixes: &[&str] = if options.alpha { &["", "a"] } else { &[""] };
+
+    let mut colors = Vec::new();
+    for scale in &options.scales {
+        colors.push(tailwind_steps(scale.as_str(), prefixes));
+    }
+    for alias in options.aliases() {
+        colors.push(tailwind_steps(&alias.name, prefixes));
+    }
+    if options.overlays {
+        for overlay in &OVERLAY_SCALES {
+            colors.push(tailwind_steps(overlay.name, &["a"]));
+        }
+    }
+
+    if colors.is_empty() {
+        return "{}\n".into();
+    }
+    format!("{{\n{}\n}}\n", colors.join(",\n"))
+}
+
+/// Writes the `"name": { ... }` entry mapping `{prefix}{step}` to
+/// `var(--{name}-{prefix}{step})` for every prefix, indented as a member of the
+/// top-level object.
+fn tailwind_steps(name: &str, prefixes: &[&str]) -> String {
+    let steps = prefixes
+        .iter()
+        .flat_map(|prefix| {
+            (1..=12).map(move |step| {
+                format!(
+                    "    {}: {}",
+                    json_string(&format!("{prefix}{step}")),
+                    json_string(&format!("var(--{name}-{prefix}{step})"))
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    format!("  {}: {{\n{}\n  }}", json_string(name), steps.join(",\n"))
+}
+
+/// Quotes `value` as a JSON string. Scale names are plain identifiers, but
+/// alias names come from the caller.
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            character if character.is_control() => {
+                let _ = write!(output, "\\u{:04x}", character as u32);
+            }
+            character => output.push(character),
+        }
+    }
+    output.push('"');
+    output
+}
+
+fn selector(options: &CssOptions, mode: Mode) -> &str {
+    match mode {
+        Mode::Light => &options.light_selector,
+        Mode::Dark => &options.dark_selector,
+    }
+}
+
+fn push_scale(block: &mut String, options: &CssOptions, scale: ScaleName, mode: Mode, p3: bool) {
+    let (solid, alpha) = match (mode, p3) {
+        (Mode::Light, false) => (scale.light(), scale.light_alpha()),
+        (Mode::Light, true) => (scale.light_p3(), scale.light_p3_alpha()),
+        (Mode::Dark, false) => (scale.dark(), scale.dark_alpha()),
+        (Mode::Dark, true) => (scale.dark_p3(), scale.dark_p3_alpha()),
+    };
+
+    push_steps(block, scale.as_str(), "", solid.as_array());
+    if options.alpha {
+        push_steps(block, scale.as_str(), "a", alpha.as_array());
+    }
+}
+
+fn push_alias(block: &mut String, options: &CssOptions, alias: &ScaleAlias) {
+    let prefixes: &[&str] = if options.alpha { &["", "a"] } else { &[""] };
+    for prefix in prefixes {
+        for step in 1..=12 {
+            let _ = writeln!(
+                block,
+                "--{}-{prefix}{step}: var(--{}-{prefix}{step});",
+                alias.name, alias.scale
+            );
+        }
+    }
+}
+
+fn push_steps(block: &mut String, name: &str, prefix: &str, values: &[&str; 12]) {
+    for (index, value) in values.iter().enumerate() {
+        let _ = writeln!(block, "--{name}-{prefix}{}: {value};", index + 1);
+    }
+}
+
+/// Appends `selector { block }`, indenting the rule by `indent` spaces.
+fn push_rule(output: &mut String, selector: &str, block: &str, indent: usize) {
+    if block.is_empty() {
+        return;
+    }
+
+    let pad = " ".repeat(indent);
+    let _ = writeln!(output, "{pad}{selector} {{");
+    for line in block.lines() {
+        let _ = writeln!(output, "{pad}  {line}");
+    }
+    let _ = writeln!(output, "{pad}}}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_options_emit_every_scale() {
+        let output = css(&CssOptions::default());
+
+        for name in ScaleName::ALL {
+            assert!(output.contains(&format!("--{name}-12: {};", name.dark().step(12))));
+        }
+    }
+
+    #[test]
+    fn css_emits_light_dark_and_p3_values() {
+        let blue = ScaleName::Blue;
+        let output = css(&CssOptions {
+            scales: vec![blue],
+            overlays: false,
+            ..Default::default()
+        });
+
+        let light = output.find(":root, .light, .light-theme {").unwrap();
+        let dark = output.find(".dark, .dark-theme {").unwrap();
+        let p3 = output.find(P3_SUPPORTS).unwrap();
+        assert!(light < dark && dark < p3);
+
+        assert!(output.contains(&format!("--blue-1: {};", BLUE[0])));
+        assert!(output.contains(&format!("--blue-a12: {};", BLUE_DARK_A[11])));
+        assert!(output[p3..].contains(&format!("--blue-9: {};", BLUE_P3[8])));
+        assert!(output[p3..].contains(&format!("--blue-9: {};", BLUE_DARK_P3[8])));
+    }
+
+    #[test]
+    fn css_respects_disabled_variants() {
+        let output = css(&CssOptions {
+            scales: vec![ScaleName::Red],
+            alpha: false,
+            p3: false,
+            overlays: false,
+            ..Default::default()
+        });
+
+        assert!(output.contains("--red-12:"));
+        assert!(!output.contains("--red-a1:"));
+        assert!(!output.contains("display-p3"));
+    }
+
+    #[test]
+    fn aliases_follow_both_modes_and_skip_self_references() {
+        let slate = ScaleName::Slate;
+        let output = css(&CssOptions {
+            scales: vec![slate],
+            aliases: vec![
+                ScaleAlias::new("gray", slate),
+                ScaleAlias::new("slate", slate),
+            ],
+            p3: false,
+            overlays: false,
+            ..Default::default()
+        });
+
+        assert_eq!(output.matches("--gray-a3: var(--slate-a3);").count(), 2);
+        assert!(!output.contains("--slate-1: var(--slate-1);"));
+    }
+
+    #[test]
+    fn tailwind_json_escapes_alias_names() {
+        let output = tailwind_colors_json(&CssOptions {
+            scales: vec![],
+            aliases: vec![ScaleAlias::new("a\"b", ScaleName::Blue)],
+            alpha: false,
+            overlays: false,
+            ..Default::default()
+        });
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(value["a\"b"]["1"], "var(--a\"b-1)");
+    }
+
+    #[test]
+    fn tailwind_json_references_custom_properties() {
+        let blue = ScaleName::Blue;
+        let output = tailwind_colors_json(&CssOptions {
+            scales: vec![blue],
+            aliases: vec![ScaleAlias::new("accent", blue)],
+            ..Default::default()
+        });
+
+        assert!(output.contains("\"blue\": {"));
+        assert!(output.contains("\"9\": \"var(--blue-9)\","));
+        assert!(output.contains("\"a3\": \"var(--accent-a3)\","));
+        assert!(output.contains("\"a12\": \"var(--white-a12)\"\n  }\n}\n"));
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&value).unwrap() + "\n", output);
+        let keys: Vec<&String> = value["blue"].as_object().unwrap().keys().collect();
+        assert_eq!(keys.len(), 24);
+        assert_eq!(
+            (keys[0], keys[11], keys[12]),
+            (&"1".into(), &"12".into(), &"a1".into())
+        );
+    }
+}
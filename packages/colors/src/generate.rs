@@ -0,0 +1,496 @@
+//! Custom scale generation from a seed color.
+//!
+//! Port of the Radix Colors custom palette algorithm: the seed is located
+//! between the two nearest shipped scales, which are blended and re-tinted
+//! with the seed's hue and chroma. Lightness is then eased towards the page
+//! background, step 9 becomes the seed itself, step 10 its hover variant, and
+//! the alpha steps are the most transparent colors that blend to the solid
+//! steps over the background.
+
+use crate::space::{
+    Gamut, delta_e_ok, format_hex, format_p3, oklab_to_oklch, oklch_to_oklab,
+    oklch_to_rgb_in_gamut, parse, rgb_to_oklab,
+};
+use crate::*;
+
+const LIGHT_MODE_EASING: [f64; 4] = [0.0, 2.0, 0.0, 2.0];
+const DARK_MODE_EASING: [f64; 4] = [1.0, 0.0, 1.0, 0.0];
+
+/// Options for [`generate_scale`].
+#[derive(Clone, Debug)]
+pub struct GenerateOptions {
+    /// Page background in light mode. Defaults to `#ffffff`.
+    pub light_background: String,
+    /// Page background in dark mode. Defaults to `#111111`.
+    pub dark_background: String,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            light_background: "#ffffff".into(),
+            dark_background: "#111111".into(),
+        }
+    }
+}
+
+/// A generated scale in the same shape as the shipped constants.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedScale {
+    pub light: [String; 12],
+    pub light_alpha: [String; 12],
+    pub light_p3: [String; 12],
+    pub light_p3_alpha: [String; 12],
+    pub dark: [String; 12],
+    pub dark_alpha: [String; 12],
+    pub dark_p3: [String; 12],
+    pub dark_p3_alpha: [String; 12],
+    /// Text color for step 9 backgrounds in light mode.
+    pub light_contrast: String,
+    /// Text color for step 9 backgrounds in dark mode.
+    pub dark_contrast: String,
+}
+
+/// Generates a 12-step light and dark scale, with alpha and Display P3
+/// variants, from a seed color given as hex or `color(display-p3 …)`.
+///
+/// Returns `None` if a color can't be parsed.
+pub fn generate_scale(seed: &str, options: &GenerateOptions) -> Option<GeneratedScale> {
+    let (seed_gamut, seed_rgb, _) = parse(seed)?;
+    let seed = oklab_to_oklch(rgb_to_oklab(seed_gamut, seed_rgb));
+
+    let light = generate_mode(seed, &options.light_background, Mode::Light)?;
+    let dark = generate_mode(seed, &options.dark_background, Mode::Dark)?;
+
+    Some(GeneratedScale {
+        light: light.solid,
+        light_alpha: light.alpha,
+        light_p3: light.solid_p3,
+        light_p3_alpha: light.alpha_p3,
+        dark: dark.solid,
+        dark_alpha: dark.alpha,
+        dark_p3: dark.solid_p3,
+        dark_p3_alpha: dark.alpha_p3,
+        light_contrast: light.contrast,
+        dark_contrast: dark.contrast,
+    })
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Mode {
+    Light,
+    Dark,
+}
+
+struct ModeScale {
+    solid: [String; 12],
+    alpha: [String; 12],
+    solid_p3: [String; 12],
+    alpha_p3: [String; 12],
+    contrast: String,
+}
+
+fn generate_mode(seed: [f64; 3], background: &str, mode: Mode) -> Option<ModeScale> {
+    let (background_gamut, background_rgb, _) = parse(background)?;
+    let background = oklab_to_oklch(rgb_to_oklab(background_gamut, background_rgb));
+
+    let mut scale = scale_from_color(seed, background, mode);
+
+    let (step_9, contrast) = step_9_colors(&scale, seed);
+    scale[8] = step_9;
+    scale[9] = button_hover_color(step_9, &scale, mode);
+
+    // Limit the chroma of the text steps.
+    let text_chroma = scale[8][1].max(scale[7][1]);
+    scale[10][1] = scale[10][1].min(text_chroma);
+    scale[11][1] = scale[11][1].min(text_chroma);
+
+    let background_srgb = oklch_to_rgb_in_gamut(Gamut::Srgb, background);
+    let background_p3 = oklch_to_rgb_in_gamut(Gamut::P3, background);
+
+    let srgb = scale.map(|color| oklch_to_rgb_in_gamut(Gamut::Srgb, color));
+    let p3 = scale.map(|color| oklch_to_rgb_in_gamut(Gamut::P3, color));
+
+    Some(ModeScale {
+        solid: srgb.map(|rgb| format_hex(quantize(rgb, 255.0), 1.0)),
+        alpha: srgb.map(|rgb| {
+            let (rgb, alpha) = alpha_color(quantize(rgb, 255.0), background_srgb, 255.0);
+            format_hex(rgb, alpha)
+        }),
+        solid_p3: p3.map(|rgb| format_p3(rgb, 1.0)),
+        alpha_p3: p3.map(|rgb| {
+            let (rgb, alpha) = alpha_color(quantize(rgb, 1000.0), background_p3, 1000.0);
+            format_p3(rgb, alpha)
+        }),
+        contrast: match contrast {
+            Some(contrast) => format_hex(oklch_to_rgb_in_gamut(Gamut::Srgb, contrast), 1.0),
+            None => "#ffffff".into(),
+        },
+    })
+}
+
+/// Blends the two shipped scales nearest to `source` and re-tints the result
+/// with its hue and chroma, easing lightness towards `background`.
+fn scale_from_color(source: [f64; 3], background: [f64; 3], mode: Mode) -> [[f64; 3]; 12] {
+    let source_lab = oklch_to_oklab(source);
+
+    let scales: Vec<(ScaleName, [[f64; 3]; 12])> = ScaleName::ALL
+        .into_iter()
+        .map(|name| {
+            let scale = match mode {
+                Mode::Light => name.light(),
+                Mode::Dark => name.dark(),
+            };
+            let steps = scale.as_array().map(|step| {
+                let (gamut, rgb, _) = parse(step).expect("Shipped color should parse.");
+                rgb_to_oklab(gamut, rgb)
+            });
+            (name, steps)
+        })
+        .collect();
+
+    // The closest step of each scale, nearest scale first.
+    let mut closest: Vec<(ScaleName, f64, [f64; 3])> = scales
+        .iter()
+        .map(|(name, steps)| {
+            steps
+                .iter()
+                .map(|step| (*name, delta_e_ok(source_lab, *step), *step))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .expect("Scale should have steps.")
+        })
+        .collect();
+    closest.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    // Don't blend a gray with another gray when the seed is chromatic.
+    let all_grays = closest.iter().all(|(name, ..)| name.is_gray());
+    if !all_grays && closest[0].0.is_gray() {
+        while closest[1].0.is_gray() {
+            closest.remove(1);
+        }
+    }
+
+    let (name_a, distance_a, color_a) = closest[0];
+    let (name_b, distance_b, color_b) = closest[1];
+
+    // Blend ratio from the triangle formed by the seed and both nearest steps.
+    let (a, b, c) = (distance_b, distance_a, delta_e_ok(color_a, color_b));
+    let ratio = if b < f64::EPSILON || c < f64::EPSILON {
+        0.0
+    } else {
+        let angle_a = ((b * b + c * c - a * a) / (2.0 * b * c))
+            .clamp(-1.0, 1.0)
+            .acos();
+        let angle_b = ((a * a + c * c - b * b) / (2.0 * a * c))
+            .clamp(-1.0, 1.0)
+            .acos();
+        let tan_c1 = angle_a.cos() / angle_a.sin();
+        let tan_c2 = angle_b.cos() / angle_b.sin();
+        let ratio = (tan_c1 / tan_c2).max(0.0) * 0.5;
+        if ratio.is_finite() { ratio } else { 0.0 }
+    };
+
+    let steps_of = |name: ScaleName| {
+        scales
+            .iter()
+            .find(|(scale, _)| *scale == name)
+            .map(|(_, steps)| *steps)
+            .expect("Scale should exist.")
+    };
+    let (steps_a, steps_b) = (steps_of(name_a), steps_of(name_b));
+    let mut scale: [[f64; 3]; 12] = std::array::from_fn(|i| {
+        let mixed = [0, 1, 2].map(|j| steps_a[i][j] * (1.0 - ratio) + steps_b[i][j] * ratio);
+        oklab_to_oklch(mixed)
+    });
+
+    // Match the chroma of the nearest step to the seed, and use the seed's hue.
+    let base = scale
+        .iter()
+        .min_by(|x, y| {
+            delta_e_ok(source_lab, oklch_to_oklab(**x))
+                .total_cmp(&delta_e_ok(source_lab, oklch_to_oklab(**y)))
+        })
+        .copied()
+        .expect("Scale should have steps.");
+    let ratio_c = if base[1] > f64::EPSILON {
+        source[1] / base[1]
+    } else {
+        0.0
+    };
+    for color in &mut scale {
+        color[1] = (source[1] * 1.5).min(color[1] * ratio_c);
+        color[2] = source[2];
+    }
+
+    let background_l = background[0].clamp(0.0, 1.0);
+    match mode {
+        Mode::Light => {
+            let mut lightness = vec![1.0];
+            lightness.extend(scale.iter().map(|color| color[0]));
+            let lightness =
+                transpose_progression_start(background_l, &lightness, LIGHT_MODE_EASING);
+            for (color, l) in scale.iter_mut().zip(lightness.iter().skip(1)) {
+                color[0] = *l;
+            }
+        }
+        Mode::Dark => {
+            let mut easing = DARK_MODE_EASING;
+            let reference_l = scale[0][0];
+            let ratio_l = background_l / reference_l;
+
+            if ratio_l > 1.0 {
+                const MAX_RATIO: f64 = 1.5;
+                for value in &mut easing {
+                    let meta_ratio = (ratio_l - 1.0) * (MAX_RATIO / (MAX_RATIO - 1.0));
+                    *value = if ratio_l > MAX_RATIO {
+                        0.0
+                    } else {
+                        (*value * (1.0 - meta_ratio)).max(0.0)
+                    };
+                }
+            }
+
+            let lightness: Vec<f64> = scale.iter().map(|color| color[0]).collect();
+            let lightness = transpose_progression_start(background_l, &lightness, easing);
+            for (color, l) in scale.iter_mut().zip(lightness) {
+                color[0] = l;
+            }
+        }
+    }
+
+    scale
+}
+
+/// Shifts the start of `values` to `to`, fading the shift out along the curve.
+fn transpose_progression_start(to: f64, values: &[f64], curve: [f64; 4]) -> Vec<f64> {
+    let last_index = (values.len() - 1) as f64;
+    let diff = values[0] - to;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| value - diff * cubic_bezier(curve, 1.0 - i as f64 / last_index))
+        .collect()
+}
+
+/// Evaluates the CSS `cubic-bezier(x1, y1, x2, y2)` timing function at `x`.
+fn cubic_bezier([x1, y1, x2, y2]: [f64; 4], x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let bezier = |t: f64, p1: f64, p2: f64| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+    };
+
+    // Bisection is plenty fast for 12 steps and handles any control points.
+    let (mut low, mut high) = (0.0, 1.0);
+    let mut t = x;
+    for _ in 0..64 {
+        t = (low + high) / 2.0;
+        if bezier(t, x1, x2) < x {
+            low = t;
+        } else {
+            high = t;
+        }
+    }
+    bezier(t, y1, y2)
+}
+
+/// Uses the seed as step 9 unless it's too close to the page background, and
+/// picks a readable text color for it. `None` means white.
+fn step_9_colors(scale: &[[f64; 3]; 12], seed: [f64; 3]) -> ([f64; 3], Option<[f64; 3]>) {
+    let distance = delta_e_ok(oklch_to_oklab(seed), oklch_to_oklab(scale[0])) * 100.0;
+    let step_9 = if distance < 25.0 { scale[8] } else { seed };
+    (step_9, text_color(step_9))
+}
+
+fn text_color(background: [f64; 3]) -> Option<[f64; 3]> {
+    let background_hex = format_hex(oklch_to_rgb_in_gamut(Gamut::Srgb, background), 1.0);
+    let white_contrast =
+        crate::contrast::apca_contrast("#ffffff", &background_hex).unwrap_or_default();
+
+    if white_contrast.abs() < 40.0 {
+        let [_, c, h] = background;
+        Some([0.25, (0.08 * c).max(0.04), h])
+    } else {
+        None
+    }
+}
+
+/// Darkens step 9 for hover, except in dark mode or on dark seeds where
+/// hover lightens.
+fn button_hover_color(source: [f64; 3], scale: &[[f64; 3]; 12], mode: Mode) -> [f64; 3] {
+    let [l, c, h] = source;
+    let darken = mode == Mode::Light && l > 0.4;
+    let new_l = if darken {
+        l - 0.03 / (l + 0.1)
+    } else {
+        l + 0.03 / (l + 0.1)
+    };
+    let new_c = if darken { c * 0.93 } else { c };
+    let hover = [new_l, new_c, h];
+
+    // Take chroma and hue from the closest step of the scale.
+    let closest = scale
+        .iter()
+        .min_by(|a, b| {
+            delta_e_ok(oklch_to_oklab(hover), oklch_to_oklab(**a))
+                .total_cmp(&delta_e_ok(oklch_to_oklab(hover), oklch_to_oklab(**b)))
+        })
+        .copied()
+        .unwrap_or(hover);
+
+    [new_l, closest[1], closest[2]]
+}
+
+/// Rounds gamma-encoded components to `precision` steps, like the output format.
+fn quantize(rgb: [f64; 3], precision: f64) -> [f64; 3] {
+    rgb.map(|c| (c * precision).round() / precision)
+}
+
+/// The most transparent color that blends to `target` over `background`.
+fn alpha_color(target: [f64; 3], background: [f64; 3], precision: f64) -> ([f64; 3], f64) {
+    let target = target.map(|c| c * precision);
+    let background = background.map(|c| (c * precision).round());
+
+    // Blend towards white if any channel is lighter than the background.
+    let desired = if (0..3).any(|i| target[i] > background[i]) {
+        precision
+    } else {
+        0.0
+    };
+
+    let alphas = [0, 1, 2].map(|i| {
+        let alpha = (target[i] - background[i]) / (desired - background[i]);
+        if alpha.is_finite() { alpha } else { 0.0 }
+    });
+
+    if alphas.iter().all(|alpha| *alpha == alphas[0]) {
+        let value = desired / precision;
+        return ([value; 3], (alphas[0] * precision).round() / precision);
+    }
+
+    let max_alpha = alphas.iter().copied().fold(0.0, f64::max);
+    let alpha = (max_alpha * precision).ceil().clamp(0.0, precision) / precision;
+    if alpha <= 0.0 {
+        return ([desired / precision; 3], 0.0);
+    }
+
+    let rgb = [0, 1, 2].map(|i| {
+        let channel = -((background[i] * (1.0 - alpha) - target[i]) / alpha);
+        channel.ceil().clamp(0.0, precision) / precision
+    });
+    (rgb, alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::space::oklab_to_rgb;
+
+    fn to_oklch(value: &str) -> [f64; 3] {
+        let oklch = Color::parse(value).unwrap().to_oklch();
+        [oklch.l, oklch.c, oklch.h]
+    }
+
+    fn distance(a: &str, b: &str) -> f64 {
+        delta_e_ok(oklch_to_oklab(to_oklch(a)), oklch_to_oklab(to_oklch(b)))
+    }
+
+    #[test]
+    fn seed_becomes_step_9() {
+        let scale = generate_scale("#0090ff", &GenerateOptions::default()).unwrap();
+
+        assert_eq!(scale.light[8], "#0090ff");
+        assert_eq!(scale.dark[8], "#0090ff");
+        assert_eq!(scale.light_contrast, "#ffffff");
+    }
+
+    #[test]
+    fn shipped_step_9_seeds_reproduce_shipped_scales() {
+        for (name, light, dark) in [
+            ("blue", BLUE, BLUE_DARK),
+            ("crimson", CRIMSON, CRIMSON_DARK),
+            ("grass", GRASS, GRASS_DARK),
+        ] {
+            let generated = generate_scale(light[8], &GenerateOptions::default()).unwrap();
+            for (step, (generated, shipped)) in generated.light.iter().zip(light).enumerate() {
+                let distance = distance(generated, shipped);
+                assert!(
+                    distance < 0.03,
+                    "{name} light step {}: {distance}",
+                    step + 1
+                );
+            }
+
+            let generated = generate_scale(dark[8], &GenerateOptions::default()).unwrap();
+            for (step, (generated, shipped)) in generated.dark.iter().zip(dark).enumerate() {
+                let distance = distance(generated, shipped);
+                assert!(distance < 0.03, "{name} dark step {}: {distance}", step + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn bright_seed_gets_dark_contrast_text() {
+        let scale = generate_scale(YELLOW[8], &GenerateOptions::default()).unwrap();
+
+        assert_ne!(scale.light_contrast, "#ffffff");
+    }
+
+    #[test]
+    fn alpha_steps_blend_to_solid_steps() {
+        let scale = generate_scale("#8e4ec6", &GenerateOptions::default()).unwrap();
+
+        for step in 0..12 {
+            let (_, alpha_rgb, alpha) = parse(&scale.light_alpha[step]).unwrap();
+            let blended = alpha_rgb.map(|c| c * alpha + (1.0 - alpha));
+            let solid = parse(&scale.light[step]).unwrap().1;
+            for channel in 0..3 {
+                assert!((blended[channel] - solid[channel]).abs() < 2.0 / 255.0);
+            }
+        }
+    }
+
+    #[test]
+    fn p3_values_match_srgb_values() {
+        let scale = generate_scale("#e54666", &GenerateOptions::default()).unwrap();
+
+        for step in 0..12 {
+            assert!(distance(&scale.light[step], &scale.light_p3[step]) < 0.01);
+            assert!(scale.light_p3[step].starts_with("color(display-p3 "));
+        }
+    }
+
+    #[test]
+    fn custom_backgrounds_shift_app_background() {
+        let options = GenerateOptions {
+            light_background: "#f8f4ec".into(),
+            ..Default::default()
+        };
+        let scale = generate_scale("#0090ff", &options).unwrap();
+        let default = generate_scale("#0090ff", &GenerateOptions::default()).unwrap();
+
+        assert!(to_oklch(&scale.light[0])[0] < to_oklch(&default.light[0])[0]);
+    }
+
+    #[test]
+    fn invalid_seed_is_rejected() {
+        assert_eq!(generate_scale("blue", &GenerateOptions::default()), None);
+    }
+
+    #[test]
+    fn oklab_round_trips_through_rgb() {
+        for gamut in [Gamut::Srgb, Gamut::P3] {
+            let rgb = [0.2, 0.5, 0.8];
+            let back = oklab_to_rgb(gamut, rgb_to_oklab(gamut, rgb));
+            for channel in 0..3 {
+                assert!((back[channel] - rgb[channel]).abs() < 1e-6);
+            }
+        }
+    }
+}
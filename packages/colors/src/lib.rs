@@ -3,13 +3,31 @@
 //! A gorgeous, accessible color system.
 //!
 //! See [`@radix-ui/colors`](https://www.npmjs.com/package/@radix-ui/colors) for the original package.
+//!
+//! The scales are exported as `[&str; 12]` constants (`BLUE`, `BLUE_DARK_A`,
+//! ...). For typed access, [`ScaleName`] enumerates the scales and returns
+//! [`Scale`]s with one-based [`Scale::step`] accessors, and [`Color`] parses
+//! the values and converts between sRGB, Display P3, OKLCH, HSL and HSV.
+//!
+//! Besides the shipped scales, [`generate`] builds custom scales from a seed
+//! color and [`contrast`] measures WCAG 2 and APCA contrast. [`css`] turns the
+//! scales into CSS custom properties and a Tailwind colors config.
 
 mod black_a;
+mod color;
 mod dark;
 mod light;
+mod scale;
+mod space;
 mod white_a;
 
+pub mod contrast;
+pub mod css;
+pub mod generate;
+
 pub use black_a::*;
+pub use color::*;
 pub use dark::*;
 pub use light::*;
+pub use scale::*;
 pub use white_a::*;
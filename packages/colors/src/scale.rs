@@ -0,0 +1,582 @@
+//! Typed access to the color scales.
+
+use std::{fmt, str::FromStr};
+
+use crate::*;
+
+/// A 12-step color scale, indexed by step number from 1 to 12.
+///
+/// ```
+/// use cardo_ui_colors::{BLUE, Scale, ScaleName};
+///
+/// assert_eq!(ScaleName::Blue.light().step(9), BLUE[8]);
+/// assert_eq!(Scale::new(BLUE).step(1), "#fbfdff");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Scale {
+    steps: [&'static str; 12],
+}
+
+impl Scale {
+    pub const fn new(steps: [&'static str; 12]) -> Self {
+        Self { steps }
+    }
+
+    /// Returns step `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not between 1 and 12.
+    pub const fn step(&self, n: usize) -> &'static str {
+        assert!(n >= 1 && n <= 12, "Step should be between 1 and 12.");
+        self.steps[n - 1]
+    }
+
+    /// Returns step `n`, or `None` if `n` is not between 1 and 12.
+    pub fn get(&self, n: usize) -> Option<&'static str> {
+        n.checked_sub(1)
+            .and_then(|index| self.steps.get(index).copied())
+    }
+
+    /// Returns step `n` parsed as a [`Color`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not between 1 and 12.
+    pub fn color(&self, n: usize) -> Color {
+        self.step(n).parse().expect("Shipped color should parse.")
+    }
+
+    /// Iterates over `(step, value)` pairs, from step 1 to 12.
+    pub fn steps(&self) -> impl Iterator<Item = (usize, &'static str)> + '_ {
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (index + 1, *value))
+    }
+
+    /// The raw values, with step 1 at index 0.
+    pub const fn as_array(&self) -> &[&'static str; 12] {
+        &self.steps
+    }
+}
+
+impl From<[&'static str; 12]> for Scale {
+    fn from(steps: [&'static str; 12]) -> Self {
+        Self::new(steps)
+    }
+}
+
+/// The name of a shipped color scale.
+///
+/// ```
+/// use cardo_ui_colors::ScaleName;
+///
+/// let accent: ScaleName = "indigo".parse().unwrap();
+/// let text = accent.dark().step(11);
+///
+/// assert!(ScaleName::ALL.iter().any(|name| name.is_gray()));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ScaleName {
+    Gray,
+    Mauve,
+    Slate,
+    Sage,
+    Olive,
+    Sand,
+    Tomato,
+    Red,
+    Ruby,
+    Crimson,
+    Pink,
+    Plum,
+    Purple,
+    Violet,
+    Iris,
+    Indigo,
+    Blue,
+    Cyan,
+    Teal,
+    Jade,
+    Green,
+    Grass,
+    Brown,
+    Bronze,
+    Gold,
+    Sky,
+    Mint,
+    Lime,
+    Yellow,
+    Amber,
+    Orange,
+}
+
+impl ScaleName {
+    /// All scale names, grays first, in the order of the Radix Colors palette.
+    pub const ALL: [ScaleName; 31] = [
+        ScaleName::Gray,
+        ScaleName::Mauve,
+        ScaleName::Slate,
+        ScaleName::Sage,
+        ScaleName::Olive,
+        ScaleName::Sand,
+        ScaleName::Tomato,
+        ScaleName::Red,
+        ScaleName::Ruby,
+        ScaleName::Crimson,
+        ScaleName::Pink,
+        ScaleName::Plum,
+        ScaleName::Purple,
+        ScaleName::Violet,
+        ScaleName::Iris,
+        ScaleName::Indigo,
+        ScaleName::Blue,
+        ScaleName::Cyan,
+        ScaleName::Teal,
+        ScaleName::Jade,
+        ScaleName::Green,
+        ScaleName::Grass,
+        ScaleName::Brown,
+        ScaleName::Bronze,
+        ScaleName::Gold,
+        ScaleName::Sky,
+        ScaleName::Mint,
+        ScaleName::Lime,
+        ScaleName::Yellow,
+        ScaleName::Amber,
+        ScaleName::Orange,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ScaleName::Gray => "gray",
+            ScaleName::Mauve => "mauve",
+            ScaleName::Slate => "slate",
+            ScaleName::Sage => "sage",
+            ScaleName::Olive => "olive",
+            ScaleName::Sand => "sand",
+            ScaleName::Tomato => "tomato",
+            ScaleName::Red => "red",
+            ScaleName::Ruby => "ruby",
+            ScaleName::Crimson => "crimson",
+            ScaleName::Pink => "pink",
+            ScaleName::Plum => "plum",
+            ScaleName::Purple => "purple",
+            ScaleName::Violet => "violet",
+            ScaleName::Iris => "iris",
+            ScaleName::Indigo => "indigo",
+            ScaleName::Blue => "blue",
+            ScaleName::Cyan => "cyan",
+            ScaleName::Teal => "teal",
+            ScaleName::Jade => "jade",
+            ScaleName::Green => "green",
+            ScaleName::Grass => "grass",
+            ScaleName::Brown => "brown",
+            ScaleName::Bronze => "bronze",
+            ScaleName::Gold => "gold",
+            ScaleName::Sky => "sky",
+            ScaleName::Mint => "mint",
+            ScaleName::Lime => "lime",
+            ScaleName::Yellow => "yellow",
+            ScaleName::Amber => "amber",
+            ScaleName::Orange => "orange",
+        }
+    }
+
+    /// Whether this is one of the gray scales.
+    pub const fn is_gray(self) -> bool {
+        matches!(
+            self,
+            ScaleName::Gray
+                | ScaleName::Mauve
+                | ScaleName::Slate
+                | ScaleName::Sage
+                | ScaleName::Olive
+                | ScaleName::Sand
+        )
+    }
+
+    /// Solid steps in light mode.
+    pub const fn light(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY,
+            ScaleName::Mauve => MAUVE,
+            ScaleName::Slate => SLATE,
+            ScaleName::Sage => SAGE,
+            ScaleName::Olive => OLIVE,
+            ScaleName::Sand => SAND,
+            ScaleName::Tomato => TOMATO,
+            ScaleName::Red => RED,
+            ScaleName::Ruby => RUBY,
+            ScaleName::Crimson => CRIMSON,
+            ScaleName::Pink => PINK,
+            ScaleName::Plum => PLUM,
+            ScaleName::Purple => PURPLE,
+            ScaleName::Violet => VIOLET,
+            ScaleName::Iris => IRIS,
+            ScaleName::Indigo => INDIGO,
+            ScaleName::Blue => BLUE,
+            ScaleName::Cyan => CYAN,
+            ScaleName::Teal => TEAL,
+            ScaleName::Jade => JADE,
+            ScaleName::Green => GREEN,
+            ScaleName::Grass => GRASS,
+            ScaleName::Brown => BROWN,
+            ScaleName::Bronze => BRONZE,
+            ScaleName::Gold => GOLD,
+            ScaleName::Sky => SKY,
+            ScaleName::Mint => MINT,
+            ScaleName::Lime => LIME,
+            ScaleName::Yellow => YELLOW,
+            ScaleName::Amber => AMBER,
+            ScaleName::Orange => ORANGE,
+        })
+    }
+
+    /// Alpha steps in light mode.
+    pub const fn light_alpha(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_A,
+            ScaleName::Mauve => MAUVE_A,
+            ScaleName::Slate => SLATE_A,
+            ScaleName::Sage => SAGE_A,
+            ScaleName::Olive => OLIVE_A,
+            ScaleName::Sand => SAND_A,
+            ScaleName::Tomato => TOMATO_A,
+            ScaleName::Red => RED_A,
+            ScaleName::Ruby => RUBY_A,
+            ScaleName::Crimson => CRIMSON_A,
+            ScaleName::Pink => PINK_A,
+            ScaleName::Plum => PLUM_A,
+            ScaleName::Purple => PURPLE_A,
+            ScaleName::Violet => VIOLET_A,
+            ScaleName::Iris => IRIS_A,
+            ScaleName::Indigo => INDIGO_A,
+            ScaleName::Blue => BLUE_A,
+            ScaleName::Cyan => CYAN_A,
+            ScaleName::Teal => TEAL_A,
+            ScaleName::Jade => JADE_A,
+            ScaleName::Green => GREEN_A,
+            ScaleName::Grass => GRASS_A,
+            ScaleName::Brown => BROWN_A,
+            ScaleName::Bronze => BRONZE_A,
+            ScaleName::Gold => GOLD_A,
+            ScaleName::Sky => SKY_A,
+            ScaleName::Mint => MINT_A,
+            ScaleName::Lime => LIME_A,
+            ScaleName::Yellow => YELLOW_A,
+            ScaleName::Amber => AMBER_A,
+            ScaleName::Orange => ORANGE_A,
+        })
+    }
+
+    /// Solid Display P3 steps in light mode.
+    pub const fn light_p3(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_P3,
+            ScaleName::Mauve => MAUVE_P3,
+            ScaleName::Slate => SLATE_P3,
+            ScaleName::Sage => SAGE_P3,
+            ScaleName::Olive => OLIVE_P3,
+            ScaleName::Sand => SAND_P3,
+            ScaleName::Tomato => TOMATO_P3,
+            ScaleName::Red => RED_P3,
+            ScaleName::Ruby => RUBY_P3,
+            ScaleName::Crimson => CRIMSON_P3,
+            ScaleName::Pink => PINK_P3,
+            ScaleName::Plum => PLUM_P3,
+            ScaleName::Purple => PURPLE_P3,
+            ScaleName::Violet => VIOLET_P3,
+            ScaleName::Iris => IRIS_P3,
+            ScaleName::Indigo => INDIGO_P3,
+            ScaleName::Blue => BLUE_P3,
+            ScaleName::Cyan => CYAN_P3,
+            ScaleName::Teal => TEAL_P3,
+            ScaleName::Jade => JADE_P3,
+            ScaleName::Green => GREEN_P3,
+            ScaleName::Grass => GRASS_P3,
+            ScaleName::Brown => BROWN_P3,
+            ScaleName::Bronze => BRONZE_P3,
+            ScaleName::Gold => GOLD_P3,
+            ScaleName::Sky => SKY_P3,
+            ScaleName::Mint => MINT_P3,
+            ScaleName::Lime => LIME_P3,
+            ScaleName::Yellow => YELLOW_P3,
+            ScaleName::Amber => AMBER_P3,
+            ScaleName::Orange => ORANGE_P3,
+        })
+    }
+
+    /// Alpha Display P3 steps in light mode.
+    pub const fn light_p3_alpha(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_P3_A,
+            ScaleName::Mauve => MAUVE_P3_A,
+            ScaleName::Slate => SLATE_P3_A,
+            ScaleName::Sage => SAGE_P3_A,
+            ScaleName::Olive => OLIVE_P3_A,
+            ScaleName::Sand => SAND_P3_A,
+            ScaleName::Tomato => TOMATO_P3_A,
+            ScaleName::Red => RED_P3_A,
+            ScaleName::Ruby => RUBY_P3_A,
+            ScaleName::Crimson => CRIMSON_P3_A,
+            ScaleName::Pink => PINK_P3_A,
+            ScaleName::Plum => PLUM_P3_A,
+            ScaleName::Purple => PURPLE_P3_A,
+            ScaleName::Violet => VIOLET_P3_A,
+            ScaleName::Iris => IRIS_P3_A,
+            ScaleName::Indigo => INDIGO_P3_A,
+            ScaleName::Blue => BLUE_P3_A,
+            ScaleName::Cyan => CYAN_P3_A,
+            ScaleName::Teal => TEAL_P3_A,
+            ScaleName::Jade => JADE_P3_A,
+            ScaleName::Green => GREEN_P3_A,
+            ScaleName::Grass => GRASS_P3_A,
+            ScaleName::Brown => BROWN_P3_A,
+            ScaleName::Bronze => BRONZE_P3_A,
+            ScaleName::Gold => GOLD_P3_A,
+            ScaleName::Sky => SKY_P3_A,
+            ScaleName::Mint => MINT_P3_A,
+            ScaleName::Lime => LIME_P3_A,
+            ScaleName::Yellow => YELLOW_P3_A,
+            ScaleName::Amber => AMBER_P3_A,
+            ScaleName::Orange => ORANGE_P3_A,
+        })
+    }
+
+    /// Solid steps in dark mode.
+    pub const fn dark(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_DARK,
+            ScaleName::Mauve => MAUVE_DARK,
+            ScaleName::Slate => SLATE_DARK,
+            ScaleName::Sage => SAGE_DARK,
+            ScaleName::Olive => OLIVE_DARK,
+            ScaleName::Sand => SAND_DARK,
+            ScaleName::Tomato => TOMATO_DARK,
+            ScaleName::Red => RED_DARK,
+            ScaleName::Ruby => RUBY_DARK,
+            ScaleName::Crimson => CRIMSON_DARK,
+            ScaleName::Pink => PINK_DARK,
+            ScaleName::Plum => PLUM_DARK,
+            ScaleName::Purple => PURPLE_DARK,
+            ScaleName::Violet => VIOLET_DARK,
+            ScaleName::Iris => IRIS_DARK,
+            ScaleName::Indigo => INDIGO_DARK,
+            ScaleName::Blue => BLUE_DARK,
+            ScaleName::Cyan => CYAN_DARK,
+            ScaleName::Teal => TEAL_DARK,
+            ScaleName::Jade => JADE_DARK,
+            ScaleName::Green => GREEN_DARK,
+            ScaleName::Grass => GRASS_DARK,
+            ScaleName::Brown => BROWN_DARK,
+            ScaleName::Bronze => BRONZE_DARK,
+            ScaleName::Gold => GOLD_DARK,
+            ScaleName::Sky => SKY_DARK,
+            ScaleName::Mint => MINT_DARK,
+            ScaleName::Lime => LIME_DARK,
+            ScaleName::Yellow => YELLOW_DARK,
+            ScaleName::Amber => AMBER_DARK,
+            ScaleName::Orange => ORANGE_DARK,
+        })
+    }
+
+    /// Alpha steps in dark mode.
+    pub const fn dark_alpha(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_DARK_A,
+            ScaleName::Mauve => MAUVE_DARK_A,
+            ScaleName::Slate => SLATE_DARK_A,
+            ScaleName::Sage => SAGE_DARK_A,
+            ScaleName::Olive => OLIVE_DARK_A,
+            ScaleName::Sand => SAND_DARK_A,
+            ScaleName::Tomato => TOMATO_DARK_A,
+            ScaleName::Red => RED_DARK_A,
+            ScaleName::Ruby => RUBY_DARK_A,
+            ScaleName::Crimson => CRIMSON_DARK_A,
+            ScaleName::Pink => PINK_DARK_A,
+            ScaleName::Plum => PLUM_DARK_A,
+            ScaleName::Purple => PURPLE_DARK_A,
+            ScaleName::Violet => VIOLET_DARK_A,
+            ScaleName::Iris => IRIS_DARK_A,
+            ScaleName::Indigo => INDIGO_DARK_A,
+            ScaleName::Blue => BLUE_DARK_A,
+            ScaleName::Cyan => CYAN_DARK_A,
+            ScaleName::Teal => TEAL_DARK_A,
+            ScaleName::Jade => JADE_DARK_A,
+            ScaleName::Green => GREEN_DARK_A,
+            ScaleName::Grass => GRASS_DARK_A,
+            ScaleName::Brown => BROWN_DARK_A,
+            ScaleName::Bronze => BRONZE_DARK_A,
+            ScaleName::Gold => GOLD_DARK_A,
+            ScaleName::Sky => SKY_DARK_A,
+            ScaleName::Mint => MINT_DARK_A,
+            ScaleName::Lime => LIME_DARK_A,
+            ScaleName::Yellow => YELLOW_DARK_A,
+            ScaleName::Amber => AMBER_DARK_A,
+            ScaleName::Orange => ORANGE_DARK_A,
+        })
+    }
+
+    /// Solid Display P3 steps in dark mode.
+    pub const fn dark_p3(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_DARK_P3,
+            ScaleName::Mauve => MAUVE_DARK_P3,
+            ScaleName::Slate => SLATE_DARK_P3,
+            ScaleName::Sage => SAGE_DARK_P3,
+            ScaleName::Olive => OLIVE_DARK_P3,
+            ScaleName::Sand => SAND_DARK_P3,
+            ScaleName::Tomato => TOMATO_DARK_P3,
+            ScaleName::Red => RED_DARK_P3,
+            ScaleName::Ruby => RUBY_DARK_P3,
+            ScaleName::Crimson => CRIMSON_DARK_P3,
+            ScaleName::Pink => PINK_DARK_P3,
+            ScaleName::Plum => PLUM_DARK_P3,
+            ScaleName::Purple => PURPLE_DARK_P3,
+            ScaleName::Violet => VIOLET_DARK_P3,
+            ScaleName::Iris => IRIS_DARK_P3,
+            ScaleName::Indigo => INDIGO_DARK_P3,
+            ScaleName::Blue => BLUE_DARK_P3,
+            ScaleName::Cyan => CYAN_DARK_P3,
+            ScaleName::Teal => TEAL_DARK_P3,
+            ScaleName::Jade => JADE_DARK_P3,
+            ScaleName::Green => GREEN_DARK_P3,
+            ScaleName::Grass => GRASS_DARK_P3,
+            ScaleName::Brown => BROWN_DARK_P3,
+            ScaleName::Bronze => BRONZE_DARK_P3,
+            ScaleName::Gold => GOLD_DARK_P3,
+            ScaleName::Sky => SKY_DARK_P3,
+            ScaleName::Mint => MINT_DARK_P3,
+            ScaleName::Lime => LIME_DARK_P3,
+            ScaleName::Yellow => YELLOW_DARK_P3,
+            ScaleName::Amber => AMBER_DARK_P3,
+            ScaleName::Orange => ORANGE_DARK_P3,
+        })
+    }
+
+    /// Alpha Display P3 steps in dark mode.
+    pub const fn dark_p3_alpha(self) -> Scale {
+        Scale::new(match self {
+            ScaleName::Gray => GRAY_DARK_P3_A,
+            ScaleName::Mauve => MAUVE_DARK_P3_A,
+            ScaleName::Slate => SLATE_DARK_P3_A,
+            ScaleName::Sage => SAGE_DARK_P3_A,
+            ScaleName::Olive => OLIVE_DARK_P3_A,
+            ScaleName::Sand => SAND_DARK_P3_A,
+            ScaleName::Tomato => TOMATO_DARK_P3_A,
+            ScaleName::Red => RED_DARK_P3_A,
+            ScaleName::Ruby => RUBY_DARK_P3_A,
+            ScaleName::Crimson => CRIMSON_DARK_P3_A,
+            ScaleName::Pink => PINK_DARK_P3_A,
+            ScaleName::Plum => PLUM_DARK_P3_A,
+            ScaleName::Purple => PURPLE_DARK_P3_A,
+            ScaleName::Violet => VIOLET_DARK_P3_A,
+            ScaleName::Iris => IRIS_DARK_P3_A,
+            ScaleName::Indigo => INDIGO_DARK_P3_A,
+            ScaleName::Blue => BLUE_DARK_P3_A,
+            ScaleName::Cyan => CYAN_DARK_P3_A,
+            ScaleName::Teal => TEAL_DARK_P3_A,
+            ScaleName::Jade => JADE_DARK_P3_A,
+            ScaleName::Green => GREEN_DARK_P3_A,
+            ScaleName::Grass => GRASS_DARK_P3_A,
+            ScaleName::Brown => BROWN_DARK_P3_A,
+            ScaleName::Bronze => BRONZE_DARK_P3_A,
+            ScaleName::Gold => GOLD_DARK_P3_A,
+            ScaleName::Sky => SKY_DARK_P3_A,
+            ScaleName::Mint => MINT_DARK_P3_A,
+            ScaleName::Lime => LIME_DARK_P3_A,
+            ScaleName::Yellow => YELLOW_DARK_P3_A,
+            ScaleName::Amber => AMBER_DARK_P3_A,
+            ScaleName::Orange => ORANGE_DARK_P3_A,
+        })
+    }
+}
+
+impl fmt::Display for ScaleName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an unknown [`ScaleName`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseScaleNameError {
+    name: String,
+}
+
+impl fmt::Display for ParseScaleNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown color scale `{}`", self.name)
+    }
+}
+
+impl std::error::Error for ParseScaleNameError {}
+
+impl FromStr for ScaleName {
+    type Err = ParseScaleNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ScaleName::ALL
+            .into_iter()
+            .find(|name| name.as_str() == s)
+            .ok_or_else(|| ParseScaleNameError { name: s.into() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_are_one_based() {
+        let scale = ScaleName::Blue.light();
+
+        assert_eq!(scale.step(1), BLUE[0]);
+        assert_eq!(scale.step(12), BLUE[11]);
+        assert_eq!(scale.get(0), None);
+        assert_eq!(scale.get(13), None);
+        assert_eq!(scale.steps().last(), Some((12, BLUE[11])));
+    }
+
+    #[test]
+    #[should_panic(expected = "Step should be between 1 and 12.")]
+    fn step_zero_panics() {
+        ScaleName::Blue.light().step(0);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for name in ScaleName::ALL {
+            assert_eq!(name.as_str().parse::<ScaleName>(), Ok(name));
+        }
+        assert!("sepia".parse::<ScaleName>().is_err());
+    }
+
+    #[test]
+    fn variants_map_to_constants() {
+        assert_eq!(ScaleName::Sand.dark_alpha().as_array(), &SAND_DARK_A);
+        assert_eq!(ScaleName::Sky.light_p3().as_array(), &SKY_P3);
+        assert_eq!(
+            ScaleName::ALL.iter().filter(|name| name.is_gray()).count(),
+            6
+        );
+    }
+
+    #[test]
+    fn every_step_parses() {
+        for name in ScaleName::ALL {
+            for scale in [
+                name.light(),
+                name.light_alpha(),
+                name.dark_p3(),
+                name.dark_p3_alpha(),
+            ] {
+                for step in 1..=12 {
+                    scale.color(step);
+                }
+            }
+        }
+    }
+}
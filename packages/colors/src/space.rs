@@ -0,0 +1,272 @@
+//! Color space conversions shared by scale generation and contrast checks.
+//!
+//! Components are `f64`s: gamma-encoded RGB in `0.0..=1.0`, OKLab/OKLCH
+//! lightness in `0.0..=1.0` and OKLCH hue in degrees.
+
+/// RGB color space of a parsed value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Gamut {
+    Srgb,
+    P3,
+}
+
+const LINEAR_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        0.486_570_948_648_216_2,
+        0.265_667_693_169_093_06,
+        0.198_217_285_234_362_5,
+    ],
+    [
+        0.228_974_564_069_748_8,
+        0.691_738_521_836_506_4,
+        0.079_286_914_093_745,
+    ],
+    [0.0, 0.045_113_381_858_902_64, 1.043_944_368_900_976],
+];
+
+const XYZ_TO_LINEAR_SRGB: [[f64; 3]; 3] = [
+    [
+        3.240_969_941_904_522_6,
+        -1.537_383_177_570_094,
+        -0.498_610_760_293_003_4,
+    ],
+    [
+        -0.969_243_636_280_879_6,
+        1.875_967_501_507_720_2,
+        0.041_555_057_407_175_59,
+    ],
+    [
+        0.055_630_079_696_993_66,
+        -0.203_976_958_888_976_52,
+        1.056_971_514_242_878_6,
+    ],
+];
+
+const LINEAR_SRGB_TO_XYZ: [[f64; 3]; 3] = [
+    [
+        0.412_390_799_265_959_34,
+        0.357_584_339_383_878,
+        0.180_480_788_401_834_3,
+    ],
+    [
+        0.212_639_005_871_510_27,
+        0.715_168_678_767_756,
+        0.072_192_315_360_733_71,
+    ],
+    [
+        0.019_330_818_715_591_82,
+        0.119_194_779_794_625_98,
+        0.950_532_152_249_660_7,
+    ],
+];
+
+const XYZ_TO_LINEAR_P3: [[f64; 3]; 3] = [
+    [
+        2.493_496_911_941_425_4,
+        -0.931_383_617_919_123_9,
+        -0.402_710_784_450_716_84,
+    ],
+    [
+        -0.829_488_969_561_574_7,
+        1.762_664_060_318_346_3,
+        0.023_624_685_841_943_577,
+    ],
+    [
+        0.035_845_830_243_784_47,
+        -0.076_172_389_268_041_82,
+        0.956_884_524_007_687_2,
+    ],
+];
+
+fn multiply(matrix: &[[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Parses `#rgb`, `#rrggbb`, `#rrggbbaa` or `color(display-p3 r g b[ / a])`
+/// into gamma-encoded components and alpha.
+pub(crate) fn parse(value: &str) -> Option<(Gamut, [f64; 3], f64)> {
+    let value = value.trim();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let digits: Vec<u8> = match hex.len() {
+            3 | 4 => hex
+                .chars()
+                .map(|c| c.to_digit(16).map(|d| (d * 17) as u8))
+                .collect::<Option<_>>()?,
+            6 | 8 => (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+                .collect::<Option<_>>()?,
+            _ => return None,
+        };
+        let channel = |i: usize| f64::from(digits[i]) / 255.0;
+        let alpha = digits.get(3).map_or(1.0, |a| f64::from(*a) / 255.0);
+        return Some((Gamut::Srgb, [channel(0), channel(1), channel(2)], alpha));
+    }
+
+    let inner = value
+        .strip_prefix("color(display-p3")?
+        .strip_suffix(')')?
+        .trim();
+    let (channels, alpha) = match inner.split_once('/') {
+        Some((channels, alpha)) => (channels, alpha.trim().parse().ok()?),
+        None => (inner, 1.0),
+    };
+    let channels: Vec<f64> = channels
+        .split_whitespace()
+        .map(|channel| channel.parse().ok())
+        .collect::<Option<_>>()?;
+
+    match channels.as_slice() {
+        [r, g, b] => Some((Gamut::P3, [*r, *g, *b], alpha)),
+        _ => None,
+    }
+}
+
+/// sRGB (and Display P3) transfer function, encoded to linear.
+pub(crate) fn to_linear(channel: f64) -> f64 {
+    let abs = channel.abs();
+    let linear = if abs <= 0.040_45 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(channel)
+}
+
+/// sRGB (and Display P3) transfer function, linear to encoded.
+pub(crate) fn from_linear(channel: f64) -> f64 {
+    let abs = channel.abs();
+    let encoded = if abs <= 0.003_130_8 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1.0 / 2.4) - 0.055
+    };
+    encoded.copysign(channel)
+}
+
+/// Converts gamma-encoded RGB in `gamut` to OKLab.
+pub(crate) fn rgb_to_oklab(gamut: Gamut, rgb: [f64; 3]) -> [f64; 3] {
+    let linear = rgb.map(to_linear);
+    let linear_srgb = match gamut {
+        Gamut::Srgb => linear,
+        Gamut::P3 => multiply(&XYZ_TO_LINEAR_SRGB, multiply(&LINEAR_P3_TO_XYZ, linear)),
+    };
+    linear_srgb_to_oklab(linear_srgb)
+}
+
+/// Converts OKLab to gamma-encoded RGB in `gamut`, without gamut mapping.
+pub(crate) fn oklab_to_rgb(gamut: Gamut, lab: [f64; 3]) -> [f64; 3] {
+    let linear_srgb = oklab_to_linear_srgb(lab);
+    let linear = match gamut {
+        Gamut::Srgb => linear_srgb,
+        Gamut::P3 => multiply(
+            &XYZ_TO_LINEAR_P3,
+            multiply(&LINEAR_SRGB_TO_XYZ, linear_srgb),
+        ),
+    };
+    linear.map(from_linear)
+}
+
+fn linear_srgb_to_oklab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+    [
+        0.210_454_255_3 * l + 0.793_617_785 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766 * s,
+    ]
+}
+
+fn oklab_to_linear_srgb([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548 * b).powi(3);
+
+    [
+        4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_,
+        -1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701 * s_,
+    ]
+}
+
+/// Converts OKLab to OKLCH. Achromatic colors get a hue of `0`.
+pub(crate) fn oklab_to_oklch([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let c = a.hypot(b);
+    let h = if c < 1e-9 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+    [l, c, h]
+}
+
+pub(crate) fn oklch_to_oklab([l, c, h]: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = h.to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+/// Euclidean distance in OKLab (`deltaEOK`).
+pub(crate) fn delta_e_ok(a: [f64; 3], b: [f64; 3]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// Converts OKLCH to gamma-encoded RGB in `gamut`, reducing chroma until the
+/// color fits the gamut.
+pub(crate) fn oklch_to_rgb_in_gamut(gamut: Gamut, lch: [f64; 3]) -> [f64; 3] {
+    const EPSILON: f64 = 1e-4;
+    let in_gamut = |rgb: [f64; 3]| rgb.iter().all(|c| (-EPSILON..=1.0 + EPSILON).contains(c));
+
+    let rgb = oklab_to_rgb(gamut, oklch_to_oklab(lch));
+    if in_gamut(rgb) {
+        return rgb.map(|c| c.clamp(0.0, 1.0));
+    }
+
+    let [l, c, h] = lch;
+    if l >= 1.0 {
+        return [1.0; 3];
+    }
+    if l <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let (mut low, mut high) = (0.0, c);
+    while high - low > 1e-5 {
+        let mid = (low + high) / 2.0;
+        if in_gamut(oklab_to_rgb(gamut, oklch_to_oklab([l, mid, h]))) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    oklab_to_rgb(gamut, oklch_to_oklab([l, low, h])).map(|c| c.clamp(0.0, 1.0))
+}
+
+/// Formats gamma-encoded sRGB as `#rrggbb`, or `#rrggbbaa` when translucent.
+pub(crate) fn format_hex(rgb: [f64; 3], alpha: f64) -> String {
+    let byte = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let [r, g, b] = rgb.map(byte);
+    if alpha < 1.0 {
+        format!("#{r:02x}{g:02x}{b:02x}{:02x}", byte(alpha))
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+/// Formats gamma-encoded Display P3 as `color(display-p3 r g b[ / a])`, with
+/// three decimals like the shipped scales.
+pub(crate) fn format_p3(rgb: [f64; 3], alpha: f64) -> String {
+    let [r, g, b] = rgb.map(format_decimal);
+    if alpha < 1.0 {
+        format!("color(display-p3 {r} {g} {b} / {})", format_decimal(alpha))
+    } else {
+        format!("color(display-p3 {r} {g} {b})")
+    }
+}
+
+fn format_decimal(value: f64) -> String {
+    let value = (value.clamp(0.0, 1.0) * 1000.0).round() / 1000.0;
+    format!("{value}")
+}
@@ -1,26 +1,64 @@
+#[cfg(feature = "sprite")]
+use super::SpriteIcon;
+use cardo_ui_class::ClassProp;
+#[cfg(not(feature = "sprite"))]
+use leptos::attribute_interceptor::AttributeInterceptor;
 use leptos::{prelude::*, svg::Svg};
+#[cfg(not(feature = "sprite"))]
 #[component]
 pub fn AlignBottomIcon(
     #[prop(default = 15.into(), into)] width: Signal<usize>,
     #[prop(default = 15.into(), into)] height: Signal<usize>,
     #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
     #[prop(optional)] node_ref: NodeRef<Svg>,
 ) -> impl IntoView {
+    let class = Signal::derive(move || class.get().map(|class| class.0));
     view! {
-        <svg
-            node_ref=node_ref
+        <AttributeInterceptor let:attrs>
+            <svg
+                node_ref=node_ref
+                class=class
+                role=move || label.get().map(|_| "img")
+                aria-hidden=move || label.get().is_none().then_some("true")
+                {..attrs}
+                width=width
+                height=height
+                viewBox="0 0 15 15"
+                fill="none"
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                {move || label.get().map(|label| view! { <title>{label}</title> })}
+                <path
+                    fill-rule="evenodd"
+                    clip-rule="evenodd"
+                    d="M9 3C9 2.44772 8.55229 2 8 2H7C6.44772 2 6 2.44772 6 3L6 14H1.5C1.22386 14 1 14.2239 1 14.5C1 14.7761 1.22386 15 1.5 15L6 15H9H13.5C13.7761 15 14 14.7761 14 14.5C14 14.2239 13.7761 14 13.5 14H9V3Z"
+                    fill=color
+                ></path>
+            </svg>
+        </AttributeInterceptor>
+    }
+}
+#[cfg(feature = "sprite")]
+#[component]
+pub fn AlignBottomIcon(
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    view! {
+        <SpriteIcon
+            id="align-bottom"
             width=width
             height=height
-            viewBox="0 0 15 15"
-            fill="none"
-            xmlns="http://www.w3.org/2000/svg"
-        >
-            <path
-                fill-rule="evenodd"
-                clip-rule="evenodd"
-                d="M9 3C9 2.44772 8.55229 2 8 2H7C6.44772 2 6 2.44772 6 3L6 14H1.5C1.22386 14 1 14.2239 1 14.5C1 14.7761 1.22386 15 1.5 15L6 15H9H13.5C13.7761 15 14 14.7761 14 14.5C14 14.2239 13.7761 14 13.5 14H9V3Z"
-                fill=color
-            ></path>
-        </svg>
+            color=color
+            class=class
+            label=label
+            node_ref=node_ref
+        />
     }
 }
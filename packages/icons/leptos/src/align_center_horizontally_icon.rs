@@ -1,26 +1,64 @@
+#[cfg(feature = "sprite")]
+use super::SpriteIcon;
+use cardo_ui_class::ClassProp;
+#[cfg(not(feature = "sprite"))]
+use leptos::attribute_interceptor::AttributeInterceptor;
 use leptos::{prelude::*, svg::Svg};
+#[cfg(not(feature = "sprite"))]
 #[component]
 pub fn AlignCenterHorizontallyIcon(
     #[prop(default = 15.into(), into)] width: Signal<usize>,
     #[prop(default = 15.into(), into)] height: Signal<usize>,
     #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
     #[prop(optional)] node_ref: NodeRef<Svg>,
 ) -> impl IntoView {
+    let class = Signal::derive(move || class.get().map(|class| class.0));
     view! {
-        <svg
-            node_ref=node_ref
+        <AttributeInterceptor let:attrs>
+            <svg
+                node_ref=node_ref
+                class=class
+                role=move || label.get().map(|_| "img")
+                aria-hidden=move || label.get().is_none().then_some("true")
+                {..attrs}
+                width=width
+                height=height
+                viewBox="0 0 15 15"
+                fill="none"
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                {move || label.get().map(|label| view! { <title>{label}</title> })}
+                <path
+                    fill-rule="evenodd"
+                    clip-rule="evenodd"
+                    d="M1.99988 6C1.44759 6 0.999877 6.44772 0.999877 7L0.999877 8C0.999877 8.55228 1.44759 9 1.99988 9L6.99988 9L6.99988 13.5C6.99988 13.7761 7.22374 14 7.49988 14C7.77602 14 7.99988 13.7761 7.99988 13.5L7.99988 9L12.9999 9C13.5522 9 13.9999 8.55228 13.9999 8L13.9999 7C13.9999 6.44772 13.5522 6 12.9999 6L7.99988 6L7.99988 1.5C7.99988 1.22386 7.77602 1 7.49988 1C7.22373 1 6.99988 1.22386 6.99988 1.5L6.99988 6L1.99988 6Z"
+                    fill=color
+                ></path>
+            </svg>
+        </AttributeInterceptor>
+    }
+}
+#[cfg(feature = "sprite")]
+#[component]
+pub fn AlignCenterHorizontallyIcon(
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    view! {
+        <SpriteIcon
+            id="align-center-horizontally"
             width=width
             height=height
-            viewBox="0 0 15 15"
-            fill="none"
-            xmlns="http://www.w3.org/2000/svg"
-        >
-            <path
-                fill-rule="evenodd"
-                clip-rule="evenodd"
-                d="M1.99988 6C1.44759 6 0.999877 6.44772 0.999877 7L0.999877 8C0.999877 8.55228 1.44759 9 1.99988 9L6.99988 9L6.99988 13.5C6.99988 13.7761 7.22374 14 7.49988 14C7.77602 14 7.99988 13.7761 7.99988 13.5L7.99988 9L12.9999 9C13.5522 9 13.9999 8.55228 13.9999 8L13.9999 7C13.9999 6.44772 13.5522 6 12.9999 6L7.99988 6L7.99988 1.5C7.99988 1.22386 7.77602 1 7.49988 1C7.22373 1 6.99988 1.22386 6.99988 1.5L6.99988 6L1.99988 6Z"
-                fill=color
-            ></path>
-        </svg>
+            color=color
+            class=class
+            label=label
+            node_ref=node_ref
+        />
     }
 }
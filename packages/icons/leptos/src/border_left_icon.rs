@@ -1,260 +1,298 @@
+#[cfg(feature = "sprite")]
+use super::SpriteIcon;
+use cardo_ui_class::ClassProp;
+#[cfg(not(feature = "sprite"))]
+use leptos::attribute_interceptor::AttributeInterceptor;
 use leptos::{prelude::*, svg::Svg};
+#[cfg(not(feature = "sprite"))]
 #[component]
 pub fn BorderLeftIcon(
     #[prop(default = 15.into(), into)] width: Signal<usize>,
     #[prop(default = 15.into(), into)] height: Signal<usize>,
     #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
     #[prop(optional)] node_ref: NodeRef<Svg>,
 ) -> impl IntoView {
+    let class = Signal::derive(move || class.get().map(|class| class.0));
     view! {
-        <svg
-            node_ref=node_ref
+        <AttributeInterceptor let:attrs>
+            <svg
+                node_ref=node_ref
+                class=class
+                role=move || label.get().map(|_| "img")
+                aria-hidden=move || label.get().is_none().then_some("true")
+                {..attrs}
+                width=width
+                height=height
+                viewBox="0 0 15 15"
+                fill="none"
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                {move || label.get().map(|label| view! { <title>{label}</title> })}
+                <path
+                    fill-rule="evenodd"
+                    clip-rule="evenodd"
+                    d="M1.75 1L1.75 14L0.249999 14L0.25 1L1.75 1Z"
+                    fill=color
+                ></path>
+                <rect
+                    x="10"
+                    y="7"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 10 7)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="10"
+                    y="13"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 10 13)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="12"
+                    y="7"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 12 7)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="12"
+                    y="13"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 12 13)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="7"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 7)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="7"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 7)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="13"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 13)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="13"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 13)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="5"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 5)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="5"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 5)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="3"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 3)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="3"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 3)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="9"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 9)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="9"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 9)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="11"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 11)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="11"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 11)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="6"
+                    y="7"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 6 7)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="6"
+                    y="13"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 6 13)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="4"
+                    y="7"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 4 7)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="4"
+                    y="13"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 4 13)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="10"
+                    y="1"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 10 1)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="12"
+                    y="1"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 12 1)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="8"
+                    y="1"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 8 1)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="14"
+                    y="1"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 14 1)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="6"
+                    y="1"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 6 1)"
+                    fill=color
+                ></rect>
+                <rect
+                    x="4"
+                    y="1"
+                    width="1"
+                    height="1"
+                    rx=".5"
+                    transform="rotate(90 4 1)"
+                    fill=color
+                ></rect>
+            </svg>
+        </AttributeInterceptor>
+    }
+}
+#[cfg(feature = "sprite")]
+#[component]
+pub fn BorderLeftIcon(
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    view! {
+        <SpriteIcon
+            id="border-left"
             width=width
             height=height
-            viewBox="0 0 15 15"
-            fill="none"
-            xmlns="http://www.w3.org/2000/svg"
-        >
-            <path
-                fill-rule="evenodd"
-                clip-rule="evenodd"
-                d="M1.75 1L1.75 14L0.249999 14L0.25 1L1.75 1Z"
-                fill=color
-            ></path>
-            <rect
-                x="10"
-                y="7"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 10 7)"
-                fill=color
-            ></rect>
-            <rect
-                x="10"
-                y="13"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 10 13)"
-                fill=color
-            ></rect>
-            <rect
-                x="12"
-                y="7"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 12 7)"
-                fill=color
-            ></rect>
-            <rect
-                x="12"
-                y="13"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 12 13)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="7"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 7)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="7"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 7)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="13"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 13)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="13"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 13)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="5"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 5)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="5"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 5)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="3"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 3)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="3"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 3)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="9"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 9)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="9"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 9)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="11"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 11)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="11"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 11)"
-                fill=color
-            ></rect>
-            <rect
-                x="6"
-                y="7"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 6 7)"
-                fill=color
-            ></rect>
-            <rect
-                x="6"
-                y="13"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 6 13)"
-                fill=color
-            ></rect>
-            <rect
-                x="4"
-                y="7"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 4 7)"
-                fill=color
-            ></rect>
-            <rect
-                x="4"
-                y="13"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 4 13)"
-                fill=color
-            ></rect>
-            <rect
-                x="10"
-                y="1"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 10 1)"
-                fill=color
-            ></rect>
-            <rect
-                x="12"
-                y="1"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 12 1)"
-                fill=color
-            ></rect>
-            <rect
-                x="8"
-                y="1"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 8 1)"
-                fill=color
-            ></rect>
-            <rect
-                x="14"
-                y="1"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 14 1)"
-                fill=color
-            ></rect>
-            <rect
-                x="6"
-                y="1"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 6 1)"
-                fill=color
-            ></rect>
-            <rect
-                x="4"
-                y="1"
-                width="1"
-                height="1"
-                rx=".5"
-                transform="rotate(90 4 1)"
-                fill=color
-            ></rect>
-        </svg>
+            color=color
+            class=class
+            label=label
+            node_ref=node_ref
+        />
     }
 }
@@ -1,52 +1,90 @@
+#[cfg(feature = "sprite")]
+use super::SpriteIcon;
+use cardo_ui_class::ClassProp;
+#[cfg(not(feature = "sprite"))]
+use leptos::attribute_interceptor::AttributeInterceptor;
 use leptos::{prelude::*, svg::Svg};
+#[cfg(not(feature = "sprite"))]
 #[component]
 pub fn BorderRightIcon(
     #[prop(default = 15.into(), into)] width: Signal<usize>,
     #[prop(default = 15.into(), into)] height: Signal<usize>,
     #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
     #[prop(optional)] node_ref: NodeRef<Svg>,
 ) -> impl IntoView {
+    let class = Signal::derive(move || class.get().map(|class| class.0));
     view! {
-        <svg
-            node_ref=node_ref
+        <AttributeInterceptor let:attrs>
+            <svg
+                node_ref=node_ref
+                class=class
+                role=move || label.get().map(|_| "img")
+                aria-hidden=move || label.get().is_none().then_some("true")
+                {..attrs}
+                width=width
+                height=height
+                viewBox="0 0 15 15"
+                fill="none"
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                {move || label.get().map(|label| view! { <title>{label}</title> })}
+                <path
+                    fill-rule="evenodd"
+                    clip-rule="evenodd"
+                    d="M13.25 1L13.25 14L14.75 14L14.75 1L13.25 1Z"
+                    fill=color
+                ></path>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 5 7)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 5 13)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 3 7)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 3 13)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 7)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 7)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 13)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 13)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 5)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 5)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 3)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 3)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 9)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 9)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 11)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 11)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 9 7)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 9 13)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 11 7)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 11 13)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 5 1)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 3 1)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 1)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 1)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 9 1)" fill=color></rect>
+                <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 11 1)" fill=color></rect>
+            </svg>
+        </AttributeInterceptor>
+    }
+}
+#[cfg(feature = "sprite")]
+#[component]
+pub fn BorderRightIcon(
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    view! {
+        <SpriteIcon
+            id="border-right"
             width=width
             height=height
-            viewBox="0 0 15 15"
-            fill="none"
-            xmlns="http://www.w3.org/2000/svg"
-        >
-            <path
-                fill-rule="evenodd"
-                clip-rule="evenodd"
-                d="M13.25 1L13.25 14L14.75 14L14.75 1L13.25 1Z"
-                fill=color
-            ></path>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 5 7)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 5 13)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 3 7)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 3 13)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 7)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 7)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 13)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 13)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 5)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 5)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 3)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 3)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 9)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 9)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 11)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 11)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 9 7)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 9 13)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 11 7)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 11 13)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 5 1)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 3 1)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 7 1)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 1 1)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 9 1)" fill=color></rect>
-            <rect width="1" height="1" rx=".5" transform="matrix(0 1 1 0 11 1)" fill=color></rect>
-        </svg>
+            color=color
+            class=class
+            label=label
+            node_ref=node_ref
+        />
     }
 }
@@ -1,44 +1,82 @@
+#[cfg(feature = "sprite")]
+use super::SpriteIcon;
+use cardo_ui_class::ClassProp;
+#[cfg(not(feature = "sprite"))]
+use leptos::attribute_interceptor::AttributeInterceptor;
 use leptos::{prelude::*, svg::Svg};
+#[cfg(not(feature = "sprite"))]
 #[component]
 pub fn DragHandleDots1Icon(
     #[prop(default = 15.into(), into)] width: Signal<usize>,
     #[prop(default = 15.into(), into)] height: Signal<usize>,
     #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
     #[prop(optional)] node_ref: NodeRef<Svg>,
 ) -> impl IntoView {
+    let class = Signal::derive(move || class.get().map(|class| class.0));
     view! {
-        <svg
-            node_ref=node_ref
+        <AttributeInterceptor let:attrs>
+            <svg
+                node_ref=node_ref
+                class=class
+                role=move || label.get().map(|_| "img")
+                aria-hidden=move || label.get().is_none().then_some("true")
+                {..attrs}
+                width=width
+                height=height
+                viewBox="0 0 15 15"
+                fill="none"
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                {move || label.get().map(|label| view! { <title>{label}</title> })}
+                <circle cx="4.5" cy="2.5" r=".6" fill=color></circle>
+                <circle cx="4.5" cy="4.5" r=".6" fill=color></circle>
+                <circle cx="4.5" cy="6.499" r=".6" fill=color></circle>
+                <circle cx="4.5" cy="8.499" r=".6" fill=color></circle>
+                <circle cx="4.5" cy="10.498" r=".6" fill=color></circle>
+                <circle cx="4.5" cy="12.498" r=".6" fill=color></circle>
+                <circle cx="6.5" cy="2.5" r=".6" fill=color></circle>
+                <circle cx="6.5" cy="4.5" r=".6" fill=color></circle>
+                <circle cx="6.5" cy="6.499" r=".6" fill=color></circle>
+                <circle cx="6.5" cy="8.499" r=".6" fill=color></circle>
+                <circle cx="6.5" cy="10.498" r=".6" fill=color></circle>
+                <circle cx="6.5" cy="12.498" r=".6" fill=color></circle>
+                <circle cx="8.499" cy="2.5" r=".6" fill=color></circle>
+                <circle cx="8.499" cy="4.5" r=".6" fill=color></circle>
+                <circle cx="8.499" cy="6.499" r=".6" fill=color></circle>
+                <circle cx="8.499" cy="8.499" r=".6" fill=color></circle>
+                <circle cx="8.499" cy="10.498" r=".6" fill=color></circle>
+                <circle cx="8.499" cy="12.498" r=".6" fill=color></circle>
+                <circle cx="10.499" cy="2.5" r=".6" fill=color></circle>
+                <circle cx="10.499" cy="4.5" r=".6" fill=color></circle>
+                <circle cx="10.499" cy="6.499" r=".6" fill=color></circle>
+                <circle cx="10.499" cy="8.499" r=".6" fill=color></circle>
+                <circle cx="10.499" cy="10.498" r=".6" fill=color></circle>
+                <circle cx="10.499" cy="12.498" r=".6" fill=color></circle>
+            </svg>
+        </AttributeInterceptor>
+    }
+}
+#[cfg(feature = "sprite")]
+#[component]
+pub fn DragHandleDots1Icon(
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    view! {
+        <SpriteIcon
+            id="drag-handle-dots-1"
             width=width
             height=height
-            viewBox="0 0 15 15"
-            fill="none"
-            xmlns="http://www.w3.org/2000/svg"
-        >
-            <circle cx="4.5" cy="2.5" r=".6" fill=color></circle>
-            <circle cx="4.5" cy="4.5" r=".6" fill=color></circle>
-            <circle cx="4.5" cy="6.499" r=".6" fill=color></circle>
-            <circle cx="4.5" cy="8.499" r=".6" fill=color></circle>
-            <circle cx="4.5" cy="10.498" r=".6" fill=color></circle>
-            <circle cx="4.5" cy="12.498" r=".6" fill=color></circle>
-            <circle cx="6.5" cy="2.5" r=".6" fill=color></circle>
-            <circle cx="6.5" cy="4.5" r=".6" fill=color></circle>
-            <circle cx="6.5" cy="6.499" r=".6" fill=color></circle>
-            <circle cx="6.5" cy="8.499" r=".6" fill=color></circle>
-            <circle cx="6.5" cy="10.498" r=".6" fill=color></circle>
-            <circle cx="6.5" cy="12.498" r=".6" fill=color></circle>
-            <circle cx="8.499" cy="2.5" r=".6" fill=color></circle>
-            <circle cx="8.499" cy="4.5" r=".6" fill=color></circle>
-            <circle cx="8.499" cy="6.499" r=".6" fill=color></circle>
-            <circle cx="8.499" cy="8.499" r=".6" fill=color></circle>
-            <circle cx="8.499" cy="10.498" r=".6" fill=color></circle>
-            <circle cx="8.499" cy="12.498" r=".6" fill=color></circle>
-            <circle cx="10.499" cy="2.5" r=".6" fill=color></circle>
-            <circle cx="10.499" cy="4.5" r=".6" fill=color></circle>
-            <circle cx="10.499" cy="6.499" r=".6" fill=color></circle>
-            <circle cx="10.499" cy="8.499" r=".6" fill=color></circle>
-            <circle cx="10.499" cy="10.498" r=".6" fill=color></circle>
-            <circle cx="10.499" cy="12.498" r=".6" fill=color></circle>
-        </svg>
+            color=color
+            class=class
+            label=label
+            node_ref=node_ref
+        />
     }
 }
@@ -0,0 +1,4895 @@
+use std::{fmt, str::FromStr};
+
+use cardo_ui_class::ClassProp;
+use leptos::{prelude::*, svg::Svg};
+
+use super::*;
+
+/// Name of an icon, for picking icons at runtime (e.g. from configuration).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IconName {
+    Accessibility,
+    ActivityLog,
+    AlignBaseline,
+    AlignBottom,
+    AlignCenterHorizontally,
+    AlignCenterVertically,
+    AlignLeft,
+    AlignRight,
+    AlignTop,
+    AllSides,
+    Angle,
+    Archive,
+    ArrowBottomLeft,
+    ArrowBottomRight,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    ArrowTopLeft,
+    ArrowTopRight,
+    ArrowUp,
+    AspectRatio,
+    Avatar,
+    Backpack,
+    Badge,
+    BarChart,
+    Bell,
+    BlendingMode,
+    BookmarkFilled,
+    Bookmark,
+    BorderAll,
+    BorderBottom,
+    BorderDashed,
+    BorderDotted,
+    BorderLeft,
+    BorderNone,
+    BorderRight,
+    BorderSolid,
+    BorderSplit,
+    BorderStyle,
+    BorderTop,
+    BorderWidth,
+    Box,
+    BoxModel,
+    Button,
+    Calendar,
+    Camera,
+    CardStack,
+    CardStackMinus,
+    CardStackPlus,
+    CaretDown,
+    CaretLeft,
+    CaretRight,
+    CaretSort,
+    CaretUp,
+    ChatBubble,
+    CheckCircled,
+    Check,
+    Checkbox,
+    ChevronDown,
+    ChevronLeft,
+    ChevronRight,
+    ChevronUp,
+    CircleBackslash,
+    Circle,
+    ClipboardCopy,
+    Clipboard,
+    Clock,
+    Code,
+    CodesandboxLogo,
+    ColorWheel,
+    ColumnSpacing,
+    Columns,
+    Commit,
+    Component1,
+    Component2,
+    ComponentBoolean,
+    ComponentInstance,
+    ComponentNone,
+    ComponentPlaceholder,
+    Container,
+    Cookie,
+    Copy,
+    CornerBottomLeft,
+    CornerBottomRight,
+    CornerTopLeft,
+    CornerTopRight,
+    Corners,
+    CountdownTimer,
+    CounterClockwiseClock,
+    Crop,
+    Cross1,
+    Cross2,
+    CrossCircled,
+    Crosshair1,
+    Crosshair2,
+    CrumpledPaper,
+    Cube,
+    CursorArrow,
+    CursorText,
+    Dash,
+    Dashboard,
+    Desktop,
+    Dimensions,
+    Disc,
+    DiscordLogo,
+    DividerHorizontal,
+    DividerVertical,
+    DotFilled,
+    Dot,
+    DotsHorizontal,
+    DotsVertical,
+    DoubleArrowDown,
+    DoubleArrowLeft,
+    DoubleArrowRight,
+    DoubleArrowUp,
+    Download,
+    DragHandleDots1,
+    DragHandleDots2,
+    DragHandleHorizontal,
+    DragHandleVertical,
+    DrawingPinFilled,
+    DrawingPin,
+    DropdownMenu,
+    EnterFullScreen,
+    Enter,
+    EnvelopeClosed,
+    EnvelopeOpen,
+    Eraser,
+    ExclamationTriangle,
+    ExitFullScreen,
+    Exit,
+    ExternalLink,
+    EyeClosed,
+    EyeNone,
+    EyeOpen,
+    Face,
+    FigmaLogo,
+    File,
+    FileMinus,
+    FilePlus,
+    FileText,
+    FontBold,
+    FontFamily,
+    FontItalic,
+    FontRoman,
+    FontSize,
+    FontStyle,
+    Frame,
+    FramerLogo,
+    Gear,
+    GithubLogo,
+    Globe,
+    Grid,
+    Group,
+    Half1,
+    Half2,
+    HamburgerMenu,
+    Hand,
+    Heading,
+    HeartFilled,
+    Heart,
+    Height,
+    HobbyKnife,
+    Home,
+    IconjarLogo,
+    IdCard,
+    Image,
+    InfoCircled,
+    Input,
+    InstagramLogo,
+    Keyboard,
+    LapTimer,
+    Laptop,
+    Layers,
+    Layout,
+    LetterCaseCapitalize,
+    LetterCaseLowercase,
+    LetterCaseToggle,
+    LetterCaseUppercase,
+    LetterSpacing,
+    LightningBolt,
+    LineHeight,
+    Link1,
+    Link2,
+    LinkBreak1,
+    LinkBreak2,
+    LinkNone1,
+    LinkNone2,
+    LinkedinLogo,
+    ListBullet,
+    LockClosed,
+    LockOpen1,
+    LockOpen2,
+    Loop,
+    MagicWand,
+    MagnifyingGlass,
+    Margin,
+    MaskOff,
+    MaskOn,
+    MinusCircled,
+    Minus,
+    Mix,
+    MixerHorizontal,
+    MixerVertical,
+    Mobile,
+    ModulzLogo,
+    Moon,
+    Move,
+    NotionLogo,
+    Opacity,
+    OpenInNewWindow,
+    Overline,
+    Padding,
+    PaperPlane,
+    Pause,
+    Pencil1,
+    Pencil2,
+    Person,
+    PieChart,
+    Pilcrow,
+    PinBottom,
+    PinLeft,
+    PinRight,
+    PinTop,
+    Play,
+    PlusCircled,
+    Plus,
+    QuestionMarkCircled,
+    QuestionMark,
+    Quote,
+    Radiobutton,
+    Reader,
+    Reload,
+    Reset,
+    Resume,
+    Rocket,
+    RotateCounterClockwise,
+    RowSpacing,
+    Rows,
+    RulerHorizontal,
+    RulerSquare,
+    Scissors,
+    Section,
+    SewingPinFilled,
+    SewingPin,
+    Shadow,
+    ShadowInner,
+    ShadowNone,
+    ShadowOuter,
+    Share1,
+    Share2,
+    Shuffle,
+    Size,
+    SketchLogo,
+    Slash,
+    Slider,
+    SpaceBetweenHorizontally,
+    SpaceBetweenVertically,
+    SpaceEvenlyHorizontally,
+    SpaceEvenlyVertically,
+    SpeakerLoud,
+    SpeakerModerate,
+    SpeakerOff,
+    SpeakerQuiet,
+    Square,
+    Stack,
+    StarFilled,
+    Star,
+    StitchesLogo,
+    Stop,
+    Stopwatch,
+    StretchHorizontally,
+    StretchVertically,
+    Strikethrough,
+    Sun,
+    Switch,
+    Symbol,
+    Table,
+    Target,
+    TextAlignBottom,
+    TextAlignCenter,
+    TextAlignJustify,
+    TextAlignLeft,
+    TextAlignMiddle,
+    TextAlignRight,
+    TextAlignTop,
+    Text,
+    TextNone,
+    ThickArrowDown,
+    ThickArrowLeft,
+    ThickArrowRight,
+    ThickArrowUp,
+    Timer,
+    Tokens,
+    TrackNext,
+    TrackPrevious,
+    Transform,
+    TransparencyGrid,
+    Trash,
+    TriangleDown,
+    TriangleLeft,
+    TriangleRight,
+    TriangleUp,
+    TwitterLogo,
+    Underline,
+    Update,
+    Upload,
+    Value,
+    ValueNone,
+    VercelLogo,
+    Video,
+    ViewGrid,
+    ViewHorizontal,
+    ViewNone,
+    ViewVertical,
+    Width,
+    ZoomIn,
+    ZoomOut,
+}
+
+/// All icon names, in alphabetical order.
+pub const ALL_ICONS: [IconName; 318] = [
+    IconName::Accessibility,
+    IconName::ActivityLog,
+    IconName::AlignBaseline,
+    IconName::AlignBottom,
+    IconName::AlignCenterHorizontally,
+    IconName::AlignCenterVertically,
+    IconName::AlignLeft,
+    IconName::AlignRight,
+    IconName::AlignTop,
+    IconName::AllSides,
+    IconName::Angle,
+    IconName::Archive,
+    IconName::ArrowBottomLeft,
+    IconName::ArrowBottomRight,
+    IconName::ArrowDown,
+    IconName::ArrowLeft,
+    IconName::ArrowRight,
+    IconName::ArrowTopLeft,
+    IconName::ArrowTopRight,
+    IconName::ArrowUp,
+    IconName::AspectRatio,
+    IconName::Avatar,
+    IconName::Backpack,
+    IconName::Badge,
+    IconName::BarChart,
+    IconName::Bell,
+    IconName::BlendingMode,
+    IconName::BookmarkFilled,
+    IconName::Bookmark,
+    IconName::BorderAll,
+    IconName::BorderBottom,
+    IconName::BorderDashed,
+    IconName::BorderDotted,
+    IconName::BorderLeft,
+    IconName::BorderNone,
+    IconName::BorderRight,
+    IconName::BorderSolid,
+    IconName::BorderSplit,
+    IconName::BorderStyle,
+    IconName::BorderTop,
+    IconName::BorderWidth,
+    IconName::Box,
+    IconName::BoxModel,
+    IconName::Button,
+    IconName::Calendar,
+    IconName::Camera,
+    IconName::CardStack,
+    IconName::CardStackMinus,
+    IconName::CardStackPlus,
+    IconName::CaretDown,
+    IconName::CaretLeft,
+    IconName::CaretRight,
+    IconName::CaretSort,
+    IconName::CaretUp,
+    IconName::ChatBubble,
+    IconName::CheckCircled,
+    IconName::Check,
+    IconName::Checkbox,
+    IconName::ChevronDown,
+    IconName::ChevronLeft,
+    IconName::ChevronRight,
+    IconName::ChevronUp,
+    IconName::CircleBackslash,
+    IconName::Circle,
+    IconName::ClipboardCopy,
+    IconName::Clipboard,
+    IconName::Clock,
+    IconName::Code,
+    IconName::CodesandboxLogo,
+    IconName::ColorWheel,
+    IconName::ColumnSpacing,
+    IconName::Columns,
+    IconName::Commit,
+    IconName::Component1,
+    IconName::Component2,
+    IconName::ComponentBoolean,
+    IconName::ComponentInstance,
+    IconName::ComponentNone,
+    IconName::ComponentPlaceholder,
+    IconName::Container,
+    IconName::Cookie,
+    IconName::Copy,
+    IconName::CornerBottomLeft,
+    IconName::CornerBottomRight,
+    IconName::CornerTopLeft,
+    IconName::CornerTopRight,
+    IconName::Corners,
+    IconName::CountdownTimer,
+    IconName::CounterClockwiseClock,
+    IconName::Crop,
+    IconName::Cross1,
+    IconName::Cross2,
+    IconName::CrossCircled,
+    IconName::Crosshair1,
+    IconName::Crosshair2,
+    IconName::CrumpledPaper,
+    IconName::Cube,
+    IconName::CursorArrow,
+    IconName::CursorText,
+    IconName::Dash,
+    IconName::Dashboard,
+    IconName::Desktop,
+    IconName::Dimensions,
+    IconName::Disc,
+    IconName::DiscordLogo,
+    IconName::DividerHorizontal,
+    IconName::DividerVertical,
+    IconName::DotFilled,
+    IconName::Dot,
+    IconName::DotsHorizontal,
+    IconName::DotsVertical,
+    IconName::DoubleArrowDown,
+    IconName::DoubleArrowLeft,
+    IconName::DoubleArrowRight,
+    IconName::DoubleArrowUp,
+    IconName::Download,
+    IconName::DragHandleDots1,
+    IconName::DragHandleDots2,
+    IconName::DragHandleHorizontal,
+    IconName::DragHandleVertical,
+    IconName::DrawingPinFilled,
+    IconName::DrawingPin,
+    IconName::DropdownMenu,
+    IconName::EnterFullScreen,
+    IconName::Enter,
+    IconName::EnvelopeClosed,
+    IconName::EnvelopeOpen,
+    IconName::Eraser,
+    IconName::ExclamationTriangle,
+    IconName::ExitFullScreen,
+    IconName::Exit,
+    IconName::ExternalLink,
+    IconName::EyeClosed,
+    IconName::EyeNone,
+    IconName::EyeOpen,
+    IconName::Face,
+    IconName::FigmaLogo,
+    IconName::File,
+    IconName::FileMinus,
+    IconName::FilePlus,
+    IconName::FileText,
+    IconName::FontBold,
+    IconName::FontFamily,
+    IconName::FontItalic,
+    IconName::FontRoman,
+    IconName::FontSize,
+    IconName::FontStyle,
+    IconName::Frame,
+    IconName::FramerLogo,
+    IconName::Gear,
+    IconName::GithubLogo,
+    IconName::Globe,
+    IconName::Grid,
+    IconName::Group,
+    IconName::Half1,
+    IconName::Half2,
+    IconName::HamburgerMenu,
+    IconName::Hand,
+    IconName::Heading,
+    IconName::HeartFilled,
+    IconName::Heart,
+    IconName::Height,
+    IconName::HobbyKnife,
+    IconName::Home,
+    IconName::IconjarLogo,
+    IconName::IdCard,
+    IconName::Image,
+    IconName::InfoCircled,
+    IconName::Input,
+    IconName::InstagramLogo,
+    IconName::Keyboard,
+    IconName::LapTimer,
+    IconName::Laptop,
+    IconName::Layers,
+    IconName::Layout,
+    IconName::LetterCaseCapitalize,
+    IconName::LetterCaseLowercase,
+    IconName::LetterCaseToggle,
+    IconName::LetterCaseUppercase,
+    IconName::LetterSpacing,
+    IconName::LightningBolt,
+    IconName::LineHeight,
+    IconName::Link1,
+    IconName::Link2,
+    IconName::LinkBreak1,
+    IconName::LinkBreak2,
+    IconName::LinkNone1,
+    IconName::LinkNone2,
+    IconName::LinkedinLogo,
+    IconName::ListBullet,
+    IconName::LockClosed,
+    IconName::LockOpen1,
+    IconName::LockOpen2,
+    IconName::Loop,
+    IconName::MagicWand,
+    IconName::MagnifyingGlass,
+    IconName::Margin,
+    IconName::MaskOff,
+    IconName::MaskOn,
+    IconName::MinusCircled,
+    IconName::Minus,
+    IconName::Mix,
+    IconName::MixerHorizontal,
+    IconName::MixerVertical,
+    IconName::Mobile,
+    IconName::ModulzLogo,
+    IconName::Moon,
+    IconName::Move,
+    IconName::NotionLogo,
+    IconName::Opacity,
+    IconName::OpenInNewWindow,
+    IconName::Overline,
+    IconName::Padding,
+    IconName::PaperPlane,
+    IconName::Pause,
+    IconName::Pencil1,
+    IconName::Pencil2,
+    IconName::Person,
+    IconName::PieChart,
+    IconName::Pilcrow,
+    IconName::PinBottom,
+    IconName::PinLeft,
+    IconName::PinRight,
+    IconName::PinTop,
+    IconName::Play,
+    IconName::PlusCircled,
+    IconName::Plus,
+    IconName::QuestionMarkCircled,
+    IconName::QuestionMark,
+    IconName::Quote,
+    IconName::Radiobutton,
+    IconName::Reader,
+    IconName::Reload,
+    IconName::Reset,
+    IconName::Resume,
+    IconName::Rocket,
+    IconName::RotateCounterClockwise,
+    IconName::RowSpacing,
+    IconName::Rows,
+    IconName::RulerHorizontal,
+    IconName::RulerSquare,
+    IconName::Scissors,
+    IconName::Section,
+    IconName::SewingPinFilled,
+    IconName::SewingPin,
+    IconName::Shadow,
+    IconName::ShadowInner,
+    IconName::ShadowNone,
+    IconName::ShadowOuter,
+    IconName::Share1,
+    IconName::Share2,
+    IconName::Shuffle,
+    IconName::Size,
+    IconName::SketchLogo,
+    IconName::Slash,
+    IconName::Slider,
+    IconName::SpaceBetweenHorizontally,
+    IconName::SpaceBetweenVertically,
+    IconName::SpaceEvenlyHorizontally,
+    IconName::SpaceEvenlyVertically,
+    IconName::SpeakerLoud,
+    IconName::SpeakerModerate,
+    IconName::SpeakerOff,
+    IconName::SpeakerQuiet,
+    IconName::Square,
+    IconName::Stack,
+    IconName::StarFilled,
+    IconName::Star,
+    IconName::StitchesLogo,
+    IconName::Stop,
+    IconName::Stopwatch,
+    IconName::StretchHorizontally,
+    IconName::StretchVertically,
+    IconName::Strikethrough,
+    IconName::Sun,
+    IconName::Switch,
+    IconName::Symbol,
+    IconName::Table,
+    IconName::Target,
+    IconName::TextAlignBottom,
+    IconName::TextAlignCenter,
+    IconName::TextAlignJustify,
+    IconName::TextAlignLeft,
+    IconName::TextAlignMiddle,
+    IconName::TextAlignRight,
+    IconName::TextAlignTop,
+    IconName::Text,
+    IconName::TextNone,
+    IconName::ThickArrowDown,
+    IconName::ThickArrowLeft,
+    IconName::ThickArrowRight,
+    IconName::ThickArrowUp,
+    IconName::Timer,
+    IconName::Tokens,
+    IconName::TrackNext,
+    IconName::TrackPrevious,
+    IconName::Transform,
+    IconName::TransparencyGrid,
+    IconName::Trash,
+    IconName::TriangleDown,
+    IconName::TriangleLeft,
+    IconName::TriangleRight,
+    IconName::TriangleUp,
+    IconName::TwitterLogo,
+    IconName::Underline,
+    IconName::Update,
+    IconName::Upload,
+    IconName::Value,
+    IconName::ValueNone,
+    IconName::VercelLogo,
+    IconName::Video,
+    IconName::ViewGrid,
+    IconName::ViewHorizontal,
+    IconName::ViewNone,
+    IconName::ViewVertical,
+    IconName::Width,
+    IconName::ZoomIn,
+    IconName::ZoomOut,
+];
+
+impl IconName {
+    /// Iterates over all icon names, in alphabetical order.
+    pub fn iter() -> impl Iterator<Item = IconName> {
+        ALL_ICONS.into_iter()
+    }
+
+    /// The kebab-case name of the icon, e.g. `"arrow-up"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            IconName::Accessibility => "accessibility",
+            IconName::ActivityLog => "activity-log",
+            IconName::AlignBaseline => "align-baseline",
+            IconName::AlignBottom => "align-bottom",
+            IconName::AlignCenterHorizontally => "align-center-horizontally",
+            IconName::AlignCenterVertically => "align-center-vertically",
+            IconName::AlignLeft => "align-left",
+            IconName::AlignRight => "align-right",
+            IconName::AlignTop => "align-top",
+            IconName::AllSides => "all-sides",
+            IconName::Angle => "angle",
+            IconName::Archive => "archive",
+            IconName::ArrowBottomLeft => "arrow-bottom-left",
+            IconName::ArrowBottomRight => "arrow-bottom-right",
+            IconName::ArrowDown => "arrow-down",
+            IconName::ArrowLeft => "arrow-left",
+            IconName::ArrowRight => "arrow-right",
+            IconName::ArrowTopLeft => "arrow-top-left",
+            IconName::ArrowTopRight => "arrow-top-right",
+            IconName::ArrowUp => "arrow-up",
+            IconName::AspectRatio => "aspect-ratio",
+            IconName::Avatar => "avatar",
+            IconName::Backpack => "backpack",
+            IconName::Badge => "badge",
+            IconName::BarChart => "bar-chart",
+            IconName::Bell => "bell",
+            IconName::BlendingMode => "blending-mode",
+            IconName::BookmarkFilled => "bookmark-filled",
+            IconName::Bookmark => "bookmark",
+            IconName::BorderAll => "border-all",
+            IconName::BorderBottom => "border-bottom",
+            IconName::BorderDashed => "border-dashed",
+            IconName::BorderDotted => "border-dotted",
+            IconName::BorderLeft => "border-left",
+            IconName::BorderNone => "border-none",
+            IconName::BorderRight => "border-right",
+            IconName::BorderSolid => "border-solid",
+            IconName::BorderSplit => "border-split",
+            IconName::BorderStyle => "border-style",
+            IconName::BorderTop => "border-top",
+            IconName::BorderWidth => "border-width",
+            IconName::Box => "box",
+            IconName::BoxModel => "box-model",
+            IconName::Button => "button",
+            IconName::Calendar => "calendar",
+            IconName::Camera => "camera",
+            IconName::CardStack => "card-stack",
+            IconName::CardStackMinus => "card-stack-minus",
+            IconName::CardStackPlus => "card-stack-plus",
+            IconName::CaretDown => "caret-down",
+            IconName::CaretLeft => "caret-left",
+            IconName::CaretRight => "caret-right",
+            IconName::CaretSort => "caret-sort",
+            IconName::CaretUp => "caret-up",
+            IconName::ChatBubble => "chat-bubble",
+            IconName::CheckCircled => "check-circled",
+            IconName::Check => "check",
+            IconName::Checkbox => "checkbox",
+            IconName::ChevronDown => "chevron-down",
+            IconName::ChevronLeft => "chevron-left",
+            IconName::ChevronRight => "chevron-right",
+            IconName::ChevronUp => "chevron-up",
+            IconName::CircleBackslash => "circle-backslash",
+            IconName::Circle => "circle",
+            IconName::ClipboardCopy => "clipboard-copy",
+            IconName::Clipboard => "clipboard",
+            IconName::Clock => "clock",
+            IconName::Code => "code",
+            IconName::CodesandboxLogo => "codesandbox-logo",
+            IconName::ColorWheel => "color-wheel",
+            IconName::ColumnSpacing => "column-spacing",
+            IconName::Columns => "columns",
+            IconName::Commit => "commit",
+            IconName::Component1 => "component-1",
+            IconName::Component2 => "component-2",
+            IconName::ComponentBoolean => "component-boolean",
+            IconName::ComponentInstance => "component-instance",
+            IconName::ComponentNone => "component-none",
+            IconName::ComponentPlaceholder => "component-placeholder",
+            IconName::Container => "container",
+            IconName::Cookie => "cookie",
+            IconName::Copy => "copy",
+            IconName::CornerBottomLeft => "corner-bottom-left",
+            IconName::CornerBottomRight => "corner-bottom-right",
+            IconName::CornerTopLeft => "corner-top-left",
+            IconName::CornerTopRight => "corner-top-right",
+            IconName::Corners => "corners",
+            IconName::CountdownTimer => "countdown-timer",
+            IconName::CounterClockwiseClock => "counter-clockwise-clock",
+            IconName::Crop => "crop",
+            IconName::Cross1 => "cross-1",
+            IconName::Cross2 => "cross-2",
+            IconName::CrossCircled => "cross-circled",
+            IconName::Crosshair1 => "crosshair-1",
+            IconName::Crosshair2 => "crosshair-2",
+            IconName::CrumpledPaper => "crumpled-paper",
+            IconName::Cube => "cube",
+            IconName::CursorArrow => "cursor-arrow",
+            IconName::CursorText => "cursor-text",
+            IconName::Dash => "dash",
+            IconName::Dashboard => "dashboard",
+            IconName::Desktop => "desktop",
+            IconName::Dimensions => "dimensions",
+            IconName::Disc => "disc",
+            IconName::DiscordLogo => "discord-logo",
+            IconName::DividerHorizontal => "divider-horizontal",
+            IconName::DividerVertical => "divider-vertical",
+            IconName::DotFilled => "dot-filled",
+            IconName::Dot => "dot",
+            IconName::DotsHorizontal => "dots-horizontal",
+            IconName::DotsVertical => "dots-vertical",
+            IconName::DoubleArrowDown => "double-arrow-down",
+            IconName::DoubleArrowLeft => "double-arrow-left",
+            IconName::DoubleArrowRight => "double-arrow-right",
+            IconName::DoubleArrowUp => "double-arrow-up",
+            IconName::Download => "download",
+            IconName::DragHandleDots1 => "drag-handle-dots-1",
+            IconName::DragHandleDots2 => "drag-handle-dots-2",
+            IconName::DragHandleHorizontal => "drag-handle-horizontal",
+            IconName::DragHandleVertical => "drag-handle-vertical",
+            IconName::DrawingPinFilled => "drawing-pin-filled",
+            IconName::DrawingPin => "drawing-pin",
+            IconName::DropdownMenu => "dropdown-menu",
+            IconName::EnterFullScreen => "enter-full-screen",
+            IconName::Enter => "enter",
+            IconName::EnvelopeClosed => "envelope-closed",
+            IconName::EnvelopeOpen => "envelope-open",
+            IconName::Eraser => "eraser",
+            IconName::ExclamationTriangle => "exclamation-triangle",
+            IconName::ExitFullScreen => "exit-full-screen",
+            IconName::Exit => "exit",
+            IconName::ExternalLink => "external-link",
+            IconName::EyeClosed => "eye-closed",
+            IconName::EyeNone => "eye-none",
+            IconName::EyeOpen => "eye-open",
+            IconName::Face => "face",
+            IconName::FigmaLogo => "figma-logo",
+            IconName::File => "file",
+            IconName::FileMinus => "file-minus",
+            IconName::FilePlus => "file-plus",
+            IconName::FileText => "file-text",
+            IconName::FontBold => "font-bold",
+            IconName::FontFamily => "font-family",
+            IconName::FontItalic => "font-italic",
+            IconName::FontRoman => "font-roman",
+            IconName::FontSize => "font-size",
+            IconName::FontStyle => "font-style",
+            IconName::Frame => "frame",
+            IconName::FramerLogo => "framer-logo",
+            IconName::Gear => "gear",
+            IconName::GithubLogo => "github-logo",
+            IconName::Globe => "globe",
+            IconName::Grid => "grid",
+            IconName::Group => "group",
+            IconName::Half1 => "half-1",
+            IconName::Half2 => "half-2",
+            IconName::HamburgerMenu => "hamburger-menu",
+            IconName::Hand => "hand",
+            IconName::Heading => "heading",
+            IconName::HeartFilled => "heart-filled",
+            IconName::Heart => "heart",
+            IconName::Height => "height",
+            IconName::HobbyKnife => "hobby-knife",
+            IconName::Home => "home",
+            IconName::IconjarLogo => "iconjar-logo",
+            IconName::IdCard => "id-card",
+            IconName::Image => "image",
+            IconName::InfoCircled => "info-circled",
+            IconName::Input => "input",
+            IconName::InstagramLogo => "instagram-logo",
+            IconName::Keyboard => "keyboard",
+            IconName::LapTimer => "lap-timer",
+            IconName::Laptop => "laptop",
+            IconName::Layers => "layers",
+            IconName::Layout => "layout",
+            IconName::LetterCaseCapitalize => "letter-case-capitalize",
+            IconName::LetterCaseLowercase => "letter-case-lowercase",
+            IconName::LetterCaseToggle => "letter-case-toggle",
+            IconName::LetterCaseUppercase => "letter-case-uppercase",
+            IconName::LetterSpacing => "letter-spacing",
+            IconName::LightningBolt => "lightning-bolt",
+            IconName::LineHeight => "line-height",
+            IconName::Link1 => "link-1",
+            IconName::Link2 => "link-2",
+            IconName::LinkBreak1 => "link-break-1",
+            IconName::LinkBreak2 => "link-break-2",
+            IconName::LinkNone1 => "link-none-1",
+            IconName::LinkNone2 => "link-none-2",
+            IconName::LinkedinLogo => "linkedin-logo",
+            IconName::ListBullet => "list-bullet",
+            IconName::LockClosed => "lock-closed",
+            IconName::LockOpen1 => "lock-open-1",
+            IconName::LockOpen2 => "lock-open-2",
+            IconName::Loop => "loop",
+            IconName::MagicWand => "magic-wand",
+            IconName::MagnifyingGlass => "magnifying-glass",
+            IconName::Margin => "margin",
+            IconName::MaskOff => "mask-off",
+            IconName::MaskOn => "mask-on",
+            IconName::MinusCircled => "minus-circled",
+            IconName::Minus => "minus",
+            IconName::Mix => "mix",
+            IconName::MixerHorizontal => "mixer-horizontal",
+            IconName::MixerVertical => "mixer-vertical",
+            IconName::Mobile => "mobile",
+            IconName::ModulzLogo => "modulz-logo",
+            IconName::Moon => "moon",
+            IconName::Move => "move",
+            IconName::NotionLogo => "notion-logo",
+            IconName::Opacity => "opacity",
+            IconName::OpenInNewWindow => "open-in-new-window",
+            IconName::Overline => "overline",
+            IconName::Padding => "padding",
+            IconName::PaperPlane => "paper-plane",
+            IconName::Pause => "pause",
+            IconName::Pencil1 => "pencil-1",
+            IconName::Pencil2 => "pencil-2",
+            IconName::Person => "person",
+            IconName::PieChart => "pie-chart",
+            IconName::Pilcrow => "pilcrow",
+            IconName::PinBottom => "pin-bottom",
+            IconName::PinLeft => "pin-left",
+            IconName::PinRight => "pin-right",
+            IconName::PinTop => "pin-top",
+            IconName::Play => "play",
+            IconName::PlusCircled => "plus-circled",
+            IconName::Plus => "plus",
+            IconName::QuestionMarkCircled => "question-mark-circled",
+            IconName::QuestionMark => "question-mark",
+            IconName::Quote => "quote",
+            IconName::Radiobutton => "radiobutton",
+            IconName::Reader => "reader",
+            IconName::Reload => "reload",
+            IconName::Reset => "reset",
+            IconName::Resume => "resume",
+            IconName::Rocket => "rocket",
+            IconName::RotateCounterClockwise => "rotate-counter-clockwise",
+            IconName::RowSpacing => "row-spacing",
+            IconName::Rows => "rows",
+            IconName::RulerHorizontal => "ruler-horizontal",
+            IconName::RulerSquare => "ruler-square",
+            IconName::Scissors => "scissors",
+            IconName::Section => "section",
+            IconName::SewingPinFilled => "sewing-pin-filled",
+            IconName::SewingPin => "sewing-pin",
+            IconName::Shadow => "shadow",
+            IconName::ShadowInner => "shadow-inner",
+            IconName::ShadowNone => "shadow-none",
+            IconName::ShadowOuter => "shadow-outer",
+            IconName::Share1 => "share-1",
+            IconName::Share2 => "share-2",
+            IconName::Shuffle => "shuffle",
+            IconName::Size => "size",
+            IconName::SketchLogo => "sketch-logo",
+            IconName::Slash => "slash",
+            IconName::Slider => "slider",
+            IconName::SpaceBetweenHorizontally => "space-between-horizontally",
+            IconName::SpaceBetweenVertically => "space-between-vertically",
+            IconName::SpaceEvenlyHorizontally => "space-evenly-horizontally",
+            IconName::SpaceEvenlyVertically => "space-evenly-vertically",
+            IconName::SpeakerLoud => "speaker-loud",
+            IconName::SpeakerModerate => "speaker-moderate",
+            IconName::SpeakerOff => "speaker-off",
+            IconName::SpeakerQuiet => "speaker-quiet",
+            IconName::Square => "square",
+            IconName::Stack => "stack",
+            IconName::StarFilled => "star-filled",
+            IconName::Star => "star",
+            IconName::StitchesLogo => "stitches-logo",
+            IconName::Stop => "stop",
+            IconName::Stopwatch => "stopwatch",
+            IconName::StretchHorizontally => "stretch-horizontally",
+            IconName::StretchVertically => "stretch-vertically",
+            IconName::Strikethrough => "strikethrough",
+            IconName::Sun => "sun",
+            IconName::Switch => "switch",
+            IconName::Symbol => "symbol",
+            IconName::Table => "table",
+            IconName::Target => "target",
+            IconName::TextAlignBottom => "text-align-bottom",
+            IconName::TextAlignCenter => "text-align-center",
+            IconName::TextAlignJustify => "text-align-justify",
+            IconName::TextAlignLeft => "text-align-left",
+            IconName::TextAlignMiddle => "text-align-middle",
+            IconName::TextAlignRight => "text-align-right",
+            IconName::TextAlignTop => "text-align-top",
+            IconName::Text => "text",
+            IconName::TextNone => "text-none",
+            IconName::ThickArrowDown => "thick-arrow-down",
+            IconName::ThickArrowLeft => "thick-arrow-left",
+            IconName::ThickArrowRight => "thick-arrow-right",
+            IconName::ThickArrowUp => "thick-arrow-up",
+            IconName::Timer => "timer",
+            IconName::Tokens => "tokens",
+            IconName::TrackNext => "track-next",
+            IconName::TrackPrevious => "track-previous",
+            IconName::Transform => "transform",
+            IconName::TransparencyGrid => "transparency-grid",
+            IconName::Trash => "trash",
+            IconName::TriangleDown => "triangle-down",
+            IconName::TriangleLeft => "triangle-left",
+            IconName::TriangleRight => "triangle-right",
+            IconName::TriangleUp => "triangle-up",
+            IconName::TwitterLogo => "twitter-logo",
+            IconName::Underline => "underline",
+            IconName::Update => "update",
+            IconName::Upload => "upload",
+            IconName::Value => "value",
+            IconName::ValueNone => "value-none",
+            IconName::VercelLogo => "vercel-logo",
+            IconName::Video => "video",
+            IconName::ViewGrid => "view-grid",
+            IconName::ViewHorizontal => "view-horizontal",
+            IconName::ViewNone => "view-none",
+            IconName::ViewVertical => "view-vertical",
+            IconName::Width => "width",
+            IconName::ZoomIn => "zoom-in",
+            IconName::ZoomOut => "zoom-out",
+        }
+    }
+}
+
+impl fmt::Display for IconName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing an unknown [`IconName`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseIconNameError {
+    name: String,
+}
+
+impl fmt::Display for ParseIconNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown icon `{}`", self.name)
+    }
+}
+
+impl std::error::Error for ParseIconNameError {}
+
+impl FromStr for IconName {
+    type Err = ParseIconNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "accessibility" => Ok(IconName::Accessibility),
+            "activity-log" => Ok(IconName::ActivityLog),
+            "align-baseline" => Ok(IconName::AlignBaseline),
+            "align-bottom" => Ok(IconName::AlignBottom),
+            "align-center-horizontally" => Ok(IconName::AlignCenterHorizontally),
+            "align-center-vertically" => Ok(IconName::AlignCenterVertically),
+            "align-left" => Ok(IconName::AlignLeft),
+            "align-right" => Ok(IconName::AlignRight),
+            "align-top" => Ok(IconName::AlignTop),
+            "all-sides" => Ok(IconName::AllSides),
+            "angle" => Ok(IconName::Angle),
+            "archive" => Ok(IconName::Archive),
+            "arrow-bottom-left" => Ok(IconName::ArrowBottomLeft),
+            "arrow-bottom-right" => Ok(IconName::ArrowBottomRight),
+            "arrow-down" => Ok(IconName::ArrowDown),
+            "arrow-left" => Ok(IconName::ArrowLeft),
+            "arrow-right" => Ok(IconName::ArrowRight),
+            "arrow-top-left" => Ok(IconName::ArrowTopLeft),
+            "arrow-top-right" => Ok(IconName::ArrowTopRight),
+            "arrow-up" => Ok(IconName::ArrowUp),
+            "aspect-ratio" => Ok(IconName::AspectRatio),
+            "avatar" => Ok(IconName::Avatar),
+            "backpack" => Ok(IconName::Backpack),
+            "badge" => Ok(IconName::Badge),
+            "bar-chart" => Ok(IconName::BarChart),
+            "bell" => Ok(IconName::Bell),
+            "blending-mode" => Ok(IconName::BlendingMode),
+            "bookmark-filled" => Ok(IconName::BookmarkFilled),
+            "bookmark" => Ok(IconName::Bookmark),
+            "border-all" => Ok(IconName::BorderAll),
+            "border-bottom" => Ok(IconName::BorderBottom),
+            "border-dashed" => Ok(IconName::BorderDashed),
+            "border-dotted" => Ok(IconName::BorderDotted),
+            "border-left" => Ok(IconName::BorderLeft),
+            "border-none" => Ok(IconName::BorderNone),
+            "border-right" => Ok(IconName::BorderRight),
+            "border-solid" => Ok(IconName::BorderSolid),
+            "border-split" => Ok(IconName::BorderSplit),
+            "border-style" => Ok(IconName::BorderStyle),
+            "border-top" => Ok(IconName::BorderTop),
+            "border-width" => Ok(IconName::BorderWidth),
+            "box" => Ok(IconName::Box),
+            "box-model" => Ok(IconName::BoxModel),
+            "button" => Ok(IconName::Button),
+            "calendar" => Ok(IconName::Calendar),
+            "camera" => Ok(IconName::Camera),
+            "card-stack" => Ok(IconName::CardStack),
+            "card-stack-minus" => Ok(IconName::CardStackMinus),
+            "card-stack-plus" => Ok(IconName::CardStackPlus),
+            "caret-down" => Ok(IconName::CaretDown),
+            "caret-left" => Ok(IconName::CaretLeft),
+            "caret-right" => Ok(IconName::CaretRight),
+            "caret-sort" => Ok(IconName::CaretSort),
+            "caret-up" => Ok(IconName::CaretUp),
+            "chat-bubble" => Ok(IconName::ChatBubble),
+            "check-circled" => Ok(IconName::CheckCircled),
+            "check" => Ok(IconName::Check),
+            "checkbox" => Ok(IconName::Checkbox),
+            "chevron-down" => Ok(IconName::ChevronDown),
+            "chevron-left" => Ok(IconName::ChevronLeft),
+            "chevron-right" => Ok(IconName::ChevronRight),
+            "chevron-up" => Ok(IconName::ChevronUp),
+            "circle-backslash" => Ok(IconName::CircleBackslash),
+            "circle" => Ok(IconName::Circle),
+            "clipboard-copy" => Ok(IconName::ClipboardCopy),
+            "clipboard" => Ok(IconName::Clipboard),
+            "clock" => Ok(IconName::Clock),
+            "code" => Ok(IconName::Code),
+            "codesandbox-logo" => Ok(IconName::CodesandboxLogo),
+            "color-wheel" => Ok(IconName::ColorWheel),
+            "column-spacing" => Ok(IconName::ColumnSpacing),
+            "columns" => Ok(IconName::Columns),
+            "commit" => Ok(IconName::Commit),
+            "component-1" => Ok(IconName::Component1),
+            "component-2" => Ok(IconName::Component2),
+            "component-boolean" => Ok(IconName::ComponentBoolean),
+            "component-instance" => Ok(IconName::ComponentInstance),
+            "component-none" => Ok(IconName::ComponentNone),
+            "component-placeholder" => Ok(IconName::ComponentPlaceholder),
+            "container" => Ok(IconName::Container),
+            "cookie" => Ok(IconName::Cookie),
+            "copy" => Ok(IconName::Copy),
+            "corner-bottom-left" => Ok(IconName::CornerBottomLeft),
+            "corner-bottom-right" => Ok(IconName::CornerBottomRight),
+            "corner-top-left" => Ok(IconName::CornerTopLeft),
+            "corner-top-right" => Ok(IconName::CornerTopRight),
+            "corners" => Ok(IconName::Corners),
+            "countdown-timer" => Ok(IconName::CountdownTimer),
+            "counter-clockwise-clock" => Ok(IconName::CounterClockwiseClock),
+            "crop" => Ok(IconName::Crop),
+            "cross-1" => Ok(IconName::Cross1),
+            "cross-2" => Ok(IconName::Cross2),
+            "cross-circled" => Ok(IconName::CrossCircled),
+            "crosshair-1" => Ok(IconName::Crosshair1),
+            "crosshair-2" => Ok(IconName::Crosshair2),
+            "crumpled-paper" => Ok(IconName::CrumpledPaper),
+            "cube" => Ok(IconName::Cube),
+            "cursor-arrow" => Ok(IconName::CursorArrow),
+            "cursor-text" => Ok(IconName::CursorText),
+            "dash" => Ok(IconName::Dash),
+            "dashboard" => Ok(IconName::Dashboard),
+            "desktop" => Ok(IconName::Desktop),
+            "dimensions" => Ok(IconName::Dimensions),
+            "disc" => Ok(IconName::Disc),
+            "discord-logo" => Ok(IconName::DiscordLogo),
+            "divider-horizontal" => Ok(IconName::DividerHorizontal),
+            "divider-vertical" => Ok(IconName::DividerVertical),
+            "dot-filled" => Ok(IconName::DotFilled),
+            "dot" => Ok(IconName::Dot),
+            "dots-horizontal" => Ok(IconName::DotsHorizontal),
+            "dots-vertical" => Ok(IconName::DotsVertical),
+            "double-arrow-down" => Ok(IconName::DoubleArrowDown),
+            "double-arrow-left" => Ok(IconName::DoubleArrowLeft),
+            "double-arrow-right" => Ok(IconName::DoubleArrowRight),
+            "double-arrow-up" => Ok(IconName::DoubleArrowUp),
+            "download" => Ok(IconName::Download),
+            "drag-handle-dots-1" => Ok(IconName::DragHandleDots1),
+            "drag-handle-dots-2" => Ok(IconName::DragHandleDots2),
+            "drag-handle-horizontal" => Ok(IconName::DragHandleHorizontal),
+            "drag-handle-vertical" => Ok(IconName::DragHandleVertical),
+            "drawing-pin-filled" => Ok(IconName::DrawingPinFilled),
+            "drawing-pin" => Ok(IconName::DrawingPin),
+            "dropdown-menu" => Ok(IconName::DropdownMenu),
+            "enter-full-screen" => Ok(IconName::EnterFullScreen),
+            "enter" => Ok(IconName::Enter),
+            "envelope-closed" => Ok(IconName::EnvelopeClosed),
+            "envelope-open" => Ok(IconName::EnvelopeOpen),
+            "eraser" => Ok(IconName::Eraser),
+            "exclamation-triangle" => Ok(IconName::ExclamationTriangle),
+            "exit-full-screen" => Ok(IconName::ExitFullScreen),
+            "exit" => Ok(IconName::Exit),
+            "external-link" => Ok(IconName::ExternalLink),
+            "eye-closed" => Ok(IconName::EyeClosed),
+            "eye-none" => Ok(IconName::EyeNone),
+            "eye-open" => Ok(IconName::EyeOpen),
+            "face" => Ok(IconName::Face),
+            "figma-logo" => Ok(IconName::FigmaLogo),
+            "file" => Ok(IconName::File),
+            "file-minus" => Ok(IconName::FileMinus),
+            "file-plus" => Ok(IconName::FilePlus),
+            "file-text" => Ok(IconName::FileText),
+            "font-bold" => Ok(IconName::FontBold),
+            "font-family" => Ok(IconName::FontFamily),
+            "font-italic" => Ok(IconName::FontItalic),
+            "font-roman" => Ok(IconName::FontRoman),
+            "font-size" => Ok(IconName::FontSize),
+            "font-style" => Ok(IconName::FontStyle),
+            "frame" => Ok(IconName::Frame),
+            "framer-logo" => Ok(IconName::FramerLogo),
+            "gear" => Ok(IconName::Gear),
+            "github-logo" => Ok(IconName::GithubLogo),
+            "globe" => Ok(IconName::Globe),
+            "grid" => Ok(IconName::Grid),
+            "group" => Ok(IconName::Group),
+            "half-1" => Ok(IconName::Half1),
+            "half-2" => Ok(IconName::Half2),
+            "hamburger-menu" => Ok(IconName::HamburgerMenu),
+            "hand" => Ok(IconName::Hand),
+            "heading" => Ok(IconName::Heading),
+            "heart-filled" => Ok(IconName::HeartFilled),
+            "heart" => Ok(IconName::Heart),
+            "height" => Ok(IconName::Height),
+            "hobby-knife" => Ok(IconName::HobbyKnife),
+            "home" => Ok(IconName::Home),
+            "iconjar-logo" => Ok(IconName::IconjarLogo),
+            "id-card" => Ok(IconName::IdCard),
+            "image" => Ok(IconName::Image),
+            "info-circled" => Ok(IconName::InfoCircled),
+            "input" => Ok(IconName::Input),
+            "instagram-logo" => Ok(IconName::InstagramLogo),
+            "keyboard" => Ok(IconName::Keyboard),
+            "lap-timer" => Ok(IconName::LapTimer),
+            "laptop" => Ok(IconName::Laptop),
+            "layers" => Ok(IconName::Layers),
+            "layout" => Ok(IconName::Layout),
+            "letter-case-capitalize" => Ok(IconName::LetterCaseCapitalize),
+            "letter-case-lowercase" => Ok(IconName::LetterCaseLowercase),
+            "letter-case-toggle" => Ok(IconName::LetterCaseToggle),
+            "letter-case-uppercase" => Ok(IconName::LetterCaseUppercase),
+            "letter-spacing" => Ok(IconName::LetterSpacing),
+            "lightning-bolt" => Ok(IconName::LightningBolt),
+            "line-height" => Ok(IconName::LineHeight),
+            "link-1" => Ok(IconName::Link1),
+            "link-2" => Ok(IconName::Link2),
+            "link-break-1" => Ok(IconName::LinkBreak1),
+            "link-break-2" => Ok(IconName::LinkBreak2),
+            "link-none-1" => Ok(IconName::LinkNone1),
+            "link-none-2" => Ok(IconName::LinkNone2),
+            "linkedin-logo" => Ok(IconName::LinkedinLogo),
+            "list-bullet" => Ok(IconName::ListBullet),
+            "lock-closed" => Ok(IconName::LockClosed),
+            "lock-open-1" => Ok(IconName::LockOpen1),
+            "lock-open-2" => Ok(IconName::LockOpen2),
+            "loop" => Ok(IconName::Loop),
+            "magic-wand" => Ok(IconName::MagicWand),
+            "magnifying-glass" => Ok(IconName::MagnifyingGlass),
+            "margin" => Ok(IconName::Margin),
+            "mask-off" => Ok(IconName::MaskOff),
+            "mask-on" => Ok(IconName::MaskOn),
+            "minus-circled" => Ok(IconName::MinusCircled),
+            "minus" => Ok(IconName::Minus),
+            "mix" => Ok(IconName::Mix),
+            "mixer-horizontal" => Ok(IconName::MixerHorizontal),
+            "mixer-vertical" => Ok(IconName::MixerVertical),
+            "mobile" => Ok(IconName::Mobile),
+            "modulz-logo" => Ok(IconName::ModulzLogo),
+            "moon" => Ok(IconName::Moon),
+            "move" => Ok(IconName::Move),
+            "notion-logo" => Ok(IconName::NotionLogo),
+            "opacity" => Ok(IconName::Opacity),
+            "open-in-new-window" => Ok(IconName::OpenInNewWindow),
+            "overline" => Ok(IconName::Overline),
+            "padding" => Ok(IconName::Padding),
+            "paper-plane" => Ok(IconName::PaperPlane),
+            "pause" => Ok(IconName::Pause),
+            "pencil-1" => Ok(IconName::Pencil1),
+            "pencil-2" => Ok(IconName::Pencil2),
+            "person" => Ok(IconName::Person),
+            "pie-chart" => Ok(IconName::PieChart),
+            "pilcrow" => Ok(IconName::Pilcrow),
+            "pin-bottom" => Ok(IconName::PinBottom),
+            "pin-left" => Ok(IconName::PinLeft),
+            "pin-right" => Ok(IconName::PinRight),
+            "pin-top" => Ok(IconName::PinTop),
+            "play" => Ok(IconName::Play),
+            "plus-circled" => Ok(IconName::PlusCircled),
+            "plus" => Ok(IconName::Plus),
+            "question-mark-circled" => Ok(IconName::QuestionMarkCircled),
+            "question-mark" => Ok(IconName::QuestionMark),
+            "quote" => Ok(IconName::Quote),
+            "radiobutton" => Ok(IconName::Radiobutton),
+            "reader" => Ok(IconName::Reader),
+            "reload" => Ok(IconName::Reload),
+            "reset" => Ok(IconName::Reset),
+            "resume" => Ok(IconName::Resume),
+            "rocket" => Ok(IconName::Rocket),
+            "rotate-counter-clockwise" => Ok(IconName::RotateCounterClockwise),
+            "row-spacing" => Ok(IconName::RowSpacing),
+            "rows" => Ok(IconName::Rows),
+            "ruler-horizontal" => Ok(IconName::RulerHorizontal),
+            "ruler-square" => Ok(IconName::RulerSquare),
+            "scissors" => Ok(IconName::Scissors),
+            "section" => Ok(IconName::Section),
+            "sewing-pin-filled" => Ok(IconName::SewingPinFilled),
+            "sewing-pin" => Ok(IconName::SewingPin),
+            "shadow" => Ok(IconName::Shadow),
+            "shadow-inner" => Ok(IconName::ShadowInner),
+            "shadow-none" => Ok(IconName::ShadowNone),
+            "shadow-outer" => Ok(IconName::ShadowOuter),
+            "share-1" => Ok(IconName::Share1),
+            "share-2" => Ok(IconName::Share2),
+            "shuffle" => Ok(IconName::Shuffle),
+            "size" => Ok(IconName::Size),
+            "sketch-logo" => Ok(IconName::SketchLogo),
+            "slash" => Ok(IconName::Slash),
+            "slider" => Ok(IconName::Slider),
+            "space-between-horizontally" => Ok(IconName::SpaceBetweenHorizontally),
+            "space-between-vertically" => Ok(IconName::SpaceBetweenVertically),
+            "space-evenly-horizontally" => Ok(IconName::SpaceEvenlyHorizontally),
+            "space-evenly-vertically" => Ok(IconName::SpaceEvenlyVertically),
+            "speaker-loud" => Ok(IconName::SpeakerLoud),
+            "speaker-moderate" => Ok(IconName::SpeakerModerate),
+            "speaker-off" => Ok(IconName::SpeakerOff),
+            "speaker-quiet" => Ok(IconName::SpeakerQuiet),
+            "square" => Ok(IconName::Square),
+            "stack" => Ok(IconName::Stack),
+            "star-filled" => Ok(IconName::StarFilled),
+            "star" => Ok(IconName::Star),
+            "stitches-logo" => Ok(IconName::StitchesLogo),
+            "stop" => Ok(IconName::Stop),
+            "stopwatch" => Ok(IconName::Stopwatch),
+            "stretch-horizontally" => Ok(IconName::StretchHorizontally),
+            "stretch-vertically" => Ok(IconName::StretchVertically),
+            "strikethrough" => Ok(IconName::Strikethrough),
+            "sun" => Ok(IconName::Sun),
+            "switch" => Ok(IconName::Switch),
+            "symbol" => Ok(IconName::Symbol),
+            "table" => Ok(IconName::Table),
+            "target" => Ok(IconName::Target),
+            "text-align-bottom" => Ok(IconName::TextAlignBottom),
+            "text-align-center" => Ok(IconName::TextAlignCenter),
+            "text-align-justify" => Ok(IconName::TextAlignJustify),
+            "text-align-left" => Ok(IconName::TextAlignLeft),
+            "text-align-middle" => Ok(IconName::TextAlignMiddle),
+            "text-align-right" => Ok(IconName::TextAlignRight),
+            "text-align-top" => Ok(IconName::TextAlignTop),
+            "text" => Ok(IconName::Text),
+            "text-none" => Ok(IconName::TextNone),
+            "thick-arrow-down" => Ok(IconName::ThickArrowDown),
+            "thick-arrow-left" => Ok(IconName::ThickArrowLeft),
+            "thick-arrow-right" => Ok(IconName::ThickArrowRight),
+            "thick-arrow-up" => Ok(IconName::ThickArrowUp),
+            "timer" => Ok(IconName::Timer),
+            "tokens" => Ok(IconName::Tokens),
+            "track-next" => Ok(IconName::TrackNext),
+            "track-previous" => Ok(IconName::TrackPrevious),
+            "transform" => Ok(IconName::Transform),
+            "transparency-grid" => Ok(IconName::TransparencyGrid),
+            "trash" => Ok(IconName::Trash),
+            "triangle-down" => Ok(IconName::TriangleDown),
+            "triangle-left" => Ok(IconName::TriangleLeft),
+            "triangle-right" => Ok(IconName::TriangleRight),
+            "triangle-up" => Ok(IconName::TriangleUp),
+            "twitter-logo" => Ok(IconName::TwitterLogo),
+            "underline" => Ok(IconName::Underline),
+            "update" => Ok(IconName::Update),
+            "upload" => Ok(IconName::Upload),
+            "value" => Ok(IconName::Value),
+            "value-none" => Ok(IconName::ValueNone),
+            "vercel-logo" => Ok(IconName::VercelLogo),
+            "video" => Ok(IconName::Video),
+            "view-grid" => Ok(IconName::ViewGrid),
+            "view-horizontal" => Ok(IconName::ViewHorizontal),
+            "view-none" => Ok(IconName::ViewNone),
+            "view-vertical" => Ok(IconName::ViewVertical),
+            "width" => Ok(IconName::Width),
+            "zoom-in" => Ok(IconName::ZoomIn),
+            "zoom-out" => Ok(IconName::ZoomOut),
+            _ => Err(ParseIconNameError { name: s.into() }),
+        }
+    }
+}
+
+/// Renders the icon named `name`.
+#[cfg(not(feature = "sprite"))]
+#[component]
+pub fn Icon(
+    #[prop(into)] name: Signal<IconName>,
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    move || match name.get() {
+        IconName::Accessibility => view! {
+            <AccessibilityIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ActivityLog => view! {
+            <ActivityLogIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignBaseline => view! {
+            <AlignBaselineIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignBottom => view! {
+            <AlignBottomIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignCenterHorizontally => view! {
+            <AlignCenterHorizontallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignCenterVertically => view! {
+            <AlignCenterVerticallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignLeft => view! {
+            <AlignLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignRight => view! {
+            <AlignRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AlignTop => view! {
+            <AlignTopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AllSides => view! {
+            <AllSidesIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Angle => view! {
+            <AngleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Archive => view! {
+            <ArchiveIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowBottomLeft => view! {
+            <ArrowBottomLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowBottomRight => view! {
+            <ArrowBottomRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowDown => view! {
+            <ArrowDownIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowLeft => view! {
+            <ArrowLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowRight => view! {
+            <ArrowRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowTopLeft => view! {
+            <ArrowTopLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowTopRight => view! {
+            <ArrowTopRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ArrowUp => view! {
+            <ArrowUpIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::AspectRatio => view! {
+            <AspectRatioIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Avatar => view! {
+            <AvatarIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Backpack => view! {
+            <BackpackIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Badge => view! {
+            <BadgeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BarChart => view! {
+            <BarChartIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Bell => view! {
+            <BellIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BlendingMode => view! {
+            <BlendingModeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BookmarkFilled => view! {
+            <BookmarkFilledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Bookmark => view! {
+            <BookmarkIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderAll => view! {
+            <BorderAllIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderBottom => view! {
+            <BorderBottomIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderDashed => view! {
+            <BorderDashedIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderDotted => view! {
+            <BorderDottedIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderLeft => view! {
+            <BorderLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderNone => view! {
+            <BorderNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderRight => view! {
+            <BorderRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderSolid => view! {
+            <BorderSolidIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderSplit => view! {
+            <BorderSplitIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderStyle => view! {
+            <BorderStyleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderTop => view! {
+            <BorderTopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BorderWidth => view! {
+            <BorderWidthIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Box => view! {
+            <BoxIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::BoxModel => view! {
+            <BoxModelIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Button => view! {
+            <ButtonIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Calendar => view! {
+            <CalendarIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Camera => view! {
+            <CameraIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CardStack => view! {
+            <CardStackIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CardStackMinus => view! {
+            <CardStackMinusIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CardStackPlus => view! {
+            <CardStackPlusIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CaretDown => view! {
+            <CaretDownIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CaretLeft => view! {
+            <CaretLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CaretRight => view! {
+            <CaretRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CaretSort => view! {
+            <CaretSortIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CaretUp => view! {
+            <CaretUpIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ChatBubble => view! {
+            <ChatBubbleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CheckCircled => view! {
+            <CheckCircledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Check => view! {
+            <CheckIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Checkbox => view! {
+            <CheckboxIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ChevronDown => view! {
+            <ChevronDownIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ChevronLeft => view! {
+            <ChevronLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ChevronRight => view! {
+            <ChevronRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ChevronUp => view! {
+            <ChevronUpIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CircleBackslash => view! {
+            <CircleBackslashIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Circle => view! {
+            <CircleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ClipboardCopy => view! {
+            <ClipboardCopyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Clipboard => view! {
+            <ClipboardIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Clock => view! {
+            <ClockIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Code => view! {
+            <CodeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CodesandboxLogo => view! {
+            <CodesandboxLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ColorWheel => view! {
+            <ColorWheelIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ColumnSpacing => view! {
+            <ColumnSpacingIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Columns => view! {
+            <ColumnsIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Commit => view! {
+            <CommitIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Component1 => view! {
+            <Component1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Component2 => view! {
+            <Component2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ComponentBoolean => view! {
+            <ComponentBooleanIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ComponentInstance => view! {
+            <ComponentInstanceIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ComponentNone => view! {
+            <ComponentNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ComponentPlaceholder => view! {
+            <ComponentPlaceholderIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Container => view! {
+            <ContainerIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Cookie => view! {
+            <CookieIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Copy => view! {
+            <CopyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CornerBottomLeft => view! {
+            <CornerBottomLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CornerBottomRight => view! {
+            <CornerBottomRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CornerTopLeft => view! {
+            <CornerTopLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CornerTopRight => view! {
+            <CornerTopRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Corners => view! {
+            <CornersIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CountdownTimer => view! {
+            <CountdownTimerIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CounterClockwiseClock => view! {
+            <CounterClockwiseClockIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Crop => view! {
+            <CropIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Cross1 => view! {
+            <Cross1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Cross2 => view! {
+            <Cross2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CrossCircled => view! {
+            <CrossCircledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Crosshair1 => view! {
+            <Crosshair1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Crosshair2 => view! {
+            <Crosshair2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CrumpledPaper => view! {
+            <CrumpledPaperIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Cube => view! {
+            <CubeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CursorArrow => view! {
+            <CursorArrowIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::CursorText => view! {
+            <CursorTextIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Dash => view! {
+            <DashIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Dashboard => view! {
+            <DashboardIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Desktop => view! {
+            <DesktopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Dimensions => view! {
+            <DimensionsIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Disc => view! {
+            <DiscIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DiscordLogo => view! {
+            <DiscordLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DividerHorizontal => view! {
+            <DividerHorizontalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DividerVertical => view! {
+            <DividerVerticalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DotFilled => view! {
+            <DotFilledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Dot => view! {
+            <DotIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DotsHorizontal => view! {
+            <DotsHorizontalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DotsVertical => view! {
+            <DotsVerticalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DoubleArrowDown => view! {
+            <DoubleArrowDownIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DoubleArrowLeft => view! {
+            <DoubleArrowLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DoubleArrowRight => view! {
+            <DoubleArrowRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DoubleArrowUp => view! {
+            <DoubleArrowUpIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Download => view! {
+            <DownloadIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DragHandleDots1 => view! {
+            <DragHandleDots1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DragHandleDots2 => view! {
+            <DragHandleDots2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DragHandleHorizontal => view! {
+            <DragHandleHorizontalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DragHandleVertical => view! {
+            <DragHandleVerticalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DrawingPinFilled => view! {
+            <DrawingPinFilledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DrawingPin => view! {
+            <DrawingPinIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::DropdownMenu => view! {
+            <DropdownMenuIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::EnterFullScreen => view! {
+            <EnterFullScreenIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Enter => view! {
+            <EnterIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::EnvelopeClosed => view! {
+            <EnvelopeClosedIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::EnvelopeOpen => view! {
+            <EnvelopeOpenIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Eraser => view! {
+            <EraserIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ExclamationTriangle => view! {
+            <ExclamationTriangleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ExitFullScreen => view! {
+            <ExitFullScreenIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Exit => view! {
+            <ExitIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ExternalLink => view! {
+            <ExternalLinkIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::EyeClosed => view! {
+            <EyeClosedIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::EyeNone => view! {
+            <EyeNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::EyeOpen => view! {
+            <EyeOpenIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Face => view! {
+            <FaceIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FigmaLogo => view! {
+            <FigmaLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::File => view! {
+            <FileIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FileMinus => view! {
+            <FileMinusIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FilePlus => view! {
+            <FilePlusIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FileText => view! {
+            <FileTextIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FontBold => view! {
+            <FontBoldIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FontFamily => view! {
+            <FontFamilyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FontItalic => view! {
+            <FontItalicIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FontRoman => view! {
+            <FontRomanIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FontSize => view! {
+            <FontSizeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FontStyle => view! {
+            <FontStyleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Frame => view! {
+            <FrameIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::FramerLogo => view! {
+            <FramerLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Gear => view! {
+            <GearIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::GithubLogo => view! {
+            <GithubLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Globe => view! {
+            <GlobeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Grid => view! {
+            <GridIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Group => view! {
+            <GroupIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Half1 => view! {
+            <Half1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Half2 => view! {
+            <Half2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::HamburgerMenu => view! {
+            <HamburgerMenuIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Hand => view! {
+            <HandIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Heading => view! {
+            <HeadingIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::HeartFilled => view! {
+            <HeartFilledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Heart => view! {
+            <HeartIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Height => view! {
+            <HeightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::HobbyKnife => view! {
+            <HobbyKnifeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Home => view! {
+            <HomeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::IconjarLogo => view! {
+            <IconjarLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::IdCard => view! {
+            <IdCardIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Image => view! {
+            <ImageIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::InfoCircled => view! {
+            <InfoCircledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Input => view! {
+            <InputIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::InstagramLogo => view! {
+            <InstagramLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Keyboard => view! {
+            <KeyboardIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LapTimer => view! {
+            <LapTimerIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Laptop => view! {
+            <LaptopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Layers => view! {
+            <LayersIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Layout => view! {
+            <LayoutIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LetterCaseCapitalize => view! {
+            <LetterCaseCapitalizeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LetterCaseLowercase => view! {
+            <LetterCaseLowercaseIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LetterCaseToggle => view! {
+            <LetterCaseToggleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LetterCaseUppercase => view! {
+            <LetterCaseUppercaseIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LetterSpacing => view! {
+            <LetterSpacingIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LightningBolt => view! {
+            <LightningBoltIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LineHeight => view! {
+            <LineHeightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Link1 => view! {
+            <Link1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Link2 => view! {
+            <Link2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LinkBreak1 => view! {
+            <LinkBreak1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LinkBreak2 => view! {
+            <LinkBreak2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LinkNone1 => view! {
+            <LinkNone1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LinkNone2 => view! {
+            <LinkNone2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LinkedinLogo => view! {
+            <LinkedinLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ListBullet => view! {
+            <ListBulletIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LockClosed => view! {
+            <LockClosedIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LockOpen1 => view! {
+            <LockOpen1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::LockOpen2 => view! {
+            <LockOpen2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Loop => view! {
+            <LoopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MagicWand => view! {
+            <MagicWandIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MagnifyingGlass => view! {
+            <MagnifyingGlassIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Margin => view! {
+            <MarginIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MaskOff => view! {
+            <MaskOffIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MaskOn => view! {
+            <MaskOnIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MinusCircled => view! {
+            <MinusCircledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Minus => view! {
+            <MinusIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Mix => view! {
+            <MixIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MixerHorizontal => view! {
+            <MixerHorizontalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::MixerVertical => view! {
+            <MixerVerticalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Mobile => view! {
+            <MobileIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ModulzLogo => view! {
+            <ModulzLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Moon => view! {
+            <MoonIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Move => view! {
+            <MoveIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::NotionLogo => view! {
+            <NotionLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Opacity => view! {
+            <OpacityIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::OpenInNewWindow => view! {
+            <OpenInNewWindowIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Overline => view! {
+            <OverlineIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Padding => view! {
+            <PaddingIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PaperPlane => view! {
+            <PaperPlaneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Pause => view! {
+            <PauseIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Pencil1 => view! {
+            <Pencil1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Pencil2 => view! {
+            <Pencil2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Person => view! {
+            <PersonIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PieChart => view! {
+            <PieChartIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Pilcrow => view! {
+            <PilcrowIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PinBottom => view! {
+            <PinBottomIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PinLeft => view! {
+            <PinLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PinRight => view! {
+            <PinRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PinTop => view! {
+            <PinTopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Play => view! {
+            <PlayIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::PlusCircled => view! {
+            <PlusCircledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Plus => view! {
+            <PlusIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::QuestionMarkCircled => view! {
+            <QuestionMarkCircledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::QuestionMark => view! {
+            <QuestionMarkIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Quote => view! {
+            <QuoteIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Radiobutton => view! {
+            <RadiobuttonIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Reader => view! {
+            <ReaderIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Reload => view! {
+            <ReloadIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Reset => view! {
+            <ResetIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Resume => view! {
+            <ResumeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Rocket => view! {
+            <RocketIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::RotateCounterClockwise => view! {
+            <RotateCounterClockwiseIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::RowSpacing => view! {
+            <RowSpacingIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Rows => view! {
+            <RowsIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::RulerHorizontal => view! {
+            <RulerHorizontalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::RulerSquare => view! {
+            <RulerSquareIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Scissors => view! {
+            <ScissorsIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Section => view! {
+            <SectionIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SewingPinFilled => view! {
+            <SewingPinFilledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SewingPin => view! {
+            <SewingPinIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Shadow => view! {
+            <ShadowIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ShadowInner => view! {
+            <ShadowInnerIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ShadowNone => view! {
+            <ShadowNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ShadowOuter => view! {
+            <ShadowOuterIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Share1 => view! {
+            <Share1Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Share2 => view! {
+            <Share2Icon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Shuffle => view! {
+            <ShuffleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Size => view! {
+            <SizeIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SketchLogo => view! {
+            <SketchLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Slash => view! {
+            <SlashIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Slider => view! {
+            <SliderIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpaceBetweenHorizontally => view! {
+            <SpaceBetweenHorizontallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpaceBetweenVertically => view! {
+            <SpaceBetweenVerticallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpaceEvenlyHorizontally => view! {
+            <SpaceEvenlyHorizontallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpaceEvenlyVertically => view! {
+            <SpaceEvenlyVerticallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpeakerLoud => view! {
+            <SpeakerLoudIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpeakerModerate => view! {
+            <SpeakerModerateIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpeakerOff => view! {
+            <SpeakerOffIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::SpeakerQuiet => view! {
+            <SpeakerQuietIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Square => view! {
+            <SquareIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Stack => view! {
+            <StackIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::StarFilled => view! {
+            <StarFilledIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Star => view! {
+            <StarIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::StitchesLogo => view! {
+            <StitchesLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Stop => view! {
+            <StopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Stopwatch => view! {
+            <StopwatchIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::StretchHorizontally => view! {
+            <StretchHorizontallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::StretchVertically => view! {
+            <StretchVerticallyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Strikethrough => view! {
+            <StrikethroughIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Sun => view! {
+            <SunIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Switch => view! {
+            <SwitchIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Symbol => view! {
+            <SymbolIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Table => view! {
+            <TableIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Target => view! {
+            <TargetIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignBottom => view! {
+            <TextAlignBottomIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignCenter => view! {
+            <TextAlignCenterIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignJustify => view! {
+            <TextAlignJustifyIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignLeft => view! {
+            <TextAlignLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignMiddle => view! {
+            <TextAlignMiddleIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignRight => view! {
+            <TextAlignRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextAlignTop => view! {
+            <TextAlignTopIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Text => view! {
+            <TextIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TextNone => view! {
+            <TextNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ThickArrowDown => view! {
+            <ThickArrowDownIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ThickArrowLeft => view! {
+            <ThickArrowLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ThickArrowRight => view! {
+            <ThickArrowRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ThickArrowUp => view! {
+            <ThickArrowUpIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Timer => view! {
+            <TimerIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Tokens => view! {
+            <TokensIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TrackNext => view! {
+            <TrackNextIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TrackPrevious => view! {
+            <TrackPreviousIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Transform => view! {
+            <TransformIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TransparencyGrid => view! {
+            <TransparencyGridIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Trash => view! {
+            <TrashIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TriangleDown => view! {
+            <TriangleDownIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TriangleLeft => view! {
+            <TriangleLeftIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TriangleRight => view! {
+            <TriangleRightIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TriangleUp => view! {
+            <TriangleUpIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::TwitterLogo => view! {
+            <TwitterLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Underline => view! {
+            <UnderlineIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Update => view! {
+            <UpdateIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Upload => view! {
+            <UploadIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Value => view! {
+            <ValueIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ValueNone => view! {
+            <ValueNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::VercelLogo => view! {
+            <VercelLogoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Video => view! {
+            <VideoIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ViewGrid => view! {
+            <ViewGridIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ViewHorizontal => view! {
+            <ViewHorizontalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ViewNone => view! {
+            <ViewNoneIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ViewVertical => view! {
+            <ViewVerticalIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::Width => view! {
+            <WidthIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ZoomIn => view! {
+            <ZoomInIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+        IconName::ZoomOut => view! {
+            <ZoomOutIcon
+                width=width
+                height=height
+                color=color
+                class=class.clone()
+                label=label
+                node_ref=node_ref
+            />
+        }
+        .into_any(),
+    }
+}
+
+/// Renders the icon named `name`.
+#[cfg(feature = "sprite")]
+#[component]
+pub fn Icon(
+    #[prop(into)] name: Signal<IconName>,
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    view! {
+        <SpriteIcon
+            id=Signal::derive(move || name.get().as_str())
+            width=width
+            height=height
+            color=color
+            class=class
+            label=label
+            node_ref=node_ref
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        for name in ALL_ICONS {
+            assert_eq!(name.as_str().parse(), Ok(name));
+            assert_eq!(name.to_string(), name.as_str());
+        }
+    }
+
+    #[test]
+    fn unknown_name_fails_to_parse() {
+        let error = "not-an-icon".parse::<IconName>().unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown icon `not-an-icon`");
+    }
+
+    #[test]
+    fn all_icons_are_sorted_and_unique() {
+        assert!(ALL_ICONS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(IconName::iter().eq(ALL_ICONS));
+    }
+}
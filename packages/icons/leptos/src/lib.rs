@@ -3,6 +3,18 @@
 //! Radix Icons is a crisp set of 15x15 icons.
 //!
 //! See [the Cardo UI book](https://cardo-ui.dev/icons/index.html) for more documenation.
+//!
+//! Each icon is a component (`CheckIcon`, `GearIcon`, ...). To pick icons at runtime,
+//! use [`Icon`] with an [`IconName`], which parses from and displays as the kebab-case
+//! icon name (`"arrow-up"`); [`ALL_ICONS`] lists every name.
+//!
+//! Icons are decorative (`aria-hidden="true"`) unless given a `label`, which adds
+//! `role="img"` and a `<title>`. They accept a `class` (`ClassProp`) and any other
+//! attribute, e.g. `<CheckIcon class="size-4" attr:data-state="on" />`.
+//!
+//! With the `sprite` feature, icons render as `<svg><use href="#cardo-icon-check" /></svg>`
+//! instead of inline paths, which keeps the WASM bundle small. Render [`IconSprite`] once to
+//! inline the sprite sheet ([`SPRITE`]) the icons refer to.
 
 mod accessibility_icon;
 mod activity_log_icon;
@@ -168,6 +180,7 @@ mod heart_icon;
 mod height_icon;
 mod hobby_knife_icon;
 mod home_icon;
+mod icon;
 mod iconjar_logo_icon;
 mod id_card_icon;
 mod image_icon;
@@ -268,6 +281,7 @@ mod speaker_loud_icon;
 mod speaker_moderate_icon;
 mod speaker_off_icon;
 mod speaker_quiet_icon;
+mod sprite;
 mod square_icon;
 mod stack_icon;
 mod star_filled_icon;
@@ -487,6 +501,7 @@ pub use heart_icon::*;
 pub use height_icon::*;
 pub use hobby_knife_icon::*;
 pub use home_icon::*;
+pub use icon::*;
 pub use iconjar_logo_icon::*;
 pub use id_card_icon::*;
 pub use image_icon::*;
@@ -587,6 +602,7 @@ pub use speaker_loud_icon::*;
 pub use speaker_moderate_icon::*;
 pub use speaker_off_icon::*;
 pub use speaker_quiet_icon::*;
+pub use sprite::*;
 pub use square_icon::*;
 pub use stack_icon::*;
 pub use star_filled_icon::*;
@@ -0,0 +1,44 @@
+use cardo_ui_class::ClassProp;
+use leptos::{attribute_interceptor::AttributeInterceptor, prelude::*, svg::Svg};
+/// The SVG sprite sheet, with a `<symbol>` per icon whose `id` is `cardo-icon-<name>`.
+pub const SPRITE: &str = include_str!("sprite.svg");
+/// Inlines the sprite sheet once, for icons rendered with [`SpriteIcon`].
+///
+/// Render it once near the root of the app, e.g. at the top of `<body>`.
+#[component]
+pub fn IconSprite() -> impl IntoView {
+    view! { <div style="display: none" aria-hidden="true" inner_html=SPRITE></div> }
+}
+/// Renders the icon named `id` (e.g. `"check"`) from the sprite sheet inlined by
+/// [`IconSprite`].
+#[component]
+pub fn SpriteIcon(
+    #[prop(into)] id: Signal<&'static str>,
+    #[prop(default = 15.into(), into)] width: Signal<usize>,
+    #[prop(default = 15.into(), into)] height: Signal<usize>,
+    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+    #[prop(into, optional)] class: ClassProp,
+    #[prop(into, optional)] label: MaybeProp<String>,
+    #[prop(optional)] node_ref: NodeRef<Svg>,
+) -> impl IntoView {
+    let class = Signal::derive(move || class.get().map(|class| class.0));
+    view! {
+        <AttributeInterceptor let:attrs>
+            <svg
+                node_ref=node_ref
+                class=class
+                role=move || label.get().map(|_| "img")
+                aria-hidden=move || label.get().is_none().then_some("true")
+                {..attrs}
+                width=width
+                height=height
+                viewBox="0 0 15 15"
+                color=color
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                {move || label.get().map(|label| view! { <title>{label}</title> })}
+                <use href=move || format!("#cardo-icon-{}", id.get()) />
+            </svg>
+        </AttributeInterceptor>
+    }
+}
@@ -1,29 +0,0 @@
-pub const BLACK_A: [&str; 12] = [
-    "rgba(0, 0, 0, 0.05)",
-    "rgba(0, 0, 0, 0.1)",
-    "rgba(0, 0, 0, 0.15)",
-    "rgba(0, 0, 0, 0.2)",
-    "rgba(0, 0, 0, 0.3)",
-    "rgba(0, 0, 0, 0.4)",
-    "rgba(0, 0, 0, 0.5)",
-    "rgba(0, 0, 0, 0.6)",
-    "rgba(0, 0, 0, 0.7)",
-    "rgba(0, 0, 0, 0.8)",
-    "rgba(0, 0, 0, 0.9)",
-    "rgba(0, 0, 0, 0.95)",
-];
-
-pub const BLACK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0.05)",
-    "color(display-p3 0 0 0 / 0.1)",
-    "color(display-p3 0 0 0 / 0.15)",
-    "color(display-p3 0 0 0 / 0.2)",
-    "color(display-p3 0 0 0 / 0.3)",
-    "color(display-p3 0 0 0 / 0.4)",
-    "color(display-p3 0 0 0 / 0.5)",
-    "color(display-p3 0 0 0 / 0.6)",
-    "color(display-p3 0 0 0 / 0.7)",
-    "color(display-p3 0 0 0 / 0.8)",
-    "color(display-p3 0 0 0 / 0.9)",
-    "color(display-p3 0 0 0 / 0.95)",
-];
@@ -1,1549 +0,0 @@
-pub const GRAY_DARK: [&str; 12] = [
-    "#111111", "#191919", "#222222", "#2a2a2a", "#313131", "#3a3a3a", "#484848", "#606060",
-    "#6e6e6e", "#7b7b7b", "#b4b4b4", "#eeeeee",
-];
-
-pub const GRAY_DARK_A: [&str; 12] = [
-    "#00000000",
-    "#ffffff09",
-    "#ffffff12",
-    "#ffffff1b",
-    "#ffffff22",
-    "#ffffff2c",
-    "#ffffff3b",
-    "#ffffff55",
-    "#ffffff64",
-    "#ffffff72",
-    "#ffffffaf",
-    "#ffffffed",
-];
-
-pub const GRAY_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.067 0.067 0.067)",
-    "color(display-p3 0.098 0.098 0.098)",
-    "color(display-p3 0.135 0.135 0.135)",
-    "color(display-p3 0.163 0.163 0.163)",
-    "color(display-p3 0.192 0.192 0.192)",
-    "color(display-p3 0.228 0.228 0.228)",
-    "color(display-p3 0.283 0.283 0.283)",
-    "color(display-p3 0.375 0.375 0.375)",
-    "color(display-p3 0.431 0.431 0.431)",
-    "color(display-p3 0.484 0.484 0.484)",
-    "color(display-p3 0.706 0.706 0.706)",
-    "color(display-p3 0.933 0.933 0.933)",
-];
-
-pub const GRAY_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0)",
-    "color(display-p3 1 1 1 / 0.034)",
-    "color(display-p3 1 1 1 / 0.071)",
-    "color(display-p3 1 1 1 / 0.105)",
-    "color(display-p3 1 1 1 / 0.134)",
-    "color(display-p3 1 1 1 / 0.172)",
-    "color(display-p3 1 1 1 / 0.231)",
-    "color(display-p3 1 1 1 / 0.332)",
-    "color(display-p3 1 1 1 / 0.391)",
-    "color(display-p3 1 1 1 / 0.445)",
-    "color(display-p3 1 1 1 / 0.685)",
-    "color(display-p3 1 1 1 / 0.929)",
-];
-
-pub const MAUVE_DARK: [&str; 12] = [
-    "#121113", "#1a191b", "#232225", "#2b292d", "#323035", "#3c393f", "#49474e", "#625f69",
-    "#6f6d78", "#7c7a85", "#b5b2bc", "#eeeef0",
-];
-
-pub const MAUVE_DARK_A: [&str; 12] = [
-    "#00000000",
-    "#f5f4f609",
-    "#ebeaf814",
-    "#eee5f81d",
-    "#efe6fe25",
-    "#f1e6fd30",
-    "#eee9ff40",
-    "#eee7ff5d",
-    "#eae6fd6e",
-    "#ece9fd7c",
-    "#f5f1ffb7",
-    "#fdfdffef",
-];
-
-pub const MAUVE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.07 0.067 0.074)",
-    "color(display-p3 0.101 0.098 0.105)",
-    "color(display-p3 0.138 0.134 0.144)",
-    "color(display-p3 0.167 0.161 0.175)",
-    "color(display-p3 0.196 0.189 0.206)",
-    "color(display-p3 0.232 0.225 0.245)",
-    "color(display-p3 0.286 0.277 0.302)",
-    "color(display-p3 0.383 0.373 0.408)",
-    "color(display-p3 0.434 0.428 0.467)",
-    "color(display-p3 0.487 0.48 0.519)",
-    "color(display-p3 0.707 0.7 0.735)",
-    "color(display-p3 0.933 0.933 0.94)",
-];
-
-pub const MAUVE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0)",
-    "color(display-p3 0.996 0.992 1 / 0.034)",
-    "color(display-p3 0.937 0.933 0.992 / 0.077)",
-    "color(display-p3 0.957 0.918 0.996 / 0.111)",
-    "color(display-p3 0.937 0.906 0.996 / 0.145)",
-    "color(display-p3 0.953 0.925 0.996 / 0.183)",
-    "color(display-p3 0.945 0.929 1 / 0.246)",
-    "color(display-p3 0.937 0.918 1 / 0.361)",
-    "color(display-p3 0.933 0.918 1 / 0.424)",
-    "color(display-p3 0.941 0.925 1 / 0.479)",
-    "color(display-p3 0.965 0.961 1 / 0.712)",
-    "color(display-p3 0.992 0.992 1 / 0.937)",
-];
-
-pub const SLATE_DARK: [&str; 12] = [
-    "#111113", "#18191b", "#212225", "#272a2d", "#2e3135", "#363a3f", "#43484e", "#5a6169",
-    "#696e77", "#777b84", "#b0b4ba", "#edeef0",
-];
-
-pub const SLATE_DARK_A: [&str; 12] = [
-    "#00000000",
-    "#d8f4f609",
-    "#ddeaf814",
-    "#d3edf81d",
-    "#d9edfe25",
-    "#d6ebfd30",
-    "#d9edff40",
-    "#d9edff5d",
-    "#dfebfd6d",
-    "#e5edfd7b",
-    "#f1f7feb5",
-    "#fcfdffef",
-];
-
-pub const SLATE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.067 0.067 0.074)",
-    "color(display-p3 0.095 0.098 0.105)",
-    "color(display-p3 0.13 0.135 0.145)",
-    "color(display-p3 0.156 0.163 0.176)",
-    "color(display-p3 0.183 0.191 0.206)",
-    "color(display-p3 0.215 0.226 0.244)",
-    "color(display-p3 0.265 0.28 0.302)",
-    "color(display-p3 0.357 0.381 0.409)",
-    "color(display-p3 0.415 0.431 0.463)",
-    "color(display-p3 0.469 0.483 0.514)",
-    "color(display-p3 0.692 0.704 0.728)",
-    "color(display-p3 0.93 0.933 0.94)",
-];
-
-pub const SLATE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0)",
-    "color(display-p3 0.875 0.992 1 / 0.034)",
-    "color(display-p3 0.882 0.933 0.992 / 0.077)",
-    "color(display-p3 0.882 0.953 0.996 / 0.111)",
-    "color(display-p3 0.878 0.929 0.996 / 0.145)",
-    "color(display-p3 0.882 0.949 0.996 / 0.183)",
-    "color(display-p3 0.882 0.929 1 / 0.246)",
-    "color(display-p3 0.871 0.937 1 / 0.361)",
-    "color(display-p3 0.898 0.937 1 / 0.42)",
-    "color(display-p3 0.918 0.945 1 / 0.475)",
-    "color(display-p3 0.949 0.969 0.996 / 0.708)",
-    "color(display-p3 0.988 0.992 1 / 0.937)",
-];
-
-pub const SAGE_DARK: [&str; 12] = [
-    "#101211", "#171918", "#202221", "#272a29", "#2e3130", "#373b39", "#444947", "#5b625f",
-    "#63706b", "#717d79", "#adb5b2", "#eceeed",
-];
-
-pub const SAGE_DARK_A: [&str; 12] = [
-    "#00000000",
-    "#f0f2f108",
-    "#f3f5f412",
-    "#f2fefd1a",
-    "#f1fbfa22",
-    "#edfbf42d",
-    "#edfcf73c",
-    "#ebfdf657",
-    "#dffdf266",
-    "#e5fdf674",
-    "#f4fefbb0",
-    "#fdfffeed",
-];
-
-pub const SAGE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.064 0.07 0.067)",
-    "color(display-p3 0.092 0.098 0.094)",
-    "color(display-p3 0.128 0.135 0.131)",
-    "color(display-p3 0.155 0.164 0.159)",
-    "color(display-p3 0.183 0.193 0.188)",
-    "color(display-p3 0.218 0.23 0.224)",
-    "color(display-p3 0.269 0.285 0.277)",
-    "color(display-p3 0.362 0.382 0.373)",
-    "color(display-p3 0.398 0.438 0.421)",
-    "color(display-p3 0.453 0.49 0.474)",
-    "color(display-p3 0.685 0.709 0.697)",
-    "color(display-p3 0.927 0.933 0.93)",
-];
-
-pub const SAGE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0)",
-    "color(display-p3 0.976 0.988 0.984 / 0.03)",
-    "color(display-p3 0.992 0.945 0.941 / 0.072)",
-    "color(display-p3 0.988 0.996 0.992 / 0.102)",
-    "color(display-p3 0.992 1 0.996 / 0.131)",
-    "color(display-p3 0.973 1 0.976 / 0.173)",
-    "color(display-p3 0.957 1 0.976 / 0.233)",
-    "color(display-p3 0.957 1 0.984 / 0.334)",
-    "color(display-p3 0.902 1 0.957 / 0.397)",
-    "color(display-p3 0.929 1 0.973 / 0.452)",
-    "color(display-p3 0.969 1 0.988 / 0.688)",
-    "color(display-p3 0.992 1 0.996 / 0.929)",
-];
-
-pub const OLIVE_DARK: [&str; 12] = [
-    "#111210", "#181917", "#212220", "#282a27", "#2f312e", "#383a36", "#454843", "#5c625b",
-    "#687066", "#767d74", "#afb5ad", "#eceeec",
-];
-
-pub const OLIVE_DARK_A: [&str; 12] = [
-    "#00000000",
-    "#f1f2f008",
-    "#f4f5f312",
-    "#f3fef21a",
-    "#f2fbf122",
-    "#f4faed2c",
-    "#f2fced3b",
-    "#edfdeb57",
-    "#ebfde766",
-    "#f0fdec74",
-    "#f6fef4b0",
-    "#fdfffded",
-];
-
-pub const OLIVE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.067 0.07 0.063)",
-    "color(display-p3 0.095 0.098 0.091)",
-    "color(display-p3 0.131 0.135 0.126)",
-    "color(display-p3 0.158 0.163 0.153)",
-    "color(display-p3 0.186 0.192 0.18)",
-    "color(display-p3 0.221 0.229 0.215)",
-    "color(display-p3 0.273 0.284 0.266)",
-    "color(display-p3 0.365 0.382 0.359)",
-    "color(display-p3 0.414 0.438 0.404)",
-    "color(display-p3 0.467 0.49 0.458)",
-    "color(display-p3 0.69 0.709 0.682)",
-    "color(display-p3 0.927 0.933 0.926)",
-];
-
-pub const OLIVE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0)",
-    "color(display-p3 0.984 0.988 0.976 / 0.03)",
-    "color(display-p3 0.992 0.996 0.988 / 0.068)",
-    "color(display-p3 0.953 0.996 0.949 / 0.102)",
-    "color(display-p3 0.969 1 0.965 / 0.131)",
-    "color(display-p3 0.973 1 0.969 / 0.169)",
-    "color(display-p3 0.98 1 0.961 / 0.228)",
-    "color(display-p3 0.961 1 0.957 / 0.334)",
-    "color(display-p3 0.949 1 0.922 / 0.397)",
-    "color(display-p3 0.953 1 0.941 / 0.452)",
-    "color(display-p3 0.976 1 0.965 / 0.688)",
-    "color(display-p3 0.992 1 0.992 / 0.929)",
-];
-
-pub const SAND_DARK: [&str; 12] = [
-    "#111110", "#191918", "#222221", "#2a2a28", "#31312e", "#3b3a37", "#494844", "#62605b",
-    "#6f6d66", "#7c7b74", "#b5b3ad", "#eeeeec",
-];
-
-pub const SAND_DARK_A: [&str; 12] = [
-    "#00000000",
-    "#f4f4f309",
-    "#f6f6f513",
-    "#fefef31b",
-    "#fbfbeb23",
-    "#fffaed2d",
-    "#fffbed3c",
-    "#fff9eb57",
-    "#fffae965",
-    "#fffdee73",
-    "#fffcf4b0",
-    "#fffffded",
-];
-
-pub const SAND_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.067 0.067 0.063)",
-    "color(display-p3 0.098 0.098 0.094)",
-    "color(display-p3 0.135 0.135 0.129)",
-    "color(display-p3 0.164 0.163 0.156)",
-    "color(display-p3 0.193 0.192 0.183)",
-    "color(display-p3 0.23 0.229 0.217)",
-    "color(display-p3 0.285 0.282 0.267)",
-    "color(display-p3 0.384 0.378 0.357)",
-    "color(display-p3 0.434 0.428 0.403)",
-    "color(display-p3 0.487 0.481 0.456)",
-    "color(display-p3 0.707 0.703 0.68)",
-    "color(display-p3 0.933 0.933 0.926)",
-];
-
-pub const SAND_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0)",
-    "color(display-p3 0.992 0.992 0.988 / 0.034)",
-    "color(display-p3 0.996 0.996 0.992 / 0.072)",
-    "color(display-p3 0.992 0.992 0.953 / 0.106)",
-    "color(display-p3 1 1 0.965 / 0.135)",
-    "color(display-p3 1 0.976 0.929 / 0.177)",
-    "color(display-p3 1 0.984 0.929 / 0.236)",
-    "color(display-p3 1 0.976 0.925 / 0.341)",
-    "color(display-p3 1 0.98 0.925 / 0.395)",
-    "color(display-p3 1 0.992 0.933 / 0.45)",
-    "color(display-p3 1 0.996 0.961 / 0.685)",
-    "color(display-p3 1 1 0.992 / 0.929)",
-];
-
-pub const TOMATO_DARK: [&str; 12] = [
-    "#181111", "#1f1513", "#391714", "#4e1511", "#5e1c16", "#6e2920", "#853a2d", "#ac4d39",
-    "#e54d2e", "#ec6142", "#ff977d", "#fbd3cb",
-];
-
-pub const TOMATO_DARK_A: [&str; 12] = [
-    "#f1121208",
-    "#ff55330f",
-    "#ff35232b",
-    "#fd201142",
-    "#fe332153",
-    "#ff4f3864",
-    "#fd644a7d",
-    "#fe6d4ea7",
-    "#fe5431e4",
-    "#ff6847eb",
-    "#ff977d",
-    "#ffd6cefb",
-];
-
-pub const TOMATO_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.09 0.068 0.067)",
-    "color(display-p3 0.115 0.084 0.076)",
-    "color(display-p3 0.205 0.097 0.083)",
-    "color(display-p3 0.282 0.099 0.077)",
-    "color(display-p3 0.339 0.129 0.101)",
-    "color(display-p3 0.398 0.179 0.141)",
-    "color(display-p3 0.487 0.245 0.194)",
-    "color(display-p3 0.629 0.322 0.248)",
-    "color(display-p3 0.831 0.345 0.231)",
-    "color(display-p3 0.862 0.415 0.298)",
-    "color(display-p3 1 0.585 0.455)",
-    "color(display-p3 0.959 0.833 0.802)",
-];
-
-pub const TOMATO_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.973 0.071 0.071 / 0.026)",
-    "color(display-p3 0.992 0.376 0.224 / 0.051)",
-    "color(display-p3 0.996 0.282 0.176 / 0.148)",
-    "color(display-p3 1 0.204 0.118 / 0.232)",
-    "color(display-p3 1 0.286 0.192 / 0.29)",
-    "color(display-p3 1 0.392 0.278 / 0.353)",
-    "color(display-p3 1 0.459 0.349 / 0.45)",
-    "color(display-p3 1 0.49 0.369 / 0.601)",
-    "color(display-p3 1 0.408 0.267 / 0.82)",
-    "color(display-p3 1 0.478 0.341 / 0.853)",
-    "color(display-p3 1 0.585 0.455)",
-    "color(display-p3 0.959 0.833 0.802)",
-];
-
-pub const RED_DARK: [&str; 12] = [
-    "#191111", "#201314", "#3b1219", "#500f1c", "#611623", "#72232d", "#8c333a", "#b54548",
-    "#e5484d", "#ec5d5e", "#ff9592", "#ffd1d9",
-];
-
-pub const RED_DARK_A: [&str; 12] = [
-    "#f4121209",
-    "#f22f3e11",
-    "#ff173f2d",
-    "#fe0a3b44",
-    "#ff204756",
-    "#ff3e5668",
-    "#ff536184",
-    "#ff5d61b0",
-    "#fe4e54e4",
-    "#ff6465eb",
-    "#ff9592",
-    "#ffd1d9",
-];
-
-pub const RED_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.093 0.068 0.067)",
-    "color(display-p3 0.118 0.077 0.079)",
-    "color(display-p3 0.211 0.081 0.099)",
-    "color(display-p3 0.287 0.079 0.113)",
-    "color(display-p3 0.348 0.11 0.142)",
-    "color(display-p3 0.414 0.16 0.183)",
-    "color(display-p3 0.508 0.224 0.236)",
-    "color(display-p3 0.659 0.298 0.297)",
-    "color(display-p3 0.83 0.329 0.324)",
-    "color(display-p3 0.861 0.403 0.387)",
-    "color(display-p3 1 0.57 0.55)",
-    "color(display-p3 0.971 0.826 0.852)",
-];
-
-pub const RED_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.984 0.071 0.071 / 0.03)",
-    "color(display-p3 0.996 0.282 0.282 / 0.055)",
-    "color(display-p3 1 0.169 0.271 / 0.156)",
-    "color(display-p3 1 0.118 0.267 / 0.236)",
-    "color(display-p3 1 0.212 0.314 / 0.303)",
-    "color(display-p3 1 0.318 0.38 / 0.374)",
-    "color(display-p3 1 0.4 0.424 / 0.475)",
-    "color(display-p3 1 0.431 0.431 / 0.635)",
-    "color(display-p3 1 0.388 0.384 / 0.82)",
-    "color(display-p3 1 0.463 0.447 / 0.853)",
-    "color(display-p3 1 0.57 0.55)",
-    "color(display-p3 0.971 0.826 0.852)",
-];
-
-pub const RUBY_DARK: [&str; 12] = [
-    "#191113", "#1e1517", "#3a141e", "#4e1325", "#5e1a2e", "#6f2539", "#883447", "#b3445a",
-    "#e54666", "#ec5a72", "#ff949d", "#fed2e1",
-];
-
-pub const RUBY_DARK_A: [&str; 12] = [
-    "#f4124a09",
-    "#fe5a7f0e",
-    "#ff235d2c",
-    "#fd195e42",
-    "#fe2d6b53",
-    "#ff447665",
-    "#ff577d80",
-    "#ff5c7cae",
-    "#fe4c70e4",
-    "#ff617beb",
-    "#ff949d",
-    "#ffd3e2fe",
-];
-
-pub const RUBY_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.093 0.068 0.074)",
-    "color(display-p3 0.113 0.083 0.089)",
-    "color(display-p3 0.208 0.088 0.117)",
-    "color(display-p3 0.279 0.092 0.147)",
-    "color(display-p3 0.337 0.12 0.18)",
-    "color(display-p3 0.401 0.166 0.223)",
-    "color(display-p3 0.495 0.224 0.281)",
-    "color(display-p3 0.652 0.295 0.359)",
-    "color(display-p3 0.83 0.323 0.408)",
-    "color(display-p3 0.857 0.392 0.455)",
-    "color(display-p3 1 0.57 0.59)",
-    "color(display-p3 0.968 0.83 0.88)",
-];
-
-pub const RUBY_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.984 0.071 0.329 / 0.03)",
-    "color(display-p3 0.992 0.376 0.529 / 0.051)",
-    "color(display-p3 0.996 0.196 0.404 / 0.152)",
-    "color(display-p3 1 0.173 0.416 / 0.227)",
-    "color(display-p3 1 0.259 0.459 / 0.29)",
-    "color(display-p3 1 0.341 0.506 / 0.358)",
-    "color(display-p3 1 0.412 0.541 / 0.458)",
-    "color(display-p3 1 0.431 0.537 / 0.627)",
-    "color(display-p3 1 0.376 0.482 / 0.82)",
-    "color(display-p3 1 0.447 0.522 / 0.849)",
-    "color(display-p3 1 0.57 0.59)",
-    "color(display-p3 0.968 0.83 0.88)",
-];
-
-pub const CRIMSON_DARK: [&str; 12] = [
-    "#191114", "#201318", "#381525", "#4d122f", "#5c1839", "#6d2545", "#873356", "#b0436e",
-    "#e93d82", "#ee518a", "#ff92ad", "#fdd3e8",
-];
-
-pub const CRIMSON_DARK_A: [&str; 12] = [
-    "#f4126709",
-    "#f22f7a11",
-    "#fe2a8b2a",
-    "#fd158741",
-    "#fd278f51",
-    "#fe459763",
-    "#fd559b7f",
-    "#fe5b9bab",
-    "#fe418de8",
-    "#ff5693ed",
-    "#ff92ad",
-    "#ffd5eafd",
-];
-
-pub const CRIMSON_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.093 0.068 0.078)",
-    "color(display-p3 0.117 0.078 0.095)",
-    "color(display-p3 0.203 0.091 0.143)",
-    "color(display-p3 0.277 0.087 0.182)",
-    "color(display-p3 0.332 0.115 0.22)",
-    "color(display-p3 0.394 0.162 0.268)",
-    "color(display-p3 0.489 0.222 0.336)",
-    "color(display-p3 0.638 0.289 0.429)",
-    "color(display-p3 0.843 0.298 0.507)",
-    "color(display-p3 0.864 0.364 0.539)",
-    "color(display-p3 1 0.56 0.66)",
-    "color(display-p3 0.966 0.834 0.906)",
-];
-
-pub const CRIMSON_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.984 0.071 0.463 / 0.03)",
-    "color(display-p3 0.996 0.282 0.569 / 0.055)",
-    "color(display-p3 0.996 0.227 0.573 / 0.148)",
-    "color(display-p3 1 0.157 0.569 / 0.227)",
-    "color(display-p3 1 0.231 0.604 / 0.286)",
-    "color(display-p3 1 0.337 0.643 / 0.349)",
-    "color(display-p3 1 0.416 0.663 / 0.454)",
-    "color(display-p3 0.996 0.427 0.651 / 0.614)",
-    "color(display-p3 1 0.345 0.596 / 0.832)",
-    "color(display-p3 1 0.42 0.62 / 0.853)",
-    "color(display-p3 1 0.56 0.66)",
-    "color(display-p3 0.966 0.834 0.906)",
-];
-
-pub const PINK_DARK: [&str; 12] = [
-    "#191117", "#21121d", "#37172f", "#4b143d", "#591c47", "#692955", "#833869", "#a84885",
-    "#d6409f", "#de51a8", "#ff8dcc", "#fdd1ea",
-];
-
-pub const PINK_DARK_A: [&str; 12] = [
-    "#f412bc09",
-    "#f420bb12",
-    "#fe37cc29",
-    "#fc1ec43f",
-    "#fd35c24e",
-    "#fd51c75f",
-    "#fd62c87b",
-    "#ff68c8a2",
-    "#fe49bcd4",
-    "#ff5cc0dc",
-    "#ff8dcc",
-    "#ffd3ecfd",
-];
-
-pub const PINK_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.093 0.068 0.089)",
-    "color(display-p3 0.121 0.073 0.11)",
-    "color(display-p3 0.198 0.098 0.179)",
-    "color(display-p3 0.271 0.095 0.231)",
-    "color(display-p3 0.32 0.127 0.273)",
-    "color(display-p3 0.382 0.177 0.326)",
-    "color(display-p3 0.477 0.238 0.405)",
-    "color(display-p3 0.612 0.304 0.51)",
-    "color(display-p3 0.775 0.297 0.61)",
-    "color(display-p3 0.808 0.356 0.645)",
-    "color(display-p3 1 0.535 0.78)",
-    "color(display-p3 0.964 0.826 0.912)",
-];
-
-pub const PINK_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.984 0.071 0.855 / 0.03)",
-    "color(display-p3 1 0.2 0.8 / 0.059)",
-    "color(display-p3 1 0.294 0.886 / 0.139)",
-    "color(display-p3 1 0.192 0.82 / 0.219)",
-    "color(display-p3 1 0.282 0.827 / 0.274)",
-    "color(display-p3 1 0.396 0.835 / 0.337)",
-    "color(display-p3 1 0.459 0.831 / 0.442)",
-    "color(display-p3 1 0.478 0.827 / 0.585)",
-    "color(display-p3 1 0.373 0.784 / 0.761)",
-    "color(display-p3 1 0.435 0.792 / 0.795)",
-    "color(display-p3 1 0.535 0.78)",
-    "color(display-p3 0.964 0.826 0.912)",
-];
-
-pub const PLUM_DARK: [&str; 12] = [
-    "#181118", "#201320", "#351a35", "#451d47", "#512454", "#5e3061", "#734079", "#92549c",
-    "#ab4aba", "#b658c4", "#e796f3", "#f4d4f4",
-];
-
-pub const PLUM_DARK_A: [&str; 12] = [
-    "#f112f108",
-    "#f22ff211",
-    "#fd4cfd27",
-    "#f646ff3a",
-    "#f455ff48",
-    "#f66dff56",
-    "#f07cfd70",
-    "#ee84ff95",
-    "#e961feb6",
-    "#ed70ffc0",
-    "#f19cfef3",
-    "#feddfef4",
-];
-
-pub const PLUM_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.09 0.068 0.092)",
-    "color(display-p3 0.118 0.077 0.121)",
-    "color(display-p3 0.192 0.105 0.202)",
-    "color(display-p3 0.25 0.121 0.271)",
-    "color(display-p3 0.293 0.152 0.319)",
-    "color(display-p3 0.343 0.198 0.372)",
-    "color(display-p3 0.424 0.262 0.461)",
-    "color(display-p3 0.54 0.341 0.595)",
-    "color(display-p3 0.624 0.313 0.708)",
-    "color(display-p3 0.666 0.365 0.748)",
-    "color(display-p3 0.86 0.602 0.933)",
-    "color(display-p3 0.936 0.836 0.949)",
-];
-
-pub const PLUM_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.973 0.071 0.973 / 0.026)",
-    "color(display-p3 0.933 0.267 1 / 0.059)",
-    "color(display-p3 0.918 0.333 0.996 / 0.148)",
-    "color(display-p3 0.91 0.318 1 / 0.219)",
-    "color(display-p3 0.914 0.388 1 / 0.269)",
-    "color(display-p3 0.906 0.463 1 / 0.328)",
-    "color(display-p3 0.906 0.529 1 / 0.425)",
-    "color(display-p3 0.906 0.553 1 / 0.568)",
-    "color(display-p3 0.875 0.427 1 / 0.69)",
-    "color(display-p3 0.886 0.471 0.996 / 0.732)",
-    "color(display-p3 0.86 0.602 0.933)",
-    "color(display-p3 0.936 0.836 0.949)",
-];
-
-pub const PURPLE_DARK: [&str; 12] = [
-    "#18111b", "#1e1523", "#301c3b", "#3d224e", "#48295c", "#54346b", "#664282", "#8457aa",
-    "#8e4ec6", "#9a5cd0", "#d19dff", "#ecd9fa",
-];
-
-pub const PURPLE_DARK_A: [&str; 12] = [
-    "#b412f90b",
-    "#b744f714",
-    "#c150ff2d",
-    "#bb53fd42",
-    "#be5cfd51",
-    "#c16dfd61",
-    "#c378fd7a",
-    "#c47effa4",
-    "#b661ffc2",
-    "#bc6fffcd",
-    "#d19dff",
-    "#f1ddfffa",
-];
-
-pub const PURPLE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.09 0.068 0.103)",
-    "color(display-p3 0.113 0.082 0.134)",
-    "color(display-p3 0.175 0.112 0.224)",
-    "color(display-p3 0.224 0.137 0.297)",
-    "color(display-p3 0.264 0.167 0.349)",
-    "color(display-p3 0.311 0.208 0.406)",
-    "color(display-p3 0.381 0.266 0.496)",
-    "color(display-p3 0.49 0.349 0.649)",
-    "color(display-p3 0.523 0.318 0.751)",
-    "color(display-p3 0.57 0.373 0.791)",
-    "color(display-p3 0.8 0.62 1)",
-    "color(display-p3 0.913 0.854 0.971)",
-];
-
-pub const PURPLE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.686 0.071 0.996 / 0.038)",
-    "color(display-p3 0.722 0.286 0.996 / 0.072)",
-    "color(display-p3 0.718 0.349 0.996 / 0.169)",
-    "color(display-p3 0.702 0.353 1 / 0.248)",
-    "color(display-p3 0.718 0.404 1 / 0.303)",
-    "color(display-p3 0.733 0.455 1 / 0.366)",
-    "color(display-p3 0.753 0.506 1 / 0.458)",
-    "color(display-p3 0.749 0.522 1 / 0.622)",
-    "color(display-p3 0.686 0.408 1 / 0.736)",
-    "color(display-p3 0.71 0.459 1 / 0.778)",
-    "color(display-p3 0.8 0.62 1)",
-    "color(display-p3 0.913 0.854 0.971)",
-];
-
-pub const VIOLET_DARK: [&str; 12] = [
-    "#14121f", "#1b1525", "#291f43", "#33255b", "#3c2e69", "#473876", "#56468b", "#6958ad",
-    "#6e56cf", "#7d66d9", "#baa7ff", "#e2ddfe",
-];
-
-pub const VIOLET_DARK_A: [&str; 12] = [
-    "#4422ff0f",
-    "#853ff916",
-    "#8354fe36",
-    "#7d51fd50",
-    "#845ffd5f",
-    "#8f6cfd6d",
-    "#9879ff83",
-    "#977dfea8",
-    "#8668ffcc",
-    "#9176fed7",
-    "#baa7ff",
-    "#e3defffe",
-];
-
-pub const VIOLET_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.077 0.071 0.118)",
-    "color(display-p3 0.101 0.084 0.141)",
-    "color(display-p3 0.154 0.123 0.256)",
-    "color(display-p3 0.191 0.148 0.345)",
-    "color(display-p3 0.226 0.182 0.396)",
-    "color(display-p3 0.269 0.223 0.449)",
-    "color(display-p3 0.326 0.277 0.53)",
-    "color(display-p3 0.399 0.346 0.656)",
-    "color(display-p3 0.417 0.341 0.784)",
-    "color(display-p3 0.477 0.402 0.823)",
-    "color(display-p3 0.72 0.65 1)",
-    "color(display-p3 0.883 0.867 0.986)",
-];
-
-pub const VIOLET_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.282 0.141 0.996 / 0.055)",
-    "color(display-p3 0.51 0.263 1 / 0.08)",
-    "color(display-p3 0.494 0.337 0.996 / 0.202)",
-    "color(display-p3 0.49 0.345 1 / 0.299)",
-    "color(display-p3 0.525 0.392 1 / 0.353)",
-    "color(display-p3 0.569 0.455 1 / 0.408)",
-    "color(display-p3 0.588 0.494 1 / 0.496)",
-    "color(display-p3 0.596 0.51 1 / 0.631)",
-    "color(display-p3 0.522 0.424 1 / 0.769)",
-    "color(display-p3 0.576 0.482 1 / 0.811)",
-    "color(display-p3 0.72 0.65 1)",
-    "color(display-p3 0.883 0.867 0.986)",
-];
-
-pub const IRIS_DARK: [&str; 12] = [
-    "#13131e", "#171625", "#202248", "#262a65", "#303374", "#3d3e82", "#4a4a95", "#5958b1",
-    "#5b5bd6", "#6e6ade", "#b1a9ff", "#e0dffe",
-];
-
-pub const IRIS_DARK_A: [&str; 12] = [
-    "#3636fe0e",
-    "#564bf916",
-    "#525bff3b",
-    "#4d58ff5a",
-    "#5b62fd6b",
-    "#6d6ffd7a",
-    "#7777fe8e",
-    "#7b7afeac",
-    "#6a6afed4",
-    "#7d79ffdc",
-    "#b1a9ff",
-    "#e1e0fffe",
-];
-
-pub const IRIS_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.075 0.075 0.114)",
-    "color(display-p3 0.089 0.086 0.14)",
-    "color(display-p3 0.128 0.134 0.272)",
-    "color(display-p3 0.153 0.165 0.382)",
-    "color(display-p3 0.192 0.201 0.44)",
-    "color(display-p3 0.239 0.241 0.491)",
-    "color(display-p3 0.291 0.289 0.565)",
-    "color(display-p3 0.35 0.345 0.673)",
-    "color(display-p3 0.357 0.357 0.81)",
-    "color(display-p3 0.428 0.416 0.843)",
-    "color(display-p3 0.685 0.662 1)",
-    "color(display-p3 0.878 0.875 0.986)",
-];
-
-pub const IRIS_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.224 0.224 0.992 / 0.051)",
-    "color(display-p3 0.361 0.314 1 / 0.08)",
-    "color(display-p3 0.357 0.373 1 / 0.219)",
-    "color(display-p3 0.325 0.361 1 / 0.337)",
-    "color(display-p3 0.38 0.4 1 / 0.4)",
-    "color(display-p3 0.447 0.447 1 / 0.454)",
-    "color(display-p3 0.486 0.486 1 / 0.534)",
-    "color(display-p3 0.502 0.494 1 / 0.652)",
-    "color(display-p3 0.431 0.431 1 / 0.799)",
-    "color(display-p3 0.502 0.486 1 / 0.832)",
-    "color(display-p3 0.685 0.662 1)",
-    "color(display-p3 0.878 0.875 0.986)",
-];
-
-pub const INDIGO_DARK: [&str; 12] = [
-    "#11131f", "#141726", "#182449", "#1d2e62", "#253974", "#304384", "#3a4f97", "#435db1",
-    "#3e63dd", "#5472e4", "#9eb1ff", "#d6e1ff",
-];
-
-pub const INDIGO_DARK_A: [&str; 12] = [
-    "#1133ff0f",
-    "#3354fa17",
-    "#2f62ff3c",
-    "#3566ff57",
-    "#4171fd6b",
-    "#5178fd7c",
-    "#5a7fff90",
-    "#5b81feac",
-    "#4671ffdb",
-    "#5c7efee3",
-    "#9eb1ff",
-    "#d6e1ff",
-];
-
-pub const INDIGO_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.068 0.074 0.118)",
-    "color(display-p3 0.081 0.089 0.144)",
-    "color(display-p3 0.105 0.141 0.275)",
-    "color(display-p3 0.129 0.18 0.369)",
-    "color(display-p3 0.163 0.22 0.439)",
-    "color(display-p3 0.203 0.262 0.5)",
-    "color(display-p3 0.245 0.309 0.575)",
-    "color(display-p3 0.285 0.362 0.674)",
-    "color(display-p3 0.276 0.384 0.837)",
-    "color(display-p3 0.354 0.445 0.866)",
-    "color(display-p3 0.63 0.69 1)",
-    "color(display-p3 0.848 0.881 0.99)",
-];
-
-pub const INDIGO_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.071 0.212 0.996 / 0.055)",
-    "color(display-p3 0.251 0.345 0.988 / 0.085)",
-    "color(display-p3 0.243 0.404 1 / 0.223)",
-    "color(display-p3 0.263 0.42 1 / 0.324)",
-    "color(display-p3 0.314 0.451 1 / 0.4)",
-    "color(display-p3 0.361 0.49 1 / 0.467)",
-    "color(display-p3 0.388 0.51 1 / 0.547)",
-    "color(display-p3 0.404 0.518 1 / 0.652)",
-    "color(display-p3 0.318 0.451 1 / 0.824)",
-    "color(display-p3 0.404 0.506 1 / 0.858)",
-    "color(display-p3 0.63 0.69 1)",
-    "color(display-p3 0.848 0.881 0.99)",
-];
-
-pub const BLUE_DARK: [&str; 12] = [
-    "#0d1520", "#111927", "#0d2847", "#003362", "#004074", "#104d87", "#205d9e", "#2870bd",
-    "#0090ff", "#3b9eff", "#70b8ff", "#c2e6ff",
-];
-
-pub const BLUE_DARK_A: [&str; 12] = [
-    "#004df211",
-    "#1166fb18",
-    "#0077ff3a",
-    "#0075ff57",
-    "#0081fd6b",
-    "#0f89fd7f",
-    "#2a91fe98",
-    "#3094feb9",
-    "#0090ff",
-    "#3b9eff",
-    "#70b8ff",
-    "#c2e6ff",
-];
-
-pub const BLUE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.057 0.081 0.122)",
-    "color(display-p3 0.072 0.098 0.147)",
-    "color(display-p3 0.078 0.154 0.27)",
-    "color(display-p3 0.033 0.197 0.37)",
-    "color(display-p3 0.08 0.245 0.441)",
-    "color(display-p3 0.14 0.298 0.511)",
-    "color(display-p3 0.195 0.361 0.6)",
-    "color(display-p3 0.239 0.434 0.72)",
-    "color(display-p3 0.247 0.556 0.969)",
-    "color(display-p3 0.344 0.612 0.973)",
-    "color(display-p3 0.49 0.72 1)",
-    "color(display-p3 0.788 0.898 0.99)",
-];
-
-pub const BLUE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.333 1 / 0.059)",
-    "color(display-p3 0.114 0.435 0.988 / 0.085)",
-    "color(display-p3 0.122 0.463 1 / 0.219)",
-    "color(display-p3 0 0.467 1 / 0.324)",
-    "color(display-p3 0.098 0.51 1 / 0.4)",
-    "color(display-p3 0.224 0.557 1 / 0.475)",
-    "color(display-p3 0.294 0.584 1 / 0.572)",
-    "color(display-p3 0.314 0.592 1 / 0.702)",
-    "color(display-p3 0.251 0.573 0.996 / 0.967)",
-    "color(display-p3 0.357 0.631 1 / 0.971)",
-    "color(display-p3 0.49 0.72 1)",
-    "color(display-p3 0.788 0.898 0.99)",
-];
-
-pub const CYAN_DARK: [&str; 12] = [
-    "#0b161a", "#101b20", "#082c36", "#003848", "#004558", "#045468", "#12677e", "#11809c",
-    "#00a2c7", "#23afd0", "#4ccce6", "#b6ecf7",
-];
-
-pub const CYAN_DARK_A: [&str; 12] = [
-    "#0091f70a",
-    "#02a7f211",
-    "#00befd28",
-    "#00baff3b",
-    "#00befd4d",
-    "#00c7fd5e",
-    "#14cdff75",
-    "#11cfff95",
-    "#00cfffc3",
-    "#28d6ffcd",
-    "#52e1fee5",
-    "#bbf3fef7",
-];
-
-pub const CYAN_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.053 0.085 0.098)",
-    "color(display-p3 0.072 0.105 0.122)",
-    "color(display-p3 0.073 0.168 0.209)",
-    "color(display-p3 0.063 0.216 0.277)",
-    "color(display-p3 0.091 0.267 0.336)",
-    "color(display-p3 0.137 0.324 0.4)",
-    "color(display-p3 0.186 0.398 0.484)",
-    "color(display-p3 0.23 0.496 0.6)",
-    "color(display-p3 0.282 0.627 0.765)",
-    "color(display-p3 0.331 0.675 0.801)",
-    "color(display-p3 0.446 0.79 0.887)",
-    "color(display-p3 0.757 0.919 0.962)",
-];
-
-pub const CYAN_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.647 0.992 / 0.034)",
-    "color(display-p3 0.133 0.733 1 / 0.059)",
-    "color(display-p3 0.122 0.741 0.996 / 0.152)",
-    "color(display-p3 0.051 0.725 1 / 0.227)",
-    "color(display-p3 0.149 0.757 1 / 0.29)",
-    "color(display-p3 0.267 0.792 1 / 0.358)",
-    "color(display-p3 0.333 0.808 1 / 0.446)",
-    "color(display-p3 0.357 0.816 1 / 0.572)",
-    "color(display-p3 0.357 0.82 1 / 0.748)",
-    "color(display-p3 0.4 0.839 1 / 0.786)",
-    "color(display-p3 0.446 0.79 0.887)",
-    "color(display-p3 0.757 0.919 0.962)",
-];
-
-pub const TEAL_DARK: [&str; 12] = [
-    "#0d1514", "#111c1b", "#0d2d2a", "#023b37", "#084843", "#145750", "#1c6961", "#207e73",
-    "#12a594", "#0eb39e", "#0bd8b6", "#adf0dd",
-];
-
-pub const TEAL_DARK_A: [&str; 12] = [
-    "#00deab05",
-    "#12fbe60c",
-    "#00ffe61e",
-    "#00ffe92d",
-    "#00ffea3b",
-    "#1cffe84b",
-    "#2efde85f",
-    "#32ffe775",
-    "#13ffe49f",
-    "#0dffe0ae",
-    "#0afed5d6",
-    "#b8ffebef",
-];
-
-pub const TEAL_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.059 0.083 0.079)",
-    "color(display-p3 0.075 0.11 0.107)",
-    "color(display-p3 0.087 0.175 0.165)",
-    "color(display-p3 0.087 0.227 0.214)",
-    "color(display-p3 0.12 0.277 0.261)",
-    "color(display-p3 0.162 0.335 0.314)",
-    "color(display-p3 0.205 0.406 0.379)",
-    "color(display-p3 0.245 0.489 0.453)",
-    "color(display-p3 0.297 0.637 0.581)",
-    "color(display-p3 0.319 0.69 0.62)",
-    "color(display-p3 0.388 0.835 0.719)",
-    "color(display-p3 0.734 0.934 0.87)",
-];
-
-pub const TEAL_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.992 0.761 / 0.017)",
-    "color(display-p3 0.235 0.988 0.902 / 0.047)",
-    "color(display-p3 0.235 1 0.898 / 0.118)",
-    "color(display-p3 0.18 0.996 0.929 / 0.173)",
-    "color(display-p3 0.31 1 0.933 / 0.227)",
-    "color(display-p3 0.396 1 0.933 / 0.286)",
-    "color(display-p3 0.443 1 0.925 / 0.366)",
-    "color(display-p3 0.459 1 0.925 / 0.454)",
-    "color(display-p3 0.443 0.996 0.906 / 0.61)",
-    "color(display-p3 0.439 0.996 0.89 / 0.669)",
-    "color(display-p3 0.388 0.835 0.719)",
-    "color(display-p3 0.734 0.934 0.87)",
-];
-
-pub const JADE_DARK: [&str; 12] = [
-    "#0d1512", "#121c18", "#0f2e22", "#0b3b2c", "#114837", "#1b5745", "#246854", "#2a7e68",
-    "#29a383", "#27b08b", "#1fd8a4", "#adf0d4",
-];
-
-pub const JADE_DARK_A: [&str; 12] = [
-    "#00de4505",
-    "#27fba60c",
-    "#02f99920",
-    "#00ffaa2d",
-    "#11ffb63b",
-    "#34ffc24b",
-    "#45fdc75e",
-    "#48ffcf75",
-    "#38feca9d",
-    "#31fec7ab",
-    "#21fec0d6",
-    "#b8ffe1ef",
-];
-
-pub const JADE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.059 0.083 0.071)",
-    "color(display-p3 0.078 0.11 0.094)",
-    "color(display-p3 0.091 0.176 0.138)",
-    "color(display-p3 0.102 0.228 0.177)",
-    "color(display-p3 0.133 0.279 0.221)",
-    "color(display-p3 0.174 0.334 0.273)",
-    "color(display-p3 0.219 0.402 0.335)",
-    "color(display-p3 0.263 0.488 0.411)",
-    "color(display-p3 0.319 0.63 0.521)",
-    "color(display-p3 0.338 0.68 0.555)",
-    "color(display-p3 0.4 0.835 0.656)",
-    "color(display-p3 0.734 0.934 0.838)",
-];
-
-pub const JADE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.992 0.298 / 0.017)",
-    "color(display-p3 0.318 0.988 0.651 / 0.047)",
-    "color(display-p3 0.267 1 0.667 / 0.118)",
-    "color(display-p3 0.275 0.996 0.702 / 0.173)",
-    "color(display-p3 0.361 1 0.741 / 0.227)",
-    "color(display-p3 0.439 1 0.796 / 0.286)",
-    "color(display-p3 0.49 1 0.804 / 0.362)",
-    "color(display-p3 0.506 1 0.835 / 0.45)",
-    "color(display-p3 0.478 0.996 0.816 / 0.606)",
-    "color(display-p3 0.478 1 0.816 / 0.656)",
-    "color(display-p3 0.4 0.835 0.656)",
-    "color(display-p3 0.734 0.934 0.838)",
-];
-
-pub const GREEN_DARK: [&str; 12] = [
-    "#0e1512", "#121b17", "#132d21", "#113b29", "#174933", "#20573e", "#28684a", "#2f7c57",
-    "#30a46c", "#33b074", "#3dd68c", "#b1f1cb",
-];
-
-pub const GREEN_DARK_A: [&str; 12] = [
-    "#00de4505",
-    "#29f99d0b",
-    "#22ff991e",
-    "#11ff992d",
-    "#2bffa23c",
-    "#44ffaa4b",
-    "#50fdac5e",
-    "#54ffad73",
-    "#44ffa49e",
-    "#43fea4ab",
-    "#46fea5d4",
-    "#bbffd7f0",
-];
-
-pub const GREEN_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.062 0.083 0.071)",
-    "color(display-p3 0.079 0.106 0.09)",
-    "color(display-p3 0.1 0.173 0.133)",
-    "color(display-p3 0.115 0.229 0.166)",
-    "color(display-p3 0.147 0.282 0.206)",
-    "color(display-p3 0.185 0.338 0.25)",
-    "color(display-p3 0.227 0.403 0.298)",
-    "color(display-p3 0.27 0.479 0.351)",
-    "color(display-p3 0.332 0.634 0.442)",
-    "color(display-p3 0.357 0.682 0.474)",
-    "color(display-p3 0.434 0.828 0.573)",
-    "color(display-p3 0.747 0.938 0.807)",
-];
-
-pub const GREEN_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.992 0.298 / 0.017)",
-    "color(display-p3 0.341 0.98 0.616 / 0.043)",
-    "color(display-p3 0.376 0.996 0.655 / 0.114)",
-    "color(display-p3 0.341 0.996 0.635 / 0.173)",
-    "color(display-p3 0.408 1 0.678 / 0.232)",
-    "color(display-p3 0.475 1 0.706 / 0.29)",
-    "color(display-p3 0.514 1 0.706 / 0.362)",
-    "color(display-p3 0.529 1 0.718 / 0.442)",
-    "color(display-p3 0.502 0.996 0.682 / 0.61)",
-    "color(display-p3 0.506 1 0.682 / 0.66)",
-    "color(display-p3 0.434 0.828 0.573)",
-    "color(display-p3 0.747 0.938 0.807)",
-];
-
-pub const GRASS_DARK: [&str; 12] = [
-    "#0e1511", "#141a15", "#1b2a1e", "#1d3a24", "#25482d", "#2d5736", "#366740", "#3e7949",
-    "#46a758", "#53b365", "#71d083", "#c2f0c2",
-];
-
-pub const GRASS_DARK_A: [&str; 12] = [
-    "#00de1205",
-    "#5ef7780a",
-    "#70fe8c1b",
-    "#57ff802c",
-    "#68ff8b3b",
-    "#71ff8f4b",
-    "#77fd925d",
-    "#77fd9070",
-    "#65ff82a1",
-    "#72ff8dae",
-    "#89ff9fcd",
-    "#ceffceef",
-];
-
-pub const GRASS_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.062 0.083 0.067)",
-    "color(display-p3 0.083 0.103 0.085)",
-    "color(display-p3 0.118 0.163 0.122)",
-    "color(display-p3 0.142 0.225 0.15)",
-    "color(display-p3 0.178 0.279 0.186)",
-    "color(display-p3 0.217 0.337 0.224)",
-    "color(display-p3 0.258 0.4 0.264)",
-    "color(display-p3 0.302 0.47 0.305)",
-    "color(display-p3 0.38 0.647 0.378)",
-    "color(display-p3 0.426 0.694 0.426)",
-    "color(display-p3 0.535 0.807 0.542)",
-    "color(display-p3 0.797 0.936 0.776)",
-];
-
-pub const GRASS_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.992 0.071 / 0.017)",
-    "color(display-p3 0.482 0.996 0.584 / 0.038)",
-    "color(display-p3 0.549 0.992 0.588 / 0.106)",
-    "color(display-p3 0.51 0.996 0.557 / 0.169)",
-    "color(display-p3 0.553 1 0.588 / 0.227)",
-    "color(display-p3 0.584 1 0.608 / 0.29)",
-    "color(display-p3 0.604 1 0.616 / 0.358)",
-    "color(display-p3 0.608 1 0.62 / 0.433)",
-    "color(display-p3 0.573 1 0.569 / 0.622)",
-    "color(display-p3 0.6 0.996 0.6 / 0.673)",
-    "color(display-p3 0.535 0.807 0.542)",
-    "color(display-p3 0.797 0.936 0.776)",
-];
-
-pub const BROWN_DARK: [&str; 12] = [
-    "#12110f", "#1c1816", "#28211d", "#322922", "#3e3128", "#4d3c2f", "#614a39", "#7c5f46",
-    "#ad7f58", "#b88c67", "#dbb594", "#f2e1ca",
-];
-
-pub const BROWN_DARK_A: [&str; 12] = [
-    "#91110002",
-    "#fba67c0c",
-    "#fcb58c19",
-    "#fbbb8a24",
-    "#fcb88931",
-    "#fdba8741",
-    "#ffbb8856",
-    "#ffbe8773",
-    "#feb87da8",
-    "#ffc18cb3",
-    "#fed1aad9",
-    "#feecd4f2",
-];
-
-pub const BROWN_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.071 0.067 0.059)",
-    "color(display-p3 0.107 0.095 0.087)",
-    "color(display-p3 0.151 0.13 0.115)",
-    "color(display-p3 0.191 0.161 0.138)",
-    "color(display-p3 0.235 0.194 0.162)",
-    "color(display-p3 0.291 0.237 0.192)",
-    "color(display-p3 0.365 0.295 0.232)",
-    "color(display-p3 0.469 0.377 0.287)",
-    "color(display-p3 0.651 0.505 0.368)",
-    "color(display-p3 0.697 0.557 0.423)",
-    "color(display-p3 0.835 0.715 0.597)",
-    "color(display-p3 0.938 0.885 0.802)",
-];
-
-pub const BROWN_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.855 0.071 0 / 0.005)",
-    "color(display-p3 0.98 0.706 0.525 / 0.043)",
-    "color(display-p3 0.996 0.745 0.576 / 0.093)",
-    "color(display-p3 1 0.765 0.592 / 0.135)",
-    "color(display-p3 1 0.761 0.588 / 0.181)",
-    "color(display-p3 1 0.773 0.592 / 0.24)",
-    "color(display-p3 0.996 0.776 0.58 / 0.32)",
-    "color(display-p3 1 0.78 0.573 / 0.433)",
-    "color(display-p3 1 0.769 0.549 / 0.627)",
-    "color(display-p3 1 0.792 0.596 / 0.677)",
-    "color(display-p3 0.835 0.715 0.597)",
-    "color(display-p3 0.938 0.885 0.802)",
-];
-
-pub const BRONZE_DARK: [&str; 12] = [
-    "#141110", "#1c1917", "#262220", "#302a27", "#3b3330", "#493e3a", "#5a4c47", "#6f5f58",
-    "#a18072", "#ae8c7e", "#d4b3a5", "#ede0d9",
-];
-
-pub const BRONZE_DARK_A: [&str; 12] = [
-    "#d1110004",
-    "#fbbc910c",
-    "#faceb817",
-    "#facdb622",
-    "#ffd2c12d",
-    "#ffd1c03c",
-    "#fdd0c04f",
-    "#ffd6c565",
-    "#fec7b09b",
-    "#fecab5a9",
-    "#ffd7c6d1",
-    "#fff1e9ec",
-];
-
-pub const BRONZE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.076 0.067 0.063)",
-    "color(display-p3 0.106 0.097 0.093)",
-    "color(display-p3 0.147 0.132 0.125)",
-    "color(display-p3 0.185 0.166 0.156)",
-    "color(display-p3 0.227 0.202 0.19)",
-    "color(display-p3 0.278 0.246 0.23)",
-    "color(display-p3 0.343 0.302 0.281)",
-    "color(display-p3 0.426 0.374 0.347)",
-    "color(display-p3 0.611 0.507 0.455)",
-    "color(display-p3 0.66 0.556 0.504)",
-    "color(display-p3 0.81 0.707 0.655)",
-    "color(display-p3 0.921 0.88 0.854)",
-];
-
-pub const BRONZE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.941 0.067 0 / 0.009)",
-    "color(display-p3 0.98 0.8 0.706 / 0.043)",
-    "color(display-p3 0.988 0.851 0.761 / 0.085)",
-    "color(display-p3 0.996 0.839 0.78 / 0.127)",
-    "color(display-p3 0.996 0.863 0.773 / 0.173)",
-    "color(display-p3 1 0.863 0.796 / 0.227)",
-    "color(display-p3 1 0.867 0.8 / 0.295)",
-    "color(display-p3 1 0.859 0.788 / 0.387)",
-    "color(display-p3 1 0.82 0.733 / 0.585)",
-    "color(display-p3 1 0.839 0.761 / 0.635)",
-    "color(display-p3 0.81 0.707 0.655)",
-    "color(display-p3 0.921 0.88 0.854)",
-];
-
-pub const GOLD_DARK: [&str; 12] = [
-    "#121211", "#1b1a17", "#24231f", "#2d2b26", "#38352e", "#444039", "#544f46", "#696256",
-    "#978365", "#a39073", "#cbb99f", "#e8e2d9",
-];
-
-pub const GOLD_DARK_A: [&str; 12] = [
-    "#91911102",
-    "#f9e29d0b",
-    "#f8ecbb15",
-    "#ffeec41e",
-    "#feecc22a",
-    "#feebcb37",
-    "#ffedcd48",
-    "#fdeaca5f",
-    "#ffdba690",
-    "#fedfb09d",
-    "#fee7c6c8",
-    "#fef7ede7",
-];
-
-pub const GOLD_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.071 0.071 0.067)",
-    "color(display-p3 0.104 0.101 0.09)",
-    "color(display-p3 0.141 0.136 0.122)",
-    "color(display-p3 0.177 0.17 0.152)",
-    "color(display-p3 0.217 0.207 0.185)",
-    "color(display-p3 0.265 0.252 0.225)",
-    "color(display-p3 0.327 0.31 0.277)",
-    "color(display-p3 0.407 0.384 0.342)",
-    "color(display-p3 0.579 0.517 0.41)",
-    "color(display-p3 0.628 0.566 0.463)",
-    "color(display-p3 0.784 0.728 0.635)",
-    "color(display-p3 0.906 0.887 0.855)",
-];
-
-pub const GOLD_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.855 0.855 0.071 / 0.005)",
-    "color(display-p3 0.98 0.89 0.616 / 0.043)",
-    "color(display-p3 1 0.949 0.753 / 0.08)",
-    "color(display-p3 1 0.933 0.8 / 0.118)",
-    "color(display-p3 1 0.949 0.804 / 0.16)",
-    "color(display-p3 1 0.925 0.8 / 0.215)",
-    "color(display-p3 1 0.945 0.831 / 0.278)",
-    "color(display-p3 1 0.937 0.82 / 0.366)",
-    "color(display-p3 0.996 0.882 0.69 / 0.551)",
-    "color(display-p3 1 0.894 0.725 / 0.601)",
-    "color(display-p3 0.784 0.728 0.635)",
-    "color(display-p3 0.906 0.887 0.855)",
-];
-
-pub const SKY_DARK: [&str; 12] = [
-    "#0d141f", "#111a27", "#112840", "#113555", "#154467", "#1b537b", "#1f6692", "#197cae",
-    "#7ce2fe", "#a8eeff", "#75c7f0", "#c2f3ff",
-];
-
-pub const SKY_DARK_A: [&str; 12] = [
-    "#0044ff0f",
-    "#1171fb18",
-    "#1184fc33",
-    "#128fff49",
-    "#1c9dfd5d",
-    "#28a5ff72",
-    "#2badfe8b",
-    "#1db2fea9",
-    "#7ce3fffe",
-    "#a8eeff",
-    "#7cd3ffef",
-    "#c2f3ff",
-];
-
-pub const SKY_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.056 0.078 0.116)",
-    "color(display-p3 0.075 0.101 0.149)",
-    "color(display-p3 0.089 0.154 0.244)",
-    "color(display-p3 0.106 0.207 0.323)",
-    "color(display-p3 0.135 0.261 0.394)",
-    "color(display-p3 0.17 0.322 0.469)",
-    "color(display-p3 0.205 0.394 0.557)",
-    "color(display-p3 0.232 0.48 0.665)",
-    "color(display-p3 0.585 0.877 0.983)",
-    "color(display-p3 0.718 0.925 0.991)",
-    "color(display-p3 0.536 0.772 0.924)",
-    "color(display-p3 0.799 0.947 0.993)",
-];
-
-pub const SKY_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.282 0.996 / 0.055)",
-    "color(display-p3 0.157 0.467 0.992 / 0.089)",
-    "color(display-p3 0.192 0.522 0.996 / 0.19)",
-    "color(display-p3 0.212 0.584 1 / 0.274)",
-    "color(display-p3 0.259 0.631 1 / 0.349)",
-    "color(display-p3 0.302 0.655 1 / 0.433)",
-    "color(display-p3 0.329 0.686 1 / 0.526)",
-    "color(display-p3 0.325 0.71 1 / 0.643)",
-    "color(display-p3 0.592 0.894 1 / 0.984)",
-    "color(display-p3 0.722 0.933 1 / 0.992)",
-    "color(display-p3 0.536 0.772 0.924)",
-    "color(display-p3 0.799 0.947 0.993)",
-];
-
-pub const MINT_DARK: [&str; 12] = [
-    "#0e1515", "#0f1b1b", "#092c2b", "#003a38", "#004744", "#105650", "#1e685f", "#277f70",
-    "#86ead4", "#a8f5e5", "#58d5ba", "#c4f5e1",
-];
-
-pub const MINT_DARK_A: [&str; 12] = [
-    "#00dede05",
-    "#00f9f90b",
-    "#00fff61d",
-    "#00fff42c",
-    "#00fff23a",
-    "#0effeb4a",
-    "#34fde55e",
-    "#41ffdf76",
-    "#92ffe7e9",
-    "#aefeedf5",
-    "#67ffded2",
-    "#cbfee9f5",
-];
-
-pub const MINT_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.059 0.082 0.081)",
-    "color(display-p3 0.068 0.104 0.105)",
-    "color(display-p3 0.077 0.17 0.168)",
-    "color(display-p3 0.068 0.224 0.22)",
-    "color(display-p3 0.104 0.275 0.264)",
-    "color(display-p3 0.154 0.332 0.313)",
-    "color(display-p3 0.207 0.403 0.373)",
-    "color(display-p3 0.258 0.49 0.441)",
-    "color(display-p3 0.62 0.908 0.834)",
-    "color(display-p3 0.725 0.954 0.898)",
-    "color(display-p3 0.482 0.825 0.733)",
-    "color(display-p3 0.807 0.955 0.887)",
-];
-
-pub const MINT_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0 0.992 0.992 / 0.017)",
-    "color(display-p3 0.071 0.98 0.98 / 0.043)",
-    "color(display-p3 0.176 0.996 0.996 / 0.11)",
-    "color(display-p3 0.071 0.996 0.973 / 0.169)",
-    "color(display-p3 0.243 1 0.949 / 0.223)",
-    "color(display-p3 0.369 1 0.933 / 0.286)",
-    "color(display-p3 0.459 1 0.914 / 0.362)",
-    "color(display-p3 0.49 1 0.89 / 0.454)",
-    "color(display-p3 0.678 0.996 0.914 / 0.904)",
-    "color(display-p3 0.761 1 0.941 / 0.95)",
-    "color(display-p3 0.482 0.825 0.733)",
-    "color(display-p3 0.807 0.955 0.887)",
-];
-
-pub const LIME_DARK: [&str; 12] = [
-    "#11130c", "#151a10", "#1f2917", "#29371d", "#334423", "#3d522a", "#496231", "#577538",
-    "#bdee63", "#d4ff70", "#bde56c", "#e3f7ba",
-];
-
-pub const LIME_DARK_A: [&str; 12] = [
-    "#11bb0003",
-    "#78f7000a",
-    "#9bfd4c1a",
-    "#a7fe5c29",
-    "#affe6537",
-    "#b2fe6d46",
-    "#b6ff6f57",
-    "#b6fd6d6c",
-    "#caff69ed",
-    "#d4ff70",
-    "#d1fe77e4",
-    "#e9febff7",
-];
-
-pub const LIME_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.067 0.073 0.048)",
-    "color(display-p3 0.086 0.1 0.067)",
-    "color(display-p3 0.13 0.16 0.099)",
-    "color(display-p3 0.172 0.214 0.126)",
-    "color(display-p3 0.213 0.266 0.153)",
-    "color(display-p3 0.257 0.321 0.182)",
-    "color(display-p3 0.307 0.383 0.215)",
-    "color(display-p3 0.365 0.456 0.25)",
-    "color(display-p3 0.78 0.928 0.466)",
-    "color(display-p3 0.865 0.995 0.519)",
-    "color(display-p3 0.771 0.893 0.485)",
-    "color(display-p3 0.905 0.966 0.753)",
-];
-
-pub const LIME_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.067 0.941 0 / 0.009)",
-    "color(display-p3 0.584 0.996 0.071 / 0.038)",
-    "color(display-p3 0.69 1 0.38 / 0.101)",
-    "color(display-p3 0.729 1 0.435 / 0.16)",
-    "color(display-p3 0.745 1 0.471 / 0.215)",
-    "color(display-p3 0.769 1 0.482 / 0.274)",
-    "color(display-p3 0.769 1 0.506 / 0.341)",
-    "color(display-p3 0.784 1 0.51 / 0.416)",
-    "color(display-p3 0.839 1 0.502 / 0.925)",
-    "color(display-p3 0.871 1 0.522 / 0.996)",
-    "color(display-p3 0.771 0.893 0.485)",
-    "color(display-p3 0.905 0.966 0.753)",
-];
-
-pub const YELLOW_DARK: [&str; 12] = [
-    "#14120b", "#1b180f", "#2d2305", "#362b00", "#433500", "#524202", "#665417", "#836a21",
-    "#ffe629", "#ffff57", "#f5e147", "#f6eeb4",
-];
-
-pub const YELLOW_DARK_A: [&str; 12] = [
-    "#d1510004",
-    "#f9b4000b",
-    "#ffaa001e",
-    "#fdb70028",
-    "#febb0036",
-    "#fec40046",
-    "#fdcb225c",
-    "#fdca327b",
-    "#ffe629",
-    "#ffff57",
-    "#fee949f5",
-    "#fef6baf6",
-];
-
-pub const YELLOW_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.078 0.069 0.047)",
-    "color(display-p3 0.103 0.094 0.063)",
-    "color(display-p3 0.168 0.137 0.039)",
-    "color(display-p3 0.209 0.169 0)",
-    "color(display-p3 0.255 0.209 0)",
-    "color(display-p3 0.31 0.261 0.07)",
-    "color(display-p3 0.389 0.331 0.135)",
-    "color(display-p3 0.497 0.42 0.182)",
-    "color(display-p3 1 0.92 0.22)",
-    "color(display-p3 1 1 0.456)",
-    "color(display-p3 0.948 0.885 0.392)",
-    "color(display-p3 0.959 0.934 0.731)",
-];
-
-pub const YELLOW_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.973 0.369 0 / 0.013)",
-    "color(display-p3 0.996 0.792 0 / 0.038)",
-    "color(display-p3 0.996 0.71 0 / 0.11)",
-    "color(display-p3 0.996 0.741 0 / 0.152)",
-    "color(display-p3 0.996 0.765 0 / 0.202)",
-    "color(display-p3 0.996 0.816 0.082 / 0.261)",
-    "color(display-p3 1 0.831 0.263 / 0.345)",
-    "color(display-p3 1 0.831 0.314 / 0.463)",
-    "color(display-p3 1 0.922 0.22)",
-    "color(display-p3 1 1 0.455)",
-    "color(display-p3 0.948 0.885 0.392)",
-    "color(display-p3 0.959 0.934 0.731)",
-];
-
-pub const AMBER_DARK: [&str; 12] = [
-    "#16120c", "#1d180f", "#302008", "#3f2700", "#4d3000", "#5c3d05", "#714f19", "#8f6424",
-    "#ffc53d", "#ffd60a", "#ffca16", "#ffe7b3",
-];
-
-pub const AMBER_DARK_A: [&str; 12] = [
-    "#e63c0006",
-    "#fd9b000d",
-    "#fa820022",
-    "#fc820032",
-    "#fd8b0041",
-    "#fd9b0051",
-    "#ffab2567",
-    "#ffae3587",
-    "#ffc53d",
-    "#ffd60a",
-    "#ffca16",
-    "#ffe7b3",
-];
-
-pub const AMBER_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.082 0.07 0.05)",
-    "color(display-p3 0.111 0.094 0.064)",
-    "color(display-p3 0.178 0.128 0.049)",
-    "color(display-p3 0.239 0.156 0)",
-    "color(display-p3 0.29 0.193 0)",
-    "color(display-p3 0.344 0.245 0.076)",
-    "color(display-p3 0.422 0.314 0.141)",
-    "color(display-p3 0.535 0.399 0.189)",
-    "color(display-p3 1 0.77 0.26)",
-    "color(display-p3 1 0.87 0.15)",
-    "color(display-p3 1 0.8 0.29)",
-    "color(display-p3 0.984 0.909 0.726)",
-];
-
-pub const AMBER_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.992 0.298 0 / 0.017)",
-    "color(display-p3 0.988 0.651 0 / 0.047)",
-    "color(display-p3 1 0.6 0 / 0.118)",
-    "color(display-p3 1 0.557 0 / 0.185)",
-    "color(display-p3 1 0.592 0 / 0.24)",
-    "color(display-p3 1 0.659 0.094 / 0.299)",
-    "color(display-p3 1 0.714 0.263 / 0.383)",
-    "color(display-p3 0.996 0.729 0.306 / 0.5)",
-    "color(display-p3 1 0.769 0.259)",
-    "color(display-p3 1 0.871 0.149)",
-    "color(display-p3 1 0.8 0.29)",
-    "color(display-p3 0.984 0.909 0.726)",
-];
-
-pub const ORANGE_DARK: [&str; 12] = [
-    "#17120e", "#1e160f", "#331e0b", "#462100", "#562800", "#66350c", "#7e451d", "#a35829",
-    "#f76b15", "#ff801f", "#ffa057", "#ffe0c2",
-];
-
-pub const ORANGE_DARK_A: [&str; 12] = [
-    "#ec360007",
-    "#fe6d000e",
-    "#fb6a0025",
-    "#ff590039",
-    "#ff61004a",
-    "#fd75045c",
-    "#ff832c75",
-    "#fe84389d",
-    "#fe6d15f7",
-    "#ff801f",
-    "#ffa057",
-    "#ffe0c2",
-];
-
-pub const ORANGE_DARK_P3: [&str; 12] = [
-    "color(display-p3 0.088 0.07 0.057)",
-    "color(display-p3 0.113 0.089 0.061)",
-    "color(display-p3 0.189 0.12 0.056)",
-    "color(display-p3 0.262 0.132 0)",
-    "color(display-p3 0.315 0.168 0.016)",
-    "color(display-p3 0.376 0.219 0.088)",
-    "color(display-p3 0.465 0.283 0.147)",
-    "color(display-p3 0.601 0.359 0.201)",
-    "color(display-p3 0.9 0.45 0.2)",
-    "color(display-p3 0.98 0.51 0.23)",
-    "color(display-p3 1 0.63 0.38)",
-    "color(display-p3 0.98 0.883 0.775)",
-];
-
-pub const ORANGE_DARK_P3_A: [&str; 12] = [
-    "color(display-p3 0.961 0.247 0 / 0.022)",
-    "color(display-p3 0.992 0.529 0 / 0.051)",
-    "color(display-p3 0.996 0.486 0 / 0.131)",
-    "color(display-p3 0.996 0.384 0 / 0.211)",
-    "color(display-p3 1 0.455 0 / 0.265)",
-    "color(display-p3 1 0.529 0.129 / 0.332)",
-    "color(display-p3 1 0.569 0.251 / 0.429)",
-    "color(display-p3 1 0.584 0.302 / 0.572)",
-    "color(display-p3 1 0.494 0.216 / 0.895)",
-    "color(display-p3 1 0.522 0.235 / 0.979)",
-    "color(display-p3 1 0.63 0.38)",
-    "color(display-p3 0.98 0.883 0.775)",
-];
@@ -1,1549 +0,0 @@
-pub const GRAY: [&str; 12] = [
-    "#fcfcfc", "#f9f9f9", "#f0f0f0", "#e8e8e8", "#e0e0e0", "#d9d9d9", "#cecece", "#bbbbbb",
-    "#8d8d8d", "#838383", "#646464", "#202020",
-];
-
-pub const GRAY_A: [&str; 12] = [
-    "#00000003",
-    "#00000006",
-    "#0000000f",
-    "#00000017",
-    "#0000001f",
-    "#00000026",
-    "#00000031",
-    "#00000044",
-    "#00000072",
-    "#0000007c",
-    "#0000009b",
-    "#000000df",
-];
-
-pub const GRAY_P3: [&str; 12] = [
-    "color(display-p3 0.988 0.988 0.988)",
-    "color(display-p3 0.975 0.975 0.975)",
-    "color(display-p3 0.939 0.939 0.939)",
-    "color(display-p3 0.908 0.908 0.908)",
-    "color(display-p3 0.88 0.88 0.88)",
-    "color(display-p3 0.849 0.849 0.849)",
-    "color(display-p3 0.807 0.807 0.807)",
-    "color(display-p3 0.732 0.732 0.732)",
-    "color(display-p3 0.553 0.553 0.553)",
-    "color(display-p3 0.512 0.512 0.512)",
-    "color(display-p3 0.392 0.392 0.392)",
-    "color(display-p3 0.125 0.125 0.125)",
-];
-
-pub const GRAY_P3_A: [&str; 12] = [
-    "color(display-p3 0 0 0 / 0.012)",
-    "color(display-p3 0 0 0 / 0.024)",
-    "color(display-p3 0 0 0 / 0.063)",
-    "color(display-p3 0 0 0 / 0.09)",
-    "color(display-p3 0 0 0 / 0.122)",
-    "color(display-p3 0 0 0 / 0.153)",
-    "color(display-p3 0 0 0 / 0.192)",
-    "color(display-p3 0 0 0 / 0.267)",
-    "color(display-p3 0 0 0 / 0.447)",
-    "color(display-p3 0 0 0 / 0.486)",
-    "color(display-p3 0 0 0 / 0.608)",
-    "color(display-p3 0 0 0 / 0.875)",
-];
-
-pub const MAUVE: [&str; 12] = [
-    "#fdfcfd", "#faf9fb", "#f2eff3", "#eae7ec", "#e3dfe6", "#dbd8e0", "#d0cdd7", "#bcbac7",
-    "#8e8c99", "#84828e", "#65636d", "#211f26",
-];
-
-pub const MAUVE_A: [&str; 12] = [
-    "#55005503",
-    "#2b005506",
-    "#30004010",
-    "#20003618",
-    "#20003820",
-    "#14003527",
-    "#10003332",
-    "#08003145",
-    "#05001d73",
-    "#0500197d",
-    "#0400119c",
-    "#020008e0",
-];
-
-pub const MAUVE_P3: [&str; 12] = [
-    "color(display-p3 0.991 0.988 0.992)",
-    "color(display-p3 0.98 0.976 0.984)",
-    "color(display-p3 0.946 0.938 0.952)",
-    "color(display-p3 0.915 0.906 0.925)",
-    "color(display-p3 0.886 0.876 0.901)",
-    "color(display-p3 0.856 0.846 0.875)",
-    "color(display-p3 0.814 0.804 0.84)",
-    "color(display-p3 0.735 0.728 0.777)",
-    "color(display-p3 0.555 0.549 0.596)",
-    "color(display-p3 0.514 0.508 0.552)",
-    "color(display-p3 0.395 0.388 0.424)",
-    "color(display-p3 0.128 0.122 0.147)",
-];
-
-pub const MAUVE_P3_A: [&str; 12] = [
-    "color(display-p3 0.349 0.024 0.349 / 0.012)",
-    "color(display-p3 0.184 0.024 0.349 / 0.024)",
-    "color(display-p3 0.129 0.008 0.255 / 0.063)",
-    "color(display-p3 0.094 0.012 0.216 / 0.095)",
-    "color(display-p3 0.098 0.008 0.224 / 0.126)",
-    "color(display-p3 0.055 0.004 0.18 / 0.153)",
-    "color(display-p3 0.067 0.008 0.184 / 0.197)",
-    "color(display-p3 0.02 0.004 0.176 / 0.271)",
-    "color(display-p3 0.02 0.004 0.106 / 0.451)",
-    "color(display-p3 0.012 0.004 0.09 / 0.491)",
-    "color(display-p3 0.016 0 0.059 / 0.612)",
-    "color(display-p3 0.008 0 0.027 / 0.879)",
-];
-
-pub const SLATE: [&str; 12] = [
-    "#fcfcfd", "#f9f9fb", "#f0f0f3", "#e8e8ec", "#e0e1e6", "#d9d9e0", "#cdced6", "#b9bbc6",
-    "#8b8d98", "#80838d", "#60646c", "#1c2024",
-];
-
-pub const SLATE_A: [&str; 12] = [
-    "#00005503",
-    "#00005506",
-    "#0000330f",
-    "#00002d17",
-    "#0009321f",
-    "#00002f26",
-    "#00062e32",
-    "#00083046",
-    "#00051d74",
-    "#00071b7f",
-    "#0007149f",
-    "#000509e3",
-];
-
-pub const SLATE_P3: [&str; 12] = [
-    "color(display-p3 0.988 0.988 0.992)",
-    "color(display-p3 0.976 0.976 0.984)",
-    "color(display-p3 0.94 0.941 0.953)",
-    "color(display-p3 0.908 0.909 0.925)",
-    "color(display-p3 0.88 0.881 0.901)",
-    "color(display-p3 0.85 0.852 0.876)",
-    "color(display-p3 0.805 0.808 0.838)",
-    "color(display-p3 0.727 0.733 0.773)",
-    "color(display-p3 0.547 0.553 0.592)",
-    "color(display-p3 0.503 0.512 0.549)",
-    "color(display-p3 0.379 0.392 0.421)",
-    "color(display-p3 0.113 0.125 0.14)",
-];
-
-pub const SLATE_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.024 0.349 / 0.012)",
-    "color(display-p3 0.024 0.024 0.349 / 0.024)",
-    "color(display-p3 0.004 0.004 0.204 / 0.059)",
-    "color(display-p3 0.012 0.012 0.184 / 0.091)",
-    "color(display-p3 0.004 0.039 0.2 / 0.122)",
-    "color(display-p3 0.008 0.008 0.165 / 0.15)",
-    "color(display-p3 0.008 0.027 0.184 / 0.197)",
-    "color(display-p3 0.004 0.031 0.176 / 0.275)",
-    "color(display-p3 0.004 0.02 0.106 / 0.455)",
-    "color(display-p3 0.004 0.027 0.098 / 0.499)",
-    "color(display-p3 0 0.02 0.063 / 0.62)",
-    "color(display-p3 0 0.012 0.031 / 0.887)",
-];
-
-pub const SAGE: [&str; 12] = [
-    "#fbfdfc", "#f7f9f8", "#eef1f0", "#e6e9e8", "#dfe2e0", "#d7dad9", "#cbcfcd", "#b8bcba",
-    "#868e8b", "#7c8481", "#5f6563", "#1a211e",
-];
-
-pub const SAGE_A: [&str; 12] = [
-    "#00804004",
-    "#00402008",
-    "#002d1e11",
-    "#001f1519",
-    "#00180820",
-    "#00140d28",
-    "#00140a34",
-    "#000f0847",
-    "#00110b79",
-    "#00100a83",
-    "#000a07a0",
-    "#000805e5",
-];
-
-pub const SAGE_P3: [&str; 12] = [
-    "color(display-p3 0.986 0.992 0.988)",
-    "color(display-p3 0.97 0.977 0.974)",
-    "color(display-p3 0.935 0.944 0.94)",
-    "color(display-p3 0.904 0.913 0.909)",
-    "color(display-p3 0.875 0.885 0.88)",
-    "color(display-p3 0.844 0.854 0.849)",
-    "color(display-p3 0.8 0.811 0.806)",
-    "color(display-p3 0.725 0.738 0.732)",
-    "color(display-p3 0.531 0.556 0.546)",
-    "color(display-p3 0.492 0.515 0.506)",
-    "color(display-p3 0.377 0.395 0.389)",
-    "color(display-p3 0.107 0.129 0.118)",
-];
-
-pub const SAGE_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.514 0.267 / 0.016)",
-    "color(display-p3 0.02 0.267 0.145 / 0.032)",
-    "color(display-p3 0.008 0.184 0.125 / 0.067)",
-    "color(display-p3 0.012 0.094 0.051 / 0.095)",
-    "color(display-p3 0.008 0.098 0.035 / 0.126)",
-    "color(display-p3 0.004 0.078 0.027 / 0.157)",
-    "color(display-p3 0 0.059 0.039 / 0.2)",
-    "color(display-p3 0.004 0.047 0.031 / 0.275)",
-    "color(display-p3 0.004 0.059 0.035 / 0.471)",
-    "color(display-p3 0 0.047 0.031 / 0.51)",
-    "color(display-p3 0 0.031 0.02 / 0.624)",
-    "color(display-p3 0 0.027 0.012 / 0.895)",
-];
-
-pub const OLIVE: [&str; 12] = [
-    "#fcfdfc", "#f8faf8", "#eff1ef", "#e7e9e7", "#dfe2df", "#d7dad7", "#cccfcc", "#b9bcb8",
-    "#898e87", "#7f847d", "#60655f", "#1d211c",
-];
-
-pub const OLIVE_A: [&str; 12] = [
-    "#00550003",
-    "#00490007",
-    "#00200010",
-    "#00160018",
-    "#00180020",
-    "#00140028",
-    "#000f0033",
-    "#040f0047",
-    "#050f0078",
-    "#040e0082",
-    "#020a00a0",
-    "#010600e3",
-];
-
-pub const OLIVE_P3: [&str; 12] = [
-    "color(display-p3 0.989 0.992 0.989)",
-    "color(display-p3 0.974 0.98 0.973)",
-    "color(display-p3 0.939 0.945 0.937)",
-    "color(display-p3 0.907 0.914 0.905)",
-    "color(display-p3 0.878 0.885 0.875)",
-    "color(display-p3 0.846 0.855 0.843)",
-    "color(display-p3 0.803 0.812 0.8)",
-    "color(display-p3 0.727 0.738 0.723)",
-    "color(display-p3 0.541 0.556 0.532)",
-    "color(display-p3 0.5 0.515 0.491)",
-    "color(display-p3 0.38 0.395 0.374)",
-    "color(display-p3 0.117 0.129 0.111)",
-];
-
-pub const OLIVE_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.349 0.024 / 0.012)",
-    "color(display-p3 0.024 0.302 0.024 / 0.028)",
-    "color(display-p3 0.008 0.129 0.008 / 0.063)",
-    "color(display-p3 0.012 0.094 0.012 / 0.095)",
-    "color(display-p3 0.035 0.098 0.008 / 0.126)",
-    "color(display-p3 0.027 0.078 0.004 / 0.157)",
-    "color(display-p3 0.02 0.059 0 / 0.2)",
-    "color(display-p3 0.02 0.059 0.004 / 0.279)",
-    "color(display-p3 0.02 0.051 0.004 / 0.467)",
-    "color(display-p3 0.024 0.047 0 / 0.51)",
-    "color(display-p3 0.012 0.039 0 / 0.628)",
-    "color(display-p3 0.008 0.024 0 / 0.891)",
-];
-
-pub const SAND: [&str; 12] = [
-    "#fdfdfc", "#f9f9f8", "#f1f0ef", "#e9e8e6", "#e2e1de", "#dad9d6", "#cfceca", "#bcbbb5",
-    "#8d8d86", "#82827c", "#63635e", "#21201c",
-];
-
-pub const SAND_A: [&str; 12] = [
-    "#55550003",
-    "#25250007",
-    "#20100010",
-    "#1f150019",
-    "#1f180021",
-    "#19130029",
-    "#19140035",
-    "#1915014a",
-    "#0f0f0079",
-    "#0c0c0083",
-    "#080800a1",
-    "#060500e3",
-];
-
-pub const SAND_P3: [&str; 12] = [
-    "color(display-p3 0.992 0.992 0.989)",
-    "color(display-p3 0.977 0.977 0.973)",
-    "color(display-p3 0.943 0.942 0.936)",
-    "color(display-p3 0.913 0.912 0.903)",
-    "color(display-p3 0.885 0.883 0.873)",
-    "color(display-p3 0.854 0.852 0.839)",
-    "color(display-p3 0.813 0.81 0.794)",
-    "color(display-p3 0.738 0.734 0.713)",
-    "color(display-p3 0.553 0.553 0.528)",
-    "color(display-p3 0.511 0.511 0.488)",
-    "color(display-p3 0.388 0.388 0.37)",
-    "color(display-p3 0.129 0.126 0.111)",
-];
-
-pub const SAND_P3_A: [&str; 12] = [
-    "color(display-p3 0.349 0.349 0.024 / 0.012)",
-    "color(display-p3 0.161 0.161 0.024 / 0.028)",
-    "color(display-p3 0.067 0.067 0.008 / 0.063)",
-    "color(display-p3 0.129 0.129 0.012 / 0.099)",
-    "color(display-p3 0.098 0.067 0.008 / 0.126)",
-    "color(display-p3 0.102 0.075 0.004 / 0.161)",
-    "color(display-p3 0.098 0.098 0.004 / 0.208)",
-    "color(display-p3 0.086 0.075 0.004 / 0.287)",
-    "color(display-p3 0.051 0.051 0.004 / 0.471)",
-    "color(display-p3 0.047 0.047 0 / 0.514)",
-    "color(display-p3 0.031 0.031 0 / 0.632)",
-    "color(display-p3 0.024 0.02 0 / 0.891)",
-];
-
-pub const TOMATO: [&str; 12] = [
-    "#fffcfc", "#fff8f7", "#feebe7", "#ffdcd3", "#ffcdc2", "#fdbdaf", "#f5a898", "#ec8e7b",
-    "#e54d2e", "#dd4425", "#d13415", "#5c271f",
-];
-
-pub const TOMATO_A: [&str; 12] = [
-    "#ff000003",
-    "#ff200008",
-    "#f52b0018",
-    "#ff35002c",
-    "#ff2e003d",
-    "#f92d0050",
-    "#e7280067",
-    "#db250084",
-    "#df2600d1",
-    "#d72400da",
-    "#cd2200ea",
-    "#460900e0",
-];
-
-pub const TOMATO_P3: [&str; 12] = [
-    "color(display-p3 0.998 0.989 0.988)",
-    "color(display-p3 0.994 0.974 0.969)",
-    "color(display-p3 0.985 0.924 0.909)",
-    "color(display-p3 0.996 0.868 0.835)",
-    "color(display-p3 0.98 0.812 0.77)",
-    "color(display-p3 0.953 0.75 0.698)",
-    "color(display-p3 0.917 0.673 0.611)",
-    "color(display-p3 0.875 0.575 0.502)",
-    "color(display-p3 0.831 0.345 0.231)",
-    "color(display-p3 0.802 0.313 0.2)",
-    "color(display-p3 0.755 0.259 0.152)",
-    "color(display-p3 0.335 0.165 0.132)",
-];
-
-pub const TOMATO_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 0.024 / 0.012)",
-    "color(display-p3 0.757 0.145 0.02 / 0.032)",
-    "color(display-p3 0.831 0.184 0.012 / 0.091)",
-    "color(display-p3 0.976 0.192 0.004 / 0.165)",
-    "color(display-p3 0.918 0.192 0.004 / 0.232)",
-    "color(display-p3 0.847 0.173 0.004 / 0.302)",
-    "color(display-p3 0.788 0.165 0.004 / 0.389)",
-    "color(display-p3 0.749 0.153 0.004 / 0.499)",
-    "color(display-p3 0.78 0.149 0 / 0.769)",
-    "color(display-p3 0.757 0.141 0 / 0.8)",
-    "color(display-p3 0.755 0.259 0.152)",
-    "color(display-p3 0.335 0.165 0.132)",
-];
-
-pub const RED: [&str; 12] = [
-    "#fffcfc", "#fff7f7", "#feebec", "#ffdbdc", "#ffcdce", "#fdbdbe", "#f4a9aa", "#eb8e90",
-    "#e5484d", "#dc3e42", "#ce2c31", "#641723",
-];
-
-pub const RED_A: [&str; 12] = [
-    "#ff000003",
-    "#ff000008",
-    "#f3000d14",
-    "#ff000824",
-    "#ff000632",
-    "#f8000442",
-    "#df000356",
-    "#d2000571",
-    "#db0007b7",
-    "#d10005c1",
-    "#c40006d3",
-    "#55000de8",
-];
-
-pub const RED_P3: [&str; 12] = [
-    "color(display-p3 0.998 0.989 0.988)",
-    "color(display-p3 0.995 0.971 0.971)",
-    "color(display-p3 0.985 0.925 0.925)",
-    "color(display-p3 0.999 0.866 0.866)",
-    "color(display-p3 0.984 0.812 0.811)",
-    "color(display-p3 0.955 0.751 0.749)",
-    "color(display-p3 0.915 0.675 0.672)",
-    "color(display-p3 0.872 0.575 0.572)",
-    "color(display-p3 0.83 0.329 0.324)",
-    "color(display-p3 0.798 0.294 0.285)",
-    "color(display-p3 0.744 0.234 0.222)",
-    "color(display-p3 0.36 0.115 0.143)",
-];
-
-pub const RED_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 0.024 / 0.012)",
-    "color(display-p3 0.863 0.024 0.024 / 0.028)",
-    "color(display-p3 0.792 0.008 0.008 / 0.075)",
-    "color(display-p3 1 0.008 0.008 / 0.134)",
-    "color(display-p3 0.918 0.008 0.008 / 0.189)",
-    "color(display-p3 0.831 0.02 0.004 / 0.251)",
-    "color(display-p3 0.741 0.016 0.004 / 0.33)",
-    "color(display-p3 0.698 0.012 0.004 / 0.428)",
-    "color(display-p3 0.749 0.008 0 / 0.675)",
-    "color(display-p3 0.714 0.012 0 / 0.714)",
-    "color(display-p3 0.744 0.234 0.222)",
-    "color(display-p3 0.36 0.115 0.143)",
-];
-
-pub const RUBY: [&str; 12] = [
-    "#fffcfd", "#fff7f8", "#feeaed", "#ffdce1", "#ffced6", "#f8bfc8", "#efacb8", "#e592a3",
-    "#e54666", "#dc3b5d", "#ca244d", "#64172b",
-];
-
-pub const RUBY_A: [&str; 12] = [
-    "#ff005503",
-    "#ff002008",
-    "#f3002515",
-    "#ff002523",
-    "#ff002a31",
-    "#e4002440",
-    "#ce002553",
-    "#c300286d",
-    "#db002cb9",
-    "#d2002cc4",
-    "#c10030db",
-    "#550016e8",
-];
-
-pub const RUBY_P3: [&str; 12] = [
-    "color(display-p3 0.998 0.989 0.992)",
-    "color(display-p3 0.995 0.971 0.974)",
-    "color(display-p3 0.983 0.92 0.928)",
-    "color(display-p3 0.987 0.869 0.885)",
-    "color(display-p3 0.968 0.817 0.839)",
-    "color(display-p3 0.937 0.758 0.786)",
-    "color(display-p3 0.897 0.685 0.721)",
-    "color(display-p3 0.851 0.588 0.639)",
-    "color(display-p3 0.83 0.323 0.408)",
-    "color(display-p3 0.795 0.286 0.375)",
-    "color(display-p3 0.728 0.211 0.311)",
-    "color(display-p3 0.36 0.115 0.171)",
-];
-
-pub const RUBY_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 0.349 / 0.012)",
-    "color(display-p3 0.863 0.024 0.024 / 0.028)",
-    "color(display-p3 0.804 0.008 0.11 / 0.079)",
-    "color(display-p3 0.91 0.008 0.125 / 0.13)",
-    "color(display-p3 0.831 0.004 0.133 / 0.185)",
-    "color(display-p3 0.745 0.004 0.118 / 0.244)",
-    "color(display-p3 0.678 0.004 0.114 / 0.314)",
-    "color(display-p3 0.639 0.004 0.125 / 0.412)",
-    "color(display-p3 0.753 0 0.129 / 0.679)",
-    "color(display-p3 0.714 0 0.125 / 0.714)",
-    "color(display-p3 0.728 0.211 0.311)",
-    "color(display-p3 0.36 0.115 0.171)",
-];
-
-pub const CRIMSON: [&str; 12] = [
-    "#fffcfd", "#fef7f9", "#ffe9f0", "#fedce7", "#facedd", "#f3bed1", "#eaacc3", "#e093b2",
-    "#e93d82", "#df3478", "#cb1d63", "#621639",
-];
-
-pub const CRIMSON_A: [&str; 12] = [
-    "#ff005503",
-    "#e0004008",
-    "#ff005216",
-    "#f8005123",
-    "#e5004f31",
-    "#d0004b41",
-    "#bf004753",
-    "#b6004a6c",
-    "#e2005bc2",
-    "#d70056cb",
-    "#c4004fe2",
-    "#530026e9",
-];
-
-pub const CRIMSON_P3: [&str; 12] = [
-    "color(display-p3 0.998 0.989 0.992)",
-    "color(display-p3 0.991 0.969 0.976)",
-    "color(display-p3 0.987 0.917 0.941)",
-    "color(display-p3 0.975 0.866 0.904)",
-    "color(display-p3 0.953 0.813 0.864)",
-    "color(display-p3 0.921 0.755 0.817)",
-    "color(display-p3 0.88 0.683 0.761)",
-    "color(display-p3 0.834 0.592 0.694)",
-    "color(display-p3 0.843 0.298 0.507)",
-    "color(display-p3 0.807 0.266 0.468)",
-    "color(display-p3 0.731 0.195 0.388)",
-    "color(display-p3 0.352 0.111 0.221)",
-];
-
-pub const CRIMSON_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 0.349 / 0.012)",
-    "color(display-p3 0.757 0.02 0.267 / 0.032)",
-    "color(display-p3 0.859 0.008 0.294 / 0.083)",
-    "color(display-p3 0.827 0.008 0.298 / 0.134)",
-    "color(display-p3 0.753 0.008 0.275 / 0.189)",
-    "color(display-p3 0.682 0.004 0.247 / 0.244)",
-    "color(display-p3 0.62 0.004 0.251 / 0.318)",
-    "color(display-p3 0.6 0.004 0.251 / 0.408)",
-    "color(display-p3 0.776 0 0.298 / 0.702)",
-    "color(display-p3 0.737 0 0.275 / 0.734)",
-    "color(display-p3 0.731 0.195 0.388)",
-    "color(display-p3 0.352 0.111 0.221)",
-];
-
-pub const PINK: [&str; 12] = [
-    "#fffcfe", "#fef7fb", "#fee9f5", "#fbdcef", "#f6cee7", "#efbfdd", "#e7acd0", "#dd93c2",
-    "#d6409f", "#cf3897", "#c2298a", "#651249",
-];
-
-pub const PINK_A: [&str; 12] = [
-    "#ff00aa03",
-    "#e0008008",
-    "#f4008c16",
-    "#e2008b23",
-    "#d1008331",
-    "#c0007840",
-    "#b6006f53",
-    "#af006f6c",
-    "#c8007fbf",
-    "#c2007ac7",
-    "#b60074d6",
-    "#59003bed",
-];
-
-pub const PINK_P3: [&str; 12] = [
-    "color(display-p3 0.998 0.989 0.996)",
-    "color(display-p3 0.992 0.97 0.985)",
-    "color(display-p3 0.981 0.917 0.96)",
-    "color(display-p3 0.963 0.867 0.932)",
-    "color(display-p3 0.939 0.815 0.899)",
-    "color(display-p3 0.907 0.756 0.859)",
-    "color(display-p3 0.869 0.683 0.81)",
-    "color(display-p3 0.825 0.59 0.751)",
-    "color(display-p3 0.775 0.297 0.61)",
-    "color(display-p3 0.748 0.27 0.581)",
-    "color(display-p3 0.698 0.219 0.528)",
-    "color(display-p3 0.363 0.101 0.279)",
-];
-
-pub const PINK_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 0.675 / 0.012)",
-    "color(display-p3 0.757 0.02 0.51 / 0.032)",
-    "color(display-p3 0.765 0.008 0.529 / 0.083)",
-    "color(display-p3 0.737 0.008 0.506 / 0.134)",
-    "color(display-p3 0.663 0.004 0.451 / 0.185)",
-    "color(display-p3 0.616 0.004 0.424 / 0.244)",
-    "color(display-p3 0.596 0.004 0.412 / 0.318)",
-    "color(display-p3 0.573 0.004 0.404 / 0.412)",
-    "color(display-p3 0.682 0 0.447 / 0.702)",
-    "color(display-p3 0.655 0 0.424 / 0.73)",
-    "color(display-p3 0.698 0.219 0.528)",
-    "color(display-p3 0.363 0.101 0.279)",
-];
-
-pub const PLUM: [&str; 12] = [
-    "#fefcff", "#fdf7fd", "#fbebfb", "#f7def8", "#f2d1f3", "#e9c2ec", "#deade3", "#cf91d8",
-    "#ab4aba", "#a144af", "#953ea3", "#53195d",
-];
-
-pub const PLUM_A: [&str; 12] = [
-    "#aa00ff03",
-    "#c000c008",
-    "#cc00cc14",
-    "#c200c921",
-    "#b700bd2e",
-    "#a400b03d",
-    "#9900a852",
-    "#9000a56e",
-    "#89009eb5",
-    "#7f0092bb",
-    "#730086c1",
-    "#40004be6",
-];
-
-pub const PLUM_P3: [&str; 12] = [
-    "color(display-p3 0.995 0.988 0.999)",
-    "color(display-p3 0.988 0.971 0.99)",
-    "color(display-p3 0.973 0.923 0.98)",
-    "color(display-p3 0.953 0.875 0.966)",
-    "color(display-p3 0.926 0.825 0.945)",
-    "color(display-p3 0.89 0.765 0.916)",
-    "color(display-p3 0.84 0.686 0.877)",
-    "color(display-p3 0.775 0.58 0.832)",
-    "color(display-p3 0.624 0.313 0.708)",
-    "color(display-p3 0.587 0.29 0.667)",
-    "color(display-p3 0.543 0.263 0.619)",
-    "color(display-p3 0.299 0.114 0.352)",
-];
-
-pub const PLUM_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 1 / 0.012)",
-    "color(display-p3 0.58 0.024 0.58 / 0.028)",
-    "color(display-p3 0.655 0.008 0.753 / 0.079)",
-    "color(display-p3 0.627 0.008 0.722 / 0.126)",
-    "color(display-p3 0.58 0.004 0.69 / 0.177)",
-    "color(display-p3 0.537 0.004 0.655 / 0.236)",
-    "color(display-p3 0.49 0.004 0.616 / 0.314)",
-    "color(display-p3 0.471 0.004 0.6 / 0.42)",
-    "color(display-p3 0.451 0 0.576 / 0.687)",
-    "color(display-p3 0.42 0 0.529 / 0.71)",
-    "color(display-p3 0.543 0.263 0.619)",
-    "color(display-p3 0.299 0.114 0.352)",
-];
-
-pub const PURPLE: [&str; 12] = [
-    "#fefcfe", "#fbf7fe", "#f7edfe", "#f2e2fc", "#ead5f9", "#e0c4f4", "#d1afec", "#be93e4",
-    "#8e4ec6", "#8347b9", "#8145b5", "#402060",
-];
-
-pub const PURPLE_A: [&str; 12] = [
-    "#aa00aa03",
-    "#8000e008",
-    "#8e00f112",
-    "#8d00e51d",
-    "#8000db2a",
-    "#7a01d03b",
-    "#6d00c350",
-    "#6600c06c",
-    "#5c00adb1",
-    "#53009eb8",
-    "#52009aba",
-    "#250049df",
-];
-
-pub const PURPLE_P3: [&str; 12] = [
-    "color(display-p3 0.995 0.988 0.996)",
-    "color(display-p3 0.983 0.971 0.993)",
-    "color(display-p3 0.963 0.931 0.989)",
-    "color(display-p3 0.937 0.888 0.981)",
-    "color(display-p3 0.904 0.837 0.966)",
-    "color(display-p3 0.86 0.774 0.942)",
-    "color(display-p3 0.799 0.69 0.91)",
-    "color(display-p3 0.719 0.583 0.874)",
-    "color(display-p3 0.523 0.318 0.751)",
-    "color(display-p3 0.483 0.289 0.7)",
-    "color(display-p3 0.473 0.281 0.687)",
-    "color(display-p3 0.234 0.132 0.363)",
-];
-
-pub const PURPLE_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.024 0.675 / 0.012)",
-    "color(display-p3 0.443 0.024 0.722 / 0.028)",
-    "color(display-p3 0.506 0.008 0.835 / 0.071)",
-    "color(display-p3 0.451 0.004 0.831 / 0.114)",
-    "color(display-p3 0.431 0.004 0.788 / 0.165)",
-    "color(display-p3 0.384 0.004 0.745 / 0.228)",
-    "color(display-p3 0.357 0.004 0.71 / 0.31)",
-    "color(display-p3 0.322 0.004 0.702 / 0.416)",
-    "color(display-p3 0.298 0 0.639 / 0.683)",
-    "color(display-p3 0.271 0 0.58 / 0.71)",
-    "color(display-p3 0.473 0.281 0.687)",
-    "color(display-p3 0.234 0.132 0.363)",
-];
-
-pub const VIOLET: [&str; 12] = [
-    "#fdfcfe", "#faf8ff", "#f4f0fe", "#ebe4ff", "#e1d9ff", "#d4cafe", "#c2b5f5", "#aa99ec",
-    "#6e56cf", "#654dc4", "#6550b9", "#2f265f",
-];
-
-pub const VIOLET_A: [&str; 12] = [
-    "#5500aa03",
-    "#4900ff07",
-    "#4400ee0f",
-    "#4300ff1b",
-    "#3600ff26",
-    "#3100fb35",
-    "#2d01dd4a",
-    "#2b00d066",
-    "#2400b7a9",
-    "#2300abb2",
-    "#1f0099af",
-    "#0b0043d9",
-];
-
-pub const VIOLET_P3: [&str; 12] = [
-    "color(display-p3 0.991 0.988 0.995)",
-    "color(display-p3 0.978 0.974 0.998)",
-    "color(display-p3 0.953 0.943 0.993)",
-    "color(display-p3 0.916 0.897 1)",
-    "color(display-p3 0.876 0.851 1)",
-    "color(display-p3 0.825 0.793 0.981)",
-    "color(display-p3 0.752 0.712 0.943)",
-    "color(display-p3 0.654 0.602 0.902)",
-    "color(display-p3 0.417 0.341 0.784)",
-    "color(display-p3 0.381 0.306 0.741)",
-    "color(display-p3 0.383 0.317 0.702)",
-    "color(display-p3 0.179 0.15 0.359)",
-];
-
-pub const VIOLET_P3_A: [&str; 12] = [
-    "color(display-p3 0.349 0.024 0.675 / 0.012)",
-    "color(display-p3 0.161 0.024 0.863 / 0.028)",
-    "color(display-p3 0.204 0.004 0.871 / 0.059)",
-    "color(display-p3 0.196 0.004 1 / 0.102)",
-    "color(display-p3 0.165 0.008 1 / 0.15)",
-    "color(display-p3 0.153 0.004 0.906 / 0.208)",
-    "color(display-p3 0.141 0.004 0.796 / 0.287)",
-    "color(display-p3 0.133 0.004 0.753 / 0.397)",
-    "color(display-p3 0.114 0 0.675 / 0.659)",
-    "color(display-p3 0.11 0 0.627 / 0.695)",
-    "color(display-p3 0.383 0.317 0.702)",
-    "color(display-p3 0.179 0.15 0.359)",
-];
-
-pub const IRIS: [&str; 12] = [
-    "#fdfdff", "#f8f8ff", "#f0f1fe", "#e6e7ff", "#dadcff", "#cbcdff", "#b8baf8", "#9b9ef0",
-    "#5b5bd6", "#5151cd", "#5753c6", "#272962",
-];
-
-pub const IRIS_A: [&str; 12] = [
-    "#0000ff02",
-    "#0000ff07",
-    "#0011ee0f",
-    "#000bff19",
-    "#000eff25",
-    "#000aff34",
-    "#0008e647",
-    "#0008d964",
-    "#0000c0a4",
-    "#0000b6ae",
-    "#0600abac",
-    "#000246d8",
-];
-
-pub const IRIS_P3: [&str; 12] = [
-    "color(display-p3 0.992 0.992 0.999)",
-    "color(display-p3 0.972 0.973 0.998)",
-    "color(display-p3 0.943 0.945 0.992)",
-    "color(display-p3 0.902 0.906 1)",
-    "color(display-p3 0.857 0.861 1)",
-    "color(display-p3 0.799 0.805 0.987)",
-    "color(display-p3 0.721 0.727 0.955)",
-    "color(display-p3 0.61 0.619 0.918)",
-    "color(display-p3 0.357 0.357 0.81)",
-    "color(display-p3 0.318 0.318 0.774)",
-    "color(display-p3 0.337 0.326 0.748)",
-    "color(display-p3 0.154 0.161 0.371)",
-];
-
-pub const IRIS_P3_A: [&str; 12] = [
-    "color(display-p3 0.02 0.02 1 / 0.008)",
-    "color(display-p3 0.024 0.024 0.863 / 0.028)",
-    "color(display-p3 0.004 0.071 0.871 / 0.059)",
-    "color(display-p3 0.012 0.051 1 / 0.099)",
-    "color(display-p3 0.008 0.035 1 / 0.142)",
-    "color(display-p3 0 0.02 0.941 / 0.2)",
-    "color(display-p3 0.004 0.02 0.847 / 0.279)",
-    "color(display-p3 0.004 0.024 0.788 / 0.389)",
-    "color(display-p3 0 0 0.706 / 0.644)",
-    "color(display-p3 0 0 0.667 / 0.683)",
-    "color(display-p3 0.337 0.326 0.748)",
-    "color(display-p3 0.154 0.161 0.371)",
-];
-
-pub const INDIGO: [&str; 12] = [
-    "#fdfdfe", "#f7f9ff", "#edf2fe", "#e1e9ff", "#d2deff", "#c1d0ff", "#abbdf9", "#8da4ef",
-    "#3e63dd", "#3358d4", "#3a5bc7", "#1f2d5c",
-];
-
-pub const INDIGO_A: [&str; 12] = [
-    "#00008002",
-    "#0040ff08",
-    "#0047f112",
-    "#0044ff1e",
-    "#0044ff2d",
-    "#003eff3e",
-    "#0037ed54",
-    "#0034dc72",
-    "#0031d2c1",
-    "#002ec9cc",
-    "#002bb7c5",
-    "#001046e0",
-];
-
-pub const INDIGO_P3: [&str; 12] = [
-    "color(display-p3 0.992 0.992 0.996)",
-    "color(display-p3 0.971 0.977 0.998)",
-    "color(display-p3 0.933 0.948 0.992)",
-    "color(display-p3 0.885 0.914 1)",
-    "color(display-p3 0.831 0.87 1)",
-    "color(display-p3 0.767 0.814 0.995)",
-    "color(display-p3 0.685 0.74 0.957)",
-    "color(display-p3 0.569 0.639 0.916)",
-    "color(display-p3 0.276 0.384 0.837)",
-    "color(display-p3 0.234 0.343 0.801)",
-    "color(display-p3 0.256 0.354 0.755)",
-    "color(display-p3 0.133 0.175 0.348)",
-];
-
-pub const INDIGO_P3_A: [&str; 12] = [
-    "color(display-p3 0.02 0.02 0.51 / 0.008)",
-    "color(display-p3 0.024 0.161 0.863 / 0.028)",
-    "color(display-p3 0.008 0.239 0.886 / 0.067)",
-    "color(display-p3 0.004 0.247 1 / 0.114)",
-    "color(display-p3 0.004 0.235 1 / 0.169)",
-    "color(display-p3 0.004 0.208 0.984 / 0.232)",
-    "color(display-p3 0.004 0.176 0.863 / 0.314)",
-    "color(display-p3 0.004 0.165 0.812 / 0.432)",
-    "color(display-p3 0 0.153 0.773 / 0.726)",
-    "color(display-p3 0 0.137 0.737 / 0.765)",
-    "color(display-p3 0.256 0.354 0.755)",
-    "color(display-p3 0.133 0.175 0.348)",
-];
-
-pub const BLUE: [&str; 12] = [
-    "#fbfdff", "#f4faff", "#e6f4fe", "#d5efff", "#c2e5ff", "#acd8fc", "#8ec8f6", "#5eb1ef",
-    "#0090ff", "#0588f0", "#0d74ce", "#113264",
-];
-
-pub const BLUE_A: [&str; 12] = [
-    "#0080ff04",
-    "#008cff0b",
-    "#008ff519",
-    "#009eff2a",
-    "#0093ff3d",
-    "#0088f653",
-    "#0083eb71",
-    "#0084e6a1",
-    "#0090ff",
-    "#0086f0fa",
-    "#006dcbf2",
-    "#002359ee",
-];
-
-pub const BLUE_P3: [&str; 12] = [
-    "color(display-p3 0.986 0.992 0.999)",
-    "color(display-p3 0.96 0.979 0.998)",
-    "color(display-p3 0.912 0.956 0.991)",
-    "color(display-p3 0.853 0.932 1)",
-    "color(display-p3 0.788 0.894 0.998)",
-    "color(display-p3 0.709 0.843 0.976)",
-    "color(display-p3 0.606 0.777 0.947)",
-    "color(display-p3 0.451 0.688 0.917)",
-    "color(display-p3 0.247 0.556 0.969)",
-    "color(display-p3 0.234 0.523 0.912)",
-    "color(display-p3 0.15 0.44 0.84)",
-    "color(display-p3 0.102 0.193 0.379)",
-];
-
-pub const BLUE_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.514 1 / 0.016)",
-    "color(display-p3 0.024 0.514 0.906 / 0.04)",
-    "color(display-p3 0.012 0.506 0.914 / 0.087)",
-    "color(display-p3 0.008 0.545 1 / 0.146)",
-    "color(display-p3 0.004 0.502 0.984 / 0.212)",
-    "color(display-p3 0.004 0.463 0.922 / 0.291)",
-    "color(display-p3 0.004 0.431 0.863 / 0.393)",
-    "color(display-p3 0 0.427 0.851 / 0.55)",
-    "color(display-p3 0 0.412 0.961 / 0.753)",
-    "color(display-p3 0 0.376 0.886 / 0.765)",
-    "color(display-p3 0.15 0.44 0.84)",
-    "color(display-p3 0.102 0.193 0.379)",
-];
-
-pub const CYAN: [&str; 12] = [
-    "#fafdfe", "#f2fafb", "#def7f9", "#caf1f6", "#b5e9f0", "#9ddde7", "#7dcedc", "#3db9cf",
-    "#00a2c7", "#0797b9", "#107d98", "#0d3c48",
-];
-
-pub const CYAN_A: [&str; 12] = [
-    "#0099cc05",
-    "#009db10d",
-    "#00c2d121",
-    "#00bcd435",
-    "#01b4cc4a",
-    "#00a7c162",
-    "#009fbb82",
-    "#00a3c0c2",
-    "#00a2c7",
-    "#0094b7f8",
-    "#007491ef",
-    "#00323ef2",
-];
-
-pub const CYAN_P3: [&str; 12] = [
-    "color(display-p3 0.982 0.992 0.996)",
-    "color(display-p3 0.955 0.981 0.984)",
-    "color(display-p3 0.888 0.965 0.975)",
-    "color(display-p3 0.821 0.941 0.959)",
-    "color(display-p3 0.751 0.907 0.935)",
-    "color(display-p3 0.671 0.862 0.9)",
-    "color(display-p3 0.564 0.8 0.854)",
-    "color(display-p3 0.388 0.715 0.798)",
-    "color(display-p3 0.282 0.627 0.765)",
-    "color(display-p3 0.264 0.583 0.71)",
-    "color(display-p3 0.08 0.48 0.63)",
-    "color(display-p3 0.108 0.232 0.277)",
-];
-
-pub const CYAN_P3_A: [&str; 12] = [
-    "color(display-p3 0.02 0.608 0.804 / 0.02)",
-    "color(display-p3 0.02 0.557 0.647 / 0.044)",
-    "color(display-p3 0.004 0.694 0.796 / 0.114)",
-    "color(display-p3 0.004 0.678 0.784 / 0.181)",
-    "color(display-p3 0.004 0.624 0.733 / 0.248)",
-    "color(display-p3 0.004 0.584 0.706 / 0.33)",
-    "color(display-p3 0.004 0.541 0.667 / 0.436)",
-    "color(display-p3 0 0.533 0.667 / 0.612)",
-    "color(display-p3 0 0.482 0.675 / 0.718)",
-    "color(display-p3 0 0.435 0.608 / 0.738)",
-    "color(display-p3 0.08 0.48 0.63)",
-    "color(display-p3 0.108 0.232 0.277)",
-];
-
-pub const TEAL: [&str; 12] = [
-    "#fafefd", "#f3fbf9", "#e0f8f3", "#ccf3ea", "#b8eae0", "#a1ded2", "#83cdc1", "#53b9ab",
-    "#12a594", "#0d9b8a", "#008573", "#0d3d38",
-];
-
-pub const TEAL_A: [&str; 12] = [
-    "#00cc9905",
-    "#00aa800c",
-    "#00c69d1f",
-    "#00c39633",
-    "#00b49047",
-    "#00a6855e",
-    "#0099807c",
-    "#009783ac",
-    "#009e8ced",
-    "#009684f2",
-    "#008573",
-    "#00332df2",
-];
-
-pub const TEAL_P3: [&str; 12] = [
-    "color(display-p3 0.983 0.996 0.992)",
-    "color(display-p3 0.958 0.983 0.976)",
-    "color(display-p3 0.895 0.971 0.952)",
-    "color(display-p3 0.831 0.949 0.92)",
-    "color(display-p3 0.761 0.914 0.878)",
-    "color(display-p3 0.682 0.864 0.825)",
-    "color(display-p3 0.581 0.798 0.756)",
-    "color(display-p3 0.433 0.716 0.671)",
-    "color(display-p3 0.297 0.637 0.581)",
-    "color(display-p3 0.275 0.599 0.542)",
-    "color(display-p3 0.08 0.5 0.43)",
-    "color(display-p3 0.11 0.235 0.219)",
-];
-
-pub const TEAL_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.757 0.514 / 0.016)",
-    "color(display-p3 0.02 0.647 0.467 / 0.044)",
-    "color(display-p3 0.004 0.741 0.557 / 0.106)",
-    "color(display-p3 0.004 0.702 0.537 / 0.169)",
-    "color(display-p3 0.004 0.643 0.494 / 0.24)",
-    "color(display-p3 0.004 0.569 0.447 / 0.318)",
-    "color(display-p3 0.004 0.518 0.424 / 0.42)",
-    "color(display-p3 0 0.506 0.424 / 0.569)",
-    "color(display-p3 0 0.482 0.404 / 0.702)",
-    "color(display-p3 0 0.451 0.369 / 0.726)",
-    "color(display-p3 0.08 0.5 0.43)",
-    "color(display-p3 0.11 0.235 0.219)",
-];
-
-pub const JADE: [&str; 12] = [
-    "#fbfefd", "#f4fbf7", "#e6f7ed", "#d6f1e3", "#c3e9d7", "#acdec8", "#8bceb6", "#56ba9f",
-    "#29a383", "#26997b", "#208368", "#1d3b31",
-];
-
-pub const JADE_A: [&str; 12] = [
-    "#00c08004",
-    "#00a3460b",
-    "#00ae4819",
-    "#00a85129",
-    "#00a2553c",
-    "#009a5753",
-    "#00945f74",
-    "#00976ea9",
-    "#00916bd6",
-    "#008764d9",
-    "#007152df",
-    "#002217e2",
-];
-
-pub const JADE_P3: [&str; 12] = [
-    "color(display-p3 0.986 0.996 0.992)",
-    "color(display-p3 0.962 0.983 0.969)",
-    "color(display-p3 0.912 0.965 0.932)",
-    "color(display-p3 0.858 0.941 0.893)",
-    "color(display-p3 0.795 0.909 0.847)",
-    "color(display-p3 0.715 0.864 0.791)",
-    "color(display-p3 0.603 0.802 0.718)",
-    "color(display-p3 0.44 0.72 0.629)",
-    "color(display-p3 0.319 0.63 0.521)",
-    "color(display-p3 0.299 0.592 0.488)",
-    "color(display-p3 0.15 0.5 0.37)",
-    "color(display-p3 0.142 0.229 0.194)",
-];
-
-pub const JADE_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.757 0.514 / 0.016)",
-    "color(display-p3 0.024 0.612 0.22 / 0.04)",
-    "color(display-p3 0.012 0.596 0.235 / 0.087)",
-    "color(display-p3 0.008 0.588 0.255 / 0.142)",
-    "color(display-p3 0.004 0.561 0.251 / 0.204)",
-    "color(display-p3 0.004 0.525 0.278 / 0.287)",
-    "color(display-p3 0.004 0.506 0.29 / 0.397)",
-    "color(display-p3 0 0.506 0.337 / 0.561)",
-    "color(display-p3 0 0.459 0.298 / 0.683)",
-    "color(display-p3 0 0.42 0.271 / 0.702)",
-    "color(display-p3 0.15 0.5 0.37)",
-    "color(display-p3 0.142 0.229 0.194)",
-];
-
-pub const GREEN: [&str; 12] = [
-    "#fbfefc", "#f4fbf6", "#e6f6eb", "#d6f1df", "#c4e8d1", "#adddc0", "#8eceaa", "#5bb98b",
-    "#30a46c", "#2b9a66", "#218358", "#193b2d",
-];
-
-pub const GREEN_A: [&str; 12] = [
-    "#00c04004",
-    "#00a32f0b",
-    "#00a43319",
-    "#00a83829",
-    "#019c393b",
-    "#00963c52",
-    "#00914071",
-    "#00924ba4",
-    "#008f4acf",
-    "#008647d4",
-    "#00713fde",
-    "#002616e6",
-];
-
-pub const GREEN_P3: [&str; 12] = [
-    "color(display-p3 0.986 0.996 0.989)",
-    "color(display-p3 0.963 0.983 0.967)",
-    "color(display-p3 0.913 0.964 0.925)",
-    "color(display-p3 0.859 0.94 0.879)",
-    "color(display-p3 0.796 0.907 0.826)",
-    "color(display-p3 0.718 0.863 0.761)",
-    "color(display-p3 0.61 0.801 0.675)",
-    "color(display-p3 0.451 0.715 0.559)",
-    "color(display-p3 0.332 0.634 0.442)",
-    "color(display-p3 0.308 0.595 0.417)",
-    "color(display-p3 0.19 0.5 0.32)",
-    "color(display-p3 0.132 0.228 0.18)",
-];
-
-pub const GREEN_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.757 0.267 / 0.016)",
-    "color(display-p3 0.024 0.565 0.129 / 0.036)",
-    "color(display-p3 0.012 0.596 0.145 / 0.087)",
-    "color(display-p3 0.008 0.588 0.145 / 0.142)",
-    "color(display-p3 0.004 0.541 0.157 / 0.204)",
-    "color(display-p3 0.004 0.518 0.157 / 0.283)",
-    "color(display-p3 0.004 0.486 0.165 / 0.389)",
-    "color(display-p3 0 0.478 0.2 / 0.55)",
-    "color(display-p3 0 0.455 0.165 / 0.667)",
-    "color(display-p3 0 0.416 0.153 / 0.691)",
-    "color(display-p3 0.19 0.5 0.32)",
-    "color(display-p3 0.132 0.228 0.18)",
-];
-
-pub const GRASS: [&str; 12] = [
-    "#fbfefb", "#f5fbf5", "#e9f6e9", "#daf1db", "#c9e8ca", "#b2ddb5", "#94ce9a", "#65ba74",
-    "#46a758", "#3e9b4f", "#2a7e3b", "#203c25",
-];
-
-pub const GRASS_A: [&str; 12] = [
-    "#00c00004",
-    "#0099000a",
-    "#00970016",
-    "#009f0725",
-    "#00930536",
-    "#008f0a4d",
-    "#018b0f6b",
-    "#008d199a",
-    "#008619b9",
-    "#007b17c1",
-    "#006514d5",
-    "#002006df",
-];
-
-pub const GRASS_P3: [&str; 12] = [
-    "color(display-p3 0.986 0.996 0.985)",
-    "color(display-p3 0.966 0.983 0.964)",
-    "color(display-p3 0.923 0.965 0.917)",
-    "color(display-p3 0.872 0.94 0.865)",
-    "color(display-p3 0.811 0.908 0.802)",
-    "color(display-p3 0.733 0.864 0.724)",
-    "color(display-p3 0.628 0.803 0.622)",
-    "color(display-p3 0.477 0.72 0.482)",
-    "color(display-p3 0.38 0.647 0.378)",
-    "color(display-p3 0.344 0.598 0.342)",
-    "color(display-p3 0.263 0.488 0.261)",
-    "color(display-p3 0.151 0.233 0.153)",
-];
-
-pub const GRASS_P3_A: [&str; 12] = [
-    "color(display-p3 0.024 0.757 0.024 / 0.016)",
-    "color(display-p3 0.024 0.565 0.024 / 0.036)",
-    "color(display-p3 0.059 0.576 0.008 / 0.083)",
-    "color(display-p3 0.035 0.565 0.008 / 0.134)",
-    "color(display-p3 0.047 0.545 0.008 / 0.197)",
-    "color(display-p3 0.031 0.502 0.004 / 0.275)",
-    "color(display-p3 0.012 0.482 0.004 / 0.377)",
-    "color(display-p3 0 0.467 0.008 / 0.522)",
-    "color(display-p3 0.008 0.435 0 / 0.624)",
-    "color(display-p3 0.008 0.388 0 / 0.659)",
-    "color(display-p3 0.263 0.488 0.261)",
-    "color(display-p3 0.151 0.233 0.153)",
-];
-
-pub const BROWN: [&str; 12] = [
-    "#fefdfc", "#fcf9f6", "#f6eee7", "#f0e4d9", "#ebdaca", "#e4cdb7", "#dcbc9f", "#cea37e",
-    "#ad7f58", "#a07553", "#815e46", "#3e332e",
-];
-
-pub const BROWN_A: [&str; 12] = [
-    "#aa550003",
-    "#aa550009",
-    "#a04b0018",
-    "#9b4a0026",
-    "#9f4d0035",
-    "#a04e0048",
-    "#a34e0060",
-    "#9f4a0081",
-    "#823c00a7",
-    "#723300ac",
-    "#522100b9",
-    "#140600d1",
-];
-
-pub const BROWN_P3: [&str; 12] = [
-    "color(display-p3 0.995 0.992 0.989)",
-    "color(display-p3 0.987 0.976 0.964)",
-    "color(display-p3 0.959 0.936 0.909)",
-    "color(display-p3 0.934 0.897 0.855)",
-    "color(display-p3 0.909 0.856 0.798)",
-    "color(display-p3 0.88 0.808 0.73)",
-    "color(display-p3 0.841 0.742 0.639)",
-    "color(display-p3 0.782 0.647 0.514)",
-    "color(display-p3 0.651 0.505 0.368)",
-    "color(display-p3 0.601 0.465 0.344)",
-    "color(display-p3 0.485 0.374 0.288)",
-    "color(display-p3 0.236 0.202 0.183)",
-];
-
-pub const BROWN_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.349 0.024 / 0.012)",
-    "color(display-p3 0.675 0.349 0.024 / 0.036)",
-    "color(display-p3 0.573 0.314 0.012 / 0.091)",
-    "color(display-p3 0.545 0.302 0.008 / 0.146)",
-    "color(display-p3 0.561 0.29 0.004 / 0.204)",
-    "color(display-p3 0.553 0.294 0.004 / 0.271)",
-    "color(display-p3 0.557 0.286 0.004 / 0.361)",
-    "color(display-p3 0.549 0.275 0.004 / 0.487)",
-    "color(display-p3 0.447 0.22 0 / 0.632)",
-    "color(display-p3 0.388 0.188 0 / 0.655)",
-    "color(display-p3 0.485 0.374 0.288)",
-    "color(display-p3 0.236 0.202 0.183)",
-];
-
-pub const BRONZE: [&str; 12] = [
-    "#fdfcfc", "#fdf7f5", "#f6edea", "#efe4df", "#e7d9d3", "#dfcdc5", "#d3bcb3", "#c2a499",
-    "#a18072", "#957468", "#7d5e54", "#43302b",
-];
-
-pub const BRONZE_A: [&str; 12] = [
-    "#55000003",
-    "#cc33000a",
-    "#92250015",
-    "#80280020",
-    "#7423002c",
-    "#7324003a",
-    "#6c1f004c",
-    "#671c0066",
-    "#551a008d",
-    "#4c150097",
-    "#3d0f00ab",
-    "#1d0600d4",
-];
-
-pub const BRONZE_P3: [&str; 12] = [
-    "color(display-p3 0.991 0.988 0.988)",
-    "color(display-p3 0.989 0.97 0.961)",
-    "color(display-p3 0.958 0.932 0.919)",
-    "color(display-p3 0.929 0.894 0.877)",
-    "color(display-p3 0.898 0.853 0.832)",
-    "color(display-p3 0.861 0.805 0.778)",
-    "color(display-p3 0.812 0.739 0.706)",
-    "color(display-p3 0.741 0.647 0.606)",
-    "color(display-p3 0.611 0.507 0.455)",
-    "color(display-p3 0.563 0.461 0.414)",
-    "color(display-p3 0.471 0.373 0.336)",
-    "color(display-p3 0.251 0.191 0.172)",
-];
-
-pub const BRONZE_P3_A: [&str; 12] = [
-    "color(display-p3 0.349 0.024 0.024 / 0.012)",
-    "color(display-p3 0.71 0.22 0.024 / 0.04)",
-    "color(display-p3 0.482 0.2 0.008 / 0.083)",
-    "color(display-p3 0.424 0.133 0.004 / 0.122)",
-    "color(display-p3 0.4 0.145 0.004 / 0.169)",
-    "color(display-p3 0.388 0.125 0.004 / 0.224)",
-    "color(display-p3 0.365 0.11 0.004 / 0.295)",
-    "color(display-p3 0.341 0.102 0.004 / 0.393)",
-    "color(display-p3 0.29 0.094 0 / 0.546)",
-    "color(display-p3 0.255 0.082 0 / 0.585)",
-    "color(display-p3 0.471 0.373 0.336)",
-    "color(display-p3 0.251 0.191 0.172)",
-];
-
-pub const GOLD: [&str; 12] = [
-    "#fdfdfc", "#faf9f2", "#f2f0e7", "#eae6db", "#e1dccf", "#d8d0bf", "#cbc0aa", "#b9a88d",
-    "#978365", "#8c7a5e", "#71624b", "#3b352b",
-];
-
-pub const GOLD_A: [&str; 12] = [
-    "#55550003",
-    "#9d8a000d",
-    "#75600018",
-    "#6b4e0024",
-    "#60460030",
-    "#64440040",
-    "#63420055",
-    "#633d0072",
-    "#5332009a",
-    "#492d00a1",
-    "#362100b4",
-    "#130c00d4",
-];
-
-pub const GOLD_P3: [&str; 12] = [
-    "color(display-p3 0.992 0.992 0.989)",
-    "color(display-p3 0.98 0.976 0.953)",
-    "color(display-p3 0.947 0.94 0.909)",
-    "color(display-p3 0.914 0.904 0.865)",
-    "color(display-p3 0.88 0.865 0.816)",
-    "color(display-p3 0.84 0.818 0.756)",
-    "color(display-p3 0.788 0.753 0.677)",
-    "color(display-p3 0.715 0.66 0.565)",
-    "color(display-p3 0.579 0.517 0.41)",
-    "color(display-p3 0.538 0.479 0.38)",
-    "color(display-p3 0.433 0.386 0.305)",
-    "color(display-p3 0.227 0.209 0.173)",
-];
-
-pub const GOLD_P3_A: [&str; 12] = [
-    "color(display-p3 0.349 0.349 0.024 / 0.012)",
-    "color(display-p3 0.592 0.514 0.024 / 0.048)",
-    "color(display-p3 0.4 0.357 0.012 / 0.091)",
-    "color(display-p3 0.357 0.298 0.008 / 0.134)",
-    "color(display-p3 0.345 0.282 0.004 / 0.185)",
-    "color(display-p3 0.341 0.263 0.004 / 0.244)",
-    "color(display-p3 0.345 0.235 0.004 / 0.322)",
-    "color(display-p3 0.345 0.22 0.004 / 0.436)",
-    "color(display-p3 0.286 0.18 0 / 0.589)",
-    "color(display-p3 0.255 0.161 0 / 0.62)",
-    "color(display-p3 0.433 0.386 0.305)",
-    "color(display-p3 0.227 0.209 0.173)",
-];
-
-pub const SKY: [&str; 12] = [
-    "#f9feff", "#f1fafd", "#e1f6fd", "#d1f0fa", "#bee7f5", "#a9daed", "#8dcae3", "#60b3d7",
-    "#7ce2fe", "#74daf8", "#00749e", "#1d3e56",
-];
-
-pub const SKY_A: [&str; 12] = [
-    "#00d5ff06",
-    "#00a4db0e",
-    "#00b3ee1e",
-    "#00ace42e",
-    "#00a1d841",
-    "#0092ca56",
-    "#0089c172",
-    "#0085bf9f",
-    "#00c7fe83",
-    "#00bcf38b",
-    "#00749e",
-    "#002540e2",
-];
-
-pub const SKY_P3: [&str; 12] = [
-    "color(display-p3 0.98 0.995 0.999)",
-    "color(display-p3 0.953 0.98 0.99)",
-    "color(display-p3 0.899 0.963 0.989)",
-    "color(display-p3 0.842 0.937 0.977)",
-    "color(display-p3 0.777 0.9 0.954)",
-    "color(display-p3 0.701 0.851 0.921)",
-    "color(display-p3 0.604 0.785 0.879)",
-    "color(display-p3 0.457 0.696 0.829)",
-    "color(display-p3 0.585 0.877 0.983)",
-    "color(display-p3 0.555 0.845 0.959)",
-    "color(display-p3 0.193 0.448 0.605)",
-    "color(display-p3 0.145 0.241 0.329)",
-];
-
-pub const SKY_P3_A: [&str; 12] = [
-    "color(display-p3 0.02 0.804 1 / 0.02)",
-    "color(display-p3 0.024 0.592 0.757 / 0.048)",
-    "color(display-p3 0.004 0.655 0.886 / 0.102)",
-    "color(display-p3 0.004 0.604 0.851 / 0.157)",
-    "color(display-p3 0.004 0.565 0.792 / 0.224)",
-    "color(display-p3 0.004 0.502 0.737 / 0.299)",
-    "color(display-p3 0.004 0.459 0.694 / 0.397)",
-    "color(display-p3 0 0.435 0.682 / 0.542)",
-    "color(display-p3 0.004 0.71 0.965 / 0.416)",
-    "color(display-p3 0.004 0.647 0.914 / 0.444)",
-    "color(display-p3 0.193 0.448 0.605)",
-    "color(display-p3 0.145 0.241 0.329)",
-];
-
-pub const MINT: [&str; 12] = [
-    "#f9fefd", "#f2fbf9", "#ddf9f2", "#c8f4e9", "#b3ecde", "#9ce0d0", "#7ecfbd", "#4cbba5",
-    "#86ead4", "#7de0cb", "#027864", "#16433c",
-];
-
-pub const MINT_A: [&str; 12] = [
-    "#00d5aa06",
-    "#00b18a0d",
-    "#00d29e22",
-    "#00cc9937",
-    "#00c0914c",
-    "#00b08663",
-    "#00a17d81",
-    "#009e7fb3",
-    "#00d3a579",
-    "#00c39982",
-    "#007763fd",
-    "#00312ae9",
-];
-
-pub const MINT_P3: [&str; 12] = [
-    "color(display-p3 0.98 0.995 0.992)",
-    "color(display-p3 0.957 0.985 0.977)",
-    "color(display-p3 0.888 0.972 0.95)",
-    "color(display-p3 0.819 0.951 0.916)",
-    "color(display-p3 0.747 0.918 0.873)",
-    "color(display-p3 0.668 0.87 0.818)",
-    "color(display-p3 0.567 0.805 0.744)",
-    "color(display-p3 0.42 0.724 0.649)",
-    "color(display-p3 0.62 0.908 0.834)",
-    "color(display-p3 0.585 0.871 0.797)",
-    "color(display-p3 0.203 0.463 0.397)",
-    "color(display-p3 0.136 0.259 0.236)",
-];
-
-pub const MINT_P3_A: [&str; 12] = [
-    "color(display-p3 0.02 0.804 0.608 / 0.02)",
-    "color(display-p3 0.02 0.647 0.467 / 0.044)",
-    "color(display-p3 0.004 0.761 0.553 / 0.114)",
-    "color(display-p3 0.004 0.741 0.545 / 0.181)",
-    "color(display-p3 0.004 0.678 0.51 / 0.255)",
-    "color(display-p3 0.004 0.616 0.463 / 0.334)",
-    "color(display-p3 0.004 0.549 0.412 / 0.432)",
-    "color(display-p3 0 0.529 0.392 / 0.581)",
-    "color(display-p3 0.004 0.765 0.569 / 0.381)",
-    "color(display-p3 0.004 0.69 0.51 / 0.416)",
-    "color(display-p3 0.203 0.463 0.397)",
-    "color(display-p3 0.136 0.259 0.236)",
-];
-
-pub const LIME: [&str; 12] = [
-    "#fcfdfa", "#f8faf3", "#eef6d6", "#e2f0bd", "#d3e7a6", "#c2da91", "#abc978", "#8db654",
-    "#bdee63", "#b0e64c", "#5c7c2f", "#37401c",
-];
-
-pub const LIME_A: [&str; 12] = [
-    "#66990005",
-    "#6b95000c",
-    "#96c80029",
-    "#8fc60042",
-    "#81bb0059",
-    "#72aa006e",
-    "#61990087",
-    "#559200ab",
-    "#93e4009c",
-    "#8fdc00b3",
-    "#375f00d0",
-    "#1e2900e3",
-];
-
-pub const LIME_P3: [&str; 12] = [
-    "color(display-p3 0.989 0.992 0.981)",
-    "color(display-p3 0.975 0.98 0.954)",
-    "color(display-p3 0.939 0.965 0.851)",
-    "color(display-p3 0.896 0.94 0.76)",
-    "color(display-p3 0.843 0.903 0.678)",
-    "color(display-p3 0.778 0.852 0.599)",
-    "color(display-p3 0.694 0.784 0.508)",
-    "color(display-p3 0.585 0.707 0.378)",
-    "color(display-p3 0.78 0.928 0.466)",
-    "color(display-p3 0.734 0.896 0.397)",
-    "color(display-p3 0.386 0.482 0.227)",
-    "color(display-p3 0.222 0.25 0.128)",
-];
-
-pub const LIME_P3_A: [&str; 12] = [
-    "color(display-p3 0.412 0.608 0.02 / 0.02)",
-    "color(display-p3 0.514 0.592 0.024 / 0.048)",
-    "color(display-p3 0.584 0.765 0.008 / 0.15)",
-    "color(display-p3 0.561 0.757 0.004 / 0.24)",
-    "color(display-p3 0.514 0.698 0.004 / 0.322)",
-    "color(display-p3 0.443 0.627 0 / 0.4)",
-    "color(display-p3 0.376 0.561 0.004 / 0.491)",
-    "color(display-p3 0.333 0.529 0 / 0.624)",
-    "color(display-p3 0.588 0.867 0 / 0.534)",
-    "color(display-p3 0.561 0.827 0 / 0.604)",
-    "color(display-p3 0.386 0.482 0.227)",
-    "color(display-p3 0.222 0.25 0.128)",
-];
-
-pub const YELLOW: [&str; 12] = [
-    "#fdfdf9", "#fefce9", "#fffab8", "#fff394", "#ffe770", "#f3d768", "#e4c767", "#d5ae39",
-    "#ffe629", "#ffdc00", "#9e6c00", "#473b1f",
-];
-
-pub const YELLOW_A: [&str; 12] = [
-    "#aaaa0006",
-    "#f4dd0016",
-    "#ffee0047",
-    "#ffe3016b",
-    "#ffd5008f",
-    "#ebbc0097",
-    "#d2a10098",
-    "#c99700c6",
-    "#ffe100d6",
-    "#ffdc00",
-    "#9e6c00",
-    "#2e2000e0",
-];
-
-pub const YELLOW_P3: [&str; 12] = [
-    "color(display-p3 0.992 0.992 0.978)",
-    "color(display-p3 0.995 0.99 0.922)",
-    "color(display-p3 0.997 0.982 0.749)",
-    "color(display-p3 0.992 0.953 0.627)",
-    "color(display-p3 0.984 0.91 0.51)",
-    "color(display-p3 0.934 0.847 0.474)",
-    "color(display-p3 0.876 0.785 0.46)",
-    "color(display-p3 0.811 0.689 0.313)",
-    "color(display-p3 1 0.92 0.22)",
-    "color(display-p3 0.977 0.868 0.291)",
-    "color(display-p3 0.6 0.44 0)",
-    "color(display-p3 0.271 0.233 0.137)",
-];
-
-pub const YELLOW_P3_A: [&str; 12] = [
-    "color(display-p3 0.675 0.675 0.024 / 0.024)",
-    "color(display-p3 0.953 0.855 0.008 / 0.079)",
-    "color(display-p3 0.988 0.925 0.004 / 0.251)",
-    "color(display-p3 0.98 0.875 0.004 / 0.373)",
-    "color(display-p3 0.969 0.816 0.004 / 0.491)",
-    "color(display-p3 0.875 0.71 0 / 0.526)",
-    "color(display-p3 0.769 0.604 0 / 0.542)",
-    "color(display-p3 0.725 0.549 0 / 0.687)",
-    "color(display-p3 1 0.898 0 / 0.781)",
-    "color(display-p3 0.969 0.812 0 / 0.71)",
-    "color(display-p3 0.6 0.44 0)",
-    "color(display-p3 0.271 0.233 0.137)",
-];
-
-pub const AMBER: [&str; 12] = [
-    "#fefdfb", "#fefbe9", "#fff7c2", "#ffee9c", "#fbe577", "#f3d673", "#e9c162", "#e2a336",
-    "#ffc53d", "#ffba18", "#ab6400", "#4f3422",
-];
-
-pub const AMBER_A: [&str; 12] = [
-    "#c0800004",
-    "#f4d10016",
-    "#ffde003d",
-    "#ffd40063",
-    "#f8cf0088",
-    "#eab5008c",
-    "#dc9b009d",
-    "#da8a00c9",
-    "#ffb300c2",
-    "#ffb300e7",
-    "#ab6400",
-    "#341500dd",
-];
-
-pub const AMBER_P3: [&str; 12] = [
-    "color(display-p3 0.995 0.992 0.985)",
-    "color(display-p3 0.994 0.986 0.921)",
-    "color(display-p3 0.994 0.969 0.782)",
-    "color(display-p3 0.989 0.937 0.65)",
-    "color(display-p3 0.97 0.902 0.527)",
-    "color(display-p3 0.936 0.844 0.506)",
-    "color(display-p3 0.89 0.762 0.443)",
-    "color(display-p3 0.85 0.65 0.3)",
-    "color(display-p3 1 0.77 0.26)",
-    "color(display-p3 0.959 0.741 0.274)",
-    "color(display-p3 0.64 0.4 0)",
-    "color(display-p3 0.294 0.208 0.145)",
-];
-
-pub const AMBER_P3_A: [&str; 12] = [
-    "color(display-p3 0.757 0.514 0.024 / 0.016)",
-    "color(display-p3 0.902 0.804 0.008 / 0.079)",
-    "color(display-p3 0.965 0.859 0.004 / 0.22)",
-    "color(display-p3 0.969 0.82 0.004 / 0.35)",
-    "color(display-p3 0.933 0.796 0.004 / 0.475)",
-    "color(display-p3 0.875 0.682 0.004 / 0.495)",
-    "color(display-p3 0.804 0.573 0 / 0.557)",
-    "color(display-p3 0.788 0.502 0 / 0.699)",
-    "color(display-p3 1 0.686 0 / 0.742)",
-    "color(display-p3 0.945 0.643 0 / 0.726)",
-    "color(display-p3 0.64 0.4 0)",
-    "color(display-p3 0.294 0.208 0.145)",
-];
-
-pub const ORANGE: [&str; 12] = [
-    "#fefcfb", "#fff7ed", "#ffefd6", "#ffdfb5", "#ffd19a", "#ffc182", "#f5ae73", "#ec9455",
-    "#f76b15", "#ef5f00", "#cc4e00", "#582d1d",
-];
-
-pub const ORANGE_A: [&str; 12] = [
-    "#c0400004",
-    "#ff8e0012",
-    "#ff9c0029",
-    "#ff91014a",
-    "#ff8b0065",
-    "#ff81007d",
-    "#ed6c008c",
-    "#e35f00aa",
-    "#f65e00ea",
-    "#ef5f00",
-    "#cc4e00",
-    "#431200e2",
-];
-
-pub const ORANGE_P3: [&str; 12] = [
-    "color(display-p3 0.995 0.988 0.985)",
-    "color(display-p3 0.994 0.968 0.934)",
-    "color(display-p3 0.989 0.938 0.85)",
-    "color(display-p3 1 0.874 0.687)",
-    "color(display-p3 1 0.821 0.583)",
-    "color(display-p3 0.975 0.767 0.545)",
-    "color(display-p3 0.919 0.693 0.486)",
-    "color(display-p3 0.877 0.597 0.379)",
-    "color(display-p3 0.9 0.45 0.2)",
-    "color(display-p3 0.87 0.409 0.164)",
-    "color(display-p3 0.76 0.34 0)",
-    "color(display-p3 0.323 0.185 0.127)",
-];
-
-pub const ORANGE_P3_A: [&str; 12] = [
-    "color(display-p3 0.757 0.267 0.024 / 0.016)",
-    "color(display-p3 0.886 0.533 0.008 / 0.067)",
-    "color(display-p3 0.922 0.584 0.008 / 0.15)",
-    "color(display-p3 1 0.604 0.004 / 0.314)",
-    "color(display-p3 1 0.569 0.004 / 0.416)",
-    "color(display-p3 0.949 0.494 0.004 / 0.455)",
-    "color(display-p3 0.839 0.408 0 / 0.514)",
-    "color(display-p3 0.804 0.349 0 / 0.62)",
-    "color(display-p3 0.878 0.314 0 / 0.8)",
-    "color(display-p3 0.843 0.29 0 / 0.836)",
-    "color(display-p3 0.76 0.34 0)",
-    "color(display-p3 0.323 0.185 0.127)",
-];
@@ -1,29 +0,0 @@
-pub const WHITE_A: [&str; 12] = [
-    "rgba(255, 255, 255, 0.05)",
-    "rgba(255, 255, 255, 0.1)",
-    "rgba(255, 255, 255, 0.15)",
-    "rgba(255, 255, 255, 0.2)",
-    "rgba(255, 255, 255, 0.3)",
-    "rgba(255, 255, 255, 0.4)",
-    "rgba(255, 255, 255, 0.5)",
-    "rgba(255, 255, 255, 0.6)",
-    "rgba(255, 255, 255, 0.7)",
-    "rgba(255, 255, 255, 0.8)",
-    "rgba(255, 255, 255, 0.9)",
-    "rgba(255, 255, 255, 0.95)",
-];
-
-pub const WHITE_P3_A: [&str; 12] = [
-    "color(display-p3 1 1 1 / 0.05)",
-    "color(display-p3 1 1 1 / 0.1)",
-    "color(display-p3 1 1 1 / 0.15)",
-    "color(display-p3 1 1 1 / 0.2)",
-    "color(display-p3 1 1 1 / 0.3)",
-    "color(display-p3 1 1 1 / 0.4)",
-    "color(display-p3 1 1 1 / 0.5)",
-    "color(display-p3 1 1 1 / 0.6)",
-    "color(display-p3 1 1 1 / 0.7)",
-    "color(display-p3 1 1 1 / 0.8)",
-    "color(display-p3 1 1 1 / 0.9)",
-    "color(display-p3 1 1 1 / 0.95)",
-];
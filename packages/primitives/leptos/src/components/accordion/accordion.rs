@@ -262,34 +262,26 @@ fn AccordionImpl(
                         "End" => {
                             next_index = end_index;
                         }
-                        "ArrowRight" => {
-                            if orientation_val == Orientation::Horizontal {
-                                if is_direction_ltr {
-                                    next_index = move_next(trigger_index);
-                                } else {
-                                    next_index = move_prev(trigger_index);
-                                }
-                            }
-                        }
-                        "ArrowDown" => {
-                            if orientation_val == Orientation::Vertical {
+                        "ArrowRight" if orientation_val == Orientation::Horizontal => {
+                            if is_direction_ltr {
                                 next_index = move_next(trigger_index);
+                            } else {
+                                next_index = move_prev(trigger_index);
                             }
                         }
-                        "ArrowLeft" => {
-                            if orientation_val == Orientation::Horizontal {
-                                if is_direction_ltr {
-                                    next_index = move_prev(trigger_index);
-                                } else {
-                                    next_index = move_next(trigger_index);
-                                }
-                            }
+                        "ArrowDown" if orientation_val == Orientation::Vertical => {
+                            next_index = move_next(trigger_index);
                         }
-                        "ArrowUp" => {
-                            if orientation_val == Orientation::Vertical {
+                        "ArrowLeft" if orientation_val == Orientation::Horizontal => {
+                            if is_direction_ltr {
                                 next_index = move_prev(trigger_index);
+                            } else {
+                                next_index = move_next(trigger_index);
                             }
                         }
+                        "ArrowUp" if orientation_val == Orientation::Vertical => {
+                            next_index = move_prev(trigger_index);
+                        }
                         _ => {}
                     }
 
@@ -170,6 +170,18 @@ struct AlertDialogContentContextValue {
 #[component]
 pub fn AlertDialogContent(
     #[prop(into, optional)] force_mount: MaybeProp<bool>,
+    /// Element to focus on open instead of the first tabbable element.
+    #[prop(into, optional_no_strip)]
+    initial_focus: Option<AnyNodeRef>,
+    /// Element to focus on close instead of the trigger.
+    #[prop(into, optional_no_strip)]
+    final_focus: Option<AnyNodeRef>,
+    /// Elements outside the dialog that focus may move to while it is trapped, e.g. third-party iframes.
+    #[prop(into, optional)]
+    external_focus_targets: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+    /// When `true`, focus trapping is suspended while the dialog stays open.
+    #[prop(into, optional)]
+    focus_trap_paused: MaybeProp<bool>,
     #[prop(into, optional)] on_open_auto_focus: Option<Callback<ev::Event>>,
     #[prop(into, optional)] on_close_auto_focus: Option<Callback<ev::Event>>,
     #[prop(into, optional)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
@@ -217,6 +229,10 @@ pub fn AlertDialogContent(
             <DialogContent
                 force_mount=force_mount
                 role="alertdialog"
+                initial_focus=initial_focus
+                final_focus=final_focus
+                external_focus_targets=external_focus_targets
+                focus_trap_paused=focus_trap_paused
                 as_child=as_child
                 node_ref=node_ref
                 on_open_auto_focus=alert_on_open_auto_focus
@@ -20,6 +20,7 @@
 //! - Indeterminate state for partial selection ("select all" patterns)
 //! - Native form participation via hidden `<input>`
 //! - Form reset support
+//! - Validation and `aria-describedby` wiring inside a `FormField`
 //!
 //! # Keyboard Interactions
 //!
@@ -46,17 +47,17 @@
 use std::fmt::{Display, Formatter};
 
 use crate::support::compose_refs::use_composed_refs;
+use crate::support::form_control::{
+    CheckboxBubbleInput, UseFormControlParams, use_form_control, use_form_reset,
+};
 use crate::support::presence::Presence;
 use crate::support::primitive::{Primitive, adapt_callback, compose_callbacks, data_attr, prop_or};
 use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
-use crate::support::use_previous::use_previous;
-use crate::support::use_size::use_size;
 use leptos::{
     attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
 };
 use leptos_node_ref::AnyNodeRef;
-use send_wrapper::SendWrapper;
-use web_sys::wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::wasm_bindgen::JsCast;
 
 /// The checked state of a checkbox.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -114,7 +115,6 @@ pub fn Checkbox(
 ) -> impl IntoView {
     let children = StoredValue::new(children);
 
-    let name = Signal::derive(move || name.get());
     let required = prop_or(required, false);
     let disabled = prop_or(disabled, false);
     let value = prop_or(value, "on".into());
@@ -143,44 +143,17 @@ pub fn Checkbox(
     });
     let checked = Signal::derive(move || checked.get().unwrap_or(CheckedState::False));
 
-    let initial_checked_state = RwSignal::new(checked.get_untracked());
-    let handle_reset: SendWrapper<Closure<dyn Fn(web_sys::Event)>> =
-        SendWrapper::new(Closure::new(move |_| {
-            set_checked.run(Some(initial_checked_state.get_untracked()));
-        }));
-    let handle_reset = StoredValue::new(handle_reset);
-
-    Effect::new(move |_| {
-        if let Some(form) = button_ref
-            .get()
-            .and_then(|button| {
-                let el: &web_sys::Element = button.unchecked_ref();
-                el.closest("form").ok()
-            })
-            .flatten()
-        {
-            handle_reset.with_value(|closure| {
-                form.add_event_listener_with_callback("reset", closure.as_ref().unchecked_ref())
-                    .expect("Reset event listener should be added.");
-            });
-        }
+    let initial_checked_state = checked.get_untracked();
+    use_form_reset(button_ref, move || {
+        set_checked.run(Some(initial_checked_state));
     });
 
-    Owner::on_cleanup(move || {
-        if let Some(form) = button_ref
-            .get()
-            .and_then(|button| {
-                let el: &web_sys::Element = button.unchecked_ref();
-                el.closest("form").ok()
-            })
-            .flatten()
-        {
-            handle_reset.with_value(|closure| {
-                form.remove_event_listener_with_callback("reset", closure.as_ref().unchecked_ref())
-                    .expect("Reset event listener should be removed.");
-            });
-        }
+    let bubble_ref = AnyNodeRef::new();
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: button_ref,
     });
+    let name = Signal::derive(move || name.get().or_else(|| form_control.name.get()));
 
     let context_value = CheckboxContextValue {
         state: checked,
@@ -206,6 +179,8 @@ pub fn Checkbox(
                     attr:data-disabled=data_attr(disabled)
                     attr:disabled=data_attr(disabled)
                     attr:value=move || value.get()
+                    attr:id=move || form_control.id.get()
+                    {..form_control.into_attrs()}
                     on:keydown=compose_callbacks(on_keydown, Some(Callback::new(move |event: ev::KeyboardEvent| {
                         // According to WAI ARIA, checkboxes don't activate on enter keypress.
                         if event.key() == "Enter" {
@@ -234,12 +209,14 @@ pub fn Checkbox(
                 </Primitive>
             </AttributeInterceptor>
             <Show when=move || is_form_control.get()>
-                <BubbleInput
-                    attr:name=move || name.get()
+                <CheckboxBubbleInput
+                    node_ref=bubble_ref
                     control_ref=button_ref
                     bubbles=Signal::derive(|| true)
+                    name=name
                     value=value
-                    checked=checked
+                    checked=Signal::derive(move || checked.get() == CheckedState::True)
+                    indeterminate=Signal::derive(move || is_indeterminiate(checked.get()))
                     required=required
                     disabled=disabled
                     form=Signal::derive(move || form.get())
@@ -294,73 +271,6 @@ pub fn CheckboxIndicator(
     }
 }
 
-#[component]
-fn BubbleInput(
-    #[prop(into)] control_ref: AnyNodeRef,
-    #[prop(into)] checked: Signal<CheckedState>,
-    #[prop(into)] bubbles: Signal<bool>,
-    #[prop(into)] required: Signal<bool>,
-    #[prop(into)] disabled: Signal<bool>,
-    #[prop(into)] value: Signal<String>,
-    #[prop(into, optional)] form: Signal<Option<String>>,
-) -> impl IntoView {
-    let node_ref: NodeRef<html::Input> = NodeRef::new();
-    let prev_checked = use_previous(checked);
-    let control_size = use_size(control_ref);
-
-    // Bubble checked change to parents
-    Effect::new(move |_| {
-        if let Some(input) = node_ref.get() {
-            if prev_checked.get() != checked.get() {
-                let init = web_sys::EventInit::new();
-                init.set_bubbles(bubbles.get());
-
-                let event = web_sys::Event::new_with_event_init_dict("click", &init)
-                    .expect("Click event should be instantiated.");
-
-                input.set_indeterminate(is_indeterminiate(checked.get()));
-                input.set_checked(match checked.get() {
-                    CheckedState::False => false,
-                    CheckedState::True => true,
-                    CheckedState::Indeterminate => false,
-                });
-
-                input
-                    .dispatch_event(&event)
-                    .expect("Click event should be dispatched.");
-            }
-        }
-    });
-
-    view! {
-        <input
-            node_ref=node_ref
-            type="checkbox"
-            aria-hidden="true"
-            checked=move || (match checked.get() {
-                CheckedState::False => false,
-                CheckedState::True => true,
-                CheckedState::Indeterminate => false,
-            }).then_some("")
-            required=move || required.get().then_some("")
-            disabled=move || disabled.get().then_some("")
-            value=move || value.get()
-            form=move || form.get()
-            tabindex="-1"
-            // We transform because the input is absolutely positioned, but we have
-            // rendered it **after** the button. This pulls it back to sit on top
-            // of the button.
-            style:transform="translateX(-100%)"
-            style:width=move || control_size.get().map(|size| format!("{}px", size.width))
-            style:height=move || control_size.get().map(|size| format!("{}px", size.height))
-            style:position="absolute"
-            style:pointer-events="none"
-            style:opacity="0"
-            style:margin="0px"
-        />
-    }
-}
-
 fn is_indeterminiate(checked: CheckedState) -> bool {
     checked == CheckedState::Indeterminate
 }
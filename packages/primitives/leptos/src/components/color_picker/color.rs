@@ -0,0 +1,294 @@
+use crate::colors::{Color, Hsl, Hsv, Rgb};
+use crate::internal::number::clamp;
+
+/* -------------------------------------------------------------------------------------------------
+ * Color helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Opaque black, the color of a [`ColorPicker`](super::ColorPicker) without a value.
+pub(super) const BLACK: Color = Color::Hsv(Hsv {
+    h: 0.0,
+    s: 0.0,
+    v: 0.0,
+    alpha: 1.0,
+});
+
+/// Parses `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`, with or without the `#`.
+pub(super) fn parse_hex(hex: &str) -> Option<Color> {
+    let hex = hex.trim();
+    Color::parse(&format!("#{}", hex.strip_prefix('#').unwrap_or(hex))).ok()
+}
+
+/// `next`, keeping the hue of grays and the saturation of black from `previous`.
+fn keep_undefined(previous: Hsv, mut next: Hsv) -> Hsv {
+    if next.s == 0.0 || next.v == 0.0 {
+        next.h = previous.h;
+    }
+    if next.v == 0.0 {
+        next.s = previous.s;
+    }
+    next
+}
+
+/// Name of `color` for people, e.g. "dark vibrant blue" or "light gray".
+pub fn color_name(color: &Color) -> String {
+    let Hsl {
+        h: hue,
+        s: saturation,
+        l: lightness,
+        ..
+    } = color.to_hsl();
+
+    if lightness < 0.08 {
+        return "black".to_string();
+    }
+    if lightness > 0.96 {
+        return "white".to_string();
+    }
+
+    let tone = if lightness < 0.3 {
+        Some("dark")
+    } else if lightness > 0.7 {
+        Some("light")
+    } else {
+        None
+    };
+    if saturation < 0.1 {
+        return [tone, Some("gray")]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+
+    let chroma = if saturation < 0.35 {
+        Some("grayish")
+    } else if saturation > 0.8 {
+        Some("vibrant")
+    } else {
+        None
+    };
+    let hue_name = HUE_NAMES
+        .iter()
+        .find(|(end, _)| hue < *end)
+        .map_or("red", |(_, name)| *name);
+
+    [tone, chroma, Some(hue_name)]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// [`color_name`] with the transparency, e.g. "vibrant red, 50% transparent".
+pub fn color_description(color: &Color) -> String {
+    let alpha = color.alpha();
+    if alpha < 1.0 {
+        let transparency = ((1.0 - alpha) * 100.0).round();
+        format!("{}, {transparency}% transparent", color_name(color))
+    } else {
+        color_name(color)
+    }
+}
+
+/// Hue names by the hue in degrees they end at.
+const HUE_NAMES: [(f64, &str); 10] = [
+    (15.0, "red"),
+    (45.0, "orange"),
+    (70.0, "yellow"),
+    (150.0, "green"),
+    (190.0, "cyan"),
+    (250.0, "blue"),
+    (290.0, "purple"),
+    (330.0, "magenta"),
+    (345.0, "pink"),
+    (360.0, "red"),
+];
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorChannel
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A channel of a [`Color`] that sliders and inputs edit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorChannel {
+    /// Hue in degrees.
+    Hue,
+    /// Saturation in the HSL model, in percent.
+    Saturation,
+    /// Lightness in the HSL model, in percent.
+    Lightness,
+    Red,
+    Green,
+    Blue,
+    /// Opacity in percent.
+    Alpha,
+}
+
+impl ColorChannel {
+    /// Minimum, maximum and step of the channel's values.
+    pub fn range(&self) -> (f64, f64, f64) {
+        match self {
+            ColorChannel::Hue => (0.0, 360.0, 1.0),
+            ColorChannel::Saturation | ColorChannel::Lightness | ColorChannel::Alpha => {
+                (0.0, 100.0, 1.0)
+            }
+            ColorChannel::Red | ColorChannel::Green | ColorChannel::Blue => (0.0, 255.0, 1.0),
+        }
+    }
+
+    /// Accessible name of the channel.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorChannel::Hue => "Hue",
+            ColorChannel::Saturation => "Saturation",
+            ColorChannel::Lightness => "Lightness",
+            ColorChannel::Red => "Red",
+            ColorChannel::Green => "Green",
+            ColorChannel::Blue => "Blue",
+            ColorChannel::Alpha => "Alpha",
+        }
+    }
+
+    /// Value of the channel of `color`, in the units of [`ColorChannel::range`].
+    pub fn value(&self, color: &Color) -> f64 {
+        let hsv = color.to_hsv();
+        let hsl = hsv.to_hsl();
+        let rgb = hsv.to_rgb();
+        match self {
+            ColorChannel::Hue => hsv.h,
+            ColorChannel::Saturation => hsl.s * 100.0,
+            ColorChannel::Lightness => hsl.l * 100.0,
+            ColorChannel::Red => (rgb.r * 255.0).round(),
+            ColorChannel::Green => (rgb.g * 255.0).round(),
+            ColorChannel::Blue => (rgb.b * 255.0).round(),
+            ColorChannel::Alpha => hsv.alpha * 100.0,
+        }
+    }
+
+    /// `color` with the channel set to `value`, in the units of [`ColorChannel::range`].
+    ///
+    /// The result is HSV, keeping the hue of grays and the saturation of black from `color`.
+    pub fn with_value(&self, color: &Color, value: f64) -> Color {
+        let (min, max, _) = self.range();
+        let value = clamp(value, [min, max]);
+        let hsv = color.to_hsv();
+        let hsl = hsv.to_hsl();
+        let rgb = hsv.to_rgb();
+        let rgb_channel = value.round() / 255.0;
+
+        let next = match self {
+            ColorChannel::Hue => return Color::Hsv(Hsv { h: value, ..hsv }),
+            ColorChannel::Alpha => {
+                return Color::Hsv(Hsv {
+                    alpha: value / 100.0,
+                    ..hsv
+                });
+            }
+            ColorChannel::Saturation => Hsl {
+                s: value / 100.0,
+                ..hsl
+            }
+            .to_hsv(),
+            ColorChannel::Lightness => Hsl {
+                l: value / 100.0,
+                ..hsl
+            }
+            .to_hsv(),
+            ColorChannel::Red => Rgb {
+                r: rgb_channel,
+                ..rgb
+            }
+            .to_hsv(),
+            ColorChannel::Green => Rgb {
+                g: rgb_channel,
+                ..rgb
+            }
+            .to_hsv(),
+            ColorChannel::Blue => Rgb {
+                b: rgb_channel,
+                ..rgb
+            }
+            .to_hsv(),
+        };
+        Color::Hsv(keep_undefined(hsv, next))
+    }
+
+    /// Accessible value of the channel of `color`, e.g. "120°, vibrant green" for the hue.
+    pub fn value_text(&self, color: &Color) -> String {
+        let value = self.value(color).round();
+        match self {
+            ColorChannel::Hue => format!("{value}°, {}", color_name(color)),
+            ColorChannel::Saturation | ColorChannel::Lightness | ColorChannel::Alpha => {
+                format!("{value}%")
+            }
+            ColorChannel::Red | ColorChannel::Green | ColorChannel::Blue => value.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(parse_hex("#ff0000").unwrap().to_hex(), "#ff0000");
+        assert_eq!(parse_hex("0f8").unwrap().to_hex(), "#00ff88");
+        assert_eq!(parse_hex(" #00000080 ").unwrap().to_hex(), "#00000080");
+        assert_eq!(parse_hex("#12345"), None);
+        assert_eq!(parse_hex("#gg0000"), None);
+        assert_eq!(parse_hex("#ä00"), None);
+        assert_eq!(parse_hex("color(display-p3 1 0 0)"), None);
+    }
+
+    #[test]
+    fn keeps_hue_and_saturation_through_grays() {
+        let color = Color::Hsv(Hsv {
+            h: 200.0,
+            s: 0.5,
+            v: 0.5,
+            alpha: 1.0,
+        });
+        let gray = ColorChannel::Saturation.with_value(&color, 0.0);
+        assert_eq!(gray.to_hsv().h, 200.0);
+        let black = ColorChannel::Lightness.with_value(&color, 0.0).to_hsv();
+        assert_eq!((black.h, black.s), (200.0, 0.5));
+        let hue = ColorChannel::Hue.with_value(&gray, 100.0);
+        assert_eq!(hue.to_hsv().h, 100.0);
+    }
+
+    #[test]
+    fn sets_channels_within_range() {
+        let color = parse_hex("#0a141e").unwrap();
+        assert_eq!(
+            ColorChannel::Red.with_value(&color, 300.0).to_hex(),
+            "#ff141e"
+        );
+        assert_eq!(ColorChannel::Alpha.with_value(&color, 50.0).alpha(), 0.5);
+        assert_eq!(ColorChannel::Hue.with_value(&color, -10.0).to_hsv().h, 0.0);
+    }
+
+    #[test]
+    fn names_colors() {
+        let name = |hex| color_name(&parse_hex(hex).unwrap());
+        assert_eq!(name("#ff0000"), "vibrant red");
+        assert_eq!(name("#000080"), "dark vibrant blue");
+        assert_eq!(name("#808080"), "gray");
+        assert_eq!(name("#d3d3d3"), "light gray");
+        assert_eq!(name("#fff"), "white");
+        assert_eq!(name("#000"), "black");
+        assert_eq!(
+            color_description(&parse_hex("#ff000080").unwrap()),
+            "vibrant red, 50% transparent"
+        );
+    }
+
+    #[test]
+    fn describes_channel_values() {
+        let color = parse_hex("#00ff00").unwrap();
+        assert_eq!(ColorChannel::Hue.value_text(&color), "120°, vibrant green");
+        assert_eq!(ColorChannel::Lightness.value_text(&color), "50%");
+        assert_eq!(ColorChannel::Green.value_text(&color), "255");
+    }
+}
@@ -0,0 +1,205 @@
+use super::*;
+
+#[derive(Clone, Copy)]
+struct ColorAreaContextValue {
+    thumb_ref: AnyNodeRef,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorArea
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Area picking saturation along the inline axis and brightness along the block axis.
+///
+/// Renders as a `<div>` with a background of the current hue. Dragging anywhere in it moves the
+/// [`ColorAreaThumb`].
+#[component]
+pub fn ColorArea(
+    #[prop(into, optional)] on_pointer_down: Option<Callback<ev::PointerEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<ColorPickerContextValue>();
+    let area_context = ColorAreaContextValue {
+        thumb_ref: AnyNodeRef::new(),
+    };
+    let dragging = RwSignal::new(false);
+
+    let update_from_pointer = move |event: &ev::PointerEvent| {
+        let Some(area) = event
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        else {
+            return;
+        };
+        let rect = area.get_bounding_client_rect();
+        let (saturation, brightness) = area_position(
+            (event.client_x() as f64, event.client_y() as f64),
+            [rect.left(), rect.right()],
+            [rect.top(), rect.bottom()],
+            context.direction.get_untracked(),
+        );
+        let hsv = context.color.get_untracked().to_hsv();
+        context.on_color_change.run(Color::Hsv(Hsv {
+            s: saturation,
+            v: brightness,
+            ..hsv
+        }));
+    };
+
+    let on_pointer_down_stored = StoredValue::new(on_pointer_down);
+
+    view! {
+        <Provider value=area_context>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-disabled=data_attr(context.disabled)
+                attr:data-dragging=move || dragging.get().then_some("")
+                style:position="relative"
+                style:touch-action="none"
+                style:background=move || {
+                    let saturation_side = match context.direction.get() {
+                        Direction::Ltr => "right",
+                        Direction::Rtl => "left",
+                    };
+                    format!(
+                        "linear-gradient(to top, #000, transparent), linear-gradient(to {saturation_side}, #fff, transparent), {}",
+                        hue_css(context.color.get().to_hsv().h),
+                    )
+                }
+                on:pointerdown=move |event: ev::PointerEvent| {
+                    if let Some(Some(cb)) = on_pointer_down_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if event.default_prevented() || context.disabled.get_untracked() {
+                        return;
+                    }
+                    if let Some(area) = event
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                    {
+                        area.set_pointer_capture(event.pointer_id()).ok();
+                    }
+                    // Prevent browser focus behaviour because we focus the thumb manually.
+                    event.prevent_default();
+                    dragging.set(true);
+                    update_from_pointer(&event);
+                    if let Some(thumb) = area_context.thumb_ref.get_untracked() {
+                        let thumb: &web_sys::HtmlElement = thumb.unchecked_ref();
+                        let _ = thumb.focus();
+                    }
+                }
+                on:pointermove=move |event: ev::PointerEvent| {
+                    if dragging.get_untracked() {
+                        update_from_pointer(&event);
+                    }
+                }
+                on:pointerup=move |event: ev::PointerEvent| {
+                    if let Some(area) = event
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                        && area.has_pointer_capture(event.pointer_id())
+                    {
+                        area.release_pointer_capture(event.pointer_id()).ok();
+                    }
+                    dragging.set(false);
+                }
+            >
+                {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+            </Primitive>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorAreaThumb
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Thumb of the [`ColorArea`] at the current saturation and brightness.
+///
+/// Renders as a `<span>` with `role="slider"` whose `aria-valuetext` names the color.
+#[component]
+pub fn ColorAreaThumb(
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<ColorPickerContextValue>();
+    let area_context = expect_context::<ColorAreaContextValue>();
+    let composed_ref = use_composed_refs(vec![node_ref, area_context.thumb_ref]);
+
+    let percent = |value: f64| (value * 100.0).round();
+    let value_text = Signal::derive(move || {
+        let color = context.color.get();
+        let hsv = color.to_hsv();
+        format!(
+            "{}, saturation {}%, brightness {}%",
+            color_name(&color),
+            percent(hsv.s),
+            percent(hsv.v),
+        )
+    });
+
+    let on_key_down_stored = StoredValue::new(on_key_down);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <Primitive
+                element=html::span
+                as_child=as_child
+                node_ref=composed_ref
+                attr:role="slider"
+                attr:aria-roledescription="2D slider"
+                attr:aria-label="Color"
+                attr:aria-valuemin="0"
+                attr:aria-valuemax="100"
+                attr:aria-valuenow=move || percent(context.color.get().to_hsv().s)
+                attr:aria-valuetext=move || value_text.get()
+                attr:aria-disabled=move || context.disabled.get().then_some("true")
+                attr:data-disabled=data_attr(context.disabled)
+                attr:tabindex=move || if context.disabled.get() { None } else { Some("0") }
+                style:position="absolute"
+                style:left=move || (context.direction.get() == Direction::Ltr).then(|| format!("{}%", context.color.get().to_hsv().s * 100.0))
+                style:right=move || (context.direction.get() == Direction::Rtl).then(|| format!("{}%", context.color.get().to_hsv().s * 100.0))
+                style:top=move || format!("{}%", (1.0 - context.color.get().to_hsv().v) * 100.0)
+                style:transform=move || match context.direction.get() {
+                    Direction::Ltr => "translate(-50%, -50%)",
+                    Direction::Rtl => "translate(50%, -50%)",
+                }
+                on:keydown=move |event: ev::KeyboardEvent| {
+                    if let Some(Some(cb)) = on_key_down_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if event.default_prevented() || context.disabled.get_untracked() {
+                        return;
+                    }
+                    let hsv = context.color.get_untracked().to_hsv();
+                    if let Some((saturation, brightness)) = area_key_position(
+                        &event.key(),
+                        event.shift_key(),
+                        (hsv.s, hsv.v),
+                        context.direction.get_untracked(),
+                    ) {
+                        event.prevent_default();
+                        context.on_color_change.run(Color::Hsv(Hsv {
+                            s: saturation,
+                            v: brightness,
+                            ..hsv
+                        }));
+                    }
+                }
+                {..attrs}
+            >
+                {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+            </Primitive>
+        </AttributeInterceptor>
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorChannelInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Number input for one channel of the color, e.g. red or lightness.
+///
+/// Commits on change; values out of range are clamped and anything else is reverted.
+#[component]
+pub fn ColorChannelInput(
+    channel: ColorChannel,
+    #[prop(into, optional)] on_change: Option<Callback<ev::Event>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let context = expect_context::<ColorPickerContextValue>();
+    let (min, max, step) = channel.range();
+    let format = move |color: &Color| channel.value(color).round().to_string();
+
+    let on_change_stored = StoredValue::new(on_change);
+
+    view! {
+        <VoidPrimitive
+            element=html::input
+            as_child=as_child
+            node_ref=node_ref
+            attr:r#type="number"
+            attr:inputmode="decimal"
+            attr:min=min
+            attr:max=max
+            attr:step=step
+            attr:aria-label=channel.label()
+            attr:disabled=data_attr(context.disabled)
+            attr:data-disabled=data_attr(context.disabled)
+            prop:value=move || format(&context.color.get())
+            on:change=move |event: ev::Event| {
+                if let Some(Some(cb)) = on_change_stored.try_get_value() {
+                    cb.run(event.clone());
+                }
+                let input: web_sys::HtmlInputElement = event.target().unwrap().unchecked_into();
+                if event.default_prevented() {
+                    return;
+                }
+                let mut color = context.color.get_untracked();
+                if let Ok(value) = input.value().trim().parse::<f64>()
+                    && value.is_finite()
+                {
+                    color = channel.with_value(&color, value);
+                    context.on_color_change.run(color);
+                }
+                // Shows the committed value, e.g. after clamping or reverting.
+                input.set_value(&format(&color));
+            }
+        >
+            {()}
+        </VoidPrimitive>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorHexInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Text input for the color as hex, accepting 3, 4, 6 or 8 digits with or without `#`.
+///
+/// Commits on change; invalid text is reverted.
+#[component]
+pub fn ColorHexInput(
+    #[prop(into, optional)] on_change: Option<Callback<ev::Event>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let context = expect_context::<ColorPickerContextValue>();
+    let on_change_stored = StoredValue::new(on_change);
+
+    view! {
+        <VoidPrimitive
+            element=html::input
+            as_child=as_child
+            node_ref=node_ref
+            attr:r#type="text"
+            attr:autocomplete="off"
+            attr:spellcheck="false"
+            attr:maxlength="9"
+            attr:aria-label="Hex color"
+            attr:disabled=data_attr(context.disabled)
+            attr:data-disabled=data_attr(context.disabled)
+            prop:value=move || context.color.get().to_hex()
+            on:change=move |event: ev::Event| {
+                if let Some(Some(cb)) = on_change_stored.try_get_value() {
+                    cb.run(event.clone());
+                }
+                let input: web_sys::HtmlInputElement = event.target().unwrap().unchecked_into();
+                if event.default_prevented() {
+                    return;
+                }
+                let mut color = context.color.get_untracked();
+                if let Some(next_color) = parse_hex(&input.value()) {
+                    color = next_color;
+                    context.on_color_change.run(color);
+                }
+                // Shows the committed value in its canonical form, or reverts invalid text.
+                input.set_value(&color.to_hex());
+            }
+        >
+            {()}
+        </VoidPrimitive>
+    }
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorEyeDropper
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Button that picks the color from anywhere on the screen with the browser's `EyeDropper` API,
+/// keeping the current alpha.
+///
+/// Renders nothing where the API doesn't exist.
+#[component]
+pub fn ColorEyeDropper(
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<ColorPickerContextValue>();
+
+    // Detected after mount, so the server and the first client render agree.
+    let supported = RwSignal::new(false);
+    Effect::new(move |_| {
+        supported.set(eye_dropper_constructor().is_some());
+    });
+
+    let on_click_stored = StoredValue::new(on_click);
+
+    view! {
+        <Show when=move || supported.get()>
+            <AttributeInterceptor let:attrs>
+                <Primitive
+                    element=html::button
+                    as_child=as_child
+                    node_ref=node_ref
+                    attr:r#type="button"
+                    attr:aria-label="Pick a color from the screen"
+                    attr:data-disabled=data_attr(context.disabled)
+                    attr:disabled=data_attr(context.disabled)
+                    on:click=move |event: ev::MouseEvent| {
+                        if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                            cb.run(event.clone());
+                        }
+                        if !event.default_prevented() && !context.disabled.get_untracked() {
+                            open_eye_dropper(move |picked: Color| {
+                                let alpha = context.color.get_untracked().alpha();
+                                context
+                                    .on_color_change
+                                    .run(ColorChannel::Alpha.with_value(&picked, alpha * 100.0));
+                            });
+                        }
+                    }
+                    {..attrs}
+                >
+                    {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+                </Primitive>
+            </AttributeInterceptor>
+        </Show>
+    }
+}
+
+/// The `EyeDropper` class, where the browser has one.
+fn eye_dropper_constructor() -> Option<js_sys::Function> {
+    let window = web_sys::window()?;
+    js_sys::Reflect::get(&window, &"EyeDropper".into())
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+/// Opens an eye dropper and calls `on_pick` with the picked color. Cancelling calls nothing.
+fn open_eye_dropper(on_pick: impl FnOnce(Color) + 'static) {
+    let Some(constructor) = eye_dropper_constructor() else {
+        return;
+    };
+    let Ok(eye_dropper) = js_sys::Reflect::construct(&constructor, &js_sys::Array::new()) else {
+        return;
+    };
+    let Some(promise) = js_sys::Reflect::get(&eye_dropper, &"open".into())
+        .ok()
+        .and_then(|open| open.dyn_into::<js_sys::Function>().ok())
+        .and_then(|open| open.call0(&eye_dropper).ok())
+        .and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok())
+    else {
+        return;
+    };
+
+    leptos::task::spawn_local(async move {
+        // The promise rejects when the user cancels, which is no error.
+        let Ok(result) = wasm_bindgen_futures::JsFuture::from(promise).await else {
+            return;
+        };
+        if let Some(color) = js_sys::Reflect::get(&result, &"sRGBHex".into())
+            .ok()
+            .and_then(|hex| hex.as_string())
+            .and_then(|hex| parse_hex(&hex))
+        {
+            on_pick(color);
+        }
+    });
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorPicker (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Root color picker component.
+///
+/// Renders as a `<div>` and provides the color to its parts. With a `name`, the color is
+/// submitted with forms as a hex string such as `#ff8800` or `#ff880080`.
+#[component]
+pub fn ColorPicker(
+    #[prop(into, optional)] value: MaybeProp<Color>,
+    #[prop(into, optional)] default_value: MaybeProp<Color>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Color>>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+    #[prop(into, optional)] dir: MaybeProp<Direction>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let bubble_ref = AnyNodeRef::new();
+    let direction = use_direction(dir);
+    let disabled = prop_or_default(disabled);
+
+    let (color_signal, set_color) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change.map(|cb| {
+            Callback::new(move |value: Option<Color>| {
+                if let Some(value) = value {
+                    cb.run(value);
+                }
+            })
+        }),
+    });
+    let color = Signal::derive(move || color_signal.get().unwrap_or(BLACK));
+
+    let initial_color = color.get_untracked();
+    use_form_reset(bubble_ref, move || {
+        set_color.run(Some(initial_color));
+    });
+
+    let context = ColorPickerContextValue {
+        color,
+        on_color_change: Callback::new(move |color: Color| {
+            set_color.run(Some(color));
+        }),
+        disabled,
+        direction,
+    };
+
+    view! {
+        <Provider value=context>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:dir=move || direction.get().to_string()
+                attr:data-disabled=data_attr(disabled)
+                style:--color-picker-color=move || color.get().to_string()
+                style:--color-picker-hue=move || hue_css(color.get().to_hsv().h)
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+
+            <ColorPickerBubbleInput
+                node_ref=bubble_ref
+                color=color
+                name=Signal::derive(move || name.get())
+                form=Signal::derive(move || form.get())
+                disabled=disabled
+            />
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorPickerBubbleInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Input that holds the hex value of the color for forms.
+#[component]
+fn ColorPickerBubbleInput(
+    node_ref: AnyNodeRef,
+    color: Signal<Color>,
+    name: Signal<Option<String>>,
+    form: Signal<Option<String>>,
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    let hex = Memo::new(move |_| color.get().to_hex());
+    let prev_hex = StoredValue::new(hex.get_untracked());
+
+    // Bubble value change to parent forms
+    Effect::new(move |_| {
+        let current = hex.get();
+        let previous = prev_hex.try_get_value().unwrap_or_default();
+        let _ = prev_hex.try_set_value(current.clone());
+
+        if previous != current
+            && let Some(input_el) = node_ref.get()
+        {
+            let input_el: web_sys::HtmlInputElement = (*input_el).clone().unchecked_into();
+            input_el.set_value(&current);
+            let event_init = web_sys::EventInit::new();
+            event_init.set_bubbles(true);
+            let event = web_sys::Event::new_with_event_init_dict("change", &event_init)
+                .expect("Event should be created.");
+            let _ = input_el.dispatch_event(&event);
+        }
+    });
+
+    view! {
+        // Hidden with display:none rather than type="hidden", like the slider's bubble input.
+        <input
+            node_ref=node_ref
+            aria-hidden="true"
+            tabindex="-1"
+            name=move || name.get()
+            form=move || form.get()
+            value=move || hex.get()
+            disabled=move || disabled.get()
+            style:display="none"
+        />
+    }
+}
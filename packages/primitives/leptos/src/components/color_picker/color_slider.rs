@@ -0,0 +1,116 @@
+use super::*;
+
+#[derive(Clone, Copy)]
+struct ColorSliderContextValue {
+    channel: ColorChannel,
+    orientation: Signal<Orientation>,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorSlider
+ * -----------------------------------------------------------------------------------------------*/
+
+/// [`Slider`] editing one channel of the color, e.g. hue or alpha.
+#[component]
+pub fn ColorSlider(
+    channel: ColorChannel,
+    #[prop(into, optional)] orientation: MaybeProp<Orientation>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<ColorPickerContextValue>();
+    let slider_context = ColorSliderContextValue {
+        channel,
+        orientation: prop_or_default(orientation),
+    };
+    let (min, max, step) = channel.range();
+
+    view! {
+        <Provider value=slider_context>
+            <Slider
+                value=Signal::derive(move || vec![channel.value(&context.color.get())])
+                on_value_change=Callback::new(move |values: Vec<f64>| {
+                    if let Some(value) = values.first() {
+                        let color = context.color.get_untracked();
+                        context.on_color_change.run(channel.with_value(&color, *value));
+                    }
+                })
+                min=min
+                max=max
+                step=step
+                disabled=context.disabled
+                orientation=slider_context.orientation
+                dir=context.direction
+                as_child=as_child
+                node_ref=node_ref
+            >
+                {children.with_value(|children| children())}
+            </Slider>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorSliderTrack
+ * -----------------------------------------------------------------------------------------------*/
+
+/// [`SliderTrack`] with a background of the colors along the channel.
+#[component]
+pub fn ColorSliderTrack(
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<ColorPickerContextValue>();
+    let slider_context = expect_context::<ColorSliderContextValue>();
+
+    let background = move || {
+        let side = match (slider_context.orientation.get(), context.direction.get()) {
+            (Orientation::Vertical, _) => "top",
+            (Orientation::Horizontal, Direction::Ltr) => "right",
+            (Orientation::Horizontal, Direction::Rtl) => "left",
+        };
+        channel_gradient(slider_context.channel, &context.color.get(), side)
+    };
+
+    view! {
+        <SliderTrack as_child=as_child node_ref=node_ref style:background=background>
+            {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+        </SliderTrack>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorSliderThumb
+ * -----------------------------------------------------------------------------------------------*/
+
+/// [`SliderThumb`] labelled with the channel, whose `aria-valuetext` describes the value, e.g.
+/// "120°, vibrant green" for the hue.
+#[component]
+pub fn ColorSliderThumb(
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<ColorPickerContextValue>();
+    let slider_context = expect_context::<ColorSliderContextValue>();
+    let channel = slider_context.channel;
+
+    view! {
+        <SliderThumb
+            as_child=as_child
+            node_ref=node_ref
+            attr:aria-label=channel.label()
+            attr:aria-valuetext=move || channel.value_text(&context.color.get())
+        >
+            {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+        </SliderThumb>
+    }
+}
@@ -0,0 +1,39 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * ColorSwatch
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Preview of a color, labelled with its name, e.g. "vibrant red, 50% transparent".
+///
+/// Shows `color` or, without it, the color of the enclosing [`ColorPicker`].
+#[component]
+pub fn ColorSwatch(
+    #[prop(into, optional)] color: MaybeProp<Color>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = use_context::<ColorPickerContextValue>();
+    let color = Signal::derive(move || {
+        color
+            .get()
+            .or_else(|| context.map(|context| context.color.get()))
+            .unwrap_or(BLACK)
+    });
+
+    view! {
+        <Primitive
+            element=html::div
+            as_child=as_child
+            node_ref=node_ref
+            attr:role="img"
+            attr:aria-label=move || color_description(&color.get())
+            style:background-color=move || color.get().to_string()
+        >
+            {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+        </Primitive>
+    }
+}
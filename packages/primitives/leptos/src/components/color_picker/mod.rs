@@ -0,0 +1,261 @@
+//! Color picker built from composable parts.
+//!
+//! A [`ColorPicker`] holds the color that its parts edit: a 2D saturation/brightness
+//! [`ColorArea`], [`ColorSlider`]s for single channels such as hue and alpha, channel and hex
+//! inputs, [`ColorSwatch`]es and an eye dropper for picking a color from the screen.
+//!
+//! Colors are [`Color`](crate::colors::Color)s from the `colors` feature. The parts emit them as
+//! HSV, which keeps the hue of grays and the saturation of black while the color is edited.
+//!
+//! `ColorSlider` is a [`Slider`](crate::slider::Slider), so it has the same keyboard
+//! interactions. The thumbs describe colors by name in `aria-valuetext`, e.g.
+//! "dark vibrant blue".
+//!
+//! # Anatomy
+//!
+//! ```text
+//! <ColorPicker>
+//!     <ColorArea>
+//!         <ColorAreaThumb />
+//!     </ColorArea>
+//!     <ColorSlider>
+//!         <ColorSliderTrack />
+//!         <ColorSliderThumb />
+//!     </ColorSlider>
+//!     <ColorSwatch />
+//!     <ColorChannelInput />
+//!     <ColorHexInput />
+//!     <ColorEyeDropper />
+//! </ColorPicker>
+//! ```
+//!
+//! # Features
+//!
+//! - Controlled and uncontrolled color, also readable through [`use_color_picker`]
+//! - Hue, saturation, lightness, RGB and alpha channels
+//! - Hex input accepting 3, 4, 6 and 8 digits
+//! - Eye dropper, rendered only where the browser supports the `EyeDropper` API
+//! - Colors described by name for assistive technology
+//! - RTL support
+//! - Native form participation of the hex value via a hidden input, with form reset support
+//!
+//! # Keyboard Interactions
+//!
+//! **ColorAreaThumb:**
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | ArrowRight / ArrowLeft | Increase/decrease saturation by one step (swapped in RTL) |
+//! | ArrowUp / ArrowDown | Increase/decrease brightness by one step |
+//! | PageUp / PageDown | Increase/decrease brightness by 10 steps |
+//! | Shift + Arrow | Increase/decrease by 10 steps |
+//! | Home / End | Set saturation to minimum/maximum |
+//!
+//! **ColorSliderThumb:** as for [`SliderThumb`](crate::slider::SliderThumb).
+//!
+//! # Data Attributes
+//!
+//! **ColorArea, ColorAreaThumb, ColorSlider, ColorEyeDropper:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-disabled` | Present when disabled |
+//!
+//! **ColorArea:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-dragging` | Present while dragging the thumb |
+//!
+//! # CSS Custom Properties
+//!
+//! | Property | Description |
+//! |----------|-------------|
+//! | `--color-picker-color` | The current color, set on the root |
+//! | `--color-picker-hue` | The current hue at full saturation and brightness, set on the root |
+
+use crate::colors::{Color, Hsv};
+use crate::internal::number::clamp;
+use crate::internal::utils::linear_scale;
+use crate::slider::{Orientation, Slider, SliderThumb, SliderTrack};
+use crate::support::compose_refs::use_composed_refs;
+use crate::support::direction::{Direction, use_direction};
+use crate::support::form_control::use_form_reset;
+use crate::support::primitive::{Primitive, VoidPrimitive, data_attr, prop_or_default};
+use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
+use leptos::{
+    attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
+};
+use leptos_node_ref::AnyNodeRef;
+use wasm_bindgen::JsCast;
+
+mod color;
+mod color_area;
+mod color_channel_input;
+mod color_eye_dropper;
+mod color_picker;
+mod color_slider;
+mod color_swatch;
+
+pub use color::*;
+pub use color_area::*;
+pub use color_channel_input::*;
+pub use color_eye_dropper::*;
+pub use color_picker::*;
+pub use color_slider::*;
+pub use color_swatch::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Context
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct ColorPickerContextValue {
+    color: Signal<Color>,
+    on_color_change: Callback<Color>,
+    disabled: Signal<bool>,
+    direction: Signal<Direction>,
+}
+
+/// The color of the enclosing [`ColorPicker`].
+pub fn use_color_picker() -> Signal<Color> {
+    expect_context::<ColorPickerContextValue>().color
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+const AREA_STEP: f64 = 0.01;
+const AREA_PAGE_STEP: f64 = 0.1;
+
+/// Opaque color of `hue` at full saturation and brightness.
+fn hue_css(hue: f64) -> String {
+    Color::Hsv(Hsv {
+        h: hue,
+        s: 1.0,
+        v: 1.0,
+        alpha: 1.0,
+    })
+    .to_string()
+}
+
+/// Background of a track for `channel`, from its minimum towards `side`, e.g. `right`.
+fn channel_gradient(channel: ColorChannel, color: &Color, side: &str) -> String {
+    let stops: Vec<String> = match channel {
+        ColorChannel::Hue => (0..=6)
+            .map(|sector| hue_css(sector as f64 * 60.0))
+            .collect(),
+        _ => {
+            let (min, max, _) = channel.range();
+            [min, (min + max) / 2.0, max]
+                .into_iter()
+                .map(|value| channel.with_value(color, value).to_string())
+                .collect()
+        }
+    };
+    format!("linear-gradient(to {side}, {})", stops.join(", "))
+}
+
+/// Saturation and brightness at `point` within `rect`, as `[left, right]` and `[top, bottom]`.
+fn area_position(
+    point: (f64, f64),
+    [left, right]: [f64; 2],
+    [top, bottom]: [f64; 2],
+    direction: Direction,
+) -> (f64, f64) {
+    let saturation_output = match direction {
+        Direction::Ltr => [0.0, 1.0],
+        Direction::Rtl => [1.0, 0.0],
+    };
+    let saturation = linear_scale([left, right], saturation_output)(point.0);
+    let brightness = linear_scale([top, bottom], [1.0, 0.0])(point.1);
+    (clamp(saturation, [0.0, 1.0]), clamp(brightness, [0.0, 1.0]))
+}
+
+/// Saturation and brightness after `key` on the area thumb, or `None` for other keys.
+fn area_key_position(
+    key: &str,
+    shift: bool,
+    (saturation, brightness): (f64, f64),
+    direction: Direction,
+) -> Option<(f64, f64)> {
+    let step = if shift { AREA_PAGE_STEP } else { AREA_STEP };
+    let forward = match direction {
+        Direction::Ltr => 1.0,
+        Direction::Rtl => -1.0,
+    };
+    let (saturation, brightness) = match key {
+        "ArrowRight" => (saturation + step * forward, brightness),
+        "ArrowLeft" => (saturation - step * forward, brightness),
+        "ArrowUp" => (saturation, brightness + step),
+        "ArrowDown" => (saturation, brightness - step),
+        "PageUp" => (saturation, brightness + AREA_PAGE_STEP),
+        "PageDown" => (saturation, brightness - AREA_PAGE_STEP),
+        "Home" => (0.0, brightness),
+        "End" => (1.0, brightness),
+        _ => return None,
+    };
+    // Avoids drift such as 0.30000000000000004 when stepping repeatedly.
+    let round = |value: f64| (value * 100.0).round() / 100.0;
+    Some((
+        clamp(round(saturation), [0.0, 1.0]),
+        clamp(round(brightness), [0.0, 1.0]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_channel_gradients() {
+        let color = parse_hex("#ff0000").unwrap();
+        assert_eq!(
+            channel_gradient(ColorChannel::Alpha, &color, "right"),
+            "linear-gradient(to right, #ff000000, #ff000080, #ff0000)"
+        );
+        assert!(
+            channel_gradient(ColorChannel::Hue, &color, "top")
+                .starts_with("linear-gradient(to top, #ff0000, #ffff00, #00ff00")
+        );
+    }
+
+    #[test]
+    fn maps_pointer_to_area_position() {
+        let position =
+            |point, direction| area_position(point, [100.0, 300.0], [0.0, 100.0], direction);
+        assert_eq!(position((150.0, 25.0), Direction::Ltr), (0.25, 0.75));
+        assert_eq!(position((150.0, 25.0), Direction::Rtl), (0.75, 0.75));
+        assert_eq!(position((0.0, 200.0), Direction::Ltr), (0.0, 0.0));
+    }
+
+    #[test]
+    fn steps_area_position_with_keys() {
+        let position = (0.5, 0.5);
+        assert_eq!(
+            area_key_position("ArrowRight", false, position, Direction::Ltr),
+            Some((0.51, 0.5))
+        );
+        assert_eq!(
+            area_key_position("ArrowRight", false, position, Direction::Rtl),
+            Some((0.49, 0.5))
+        );
+        assert_eq!(
+            area_key_position("ArrowUp", true, position, Direction::Ltr),
+            Some((0.5, 0.6))
+        );
+        assert_eq!(
+            area_key_position("PageDown", false, (0.5, 0.05), Direction::Ltr),
+            Some((0.5, 0.0))
+        );
+        assert_eq!(
+            area_key_position("End", false, position, Direction::Ltr),
+            Some((1.0, 0.5))
+        );
+        assert_eq!(
+            area_key_position("a", false, position, Direction::Ltr),
+            None
+        );
+    }
+}
@@ -97,6 +97,21 @@ pub fn Combobox(
     let active_descendant_id: RwSignal<Option<String>> = RwSignal::new(None);
     let highlighted_chip_index: RwSignal<Option<usize>> = RwSignal::new(None);
 
+    let initial_value = value_state.get_untracked();
+    let initial_values = values_state.get_untracked();
+    let initial_input_value = input_value_state.get_untracked();
+    use_form_reset(input_ref, move || {
+        set_value.run(initial_value.clone());
+        set_values.run(Some(initial_values.clone()));
+        set_input_value.run(Some(initial_input_value.clone()));
+    });
+
+    let bubble_ref = AnyNodeRef::new();
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: input_ref,
+    });
+
     let on_value_change_cb = Callback::new(move |val: String| {
         if multiple {
             // Toggle the value in the multi-select list
@@ -136,6 +151,7 @@ pub fn Combobox(
         active_descendant_id,
         highlighted_chip_index,
         multiple,
+        form_control,
     };
 
     // Native input for form integration
@@ -150,10 +166,15 @@ pub fn Combobox(
                 </CollectionProvider<ComboboxItemData>>
 
                 <ComboboxBubbleInput
+                    node_ref=bubble_ref
                     value=value_state
                     values=values_state
                     multiple=multiple
-                    name=Signal::derive(move || name.try_with_value(|n| n.get()).flatten())
+                    name=Signal::derive(move || {
+                        name.try_with_value(|n| n.get())
+                            .flatten()
+                            .or_else(|| form_control.name.get())
+                    })
                     form=Signal::derive(move || form.try_with_value(|f| f.get()).flatten())
                     disabled=disabled_state
                     required=required_state
@@ -231,6 +252,8 @@ pub fn ComboboxInput(
                 attr:data-chip-highlighted=move || context.highlighted_chip_index.get().is_some().then_some("")
                 attr:disabled=data_attr(context.disabled)
                 attr:dir=move || context.dir.get().to_string()
+                attr:id=move || context.form_control.id.get()
+                {..context.form_control.into_attrs()}
                 attr:placeholder=move || {
                     // Hide placeholder when a chip is highlighted or when values are selected in multi-select
                     if context.highlighted_chip_index.get().is_some()
@@ -441,6 +464,7 @@ pub fn ComboboxClear(
 /// Hidden native <input> element for form integration.
 #[component]
 fn ComboboxBubbleInput(
+    node_ref: AnyNodeRef,
     value: Signal<Option<String>>,
     values: Signal<Vec<String>>,
     multiple: bool,
@@ -449,7 +473,6 @@ fn ComboboxBubbleInput(
     disabled: Signal<bool>,
     required: Signal<bool>,
 ) -> impl IntoView {
-    let bubble_ref = AnyNodeRef::new();
     let prev_value: StoredValue<Option<String>> = StoredValue::new(None);
 
     // Bubble value change to parent forms
@@ -468,7 +491,7 @@ fn ComboboxBubbleInput(
         let _ = prev_value.try_set_value(current_value.clone());
 
         if previous != current_value
-            && let Some(input_el) = bubble_ref.get()
+            && let Some(input_el) = node_ref.get()
         {
             let input_el: web_sys::HtmlInputElement = (*input_el).clone().unchecked_into();
             input_el.set_value(&current_value.clone().unwrap_or_default());
@@ -481,9 +504,9 @@ fn ComboboxBubbleInput(
     });
 
     view! {
+        // Not `type="hidden"`, which is barred from constraint validation, e.g. `required`.
         <input
-            node_ref=bubble_ref
-            type="hidden"
+            node_ref=node_ref
             aria-hidden="true"
             tabindex="-1"
             name=move || name.get()
@@ -548,46 +571,36 @@ fn handle_input_keydown(
                 handle_enter_select(context, get_items);
             }
         }
-        "Escape" => {
-            if context.open.get_untracked() {
-                event.prevent_default();
-                context.dismiss();
-            }
+        "Escape" if context.open.get_untracked() => {
+            event.prevent_default();
+            context.dismiss();
         }
         "Backspace" => handle_backspace(event, context),
-        "Delete" => {
-            if context.multiple {
-                if let Some(idx) = context.highlighted_chip_index.get_untracked() {
-                    event.prevent_default();
-                    remove_chip_at(context, idx, context.values.get_untracked());
-                }
+        "Delete" if context.multiple => {
+            if let Some(idx) = context.highlighted_chip_index.get_untracked() {
+                event.prevent_default();
+                remove_chip_at(context, idx, context.values.get_untracked());
             }
         }
         "ArrowLeft" => handle_arrow_left(event, context),
-        "ArrowRight" => {
-            if context.multiple {
-                if let Some(idx) = context.highlighted_chip_index.get_untracked() {
-                    event.prevent_default();
-                    let values = context.values.get_untracked();
-                    if idx + 1 < values.len() {
-                        context.highlighted_chip_index.set(Some(idx + 1));
-                    } else {
-                        context.highlighted_chip_index.set(None);
-                    }
+        "ArrowRight" if context.multiple => {
+            if let Some(idx) = context.highlighted_chip_index.get_untracked() {
+                event.prevent_default();
+                let values = context.values.get_untracked();
+                if idx + 1 < values.len() {
+                    context.highlighted_chip_index.set(Some(idx + 1));
+                } else {
+                    context.highlighted_chip_index.set(None);
                 }
             }
         }
-        "Home" => {
-            if context.open.get_untracked() {
-                event.prevent_default();
-                navigate_to_edge(context, get_items, true);
-            }
+        "Home" if context.open.get_untracked() => {
+            event.prevent_default();
+            navigate_to_edge(context, get_items, true);
         }
-        "End" => {
-            if context.open.get_untracked() {
-                event.prevent_default();
-                navigate_to_edge(context, get_items, false);
-            }
+        "End" if context.open.get_untracked() => {
+            event.prevent_default();
+            navigate_to_edge(context, get_items, false);
         }
         _ => {}
     }
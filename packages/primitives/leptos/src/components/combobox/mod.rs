@@ -47,7 +47,8 @@
 //! - Consumer-managed filtering via `on_input_value_change`
 //! - Keyboard navigation with `aria-activedescendant`
 //! - Multi-select chip display
-//! - Native form participation via hidden input
+//! - Native form participation via hidden input, with form reset support
+//! - Validation and `aria-describedby` wiring inside a `FormField`
 //! - Full keyboard navigation
 //!
 //! # Keyboard Interactions
@@ -86,6 +87,9 @@ use crate::support::collection::{
 use crate::support::compose_refs::use_composed_refs;
 use crate::support::direction::{Direction, use_direction};
 use crate::support::dismissable_layer::DismissableLayer;
+use crate::support::form_control::{
+    FormControlAttributes, UseFormControlParams, use_form_control, use_form_reset,
+};
 use crate::support::id::use_id;
 use crate::support::popper::{
     Popper, PopperAnchor, PopperArrow, PopperContent, UpdatePositionStrategy, provide_popper_scope,
@@ -158,6 +162,7 @@ struct ComboboxContextValue {
     /// `None` means no chip is highlighted (focus is on the input).
     highlighted_chip_index: RwSignal<Option<usize>>,
     multiple: bool,
+    form_control: FormControlAttributes,
 }
 
 impl ComboboxContextValue {
@@ -22,6 +22,10 @@ struct ContentOptions {
     /// The ARIA role for the content element. Defaults to `"dialog"`.
     /// AlertDialog overrides this to `"alertdialog"`.
     role: StoredValue<String>,
+    initial_focus: Option<AnyNodeRef>,
+    final_focus: Option<AnyNodeRef>,
+    external_focus_targets: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+    focus_trap_paused: MaybeProp<bool>,
 }
 
 #[component]
@@ -31,6 +35,18 @@ pub fn DialogContent(
     /// AlertDialog overrides this to `"alertdialog"`.
     #[prop(into, optional)]
     role: Option<String>,
+    /// Element to focus on open instead of the first tabbable element.
+    #[prop(into, optional_no_strip)]
+    initial_focus: Option<AnyNodeRef>,
+    /// Element to focus on close instead of the trigger.
+    #[prop(into, optional_no_strip)]
+    final_focus: Option<AnyNodeRef>,
+    /// Elements outside the dialog that focus may move to while it is trapped, e.g. third-party iframes.
+    #[prop(into, optional)]
+    external_focus_targets: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+    /// When `true`, focus trapping is suspended while the dialog stays open.
+    #[prop(into, optional)]
+    focus_trap_paused: MaybeProp<bool>,
     #[prop(into, optional)] on_open_auto_focus: Option<Callback<ev::Event>>,
     #[prop(into, optional)] on_close_auto_focus: Option<Callback<ev::Event>>,
     #[prop(into, optional)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
@@ -61,6 +77,10 @@ pub fn DialogContent(
 
     let options = ContentOptions {
         role: StoredValue::new(role.unwrap_or_else(|| "dialog".to_string())),
+        initial_focus,
+        final_focus,
+        external_focus_targets,
+        focus_trap_paused,
     };
 
     let presence_ref = AnyNodeRef::new();
@@ -119,33 +139,10 @@ fn DialogContentModal(
         presence_ref,
     ]);
 
-    // aria-hide everything except the content (better supported equivalent to setting aria-modal).
-    // Deferred to requestAnimationFrame to emulate React's useEffect timing (post-layout):
-    // FocusScope auto-focus runs first (as a synchronous Effect, like React's useLayoutEffect),
-    // moving focus into the dialog content before hide_others sets aria-hidden on outside elements.
-    // Without this deferral, hide_others would run while focus is still on the trigger, causing
-    // a "Blocked aria-hidden on an element because its descendant retained focus" browser warning.
-    //
-    // The hidden_elements signal is managed at this level so on_close_auto_focus can remove
-    // aria-hidden BEFORE focusing the trigger (preventing the same warning on close).
-    let hidden_elements: RwSignal<Vec<SendWrapper<web_sys::Element>>> = RwSignal::new(Vec::new());
-
-    Effect::new(move |_| {
-        if let Some(content) = content_ref.get() {
-            let content: web_sys::HtmlElement = content.unchecked_into();
-            let cb = Closure::once_into_js(move || {
-                hide_others(&content, hidden_elements);
-            });
-            web_sys::window()
-                .expect("Window should exist.")
-                .request_animation_frame(cb.unchecked_ref())
-                .ok();
-        }
-    });
-
-    on_cleanup(move || {
-        unhide_others(hidden_elements);
-    });
+    // Make everything except the content inert (better supported equivalent to setting
+    // aria-modal). The release callback is kept at this level so on_close_auto_focus can
+    // restore outside elements BEFORE focusing the trigger.
+    let release_inert = use_inert_others(content_ref, options.external_focus_targets);
 
     let on_close_auto_focus = Callback::new(move |event: web_sys::Event| {
         callbacks.on_close_auto_focus.with_value(|cb| {
@@ -153,17 +150,16 @@ fn DialogContentModal(
                 cb.run(event.clone());
             }
         });
-        // Remove aria-hidden from outside elements BEFORE focusing the trigger.
-        // Without this, the trigger (inside an aria-hidden ancestor) would receive focus,
-        // causing a "Blocked aria-hidden on a focused element's ancestor" browser warning.
-        unhide_others(hidden_elements);
+        // Restore outside elements BEFORE focusing the trigger. Without this, the trigger
+        // (inside an inert ancestor) could not receive focus.
+        release_inert.run(());
         // Only apply default focus-to-trigger behavior if the consumer hasn't
         // already called preventDefault() (matches React's composeEventHandlers).
         if !event.default_prevented() {
             event.prevent_default();
-            if let Some(trigger) = context.trigger_ref.get_untracked() {
-                let trigger: &web_sys::HtmlElement = trigger.unchecked_ref();
-                trigger.focus().ok();
+            if let Some(target) = final_focus_target(options.final_focus, context.trigger_ref) {
+                let target: &web_sys::HtmlElement = target.unchecked_ref();
+                target.focus().ok();
             }
         }
     });
@@ -214,7 +210,7 @@ fn DialogContentModal(
         <DialogContentImpl
             as_child=as_child
             node_ref=composed_refs
-            role=options.role.get_value().clone()
+            options=options
             trap_focus=context.open
             disable_outside_pointer_events=true
             on_open_auto_focus=on_open_auto_focus
@@ -259,10 +255,10 @@ fn DialogContentNonModal(
 
         if !event.default_prevented() {
             if !has_interacted_outside.get_untracked()
-                && let Some(trigger) = context.trigger_ref.get_untracked()
+                && let Some(target) = final_focus_target(options.final_focus, context.trigger_ref)
             {
-                let trigger: &web_sys::HtmlElement = trigger.unchecked_ref();
-                trigger.focus().ok();
+                let target: &web_sys::HtmlElement = target.unchecked_ref();
+                target.focus().ok();
             }
             // Always prevent auto focus because we either focus manually or want user agent focus
             event.prevent_default();
@@ -341,7 +337,7 @@ fn DialogContentNonModal(
         <DialogContentImpl
             as_child=as_child
             node_ref=composed_refs
-            role=options.role.get_value().clone()
+            options=options
             trap_focus=false
             disable_outside_pointer_events=false
             on_open_auto_focus=on_open_auto_focus
@@ -362,7 +358,7 @@ fn DialogContentNonModal(
 
 #[component]
 fn DialogContentImpl(
-    #[prop(into, optional)] role: Option<String>,
+    options: ContentOptions,
     #[prop(into, optional)] trap_focus: MaybeProp<bool>,
     #[prop(into, optional)] disable_outside_pointer_events: MaybeProp<bool>,
     #[prop(into)] on_open_auto_focus: Callback<web_sys::Event>,
@@ -378,7 +374,7 @@ fn DialogContentImpl(
     let children = StoredValue::new(children);
 
     let context = expect_context::<DialogContextValue>();
-    let role = StoredValue::new(role.unwrap_or_else(|| "dialog".to_string()));
+    let role = options.role;
 
     // Make sure the whole tree has focus guards as our `Dialog` will be
     // the last element in the DOM (because of the `Portal`)
@@ -429,6 +425,10 @@ fn DialogContentImpl(
             trapped=trapped
             on_mount_auto_focus=on_open_auto_focus
             on_unmount_auto_focus=Some(on_close_auto_focus)
+            initial_focus=options.initial_focus
+            final_focus=options.final_focus
+            external_focus_targets=options.external_focus_targets
+            paused=options.focus_trap_paused
         >
             <DismissableLayer
                 as_child=as_child
@@ -73,9 +73,14 @@ fn DialogOverlayImpl(
     let children = StoredValue::new(children);
 
     let context = expect_context::<DialogContextValue>();
-    let composed_ref = use_composed_refs(vec![node_ref, presence_ref]);
-    // Body scroll lock: set overflow hidden on body while overlay is mounted
-    use_body_scroll_lock();
+    let overlay_ref = AnyNodeRef::new();
+    let composed_ref = use_composed_refs(vec![node_ref, presence_ref, overlay_ref]);
+    // Body scroll lock while the overlay is mounted. The content is rendered as a
+    // sibling of the overlay, so it is registered as a shard to keep it scrollable.
+    use_scroll_lock(UseScrollLockParams {
+        layer: overlay_ref,
+        shards: vec![context.content_ref],
+    });
 
     view! {
         <AttributeInterceptor let:attrs>
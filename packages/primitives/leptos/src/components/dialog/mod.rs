@@ -46,25 +46,25 @@
 //! |-----------|--------|
 //! | `data-state` | `open`, `closed` |
 
-use crate::support::aria_hidden::{hide_others, unhide_others};
 use crate::support::compose_refs::use_composed_refs;
 use crate::support::dismissable_layer::DismissableLayer;
 use crate::support::focus_guards::use_focus_guards;
-use crate::support::focus_scope::FocusScope;
+use crate::support::focus_scope::{FocusScope, final_focus_target};
 use crate::support::id::use_id;
+use crate::support::layer_stack::use_inert_others;
 use crate::support::portal::{ScopedPortal, resolve_force_mount};
 use crate::support::presence::Presence;
 use crate::support::primitive::{
     Primitive, adapt_callback, compose_callbacks, open_closed_state, prop_or, prop_or_default,
 };
-use crate::support::scroll_lock::use_body_scroll_lock;
+use crate::support::scroll_lock::{UseScrollLockParams, use_scroll_lock};
 use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
 use leptos::{
     attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
 };
 use leptos_node_ref::AnyNodeRef;
 use send_wrapper::SendWrapper;
-use web_sys::wasm_bindgen::{JsCast, closure::Closure};
+use web_sys::wasm_bindgen::JsCast;
 
 mod dialog;
 mod dialog_content;
@@ -0,0 +1,192 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * FileUpload (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn FileUpload(
+    #[prop(into, optional)] files: MaybeProp<Vec<UploadFile>>,
+    #[prop(into, optional)] default_files: MaybeProp<Vec<UploadFile>>,
+    #[prop(into, optional)] on_files_change: Option<Callback<Vec<UploadFile>>>,
+    /// Accepted file types, as in the `accept` attribute of file inputs, e.g. `image/*,.pdf`.
+    #[prop(into, optional)]
+    accept: MaybeProp<String>,
+    #[prop(into, optional)] multiple: MaybeProp<bool>,
+    /// Maximum size of a file in bytes.
+    #[prop(into, optional)]
+    max_size: MaybeProp<f64>,
+    /// Maximum number of files when `multiple`.
+    #[prop(into, optional)]
+    max_files: MaybeProp<usize>,
+    /// Called for each file that was not added.
+    #[prop(into, optional)]
+    on_file_reject: Option<Callback<FileRejection>>,
+    /// Message of a rejection for the enclosing `FormField`. Defaults to
+    /// [`FileRejectionReason::default_message`].
+    #[prop(into, optional)]
+    rejection_message: Option<Callback<FileRejection, String>>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let root_ref = AnyNodeRef::new();
+    let composed_ref = use_composed_refs(vec![node_ref, root_ref]);
+    let picker_ref = AnyNodeRef::new();
+    let bubble_ref = AnyNodeRef::new();
+
+    let (files_signal, set_files) = use_controllable_state(UseControllableStateParams {
+        prop: files,
+        default_prop: default_files,
+        on_change: on_files_change.map(|cb| {
+            Callback::new(move |value: Option<Vec<UploadFile>>| {
+                if let Some(value) = value {
+                    cb.run(value);
+                }
+            })
+        }),
+    });
+    let files = Signal::derive(move || files_signal.get().unwrap_or_default());
+
+    let disabled = prop_or_default(disabled);
+    let required = prop_or_default(required);
+    let multiple = prop_or_default(multiple);
+    let accept = Signal::derive(move || accept.get());
+    let invalid = RwSignal::new(false);
+
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: root_ref,
+    });
+    let report_error = use_form_control_error(bubble_ref);
+
+    let context = FileUploadContextValue {
+        picker_ref,
+        files,
+        on_files_change: Callback::new(move |files: Vec<UploadFile>| {
+            set_files.run(Some(files));
+        }),
+        accept,
+        multiple,
+        max_size: Signal::derive(move || max_size.get()),
+        max_files: Signal::derive(move || max_files.get()),
+        on_file_reject,
+        rejection_message,
+        report_error,
+        invalid,
+        disabled,
+    };
+
+    let initial_files = files.get_untracked();
+    use_form_reset(bubble_ref, move || {
+        set_files.run(Some(initial_files.clone()));
+        context.clear_error();
+    });
+
+    let name = Signal::derive(move || name.get().or_else(|| form_control.name.get()));
+    let form = Signal::derive(move || form.get());
+
+    view! {
+        <Provider value=context>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=composed_ref
+                attr:data-disabled=data_attr(disabled)
+                attr:data-invalid=move || invalid.get().then_some("")
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+
+            // Opened by the trigger and the dropzone. It has no name, so it isn't submitted.
+            <input
+                node_ref=picker_ref
+                r#type="file"
+                aria-hidden="true"
+                tabindex="-1"
+                accept=move || accept.get()
+                multiple=move || multiple.get()
+                disabled=move || disabled.get()
+                style="display: none;"
+                on:change=move |event: ev::Event| {
+                    let picker: web_sys::HtmlInputElement = event.target().unwrap().unchecked_into();
+                    context.add(file_list_to_vec(picker.files()));
+                    // Picking the same file again should add it again.
+                    picker.set_value("");
+                }
+            />
+
+            <FileUploadBubbleInput
+                node_ref=bubble_ref
+                files=files
+                multiple=multiple
+                name=name
+                form=form
+                disabled=disabled
+                required=required
+            />
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FileUploadBubbleInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// File input that holds the files for forms and constraint validation.
+#[component]
+fn FileUploadBubbleInput(
+    node_ref: AnyNodeRef,
+    files: Signal<Vec<UploadFile>>,
+    multiple: Signal<bool>,
+    name: Signal<Option<String>>,
+    form: Signal<Option<String>>,
+    disabled: Signal<bool>,
+    required: Signal<bool>,
+) -> impl IntoView {
+    let prev_files: StoredValue<Vec<UploadFile>> = StoredValue::new(Vec::new());
+
+    // Bubble files change to parent forms
+    Effect::new(move |_| {
+        let current_files = files.get();
+        let previous = prev_files.try_get_value().unwrap_or_default();
+        let _ = prev_files.try_set_value(current_files.clone());
+
+        if previous != current_files
+            && let Some(input_el) = node_ref.get()
+            && let Ok(data_transfer) = web_sys::DataTransfer::new()
+        {
+            for file in &current_files {
+                let _ = data_transfer.items().add_with_file(file.file());
+            }
+            let input_el: web_sys::HtmlInputElement = (*input_el).clone().unchecked_into();
+            input_el.set_files(data_transfer.files().as_ref());
+            let event_init = web_sys::EventInit::new();
+            event_init.set_bubbles(true);
+            let event = web_sys::Event::new_with_event_init_dict("change", &event_init)
+                .expect("Event should be created.");
+            let _ = input_el.dispatch_event(&event);
+        }
+    });
+
+    view! {
+        <input
+            node_ref=node_ref
+            r#type="file"
+            aria-hidden="true"
+            tabindex="-1"
+            multiple=move || multiple.get()
+            name=move || name.get()
+            form=move || form.get()
+            disabled=move || disabled.get()
+            required=move || required.get()
+            style=VISUALLY_HIDDEN_STYLES_STR
+        />
+    }
+}
@@ -0,0 +1,169 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * FileUploadDropzone
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Area that accepts dropped and pasted files, and opens the file picker when activated.
+#[component]
+pub fn FileUploadDropzone(
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] on_paste: Option<Callback<ev::ClipboardEvent>>,
+    #[prop(into, optional)] on_drop: Option<Callback<ev::DragEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<FileUploadContextValue>();
+    let dragging = RwSignal::new(false);
+
+    let on_click_stored = StoredValue::new(on_click);
+    let on_key_down_stored = StoredValue::new(on_key_down);
+    let on_paste_stored = StoredValue::new(on_paste);
+    let on_drop_stored = StoredValue::new(on_drop);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:role="button"
+                attr:tabindex=move || (!context.disabled.get()).then_some("0")
+                attr:aria-disabled=move || context.disabled.get().then_some("true")
+                attr:data-dragging=move || dragging.get().then_some("")
+                attr:data-disabled=data_attr(context.disabled)
+                attr:data-invalid=move || context.invalid.get().then_some("")
+                on:click=move |event: ev::MouseEvent| {
+                    if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    // A `FileUploadTrigger` inside has opened the picker already.
+                    if !event.default_prevented() {
+                        context.open_picker();
+                    }
+                }
+                on:keydown=move |event: ev::KeyboardEvent| {
+                    if let Some(Some(cb)) = on_key_down_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    let is_own_event = event.target() == event.current_target();
+                    if !event.default_prevented()
+                        && is_own_event
+                        && matches!(event.key().as_str(), "Enter" | " ")
+                    {
+                        event.prevent_default();
+                        context.open_picker();
+                    }
+                }
+                on:paste=move |event: ev::ClipboardEvent| {
+                    if let Some(Some(cb)) = on_paste_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented() {
+                        let files = file_list_to_vec(
+                            event.clipboard_data().and_then(|data| data.files()),
+                        );
+                        if !files.is_empty() {
+                            event.prevent_default();
+                            context.add(files);
+                        }
+                    }
+                }
+                on:dragenter=move |event: ev::DragEvent| {
+                    if !context.disabled.get_untracked() {
+                        event.prevent_default();
+                        dragging.set(true);
+                    }
+                }
+                on:dragover=move |event: ev::DragEvent| {
+                    if !context.disabled.get_untracked() {
+                        // Allows dropping.
+                        event.prevent_default();
+                        if let Some(data_transfer) = event.data_transfer() {
+                            data_transfer.set_drop_effect("copy");
+                        }
+                    }
+                }
+                on:dragleave=move |event: ev::DragEvent| {
+                    // Moving over children of the dropzone is no leave.
+                    let current_target = event
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    let related_target = event
+                        .related_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    if !current_target
+                        .is_some_and(|current_target| current_target.contains(related_target.as_ref()))
+                    {
+                        dragging.set(false);
+                    }
+                }
+                on:drop=move |event: ev::DragEvent| {
+                    if let Some(Some(cb)) = on_drop_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    let is_handled = event.default_prevented();
+                    // Keep the browser from opening the file, even when the drop is ignored.
+                    event.prevent_default();
+                    dragging.set(false);
+                    if !is_handled {
+                        context.add(file_list_to_vec(
+                            event.data_transfer().and_then(|data| data.files()),
+                        ));
+                    }
+                }
+                {..attrs}
+            >
+                {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+            </Primitive>
+        </AttributeInterceptor>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FileUploadTrigger
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Button that opens the file picker.
+#[component]
+pub fn FileUploadTrigger(
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<FileUploadContextValue>();
+    let on_click_stored = StoredValue::new(on_click);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=node_ref
+                attr:r#type="button"
+                attr:data-disabled=data_attr(context.disabled)
+                attr:disabled=data_attr(context.disabled)
+                on:click=move |event: ev::MouseEvent| {
+                    if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented() {
+                        context.open_picker();
+                        // Keeps an enclosing `FileUploadDropzone` from opening it again.
+                        event.prevent_default();
+                    }
+                }
+                {..attrs}
+            >
+                {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+            </Primitive>
+        </AttributeInterceptor>
+    }
+}
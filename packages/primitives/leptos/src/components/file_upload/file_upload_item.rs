@@ -0,0 +1,82 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * FileUploadItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A file of the [`FileUpload`], e.g. one for each file of [`use_file_upload`].
+#[component]
+pub fn FileUploadItem(
+    file: UploadFile,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<FileUploadContextValue>();
+    let item_context = FileUploadItemContextValue { file };
+
+    view! {
+        <Provider value=item_context>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:data-disabled=data_attr(context.disabled)
+            >
+                {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+            </Primitive>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FileUploadItemDelete
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Button to remove the file of the enclosing [`FileUploadItem`].
+#[component]
+pub fn FileUploadItemDelete(
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<FileUploadContextValue>();
+    let item_context = expect_context::<FileUploadItemContextValue>();
+    let aria_label = format!("Remove {}", item_context.file.name());
+    let file = StoredValue::new(item_context.file);
+    let on_click_stored = StoredValue::new(on_click);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=node_ref
+                attr:r#type="button"
+                attr:aria-label=aria_label.clone()
+                attr:data-disabled=data_attr(context.disabled)
+                attr:disabled=data_attr(context.disabled)
+                on:click=move |event: ev::MouseEvent| {
+                    if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented() && !context.disabled.get_untracked() {
+                        file.with_value(|file| context.remove(file));
+                        // Keeps an enclosing `FileUploadDropzone` from opening the picker.
+                        event.prevent_default();
+                    }
+                }
+                {..attrs}
+            >
+                {children.try_with_value(|children| {
+                    children.as_ref().map(|c| c()).unwrap_or_else(|| "\u{2715}".into_any())
+                })}
+            </Primitive>
+        </AttributeInterceptor>
+    }
+}
@@ -0,0 +1,408 @@
+//! File upload with a dropzone.
+//!
+//! Collects files from a file picker, drag and drop or the clipboard, validates them and submits
+//! them with forms.
+//!
+//! # Anatomy
+//!
+//! ```text
+//! <FileUpload>
+//!     <FileUploadDropzone>
+//!         <FileUploadTrigger />
+//!     </FileUploadDropzone>
+//!     <FileUploadItem>
+//!         <FileUploadItemDelete />
+//!     </FileUploadItem>
+//! </FileUpload>
+//! ```
+//!
+//! # Features
+//!
+//! - Controlled and uncontrolled files, also readable through [`use_file_upload`]
+//! - Drag and drop, paste from the clipboard and keyboard activation of the dropzone
+//! - `accept`, `max_size` and `max_files` validation, reported via `on_file_reject`
+//! - Native form participation via a hidden file input, with form reset support
+//! - Rejections mark the enclosing `FormField` invalid
+//!
+//! # Keyboard Interactions
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | Enter / Space | Opens the file picker from the dropzone |
+//! | Ctrl/Cmd + V | Adds files from the clipboard to the focused dropzone |
+//!
+//! # Data Attributes
+//!
+//! **FileUploadDropzone:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-dragging` | Present while files are dragged over |
+//! | `data-disabled` | Present when disabled |
+//! | `data-invalid` | Present when the last added files were rejected |
+
+use crate::support::compose_refs::use_composed_refs;
+use crate::support::form_control::{
+    UseFormControlParams, use_form_control, use_form_control_error, use_form_reset,
+};
+use crate::support::primitive::{Primitive, data_attr, prop_or_default};
+use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
+use leptos::{
+    attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
+};
+use leptos_node_ref::AnyNodeRef;
+use send_wrapper::SendWrapper;
+use wasm_bindgen::JsCast;
+
+mod file_upload;
+mod file_upload_dropzone;
+mod file_upload_item;
+
+pub use file_upload::*;
+pub use file_upload_dropzone::*;
+pub use file_upload_item::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Types
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A file of a [`FileUpload`].
+#[derive(Clone, Debug)]
+pub struct UploadFile(SendWrapper<web_sys::File>);
+
+impl UploadFile {
+    pub fn new(file: web_sys::File) -> Self {
+        Self(SendWrapper::new(file))
+    }
+
+    pub fn file(&self) -> &web_sys::File {
+        &self.0
+    }
+
+    pub fn name(&self) -> String {
+        self.0.name()
+    }
+
+    /// Size in bytes.
+    pub fn size(&self) -> f64 {
+        self.0.size()
+    }
+
+    /// MIME type, empty when the browser can't tell.
+    pub fn mime_type(&self) -> String {
+        self.0.type_()
+    }
+}
+
+impl PartialEq for UploadFile {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+/// Why a file was not added, passed to `on_file_reject` of [`FileUpload`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileRejectionReason {
+    /// The file matches none of the `accept` types.
+    InvalidType,
+    /// The file is larger than `max_size`.
+    TooLarge,
+    /// The maximum number of files is reached.
+    TooMany,
+}
+
+impl FileRejectionReason {
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            Self::InvalidType => "This file type is not accepted",
+            Self::TooLarge => "This file is too large",
+            Self::TooMany => "Too many files",
+        }
+    }
+}
+
+/// A file that was not added.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileRejection {
+    pub file: UploadFile,
+    pub reason: FileRejectionReason,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Contexts
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct FileUploadContextValue {
+    /// File input opened by the trigger and the dropzone. Not part of the form.
+    picker_ref: AnyNodeRef,
+    files: Signal<Vec<UploadFile>>,
+    on_files_change: Callback<Vec<UploadFile>>,
+    accept: Signal<Option<String>>,
+    multiple: Signal<bool>,
+    max_size: Signal<Option<f64>>,
+    max_files: Signal<Option<usize>>,
+    on_file_reject: Option<Callback<FileRejection>>,
+    rejection_message: Option<Callback<FileRejection, String>>,
+    report_error: Callback<Option<String>>,
+    /// Whether the last added files were rejected.
+    invalid: RwSignal<bool>,
+    disabled: Signal<bool>,
+}
+
+impl FileUploadContextValue {
+    /// Opens the file picker.
+    fn open_picker(&self) {
+        if self.disabled.get_untracked() {
+            return;
+        }
+        if let Some(picker) = self.picker_ref.get_untracked() {
+            let picker: &web_sys::HtmlElement = (*picker).unchecked_ref();
+            picker.click();
+        }
+    }
+
+    /// Adds the files that pass the rules and reports the others through `on_file_reject` and to
+    /// the enclosing form field.
+    fn add(&self, candidates: Vec<UploadFile>) {
+        if self.disabled.get_untracked() || candidates.is_empty() {
+            return;
+        }
+        let accept = parse_accept(&self.accept.get_untracked().unwrap_or_default());
+        let rules = FileRules {
+            accept: &accept,
+            multiple: self.multiple.get_untracked(),
+            max_size: self.max_size.get_untracked(),
+            max_files: self.max_files.get_untracked(),
+        };
+        let current_files = self.files.get_untracked();
+        let (files, rejections) = add_files(&current_files, candidates, &rules, |file| {
+            (file.name(), file.mime_type(), file.size())
+        });
+
+        if files != current_files {
+            self.on_files_change.run(files);
+        }
+
+        let rejections: Vec<FileRejection> = rejections
+            .into_iter()
+            .map(|(file, reason)| FileRejection { file, reason })
+            .collect();
+        // Only report when the error changes, so the field doesn't validate on every change.
+        if self.invalid.get_untracked() || !rejections.is_empty() {
+            self.invalid.set(!rejections.is_empty());
+            self.report_error.run(rejections.first().map(|rejection| {
+                self.rejection_message.map_or_else(
+                    || rejection.reason.default_message().to_string(),
+                    |rejection_message| rejection_message.run(rejection.clone()),
+                )
+            }));
+        }
+        if let Some(on_file_reject) = self.on_file_reject {
+            for rejection in rejections {
+                on_file_reject.run(rejection);
+            }
+        }
+    }
+
+    fn remove(&self, file: &UploadFile) {
+        let mut files = self.files.get_untracked();
+        files.retain(|f| f != file);
+        self.on_files_change.run(files);
+        self.clear_error();
+    }
+
+    fn clear_error(&self) {
+        if self.invalid.get_untracked() {
+            self.invalid.set(false);
+            self.report_error.run(None);
+        }
+    }
+}
+
+#[derive(Clone)]
+struct FileUploadItemContextValue {
+    file: UploadFile,
+}
+
+/// Returns the files of the enclosing [`FileUpload`], e.g. to render a [`FileUploadItem`] for
+/// each.
+///
+/// # Panics
+///
+/// Panics when called outside of a [`FileUpload`].
+pub fn use_file_upload() -> Signal<Vec<UploadFile>> {
+    expect_context::<FileUploadContextValue>().files
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Utilities
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Visually hidden styles for the bubble input element
+const VISUALLY_HIDDEN_STYLES_STR: &str = "position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;";
+
+/// Files of a `FileList`, e.g. from a file input, a drop or a paste.
+fn file_list_to_vec(file_list: Option<web_sys::FileList>) -> Vec<UploadFile> {
+    file_list
+        .map(|file_list| {
+            (0..file_list.length())
+                .filter_map(|index| file_list.item(index))
+                .map(UploadFile::new)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+struct FileRules<'a> {
+    accept: &'a [String],
+    multiple: bool,
+    max_size: Option<f64>,
+    max_files: Option<usize>,
+}
+
+/// Splits an `accept` attribute into lowercase extensions (`.pdf`) and MIME types (`image/*`).
+fn parse_accept(accept: &str) -> Vec<String> {
+    accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .collect()
+}
+
+/// Whether a file named `name` of type `mime_type` matches one of the `accept` patterns. Accepts
+/// all files when there are no patterns.
+fn is_accepted(accept: &[String], name: &str, mime_type: &str) -> bool {
+    let name = name.to_lowercase();
+    let mime_type = mime_type.to_lowercase();
+    accept.is_empty()
+        || accept.iter().any(|pattern| {
+            if pattern.starts_with('.') {
+                name.ends_with(pattern.as_str())
+            } else if let Some(kind) = pattern.strip_suffix("/*") {
+                mime_type.split('/').next() == Some(kind)
+            } else {
+                mime_type == *pattern
+            }
+        })
+}
+
+/// Adds the `candidates` that pass `rules` to `files`; without `multiple`, the first accepted
+/// candidate replaces the files. `describe` returns the name, MIME type and size of a file.
+/// Returns the new files and the rejected candidates.
+fn add_files<T: Clone>(
+    current_files: &[T],
+    candidates: Vec<T>,
+    rules: &FileRules,
+    describe: impl Fn(&T) -> (String, String, f64),
+) -> (Vec<T>, Vec<(T, FileRejectionReason)>) {
+    let mut files = if rules.multiple {
+        current_files.to_vec()
+    } else {
+        Vec::new()
+    };
+    let max_files = if rules.multiple {
+        rules.max_files
+    } else {
+        Some(1)
+    };
+    let mut rejections = Vec::new();
+
+    for candidate in candidates {
+        let (name, mime_type, size) = describe(&candidate);
+        let rejection = if !is_accepted(rules.accept, &name, &mime_type) {
+            Some(FileRejectionReason::InvalidType)
+        } else if rules.max_size.is_some_and(|max_size| size > max_size) {
+            Some(FileRejectionReason::TooLarge)
+        } else if max_files.is_some_and(|max_files| files.len() >= max_files) {
+            Some(FileRejectionReason::TooMany)
+        } else {
+            None
+        };
+        match rejection {
+            Some(reason) => rejections.push((candidate, reason)),
+            None => files.push(candidate),
+        }
+    }
+
+    // A single file that was rejected keeps the current file.
+    if !rules.multiple && files.is_empty() {
+        return (current_files.to_vec(), rejections);
+    }
+    (files, rejections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestFile = (&'static str, &'static str, f64);
+
+    fn describe(file: &TestFile) -> (String, String, f64) {
+        (file.0.to_string(), file.1.to_string(), file.2)
+    }
+
+    fn rules(accept: &[String], multiple: bool) -> FileRules<'_> {
+        FileRules {
+            accept,
+            multiple,
+            max_size: Some(1000.0),
+            max_files: Some(2),
+        }
+    }
+
+    const PHOTO: TestFile = ("photo.JPG", "image/jpeg", 500.0);
+    const SCAN: TestFile = ("scan.pdf", "application/pdf", 800.0);
+    const VIDEO: TestFile = ("clip.mp4", "video/mp4", 500.0);
+    const POSTER: TestFile = ("poster.png", "image/png", 5000.0);
+
+    #[test]
+    fn parses_accept() {
+        assert_eq!(
+            parse_accept(" image/*, .PDF ,,"),
+            vec!["image/*".to_string(), ".pdf".to_string()]
+        );
+    }
+
+    #[test]
+    fn matches_extensions_and_mime_types() {
+        let accept = parse_accept("image/*,.pdf,video/webm");
+        assert!(is_accepted(&accept, "photo.JPG", "image/jpeg"));
+        assert!(is_accepted(&accept, "scan.pdf", ""));
+        assert!(is_accepted(&accept, "clip.webm", "video/webm"));
+        assert!(!is_accepted(&accept, "clip.mp4", "video/mp4"));
+        assert!(is_accepted(&[], "clip.mp4", "video/mp4"));
+    }
+
+    #[test]
+    fn rejects_type_size_and_count() {
+        let accept = parse_accept("image/*,.pdf");
+        let (files, rejections) = add_files(
+            &[PHOTO],
+            vec![VIDEO, POSTER, SCAN, PHOTO],
+            &rules(&accept, true),
+            describe,
+        );
+        assert_eq!(files, vec![PHOTO, SCAN]);
+        assert_eq!(
+            rejections,
+            vec![
+                (VIDEO, FileRejectionReason::InvalidType),
+                (POSTER, FileRejectionReason::TooLarge),
+                (PHOTO, FileRejectionReason::TooMany),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_file_replaces_current_file() {
+        let (files, rejections) =
+            add_files(&[PHOTO], vec![SCAN, VIDEO], &rules(&[], false), describe);
+        assert_eq!(files, vec![SCAN]);
+        assert_eq!(rejections, vec![(VIDEO, FileRejectionReason::TooMany)]);
+
+        let (files, rejections) = add_files(&[PHOTO], vec![POSTER], &rules(&[], false), describe);
+        assert_eq!(files, vec![PHOTO]);
+        assert_eq!(rejections, vec![(POSTER, FileRejectionReason::TooLarge)]);
+    }
+}
@@ -7,6 +7,17 @@ use super::*;
 #[component]
 pub fn Form(
     #[prop(into, optional)] on_clear_server_errors: Option<Callback<()>>,
+    /// Called with the form deserialized into `T` when it is submitted, instead of submitting
+    /// natively. Accepts a `Callback<T>` or an `Action<T, O>`.
+    #[prop(into, optional)]
+    on_valid_submit: Option<OnValidSubmit>,
+    /// Validation errors from the server, shown on the fields with the same name and in
+    /// [`FormErrorSummary`].
+    #[prop(into, optional)]
+    server_errors: MaybeProp<ServerErrors>,
+    /// When fields validate, unless their [`FormField`] sets its own mode.
+    #[prop(into, optional)]
+    validation_mode: MaybeProp<ValidationMode>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
     children: ChildrenFn,
@@ -15,10 +26,23 @@ pub fn Form(
         validity_map: RwSignal::new(HashMap::new()),
         custom_matcher_entries_map: StoredValue::new(HashMap::new()),
         custom_errors_map: RwSignal::new(HashMap::new()),
+        control_errors_map: RwSignal::new(HashMap::new()),
+        constraints_map: RwSignal::new(HashMap::new()),
+        validating: RwSignal::new(HashSet::new()),
+        validation_generations: StoredValue::new(HashMap::new()),
+        validation_timeouts: StoredValue::new(HashMap::new()),
+        validation_aborts: StoredValue::new(HashMap::new()),
+        validation_mode: prop_or_default(validation_mode),
     };
     let aria_description_context = AriaDescriptionContextValue {
         message_ids_map: RwSignal::new(HashMap::new()),
     };
+    let form_state = FormState::new(
+        Signal::derive(move || {
+            on_valid_submit.is_some_and(|on_valid_submit| on_valid_submit.pending())
+        }),
+        Signal::derive(move || server_errors.get().unwrap_or_default()),
+    );
 
     let internal_ref = AnyNodeRef::new();
     let composed_ref = use_composed_refs(vec![node_ref, internal_ref]);
@@ -37,10 +61,10 @@ pub fn Form(
                     .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok());
 
                 if let Some(target) = target
-                    && let Some(first_invalid) = get_first_invalid_control(&form_clone)
-                    && first_invalid == target
+                    && let Some(first_invalid) = first_invalid_control(&form_clone)
+                    && *first_invalid.element() == target
                 {
-                    first_invalid.focus().ok();
+                    first_invalid.element().focus().ok();
                 }
 
                 // Prevent default browser UI for form validation (built-in tooltips).
@@ -70,29 +94,85 @@ pub fn Form(
         }
     });
 
+    let read_values = move || {
+        internal_ref
+            .get_untracked()
+            .and_then(|node| node.dyn_into::<web_sys::HtmlFormElement>().ok())
+            .and_then(|form_el| web_sys::FormData::new_with_form(&form_el).ok())
+            .map(|form_data| FormValues::from_form_data(&form_data))
+    };
+
+    // Values when the form is mounted, for `is_dirty`.
+    Effect::new(move |_| {
+        if internal_ref.get().is_some()
+            && let Some(values) = read_values()
+        {
+            form_state.set_initial_values(values);
+        }
+    });
+
+    let update_values = move |event: &web_sys::Event| {
+        if let Some(values) = read_values() {
+            form_state.set_values(values);
+        }
+        if let Some(name) = get_target_name(event) {
+            form_state.clear_field_errors(&name);
+        }
+    };
+
     let on_clear = on_clear_server_errors;
     let children = StoredValue::new(children);
 
     view! {
         <Provider value=validation_context>
             <Provider value=aria_description_context>
-                <Primitive
-                    element=html::form
-                    as_child=as_child
-                    node_ref=composed_ref
-                    on:submit=move |_event: web_sys::SubmitEvent| {
-                        if let Some(on_clear) = &on_clear {
-                            on_clear.run(());
+                <Provider value=form_state>
+                    <Primitive
+                        element=html::form
+                        as_child=as_child
+                        node_ref=composed_ref
+                        on:submit=move |event: web_sys::SubmitEvent| {
+                            if let Some(on_clear) = &on_clear {
+                                on_clear.run(());
+                            }
+
+                            if let Some(on_valid_submit) = on_valid_submit {
+                                event.prevent_default();
+                                form_state.clear_errors();
+                                if let Some(values) = read_values()
+                                    && let Err(error) = on_valid_submit.run(values)
+                                {
+                                    form_state.set_error(error);
+                                }
+                            }
+                        }
+                        on:reset=move |_event: web_sys::Event| {
+                            if let Some(on_clear) = &on_clear {
+                                on_clear.run(());
+                            }
+                            form_state.reset();
+                        }
+                        on:input=move |event: web_sys::Event| update_values(&event)
+                        on:change=move |event: web_sys::Event| update_values(&event)
+                        on:click=move |event: web_sys::MouseEvent| {
+                            // The bubble inputs of `Checkbox` and `Switch` dispatch `click`
+                            // instead of `change`.
+                            if event
+                                .target()
+                                .is_some_and(|target| target.has_type::<web_sys::HtmlInputElement>())
+                            {
+                                update_values(&event);
+                            }
                         }
-                    }
-                    on:reset=move |_event: web_sys::Event| {
-                        if let Some(on_clear) = &on_clear {
-                            on_clear.run(());
+                        on:focusout=move |event: web_sys::FocusEvent| {
+                            if let Some(name) = get_target_name(&event) {
+                                form_state.touch(&name);
+                            }
                         }
-                    }
-                >
-                    {children.with_value(|children| children())}
-                </Primitive>
+                    >
+                        {children.with_value(|children| children())}
+                    </Primitive>
+                </Provider>
             </Provider>
         </Provider>
     }
@@ -0,0 +1,73 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * FormErrorSummary
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Lists the errors of the form when there are any: form-level errors first, then field errors
+/// linking to their controls.
+///
+/// Errors come from `server_errors` and `on_valid_submit` of the enclosing [`Form`]. Children
+/// render before the list, e.g. for a heading.
+#[component]
+pub fn FormErrorSummary(
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let form_state = expect_context::<FormState>();
+
+    let form_errors = Memo::new(move |_| form_state.form_errors());
+    let field_errors = Memo::new(move |_| form_state.field_error_list());
+    let has_errors =
+        Memo::new(move |_| !form_errors.get().is_empty() || !field_errors.get().is_empty());
+
+    let children = StoredValue::new(children);
+
+    view! {
+        <Show when=move || has_errors.get()>
+            <Primitive element=html::div node_ref=node_ref attr:role="alert">
+                {children.with_value(|children| children.as_ref().map(|children| children()))}
+                <ul>
+                    <For
+                        each=move || form_errors.get().into_iter().enumerate()
+                        key=|error| error.clone()
+                        let:error
+                    >
+                        <li>{error.1}</li>
+                    </For>
+                    <For
+                        each=move || field_errors.get()
+                        key=|error| error.clone()
+                        let:error
+                    >
+                        <li data-field=error.name>
+                            {match error.id {
+                                Some(id) => view! {
+                                    <a
+                                        href=format!("#{id}")
+                                        on:click=move |event| {
+                                            // Focus the control, not just scroll to it.
+                                            if let Some(control) = document()
+                                                .get_element_by_id(&id)
+                                                .and_then(|control| {
+                                                    control.dyn_into::<web_sys::HtmlElement>().ok()
+                                                })
+                                            {
+                                                event.prevent_default();
+                                                control.focus().ok();
+                                            }
+                                        }
+                                    >
+                                        {error.message}
+                                    </a>
+                                }
+                                .into_any(),
+                                None => error.message.into_any(),
+                            }}
+                        </li>
+                    </For>
+                </ul>
+            </Primitive>
+        </Show>
+    }
+}
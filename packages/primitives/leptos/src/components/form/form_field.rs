@@ -8,42 +8,110 @@ use super::*;
 pub fn FormField(
     #[prop(into)] name: String,
     #[prop(into, optional)] server_invalid: MaybeProp<bool>,
+    /// When the field validates. Defaults to the mode of the [`Form`].
+    #[prop(into, optional)]
+    validation_mode: MaybeProp<ValidationMode>,
+    /// Delay in milliseconds before async custom matchers run, restarted by every validation.
+    #[prop(into, optional)]
+    validation_debounce: MaybeProp<u32>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
     children: ChildrenFn,
 ) -> impl IntoView {
     let validation_context = expect_context::<ValidationContextValue>();
+    let aria_description_context = expect_context::<AriaDescriptionContextValue>();
+    let form_state = expect_context::<FormState>();
     let id = use_id(None);
 
     let field_name = name.clone();
-    let server_invalid_signal = prop_or_default(server_invalid);
+    let server_invalid_prop = prop_or_default(server_invalid);
+    // Errors from `server_errors` and `on_valid_submit` mark the field invalid.
+    let errors_name = field_name.clone();
+    let server_invalid_signal = Signal::derive(move || {
+        server_invalid_prop.get() || form_state.has_field_errors(&errors_name)
+    });
 
+    let form_validation_mode = validation_context.validation_mode;
     let field_context = FormFieldContextValue {
         id: id.get_untracked(),
         name: field_name.clone(),
         server_invalid: server_invalid_signal,
+        validation_mode: Signal::derive(move || {
+            validation_mode
+                .get()
+                .unwrap_or_else(|| form_validation_mode.get())
+        }),
+        validation_debounce: prop_or_default(validation_debounce),
     };
 
     let validity_name = field_name.clone();
-    let validity = Memo::new(move |_| validation_context.get_field_validity(&validity_name));
+    let validity_ctx = validation_context.clone();
+    let validity = Memo::new(move |_| validity_ctx.get_field_validity(&validity_name));
     let valid_attr =
         Memo::new(move |_| get_valid_attribute(&validity.get(), server_invalid_signal.get()));
     let invalid_attr =
         Memo::new(move |_| get_invalid_attribute(&validity.get(), server_invalid_signal.get()));
+    let validating_name = field_name.clone();
+    let validating_ctx = validation_context.clone();
+    let validating = Memo::new(move |_| validating_ctx.is_field_validating(&validating_name));
+
+    // Lets non-native controls, e.g. `Checkbox` and `Select`, take part through
+    // `use_form_control`.
+    let description_name = field_name.clone();
+    let control_name = field_name.clone();
+    let control_field_context = field_context.clone();
+    let error_name = field_name.clone();
+    let error_validation_context = validation_context.clone();
+    let touched = StoredValue::new(false);
+    let control_context = FormControlContextValue {
+        attributes: FormControlAttributes {
+            id: Signal::stored(Some(field_context.id.clone())),
+            name: Signal::stored(Some(field_name.clone())),
+            aria_describedby: Signal::derive(move || {
+                aria_description_context.get_field_description(&description_name)
+            }),
+            // Non-native controls have no native invalid state, so they always set `aria-invalid`.
+            aria_invalid: invalid_attr.into(),
+            data_valid: valid_attr.into(),
+            data_invalid: invalid_attr.into(),
+            data_validating: Signal::derive(move || validating.get().then_some("true")),
+        },
+        on_event: Callback::new(move |(event, control): (FormControlEvent, NativeControl)| {
+            if event == FormControlEvent::Blur {
+                form_state.touch(&control_name);
+            }
+            handle_control_event(
+                event,
+                &control,
+                &control_name,
+                &validation_context,
+                &control_field_context,
+                touched,
+            );
+        }),
+        on_error: Callback::new(move |(message, control): (Option<String>, NativeControl)| {
+            error_validation_context.set_field_control_error(&error_name, message);
+            update_control_validity(&control, &error_name, &error_validation_context, 0);
+        }),
+    };
 
     let children = StoredValue::new(children);
 
     view! {
         <Provider value=field_context>
-            <Primitive
-                element=html::div
-                as_child=as_child
-                node_ref=node_ref
-                attr:data-valid=move || valid_attr.get()
-                attr:data-invalid=move || invalid_attr.get()
-            >
-                {children.with_value(|children| children())}
-            </Primitive>
+            <Provider value=control_context>
+                <Primitive
+                    element=html::div
+                    as_child=as_child
+                    node_ref=node_ref
+                    attr:data-valid=move || valid_attr.get()
+                    attr:data-invalid=move || invalid_attr.get()
+                    attr:data-validating=move || validating.get().then_some("true")
+                >
+                    {children.with_value(|children| children())}
+                    <FormFieldErrors />
+                </Primitive>
+            </Provider>
         </Provider>
     }
 }
@@ -103,10 +171,21 @@ pub fn FormControl(
     let validation_context = expect_context::<ValidationContextValue>();
     let field_context = expect_context::<FormFieldContextValue>();
     let aria_description_context = expect_context::<AriaDescriptionContextValue>();
+    let form_state = expect_context::<FormState>();
 
     let control_name = name.unwrap_or_else(|| field_context.name.clone());
     let control_id = id.unwrap_or_else(|| field_context.id.clone());
     let server_invalid = field_context.server_invalid;
+    // Whether the control has lost focus, for `ValidationMode::OnTouched`.
+    let touched = StoredValue::new(false);
+
+    // Lets `FormErrorSummary` link to the control.
+    form_state.register_field(&control_name, &control_id);
+    let cleanup_name = control_name.clone();
+    let cleanup_id = control_id.clone();
+    Owner::on_cleanup(move || {
+        form_state.unregister_field(&cleanup_name, &cleanup_id);
+    });
 
     let internal_ref = AnyNodeRef::new();
     let composed_ref = use_composed_refs(vec![node_ref, internal_ref]);
@@ -123,6 +202,10 @@ pub fn FormControl(
     let aria_describedby =
         Memo::new(move |_| aria_description_context.get_field_description(&desc_name));
 
+    let validating_name = control_name.clone();
+    let validating_ctx = validation_context.clone();
+    let validating = Memo::new(move |_| validating_ctx.is_field_validating(&validating_name));
+
     let aria_invalid_attr = Memo::new(move |_| {
         if server_invalid.get() {
             Some("true")
@@ -131,55 +214,88 @@ pub fn FormControl(
         }
     });
 
-    // Set up native `change` event listener for validation.
-    // We use the native `change` event (not Leptos `on:change` which fires on input)
-    // to validate only when the user finishes changing the value, not on every keystroke.
-    let change_name = control_name.clone();
-    let change_validation_context = validation_context.clone();
+    // Set up native `change`, `input`, `blur` and `invalid` event listeners for validation.
+    // We use `addEventListener` directly because `on:invalid` on a component (VoidPrimitive)
+    // may not reliably forward to the underlying <input> element through component layers, and
+    // the native `change` event (not Leptos `on:change` which fires on input) to validate only
+    // when the user finishes changing the value.
+    // React's `onChange` is actually the native `input` event (fires on every keystroke).
+    let listener_name = control_name.clone();
+    let listener_validation_context = validation_context.clone();
+    let listener_field_context = field_context.clone();
     Effect::new(move |_| {
         if let Some(node) = internal_ref.get()
-            && let Some(control_el) = node.dyn_ref::<web_sys::HtmlInputElement>()
+            && let Some(control) = NativeControl::from_element(&node)
         {
-            let name = change_name.clone();
-            let ctx = change_validation_context.clone();
-            let control_clone = control_el.clone();
-
-            let closure = Closure::<dyn Fn()>::new(move || {
-                update_control_validity(&control_clone, &name, &ctx);
-            });
-
-            control_el
-                .add_event_listener_with_callback("change", closure.as_ref().unchecked_ref())
-                .ok();
-
-            let control_cleanup = SendWrapper::new(control_el.clone());
-            let closure_cleanup = SendWrapper::new(closure);
-            Owner::on_cleanup(move || {
-                control_cleanup
-                    .remove_event_listener_with_callback(
-                        "change",
-                        closure_cleanup.as_ref().unchecked_ref(),
-                    )
+            for (event_name, control_event, callback) in [
+                ("change", FormControlEvent::Change, None),
+                ("input", FormControlEvent::Input, on_change),
+                ("blur", FormControlEvent::Blur, None),
+                ("invalid", FormControlEvent::Invalid, on_invalid),
+            ] {
+                let name = listener_name.clone();
+                let validation_context = listener_validation_context.clone();
+                let field_context = listener_field_context.clone();
+                let control_clone = control.clone();
+
+                let closure =
+                    Closure::<dyn Fn(web_sys::Event)>::new(move |event: web_sys::Event| {
+                        if let Some(callback) = &callback {
+                            callback.run(event);
+                        }
+
+                        handle_control_event(
+                            control_event,
+                            &control_clone,
+                            &name,
+                            &validation_context,
+                            &field_context,
+                            touched,
+                        );
+                    });
+
+                control
+                    .element()
+                    .add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())
                     .ok();
-            });
+
+                let control_cleanup = SendWrapper::new(control.clone());
+                let closure_cleanup = SendWrapper::new(closure);
+                Owner::on_cleanup(move || {
+                    control_cleanup
+                        .element()
+                        .remove_event_listener_with_callback(
+                            event_name,
+                            closure_cleanup.as_ref().unchecked_ref(),
+                        )
+                        .ok();
+                });
+            }
         }
     });
 
     // Set up form `reset` event listener to clear validation
     let reset_name = control_name.clone();
     let reset_validation_context = validation_context.clone();
+    let reset_field_context = field_context.clone();
     Effect::new(move |_| {
         if let Some(node) = internal_ref.get()
-            && let Some(control_el) = node.dyn_ref::<web_sys::HtmlInputElement>()
-            && let Some(form) = control_el.form()
+            && let Some(control) = NativeControl::from_element(&node)
+            && let Some(form) = control.form()
         {
             let name = reset_name.clone();
-            let ctx = reset_validation_context.clone();
-            let control_clone = control_el.clone();
+            let validation_context = reset_validation_context.clone();
+            let field_context = reset_field_context.clone();
 
             let closure = Closure::<dyn Fn()>::new(move || {
-                control_clone.set_custom_validity("");
-                ctx.clear_field_validation(&name);
+                handle_control_event(
+                    FormControlEvent::Reset,
+                    &control,
+                    &name,
+                    &validation_context,
+                    &field_context,
+                    touched,
+                );
             });
 
             form.add_event_listener_with_callback("reset", closure.as_ref().unchecked_ref())
@@ -202,99 +318,11 @@ pub fn FormControl(
     Effect::new(move |_| {
         if server_invalid.get()
             && let Some(node) = internal_ref.get()
-            && let Some(control_el) = node.dyn_ref::<web_sys::HtmlInputElement>()
-            && let Some(form) = control_el.closest("form").ok().flatten()
+            && let Some(control) = NativeControl::from_element(&node)
+            && let Some(form) = control.form()
+            && first_invalid_control(&form).as_ref() == Some(&control)
         {
-            let form: web_sys::HtmlFormElement = form.unchecked_into();
-            if let Some(first_invalid) = get_first_invalid_control(&form) {
-                let control_html: &web_sys::HtmlElement = control_el.as_ref();
-                if first_invalid == *control_html {
-                    first_invalid.focus().ok();
-                }
-            }
-        }
-    });
-
-    // Set up native `invalid` event listener for validation.
-    // We use `addEventListener` directly because `on:invalid` on a component (VoidPrimitive)
-    // may not reliably forward to the underlying <input> element through component layers.
-    let invalid_name = control_name.clone();
-    let invalid_validation_context = validation_context.clone();
-    let on_invalid_prop = on_invalid;
-    Effect::new(move |_| {
-        if let Some(node) = internal_ref.get()
-            && let Some(control_el) = node.dyn_ref::<web_sys::HtmlInputElement>()
-        {
-            let name = invalid_name.clone();
-            let ctx = invalid_validation_context.clone();
-            let control_clone = control_el.clone();
-            let on_invalid_prop = on_invalid_prop;
-
-            let closure = Closure::<dyn Fn(web_sys::Event)>::new(move |event: web_sys::Event| {
-                if let Some(cb) = &on_invalid_prop {
-                    cb.run(event.clone());
-                }
-
-                // Always update validity — don't check defaultPrevented here because
-                // the Form's capture-phase invalid listener already calls preventDefault
-                // to suppress browser tooltips. React doesn't check defaultPrevented either.
-                update_control_validity(&control_clone, &name, &ctx);
-            });
-
-            control_el
-                .add_event_listener_with_callback("invalid", closure.as_ref().unchecked_ref())
-                .ok();
-
-            let control_cleanup = SendWrapper::new(control_el.clone());
-            let closure_cleanup = SendWrapper::new(closure);
-            Owner::on_cleanup(move || {
-                control_cleanup
-                    .remove_event_listener_with_callback(
-                        "invalid",
-                        closure_cleanup.as_ref().unchecked_ref(),
-                    )
-                    .ok();
-            });
-        }
-    });
-
-    // Set up native `input` event listener to reset validity when user changes value.
-    // React's `onChange` is actually the native `input` event (fires on every keystroke).
-    let reset_validity_name = control_name.clone();
-    let reset_validity_ctx = validation_context.clone();
-    let on_change_prop = on_change;
-    Effect::new(move |_| {
-        if let Some(node) = internal_ref.get()
-            && let Some(control_el) = node.dyn_ref::<web_sys::HtmlInputElement>()
-        {
-            let name = reset_validity_name.clone();
-            let ctx = reset_validity_ctx.clone();
-            let control_clone = control_el.clone();
-            let on_change_prop = on_change_prop;
-
-            let closure = Closure::<dyn Fn(web_sys::Event)>::new(move |event: web_sys::Event| {
-                if let Some(cb) = &on_change_prop {
-                    cb.run(event);
-                }
-
-                control_clone.set_custom_validity("");
-                ctx.clear_field_validation(&name);
-            });
-
-            control_el
-                .add_event_listener_with_callback("input", closure.as_ref().unchecked_ref())
-                .ok();
-
-            let control_cleanup = SendWrapper::new(control_el.clone());
-            let closure_cleanup = SendWrapper::new(closure);
-            Owner::on_cleanup(move || {
-                control_cleanup
-                    .remove_event_listener_with_callback(
-                        "input",
-                        closure_cleanup.as_ref().unchecked_ref(),
-                    )
-                    .ok();
-            });
+            control.element().focus().ok();
         }
     });
 
@@ -307,6 +335,7 @@ pub fn FormControl(
             node_ref=composed_ref
             attr:data-valid=move || valid_attr.get()
             attr:data-invalid=move || invalid_attr.get()
+            attr:data-validating=move || validating.get().then_some("true")
             attr:aria-invalid=move || aria_invalid_attr.get()
             attr:aria-describedby=move || aria_describedby.get()
             attr:title=""
@@ -19,19 +19,20 @@ pub fn FormMessage(
     let generated_id = use_id(None);
     let id = id.unwrap_or_else(|| generated_id.get_untracked());
     let children = StoredValue::new(children);
+    let invalid_message = default_message(None, &name);
 
     match r#match {
         None => view! {
             <FormMessageImpl name=name.clone() id=id.clone() as_child=as_child node_ref=node_ref>
                 {children.with_value(|c| match c {
                     Some(c) => c().into_any(),
-                    None => view! { {DEFAULT_INVALID_MESSAGE} }.into_any(),
+                    None => view! { {invalid_message.clone()} }.into_any(),
                 })}
             </FormMessageImpl>
         }
         .into_any(),
         Some(Match::BuiltIn(matcher)) => {
-            let default_msg = matcher.default_message();
+            let default_msg = default_message(Some(matcher), &name);
             view! {
                 <FormBuiltInMessage
                     r#match=matcher
@@ -43,7 +44,7 @@ pub fn FormMessage(
                 >
                     {children.with_value(|c| match c {
                         Some(c) => c().into_any(),
-                        None => view! { {default_msg} }.into_any(),
+                        None => view! { {default_msg.clone()} }.into_any(),
                     })}
                 </FormBuiltInMessage>
             }
@@ -60,7 +61,7 @@ pub fn FormMessage(
             >
                 {children.with_value(|c| match c {
                     Some(c) => c().into_any(),
-                    None => view! { {DEFAULT_INVALID_MESSAGE} }.into_any(),
+                    None => view! { {invalid_message.clone()} }.into_any(),
                 })}
             </FormCustomMessage>
         }
@@ -76,7 +77,7 @@ pub fn FormMessage(
             >
                 {children.with_value(|c| match c {
                     Some(c) => c().into_any(),
-                    None => view! { {DEFAULT_INVALID_MESSAGE} }.into_any(),
+                    None => view! { {invalid_message.clone()} }.into_any(),
                 })}
             </FormCustomMessage>
         }
@@ -84,6 +85,43 @@ pub fn FormMessage(
     }
 }
 
+/// Message from [`ValidationMessages`] for `matcher`, interpolating the constraints of the field
+/// named `name`.
+fn default_message(
+    matcher: Option<ValidityMatcher>,
+    name: &str,
+) -> impl Fn() -> String + Clone + Send + Sync + 'static {
+    let validation_context = expect_context::<ValidationContextValue>();
+    let messages = use_validation_messages();
+    let name = name.to_string();
+    move || messages.resolve(matcher, &validation_context.get_field_constraints(&name))
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FormFieldErrors
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Renders the errors of the enclosing field from `server_errors` and `on_valid_submit` as
+/// [`FormMessage`]s.
+#[component]
+pub(super) fn FormFieldErrors() -> impl IntoView {
+    let form_state = expect_context::<FormState>();
+    let field_context = expect_context::<FormFieldContextValue>();
+    let name = field_context.name;
+
+    let errors = Memo::new(move |_| form_state.field_errors(&name));
+
+    view! {
+        <For
+            each=move || errors.get().into_iter().enumerate()
+            key=|error| error.clone()
+            let:error
+        >
+            <FormMessage>{error.1.clone()}</FormMessage>
+        </For>
+    }
+}
+
 /* -------------------------------------------------------------------------------------------------
  * FormBuiltInMessage
  * -----------------------------------------------------------------------------------------------*/
@@ -226,8 +264,30 @@ pub fn FormValidityState(
     let validation_context = expect_context::<ValidationContextValue>();
     let field_context = expect_context::<FormFieldContextValue>();
     let name = name.unwrap_or_else(|| field_context.name.clone());
+    let messages = use_validation_messages();
 
-    let validity = Memo::new(move |_| validation_context.get_field_validity(&name));
+    let validity = Memo::new(move |_| {
+        validation_context
+            .get_field_validity(&name)
+            .map(|validity| {
+                let constraints = validation_context.get_field_constraints(&name);
+                let message = match validity.failing_matcher() {
+                    Some(matcher) => Some(messages.resolve(Some(matcher), &constraints)),
+                    // Errors reported by the control, e.g. a rejected file, carry their message.
+                    None if validity.custom_error => Some(
+                        validation_context
+                            .get_field_control_error(&name)
+                            .unwrap_or_else(|| messages.resolve(None, &constraints)),
+                    ),
+                    None => None,
+                };
+                Validity {
+                    validating: validation_context.is_field_validating(&name),
+                    message,
+                    ..validity
+                }
+            })
+    });
 
     move || children.run(validity.get())
 }
@@ -0,0 +1,276 @@
+use serde::de::DeserializeOwned;
+
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * FormState
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Reactive state of the enclosing [`Form`]: values, dirty and touched fields, submission and
+/// errors.
+///
+/// Returned by [`use_form`]. All getters are tracked.
+#[derive(Clone, Copy)]
+pub struct FormState {
+    pub(super) values: RwSignal<FormValues>,
+    pub(super) initial_values: RwSignal<FormValues>,
+    pub(super) touched: RwSignal<HashSet<String>>,
+    pub(super) errors: RwSignal<HashMap<String, Vec<String>>>,
+    pub(super) form_errors: RwSignal<Vec<String>>,
+    pub(super) server_errors: Signal<ServerErrors>,
+    /// Control ids by field name, in the order the controls were rendered.
+    pub(super) field_ids: RwSignal<Vec<(String, String)>>,
+    pub(super) is_submitting: Signal<bool>,
+}
+
+/// Error of a field for [`FormErrorSummary`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct FieldError {
+    pub(super) name: String,
+    /// Id of the control of the field, if it is rendered.
+    pub(super) id: Option<String>,
+    pub(super) message: String,
+}
+
+impl FormState {
+    pub(super) fn new(is_submitting: Signal<bool>, server_errors: Signal<ServerErrors>) -> Self {
+        Self {
+            values: RwSignal::new(FormValues::new()),
+            initial_values: RwSignal::new(FormValues::new()),
+            touched: RwSignal::new(HashSet::new()),
+            errors: RwSignal::new(HashMap::new()),
+            form_errors: RwSignal::new(Vec::new()),
+            server_errors,
+            field_ids: RwSignal::new(Vec::new()),
+            is_submitting,
+        }
+    }
+
+    /// Current values of all controls.
+    pub fn values(&self) -> FormValues {
+        self.values.get()
+    }
+
+    /// Current value of the control named `name`.
+    pub fn value(&self, name: &str) -> Option<String> {
+        self.values
+            .with(|values| values.get(name).map(str::to_string))
+    }
+
+    /// Whether any value differs from its value when the form was mounted or last reset.
+    pub fn is_dirty(&self) -> bool {
+        self.values.with(|values| {
+            self.initial_values
+                .with(|initial_values| values != initial_values)
+        })
+    }
+
+    /// Whether the values of `name` differ from when the form was mounted or last reset.
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.values.with(|values| {
+            self.initial_values
+                .with(|initial_values| values.get_all(name) != initial_values.get_all(name))
+        })
+    }
+
+    /// Whether the control named `name` has lost focus since the form was mounted or last reset.
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.touched.with(|touched| touched.contains(name))
+    }
+
+    /// Whether the `Action` passed as `on_valid_submit` is pending.
+    pub fn is_submitting(&self) -> bool {
+        self.is_submitting.get()
+    }
+
+    /// Errors of the field named `name`, from the server and from the last submission.
+    pub fn field_errors(&self, name: &str) -> Vec<String> {
+        let mut field_errors = self
+            .server_errors
+            .with(|server_errors| server_errors.fields.get(name).cloned().unwrap_or_default());
+        self.errors.with(|errors| {
+            field_errors.extend(errors.get(name).into_iter().flatten().cloned());
+        });
+        field_errors
+    }
+
+    /// Errors that don't belong to a field, from the server and from the last submission.
+    pub fn form_errors(&self) -> Vec<String> {
+        let mut form_errors = self
+            .server_errors
+            .with(|server_errors| server_errors.form.clone());
+        form_errors.extend(self.form_errors.get());
+        form_errors
+    }
+
+    pub(super) fn has_field_errors(&self, name: &str) -> bool {
+        let has_errors =
+            |errors: Option<&Vec<String>>| errors.is_some_and(|errors| !errors.is_empty());
+        self.server_errors
+            .with(|server_errors| has_errors(server_errors.fields.get(name)))
+            || self.errors.with(|errors| has_errors(errors.get(name)))
+    }
+
+    /// Errors of all fields, in the order their controls were rendered, then the errors of fields
+    /// without a control by name.
+    pub(super) fn field_error_list(&self) -> Vec<FieldError> {
+        let mut names: Vec<String> = self
+            .server_errors
+            .with(|server_errors| server_errors.fields.keys().cloned().collect());
+        self.errors
+            .with(|errors| names.extend(errors.keys().cloned()));
+        names.sort();
+        names.dedup();
+
+        let field_ids = self.field_ids.get();
+        let position = |name: &str| {
+            field_ids
+                .iter()
+                .position(|(field_name, _)| field_name == name)
+                .unwrap_or(usize::MAX)
+        };
+        names.sort_by_key(|name| position(name));
+
+        names
+            .into_iter()
+            .flat_map(|name| {
+                let id = field_ids
+                    .iter()
+                    .find(|(field_name, _)| *field_name == name)
+                    .map(|(_, id)| id.clone());
+                self.field_errors(&name)
+                    .into_iter()
+                    .map(move |message| FieldError {
+                        name: name.clone(),
+                        id: id.clone(),
+                        message,
+                    })
+            })
+            .collect()
+    }
+
+    pub(super) fn register_field(&self, name: &str, id: &str) {
+        self.field_ids
+            .update(|field_ids| field_ids.push((name.to_string(), id.to_string())));
+    }
+
+    pub(super) fn unregister_field(&self, name: &str, id: &str) {
+        self.field_ids.update(|field_ids| {
+            field_ids.retain(|(field_name, field_id)| field_name != name || field_id != id)
+        });
+    }
+
+    pub(super) fn set_values(&self, values: FormValues) {
+        self.values.set(values);
+    }
+
+    pub(super) fn set_initial_values(&self, values: FormValues) {
+        self.initial_values.set(values.clone());
+        self.values.set(values);
+    }
+
+    pub(super) fn touch(&self, name: &str) {
+        if !self
+            .touched
+            .with_untracked(|touched| touched.contains(name))
+        {
+            self.touched.update(|touched| {
+                touched.insert(name.to_string());
+            });
+        }
+    }
+
+    pub(super) fn set_error(&self, error: FormDataError) {
+        match error.field {
+            Some(field) => self.errors.update(|errors| {
+                errors.entry(field).or_default().push(error.message);
+            }),
+            None => self
+                .form_errors
+                .update(|form_errors| form_errors.push(error.message)),
+        }
+    }
+
+    pub(super) fn clear_field_errors(&self, name: &str) {
+        if self
+            .errors
+            .with_untracked(|errors| errors.contains_key(name))
+        {
+            self.errors.update(|errors| {
+                errors.remove(name);
+            });
+        }
+    }
+
+    pub(super) fn clear_errors(&self) {
+        self.errors.set(HashMap::new());
+        self.form_errors.set(Vec::new());
+    }
+
+    pub(super) fn reset(&self) {
+        self.values.set(self.initial_values.get_untracked());
+        self.touched.set(HashSet::new());
+        self.clear_errors();
+    }
+}
+
+/// Returns the [`FormState`] of the enclosing [`Form`].
+///
+/// # Panics
+///
+/// Panics when called outside of a [`Form`].
+pub fn use_form() -> FormState {
+    expect_context::<FormState>()
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * OnValidSubmit
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Typed submit handler for [`Form`], created from a `Callback<T>` or an `Action<T, O>` where `T`
+/// deserializes from [`FormValues`].
+///
+/// Deserialization errors are mapped back onto the [`FormField`] with the same name.
+#[derive(Clone, Copy)]
+pub struct OnValidSubmit {
+    handler: Callback<FormValues, Result<(), FormDataError>>,
+    pending: Option<Memo<bool>>,
+}
+
+impl OnValidSubmit {
+    pub(super) fn run(&self, values: FormValues) -> Result<(), FormDataError> {
+        self.handler.run(values)
+    }
+
+    pub(super) fn pending(&self) -> bool {
+        self.pending.is_some_and(|pending| pending.get())
+    }
+}
+
+impl<T: DeserializeOwned + 'static> From<Callback<T>> for OnValidSubmit {
+    fn from(callback: Callback<T>) -> Self {
+        Self {
+            handler: Callback::new(move |values: FormValues| {
+                callback.run(values.deserialize()?);
+                Ok(())
+            }),
+            pending: None,
+        }
+    }
+}
+
+impl<T, O> From<Action<T, O>> for OnValidSubmit
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    fn from(action: Action<T, O>) -> Self {
+        Self {
+            handler: Callback::new(move |values: FormValues| {
+                action.dispatch(values.deserialize()?);
+                Ok(())
+            }),
+            pending: Some(action.pending()),
+        }
+    }
+}
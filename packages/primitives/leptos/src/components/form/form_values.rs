@@ -0,0 +1,489 @@
+use std::fmt;
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Unexpected,
+    Visitor,
+};
+
+use super::DEFAULT_INVALID_MESSAGE;
+
+/* -------------------------------------------------------------------------------------------------
+ * FormValues
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Values of the controls of a form by name, in document order, like the browser's `FormData`.
+///
+/// A name can have several values, e.g. for checkboxes sharing a name. File inputs are left out.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormValues(Vec<(String, String)>);
+
+impl FormValues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn from_form_data(form_data: &web_sys::FormData) -> Self {
+        let mut values = Self::new();
+        for entry in form_data.entries().into_iter().flatten() {
+            let entry = js_sys::Array::from(&entry);
+            if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string())
+            {
+                values.append(name, value);
+            }
+        }
+        values
+    }
+
+    pub fn append(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.push((name.into(), value.into()));
+    }
+
+    /// First value of `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(entry_name, _)| entry_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// All values of `name`, in document order.
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(entry_name, _)| entry_name == name)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Deserializes the values into `T`, typically a struct with a field per control name.
+    ///
+    /// Values are parsed from strings: numbers with [`str::parse`], booleans from `"true"`/`"on"`
+    /// and `"false"`/`"off"`, and `Option`s are `None` when the value is empty. Names with several
+    /// values deserialize into sequences such as `Vec<String>`. Unchecked checkboxes submit no
+    /// value, so use `#[serde(default)]` for them.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, FormDataError> {
+        let mut fields: Vec<(&str, Vec<&str>)> = Vec::new();
+        for (name, value) in self.iter() {
+            match fields.iter_mut().find(|(field, _)| *field == name) {
+                Some((_, values)) => values.push(value),
+                None => fields.push((name, vec![value])),
+            }
+        }
+
+        T::deserialize(FormValuesDeserializer {
+            fields: fields.into_iter(),
+            field: None,
+            values: None,
+        })
+    }
+}
+
+impl<N: Into<String>, V: Into<String>> FromIterator<(N, V)> for FormValues {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FormDataError
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Error from deserializing [`FormValues`], with the name of the field it belongs to, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormDataError {
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl FormDataError {
+    fn with_field(mut self, field: &str) -> Self {
+        if self.field.is_none() {
+            self.field = Some(field.to_string());
+        }
+        self
+    }
+}
+
+impl fmt::Display for FormDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{field}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for FormDataError {}
+
+impl de::Error for FormDataError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self {
+            field: None,
+            message: msg.to_string(),
+        }
+    }
+
+    fn invalid_type(_unexp: Unexpected, _exp: &dyn de::Expected) -> Self {
+        Self::custom(DEFAULT_INVALID_MESSAGE)
+    }
+
+    fn invalid_value(_unexp: Unexpected, _exp: &dyn de::Expected) -> Self {
+        Self::custom(DEFAULT_INVALID_MESSAGE)
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self {
+            field: Some(field.to_string()),
+            message: "This value is missing".to_string(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Deserializers
+ * -----------------------------------------------------------------------------------------------*/
+
+struct FormValuesDeserializer<'a> {
+    fields: std::vec::IntoIter<(&'a str, Vec<&'a str>)>,
+    field: Option<&'a str>,
+    values: Option<Vec<&'a str>>,
+}
+
+impl<'de> de::Deserializer<'de> for FormValuesDeserializer<'de> {
+    type Error = FormDataError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for FormValuesDeserializer<'de> {
+    type Error = FormDataError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.fields.next() {
+            Some((field, values)) => {
+                self.field = Some(field);
+                self.values = Some(values);
+                seed.deserialize(field.into_deserializer())
+                    .map(Some)
+                    .map_err(|error: FormDataError| error.with_field(field))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let field = self.field.unwrap_or_default();
+        let values = self
+            .values
+            .take()
+            .ok_or_else(|| de::Error::custom("value is missing a key"))?;
+        seed.deserialize(ValueDeserializer(values))
+            .map_err(|error| error.with_field(field))
+    }
+}
+
+/// Values of a single name. Scalars use the last value.
+struct ValueDeserializer<'a>(Vec<&'a str>);
+
+impl<'a> ValueDeserializer<'a> {
+    fn last(&self) -> &'a str {
+        self.0.last().copied().unwrap_or_default()
+    }
+
+    fn parse<T: std::str::FromStr>(&self, exp: &dyn de::Expected) -> Result<T, FormDataError> {
+        let value = self.last();
+        value
+            .trim()
+            .parse()
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(value), exp))
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let value = self.parse(&visitor)?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = FormDataError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.0.len() > 1 {
+            self.deserialize_seq(visitor)
+        } else {
+            visitor.visit_borrowed_str(self.last())
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.last() {
+            "true" | "on" => visitor.visit_bool(true),
+            "false" | "off" => visitor.visit_bool(false),
+            value => Err(de::Error::invalid_value(Unexpected::Str(value), &visitor)),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.0.iter().all(|value| value.is_empty()) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(ValuesSeqAccess(self.0.into_iter()))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.last().into_deserializer())
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct map struct identifier ignored_any
+    }
+}
+
+struct ValuesSeqAccess<'a>(std::vec::IntoIter<&'a str>);
+
+impl<'de> SeqAccess<'de> for ValuesSeqAccess<'de> {
+    type Error = FormDataError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(ValueDeserializer(vec![value])))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    fn values(entries: &[(&str, &str)]) -> FormValues {
+        entries.iter().copied().collect()
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct SignUp {
+        email: String,
+        age: u32,
+        #[serde(default)]
+        newsletter: bool,
+        nickname: Option<String>,
+        #[serde(default)]
+        topics: Vec<String>,
+    }
+
+    // ── FormValues ──────────────────────────────────────────
+
+    #[test]
+    fn get_returns_first_value() {
+        let values = values(&[("topic", "rust"), ("topic", "leptos")]);
+        assert_eq!(values.get("topic"), Some("rust"));
+        assert_eq!(values.get("email"), None);
+    }
+
+    #[test]
+    fn get_all_returns_values_in_order() {
+        let values = values(&[("topic", "rust"), ("email", "a@b.c"), ("topic", "leptos")]);
+        assert_eq!(values.get_all("topic"), vec!["rust", "leptos"]);
+        assert!(values.get_all("age").is_empty());
+    }
+
+    // ── FormValues::deserialize ─────────────────────────────
+
+    #[test]
+    fn deserializes_struct() {
+        let values = values(&[
+            ("email", "ada@example.com"),
+            ("age", " 36 "),
+            ("newsletter", "on"),
+            ("nickname", ""),
+            ("topics", "rust"),
+            ("topics", "leptos"),
+        ]);
+        assert_eq!(
+            values.deserialize::<SignUp>(),
+            Ok(SignUp {
+                email: "ada@example.com".into(),
+                age: 36,
+                newsletter: true,
+                nickname: None,
+                topics: vec!["rust".into(), "leptos".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn deserializes_defaults_for_absent_fields() {
+        let values = values(&[("email", "ada@example.com"), ("age", "36")]);
+        let sign_up = values.deserialize::<SignUp>().unwrap();
+        assert!(!sign_up.newsletter);
+        assert_eq!(sign_up.nickname, None);
+        assert!(sign_up.topics.is_empty());
+    }
+
+    #[test]
+    fn deserializes_unit_enum() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "lowercase")]
+        enum Plan {
+            Free,
+            Pro,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Subscription {
+            plan: Plan,
+        }
+
+        assert_eq!(
+            values(&[("plan", "pro")]).deserialize::<Subscription>(),
+            Ok(Subscription { plan: Plan::Pro })
+        );
+        assert_eq!(
+            values(&[("plan", "free")]).deserialize::<Subscription>(),
+            Ok(Subscription { plan: Plan::Free })
+        );
+    }
+
+    #[test]
+    fn missing_field_error_has_field() {
+        let error = values(&[("email", "ada@example.com")])
+            .deserialize::<SignUp>()
+            .unwrap_err();
+        assert_eq!(error.field.as_deref(), Some("age"));
+        assert_eq!(error.message, "This value is missing");
+    }
+
+    #[test]
+    fn invalid_value_error_has_field() {
+        let error = values(&[("email", "ada@example.com"), ("age", "old")])
+            .deserialize::<SignUp>()
+            .unwrap_err();
+        assert_eq!(error.field.as_deref(), Some("age"));
+        assert_eq!(error.message, DEFAULT_INVALID_MESSAGE);
+        assert_eq!(error.to_string(), format!("age: {DEFAULT_INVALID_MESSAGE}"));
+    }
+
+    #[test]
+    fn custom_error_keeps_message() {
+        #[derive(Debug, Deserialize)]
+        #[serde(try_from = "String")]
+        struct Username(#[allow(dead_code)] String);
+
+        impl TryFrom<String> for Username {
+            type Error = String;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                if value.len() < 3 {
+                    Err("Username is too short".into())
+                } else {
+                    Ok(Self(value))
+                }
+            }
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Account {
+            #[allow(dead_code)]
+            username: Username,
+        }
+
+        let error = values(&[("username", "al")])
+            .deserialize::<Account>()
+            .unwrap_err();
+        assert_eq!(error.field.as_deref(), Some("username"));
+        assert_eq!(error.message, "Username is too short");
+    }
+}
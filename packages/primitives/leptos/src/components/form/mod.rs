@@ -16,6 +16,7 @@
 //!         <FormMessage match_fn=... />       <!-- custom matcher -->
 //!         <FormValidityMessage match_val=... /> <!-- ValidityState -->
 //!     </FormField>
+//!     <FormErrorSummary />                <!-- form-level and field errors -->
 //!     <FormSubmit />
 //! </Form>
 //! ```
@@ -23,10 +24,57 @@
 //! # Features
 //!
 //! - Native `ValidityState` message matching
-//! - Custom validation functions (sync and async)
-//! - Server-side error integration
+//! - Custom validation functions (sync and async), with per-field [`ValidationMode`]s and
+//!   debounced, cancellable async validation
+//! - Server-side error integration: field and form-level messages via [`ServerErrors`], listed
+//!   by [`FormErrorSummary`]
 //! - Automatic `aria-describedby` and `aria-invalid` association
+//! - Localizable default messages via [`ValidationMessagesProvider`], interpolating constraints
+//!   such as `{min}` and `{maxlength}` read from the control
 //! - Form-level and field-level clear-on-submit
+//! - Reactive values, dirty and touched state via [`use_form`]
+//! - Typed submission: `on_valid_submit` deserializes the form into a `serde::Deserialize` type
+//!   and maps errors back onto fields by name
+//!
+//! # Typed submission
+//!
+//! ```ignore
+//! #[derive(Deserialize)]
+//! struct SignUp {
+//!     email: String,
+//!     age: u32,
+//! }
+//!
+//! view! {
+//!     <Form on_valid_submit=Callback::new(move |sign_up: SignUp| { /* ... */ })>
+//!         <FormField name="age">
+//!             <FormControl attr:r#type="number" />
+//!         </FormField>
+//!     </Form>
+//! }
+//! ```
+//!
+//! Passing an `Action<T, O>` instead of a `Callback<T>` dispatches it, and
+//! [`FormState::is_submitting`] follows its pending state.
+//!
+//! # Server errors
+//!
+//! Field messages in `server_errors` mark their [`FormField`] invalid and render as
+//! [`FormMessage`]s after its children. With an `ActionForm`, render it as the form element
+//! and read the errors from the action:
+//!
+//! ```ignore
+//! view! {
+//!     <Form as_child=true server_errors=ServerErrors::from_action(sign_up)>
+//!         <ActionForm action=sign_up>
+//!             <FormErrorSummary />
+//!             // ...
+//!         </ActionForm>
+//!     </Form>
+//! }
+//! ```
+//!
+//! The server function returns them as `Err(ServerErrors::new().field("email", "...").into())`.
 
 use std::collections::{HashMap, HashSet};
 use std::future::Future;
@@ -34,8 +82,13 @@ use std::pin::Pin;
 use std::rc::Rc;
 
 use crate::support::compose_refs::use_composed_refs;
+use crate::support::form_control::{
+    FormControlAttributes, FormControlContextValue, FormControlEvent, NativeControl,
+    first_invalid_control,
+};
 use crate::support::id::use_id;
 use crate::support::primitive::{Primitive, VoidPrimitive, prop_or_default};
+use futures::future::{AbortHandle, Abortable};
 use leptos::{context::Provider, ev, html, prelude::*};
 use leptos_node_ref::AnyNodeRef;
 use send_wrapper::SendWrapper;
@@ -43,12 +96,22 @@ use web_sys::wasm_bindgen::JsCast;
 use web_sys::wasm_bindgen::closure::Closure;
 
 mod form;
+mod form_error_summary;
 mod form_field;
 mod form_message;
+mod form_state;
+mod form_values;
+mod server_errors;
+mod validation_messages;
 
 pub use form::*;
+pub use form_error_summary::*;
 pub use form_field::*;
 pub use form_message::*;
+pub use form_state::*;
+pub use form_values::*;
+pub use server_errors::*;
+pub use validation_messages::*;
 
 /* -------------------------------------------------------------------------------------------------
  * Validity types
@@ -57,6 +120,12 @@ pub use form_message::*;
 /// Mirror of the browser's `ValidityState` interface for use in validation matching.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Validity {
+    /// Whether async custom matchers are still running. Not part of the browser's
+    /// `ValidityState`.
+    pub validating: bool,
+    /// Message of the first failing constraint from [`ValidationMessages`], set by
+    /// [`FormValidityState`]. Not part of the browser's `ValidityState`.
+    pub message: Option<String>,
     pub bad_input: bool,
     pub custom_error: bool,
     pub pattern_mismatch: bool,
@@ -73,6 +142,8 @@ pub struct Validity {
 impl From<web_sys::ValidityState> for Validity {
     fn from(v: web_sys::ValidityState) -> Self {
         Self {
+            validating: false,
+            message: None,
             bad_input: v.bad_input(),
             custom_error: v.custom_error(),
             pattern_mismatch: v.pattern_mismatch(),
@@ -88,7 +159,61 @@ impl From<web_sys::ValidityState> for Validity {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+impl Validity {
+    /// The first failing built-in constraint, in the order the browser reports them.
+    pub(super) fn failing_matcher(&self) -> Option<ValidityMatcher> {
+        [
+            ValidityMatcher::ValueMissing,
+            ValidityMatcher::TypeMismatch,
+            ValidityMatcher::PatternMismatch,
+            ValidityMatcher::TooLong,
+            ValidityMatcher::TooShort,
+            ValidityMatcher::RangeUnderflow,
+            ValidityMatcher::RangeOverflow,
+            ValidityMatcher::StepMismatch,
+            ValidityMatcher::BadInput,
+        ]
+        .into_iter()
+        .find(|matcher| matcher.matches(self))
+    }
+}
+
+/// When a field validates. Fields always validate when the form is submitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// When the value is committed (native `change` event), e.g. when a text input loses focus
+    /// after an edit.
+    #[default]
+    OnChange,
+    /// On every edit (native `input` event).
+    OnInput,
+    /// When the control loses focus.
+    OnBlur,
+    /// Only when the form is submitted.
+    OnSubmit,
+    /// When the control first loses focus, then on every edit.
+    OnTouched,
+}
+
+impl ValidationMode {
+    pub(super) fn validates_on_change(self) -> bool {
+        self == Self::OnChange
+    }
+
+    pub(super) fn validates_on_input(self, touched: bool) -> bool {
+        match self {
+            Self::OnInput => true,
+            Self::OnTouched => touched,
+            _ => false,
+        }
+    }
+
+    pub(super) fn validates_on_blur(self) -> bool {
+        matches!(self, Self::OnBlur | Self::OnTouched)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ValidityMatcher {
     BadInput,
     PatternMismatch,
@@ -135,6 +260,8 @@ impl ValidityMatcher {
 }
 
 pub type SyncCustomMatcherFn = Rc<dyn Fn(String, web_sys::FormData) -> bool>;
+/// Validating the field again drops the returned future, so it stops at its next `.await`.
+/// Work it has already handed off, such as a `fetch` request, still runs to completion.
 pub type AsyncCustomMatcherFn =
     Rc<dyn Fn(String, web_sys::FormData) -> Pin<Box<dyn Future<Output = bool>>>>;
 
@@ -174,6 +301,20 @@ pub(super) struct ValidationContextValue {
     pub(super) custom_matcher_entries_map:
         StoredValue<HashMap<String, Vec<SendWrapper<CustomMatcherEntry>>>>,
     pub(super) custom_errors_map: RwSignal<HashMap<String, HashMap<String, bool>>>,
+    /// Errors reported by controls through `use_form_control_error`, e.g. rejected files.
+    pub(super) control_errors_map: RwSignal<HashMap<String, String>>,
+    /// Constraint attributes of the control of each field, for [`ValidationMessages`].
+    pub(super) constraints_map: RwSignal<HashMap<String, ValidationConstraints>>,
+    /// Fields with async custom matchers running.
+    pub(super) validating: RwSignal<HashSet<String>>,
+    /// Bumped for every validation of a field, so results of older async validations are
+    /// discarded.
+    pub(super) validation_generations: StoredValue<HashMap<String, u64>>,
+    pub(super) validation_timeouts: StoredValue<HashMap<String, TimeoutHandle>>,
+    /// Aborts the async matchers of a field that are running.
+    pub(super) validation_aborts: StoredValue<HashMap<String, AbortHandle>>,
+    /// Default mode of the fields of the form.
+    pub(super) validation_mode: Signal<ValidationMode>,
 }
 
 impl ValidationContextValue {
@@ -187,6 +328,38 @@ impl ValidationContextValue {
         });
     }
 
+    pub(super) fn get_field_control_error(&self, field_name: &str) -> Option<String> {
+        self.control_errors_map
+            .with(|map| map.get(field_name).cloned())
+    }
+
+    pub(super) fn set_field_control_error(&self, field_name: &str, message: Option<String>) {
+        self.control_errors_map.update(|map| match message {
+            Some(message) => {
+                map.insert(field_name.to_string(), message);
+            }
+            None => {
+                map.remove(field_name);
+            }
+        });
+    }
+
+    pub(super) fn get_field_constraints(&self, field_name: &str) -> ValidationConstraints {
+        self.constraints_map
+            .with(|map| map.get(field_name).cloned().unwrap_or_default())
+    }
+
+    fn set_field_constraints(&self, field_name: &str, constraints: ValidationConstraints) {
+        if self
+            .constraints_map
+            .with_untracked(|map| map.get(field_name) != Some(&constraints))
+        {
+            self.constraints_map.update(|map| {
+                map.insert(field_name.to_string(), constraints);
+            });
+        }
+    }
+
     pub(super) fn get_field_custom_matcher_entries(
         &self,
         field_name: &str,
@@ -228,7 +401,58 @@ impl ValidationContextValue {
         });
     }
 
+    pub(super) fn is_field_validating(&self, field_name: &str) -> bool {
+        self.validating
+            .with(|validating| validating.contains(field_name))
+    }
+
+    fn set_field_validating(&self, field_name: &str, validating: bool) {
+        if self
+            .validating
+            .with_untracked(|fields| fields.contains(field_name) != validating)
+        {
+            self.validating.update(|fields| {
+                if validating {
+                    fields.insert(field_name.to_string());
+                } else {
+                    fields.remove(field_name);
+                }
+            });
+        }
+    }
+
+    /// Cancels pending async validation of the field: clears its debounce timeout, aborts
+    /// in-flight matchers, dropping their futures, and makes their results stale. Returns the
+    /// generation of the next validation.
+    pub(super) fn cancel_field_validation(&self, field_name: &str) -> u64 {
+        self.validation_timeouts.update_value(|timeouts| {
+            if let Some(timeout) = timeouts.remove(field_name) {
+                timeout.clear();
+            }
+        });
+        self.validation_aborts.update_value(|aborts| {
+            if let Some(abort) = aborts.remove(field_name) {
+                abort.abort();
+            }
+        });
+        self.set_field_validating(field_name, false);
+
+        let mut generation = 0;
+        self.validation_generations.update_value(|generations| {
+            let entry = generations.entry(field_name.to_string()).or_default();
+            *entry += 1;
+            generation = *entry;
+        });
+        generation
+    }
+
+    pub(super) fn is_current_validation(&self, field_name: &str, generation: u64) -> bool {
+        self.validation_generations
+            .with_value(|generations| generations.get(field_name) == Some(&generation))
+    }
+
     pub(super) fn clear_field_validation(&self, field_name: &str) {
+        self.cancel_field_validation(field_name);
         self.validity_map.update(|map| {
             map.remove(field_name);
         });
@@ -277,6 +501,9 @@ pub(super) struct FormFieldContextValue {
     pub(super) id: String,
     pub(super) name: String,
     pub(super) server_invalid: Signal<bool>,
+    pub(super) validation_mode: Signal<ValidationMode>,
+    /// Delay of async custom matchers in milliseconds.
+    pub(super) validation_debounce: Signal<u32>,
 }
 
 /* -------------------------------------------------------------------------------------------------
@@ -323,29 +550,75 @@ pub(super) fn get_invalid_attribute(
     None
 }
 
-pub(super) fn get_first_invalid_control(
-    form: &web_sys::HtmlFormElement,
-) -> Option<web_sys::HtmlElement> {
-    let elements = form.elements();
-    let len = elements.length();
-    for i in 0..len {
-        if let Some(element) = elements.item(i)
-            && let Ok(html_element) = element.dyn_into::<web_sys::HtmlElement>()
-            && let Some(input) = html_element.dyn_ref::<web_sys::HtmlInputElement>()
-            && (!input.validity().valid()
-                || html_element.get_attribute("aria-invalid").as_deref() == Some("true"))
-        {
-            return Some(html_element);
+/// Name of the control an event was dispatched to.
+pub(super) fn get_target_name(event: &web_sys::Event) -> Option<String> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|element| element.get_attribute("name"))
+}
+
+/// Updates the validation of a field for an `event` of its control, following the field's
+/// validation mode. `touched` tracks whether the control has lost focus.
+pub(super) fn handle_control_event(
+    event: FormControlEvent,
+    control: &NativeControl,
+    name: &str,
+    validation_context: &ValidationContextValue,
+    field_context: &FormFieldContextValue,
+    touched: StoredValue<bool>,
+) {
+    let validation_mode = field_context.validation_mode.get_untracked();
+    let debounce = field_context.validation_debounce.get_untracked();
+
+    match event {
+        // Validate only when the user finishes changing the value (`ValidationMode::OnChange`,
+        // the default), not on every keystroke.
+        FormControlEvent::Change => {
+            if validation_mode.validates_on_change() {
+                update_control_validity(control, name, validation_context, debounce);
+            }
+        }
+        // Editing the value resets its validity.
+        FormControlEvent::Input => {
+            control.set_custom_validity("");
+            validation_context.clear_field_validation(name);
+
+            if validation_mode.validates_on_input(touched.get_value()) {
+                update_control_validity(control, name, validation_context, debounce);
+            }
+        }
+        FormControlEvent::Blur => {
+            touched.set_value(true);
+
+            if validation_mode.validates_on_blur() {
+                update_control_validity(control, name, validation_context, debounce);
+            }
+        }
+        // Don't check `defaultPrevented` here because the Form's capture-phase invalid listener
+        // already calls `preventDefault` to suppress browser tooltips. Submitting validates right
+        // away, without debouncing.
+        FormControlEvent::Invalid => update_control_validity(control, name, validation_context, 0),
+        FormControlEvent::Reset => {
+            control.set_custom_validity("");
+            validation_context.clear_field_validation(name);
+            touched.set_value(false);
         }
     }
-    None
 }
 
+/// Validates the control. Async custom matchers start after `debounce` milliseconds; validating
+/// the field again cancels them.
 pub(super) fn update_control_validity(
-    control: &web_sys::HtmlInputElement,
+    control: &NativeControl,
     name: &str,
     validation_context: &ValidationContextValue,
+    debounce: u32,
 ) {
+    // 0. Cancel pending async validation, its result would be stale
+    let generation = validation_context.cancel_field_validation(name);
+    validation_context.set_field_constraints(name, ValidationConstraints::from_control(control));
+
     // 1. First, if we have built-in errors we stop here
     let validity: Validity = control.validity().into();
     if has_built_in_error(&validity) {
@@ -381,46 +654,95 @@ pub(super) fn update_control_validity(
         }
     }
     let has_sync_custom_errors = sync_custom_errors.values().any(|&v| v);
-    let has_custom_error = has_sync_custom_errors;
-    control.set_custom_validity(if has_custom_error {
-        DEFAULT_INVALID_MESSAGE
-    } else {
-        ""
+    // Errors reported by the control itself outlast the matchers.
+    let control_error = validation_context.get_field_control_error(name);
+    let has_custom_error = has_sync_custom_errors || control_error.is_some();
+    control.set_custom_validity(match &control_error {
+        Some(message) => message,
+        None if has_custom_error => DEFAULT_INVALID_MESSAGE,
+        None => "",
     });
     let control_validity: Validity = control.validity().into();
     validation_context.set_field_validity(name, control_validity);
     validation_context.set_field_custom_errors(name, sync_custom_errors);
 
-    // 5. Run async custom matchers and update control validity / internal validity + errors
-    if !has_sync_custom_errors && !async_entries.is_empty() {
+    // 5. Run async custom matchers (after the debounce) and update control validity / internal
+    //    validity + errors, unless the field has been validated again since
+    if !has_custom_error && !async_entries.is_empty() {
         let name = name.to_string();
         let validation_context = validation_context.clone();
         let control = control.clone();
-
-        type AsyncMatcherFutures = Vec<(String, Pin<Box<dyn Future<Output = bool>>>)>;
-        let mut futures: AsyncMatcherFutures = Vec::new();
-        for entry in &async_entries {
-            if let CustomMatcher::Async(matcher) = &entry.matcher {
-                futures.push((entry.id.clone(), matcher(value.clone(), form_data.clone())));
+        let matchers: Vec<(String, AsyncCustomMatcherFn)> = async_entries
+            .iter()
+            .filter_map(|entry| match &entry.matcher {
+                CustomMatcher::Async(matcher) => Some((entry.id.clone(), matcher.clone())),
+                CustomMatcher::Sync(_) => None,
+            })
+            .collect();
+
+        validation_context.set_field_validating(&name, true);
+
+        let timeout_name = name.clone();
+        let timeout_context = validation_context.clone();
+        let run = move || {
+            if !validation_context.is_current_validation(&name, generation) {
+                return;
             }
-        }
 
-        leptos::task::spawn_local(async move {
-            let mut async_custom_errors = HashMap::new();
-            for (id, future) in futures {
-                let matches = future.await;
-                async_custom_errors.insert(id, matches);
-            }
-            let has_async_custom_errors = async_custom_errors.values().any(|&v| v);
-            control.set_custom_validity(if has_async_custom_errors {
-                DEFAULT_INVALID_MESSAGE
-            } else {
-                ""
+            type AsyncMatcherFutures = Vec<(String, Pin<Box<dyn Future<Output = bool>>>)>;
+            let futures: AsyncMatcherFutures = matchers
+                .iter()
+                .map(|(id, matcher)| (id.clone(), matcher(value.clone(), form_data.clone())))
+                .collect();
+
+            let (abort, abort_registration) = AbortHandle::new_pair();
+            validation_context.validation_aborts.update_value(|aborts| {
+                aborts.insert(name.clone(), abort);
             });
-            let control_validity: Validity = control.validity().into();
-            validation_context.set_field_validity(&name, control_validity);
-            validation_context.set_field_custom_errors(&name, async_custom_errors);
-        });
+            let validate = async move {
+                let mut async_custom_errors = HashMap::new();
+                for (id, future) in futures {
+                    async_custom_errors.insert(id, future.await);
+                }
+                async_custom_errors
+            };
+
+            leptos::task::spawn_local(async move {
+                // Aborted by `cancel_field_validation`, which drops the matcher futures
+                let Ok(async_custom_errors) = Abortable::new(validate, abort_registration).await
+                else {
+                    return;
+                };
+                if !validation_context.is_current_validation(&name, generation) {
+                    return;
+                }
+                validation_context.validation_aborts.update_value(|aborts| {
+                    aborts.remove(&name);
+                });
+                let has_async_custom_errors = async_custom_errors.values().any(|&v| v);
+                control.set_custom_validity(if has_async_custom_errors {
+                    DEFAULT_INVALID_MESSAGE
+                } else {
+                    ""
+                });
+                let control_validity: Validity = control.validity().into();
+                validation_context.set_field_validity(&name, control_validity);
+                validation_context.set_field_custom_errors(&name, async_custom_errors);
+                validation_context.set_field_validating(&name, false);
+            });
+        };
+
+        if debounce == 0 {
+            run();
+        } else if let Ok(timeout) =
+            set_timeout_with_handle(run, std::time::Duration::from_millis(debounce.into()))
+        {
+            timeout_context
+                .validation_timeouts
+                .update_value(|timeouts| {
+                    timeouts.insert(timeout_name, timeout);
+                });
+        }
     }
 }
 
@@ -430,6 +752,7 @@ pub(super) fn update_control_validity(
 
 pub use Form as Root;
 pub use FormControl as Control;
+pub use FormErrorSummary as ErrorSummary;
 pub use FormField as Field;
 pub use FormLabel as Label;
 pub use FormMessage as Message;
@@ -439,6 +762,21 @@ pub use FormValidityState as ValidityState;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use any_spawner::Executor;
+
+    struct NoopExecutor;
+
+    impl any_spawner::CustomExecutor for NoopExecutor {
+        fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+        fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+        fn poll_local(&self) {}
+    }
+
+    fn with_owner<T>(f: impl FnOnce() -> T) -> T {
+        let _ = Executor::init_custom_executor(NoopExecutor);
+        let owner = Owner::new_root(None);
+        owner.with(f)
+    }
 
     fn validity_all_false() -> Validity {
         Validity::default()
@@ -524,6 +862,21 @@ mod tests {
 
     // ── ValidityMatcher::default_message ────────────────────
 
+    #[test]
+    fn failing_matcher_follows_browser_order() {
+        let v = validity_with(|v| {
+            v.value_missing = true;
+            v.too_short = true;
+        });
+        assert_eq!(v.failing_matcher(), Some(ValidityMatcher::ValueMissing));
+        let v = validity_with(|v| v.range_overflow = true);
+        assert_eq!(v.failing_matcher(), Some(ValidityMatcher::RangeOverflow));
+        assert_eq!(
+            validity_with(|v| v.custom_error = true).failing_matcher(),
+            None
+        );
+    }
+
     #[test]
     fn default_messages() {
         assert_eq!(
@@ -655,4 +1008,250 @@ mod tests {
         let v = validity_with(|v| v.valid = true);
         assert_eq!(get_invalid_attribute(&Some(v), true), Some("true"));
     }
+
+    // ── FormState ───────────────────────────────────────────
+
+    fn form_state() -> FormState {
+        let form_state = FormState::new(
+            Signal::stored(false),
+            Signal::stored(
+                ServerErrors::new()
+                    .field("topic", "Topic is archived")
+                    .form("Try again later"),
+            ),
+        );
+        form_state.set_initial_values(
+            [("email", "ada@example.com"), ("topic", "rust")]
+                .into_iter()
+                .collect(),
+        );
+        form_state
+    }
+
+    #[test]
+    fn form_state_initially_clean() {
+        with_owner(|| {
+            let form_state = form_state();
+            assert!(!form_state.is_dirty());
+            assert!(!form_state.is_field_dirty("email"));
+            assert_eq!(
+                form_state.value("email").as_deref(),
+                Some("ada@example.com")
+            );
+        });
+    }
+
+    #[test]
+    fn form_state_dirty_after_change() {
+        with_owner(|| {
+            let form_state = form_state();
+            form_state.set_values(
+                [
+                    ("email", "ada@example.com"),
+                    ("topic", "rust"),
+                    ("topic", "leptos"),
+                ]
+                .into_iter()
+                .collect(),
+            );
+            assert!(form_state.is_dirty());
+            assert!(form_state.is_field_dirty("topic"));
+            assert!(!form_state.is_field_dirty("email"));
+        });
+    }
+
+    #[test]
+    fn form_state_touch_and_reset() {
+        with_owner(|| {
+            let form_state = form_state();
+            form_state.touch("email");
+            form_state.set_values(FormValues::new());
+            form_state.set_error(FormDataError {
+                field: Some("email".into()),
+                message: DEFAULT_INVALID_MESSAGE.into(),
+            });
+            assert!(form_state.is_touched("email"));
+            assert!(!form_state.is_touched("topic"));
+
+            form_state.reset();
+            assert!(!form_state.is_touched("email"));
+            assert!(!form_state.is_dirty());
+            assert!(form_state.field_errors("email").is_empty());
+        });
+    }
+
+    #[test]
+    fn form_state_maps_errors_by_field() {
+        with_owner(|| {
+            let form_state = form_state();
+            form_state.set_error(FormDataError {
+                field: Some("email".into()),
+                message: DEFAULT_INVALID_MESSAGE.into(),
+            });
+            form_state.set_error(FormDataError {
+                field: None,
+                message: "Something went wrong".into(),
+            });
+            assert!(form_state.has_field_errors("email"));
+            assert_eq!(
+                form_state.field_errors("email"),
+                vec![DEFAULT_INVALID_MESSAGE.to_string()]
+            );
+            assert_eq!(
+                form_state.form_errors(),
+                vec!["Try again later", "Something went wrong"]
+            );
+
+            form_state.clear_field_errors("email");
+            assert!(!form_state.has_field_errors("email"));
+        });
+    }
+
+    #[test]
+    fn form_state_combines_server_errors() {
+        with_owner(|| {
+            let form_state = form_state();
+            form_state.set_error(FormDataError {
+                field: Some("topic".into()),
+                message: DEFAULT_INVALID_MESSAGE.into(),
+            });
+            assert!(form_state.has_field_errors("topic"));
+            assert_eq!(
+                form_state.field_errors("topic"),
+                vec!["Topic is archived", DEFAULT_INVALID_MESSAGE]
+            );
+            assert_eq!(form_state.form_errors(), vec!["Try again later"]);
+
+            // Server errors stay until the server errors change.
+            form_state.clear_field_errors("topic");
+            assert_eq!(form_state.field_errors("topic"), vec!["Topic is archived"]);
+        });
+    }
+
+    #[test]
+    fn field_error_list_follows_control_order() {
+        with_owner(|| {
+            let form_state = form_state();
+            form_state.register_field("email", "email-id");
+            form_state.register_field("topic", "topic-id");
+            form_state.set_error(FormDataError {
+                field: Some("email".into()),
+                message: DEFAULT_INVALID_MESSAGE.into(),
+            });
+            form_state.set_error(FormDataError {
+                field: Some("age".into()),
+                message: DEFAULT_INVALID_MESSAGE.into(),
+            });
+
+            let field_errors = form_state.field_error_list();
+            let fields: Vec<_> = field_errors
+                .iter()
+                .map(|error| (error.name.as_str(), error.id.as_deref()))
+                .collect();
+            assert_eq!(
+                fields,
+                vec![
+                    ("email", Some("email-id")),
+                    ("topic", Some("topic-id")),
+                    ("age", None)
+                ]
+            );
+
+            form_state.unregister_field("topic", "topic-id");
+            assert_eq!(form_state.field_error_list()[1].id, None);
+        });
+    }
+
+    // ── ValidationMode ──────────────────────────────────────
+
+    #[test]
+    fn validation_mode_triggers() {
+        use ValidationMode::*;
+
+        assert!(OnChange.validates_on_change());
+        assert!(!OnChange.validates_on_input(true));
+        assert!(!OnChange.validates_on_blur());
+
+        assert!(OnInput.validates_on_input(false));
+        assert!(!OnInput.validates_on_change());
+
+        assert!(OnBlur.validates_on_blur());
+        assert!(!OnBlur.validates_on_input(true));
+
+        assert!(!OnSubmit.validates_on_change());
+        assert!(!OnSubmit.validates_on_input(true));
+        assert!(!OnSubmit.validates_on_blur());
+
+        assert!(OnTouched.validates_on_blur());
+        assert!(!OnTouched.validates_on_input(false));
+        assert!(OnTouched.validates_on_input(true));
+    }
+
+    // ── Async validation cancellation ───────────────────────
+
+    fn validation_context() -> ValidationContextValue {
+        ValidationContextValue {
+            validity_map: RwSignal::new(HashMap::new()),
+            custom_matcher_entries_map: StoredValue::new(HashMap::new()),
+            custom_errors_map: RwSignal::new(HashMap::new()),
+            control_errors_map: RwSignal::new(HashMap::new()),
+            constraints_map: RwSignal::new(HashMap::new()),
+            validating: RwSignal::new(HashSet::new()),
+            validation_generations: StoredValue::new(HashMap::new()),
+            validation_timeouts: StoredValue::new(HashMap::new()),
+            validation_aborts: StoredValue::new(HashMap::new()),
+            validation_mode: Signal::stored(ValidationMode::default()),
+        }
+    }
+
+    #[test]
+    fn new_validation_makes_previous_stale() {
+        with_owner(|| {
+            let ctx = validation_context();
+            let first = ctx.cancel_field_validation("username");
+            assert!(ctx.is_current_validation("username", first));
+
+            let second = ctx.cancel_field_validation("username");
+            assert!(!ctx.is_current_validation("username", first));
+            assert!(ctx.is_current_validation("username", second));
+
+            // Other fields are independent.
+            let other = ctx.cancel_field_validation("email");
+            assert!(ctx.is_current_validation("username", second));
+            assert!(ctx.is_current_validation("email", other));
+        });
+    }
+
+    #[test]
+    fn cancel_aborts_running_matchers() {
+        with_owner(|| {
+            let ctx = validation_context();
+            let (username, _) = AbortHandle::new_pair();
+            let (email, _) = AbortHandle::new_pair();
+            ctx.validation_aborts.update_value(|aborts| {
+                aborts.insert("username".into(), username.clone());
+                aborts.insert("email".into(), email.clone());
+            });
+
+            ctx.cancel_field_validation("username");
+            assert!(username.is_aborted());
+            assert!(!email.is_aborted());
+            assert!(
+                ctx.validation_aborts
+                    .with_value(|aborts| !aborts.contains_key("username"))
+            );
+        });
+    }
+
+    #[test]
+    fn cancel_clears_validating() {
+        with_owner(|| {
+            let ctx = validation_context();
+            ctx.set_field_validating("username", true);
+            assert!(ctx.is_field_validating("username"));
+
+            ctx.clear_field_validation("username");
+            assert!(!ctx.is_field_validating("username"));
+        });
+    }
 }
@@ -0,0 +1,166 @@
+use leptos::server_fn::ServerFn;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * ServerErrors
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Validation errors from the server: messages by field name, and messages that don't belong to a
+/// field.
+///
+/// Pass them to [`Form`]'s `server_errors` to mark the fields invalid, render the messages
+/// through [`FormMessage`] and list them in [`FormErrorSummary`].
+///
+/// To return them from a server function, convert them into a `ServerFnError`, which carries
+/// them as JSON. [`ServerErrors::from_action`] reads them back from the action's value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerErrors {
+    #[serde(default)]
+    pub fields: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub form: Vec<String>,
+}
+
+impl ServerErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `message` to the errors of the field named `name`.
+    pub fn field(mut self, name: impl Into<String>, message: impl Into<String>) -> Self {
+        self.fields
+            .entry(name.into())
+            .or_default()
+            .push(message.into());
+        self
+    }
+
+    /// Adds `message` to the errors that don't belong to a field.
+    pub fn form(mut self, message: impl Into<String>) -> Self {
+        self.form.push(message.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.form.is_empty() && self.fields.values().all(Vec::is_empty)
+    }
+
+    /// Errors of the last submission of `action`, e.g. from an `ActionForm`.
+    ///
+    /// Empty while the action has no value or its last call succeeded.
+    pub fn from_action<S>(action: ServerAction<S>) -> Signal<ServerErrors>
+    where
+        S: ServerFn<Error = ServerFnError> + Clone + Send + Sync + 'static,
+        S::Output: Send + Sync + 'static,
+    {
+        let value = action.value();
+        Signal::derive(move || {
+            value.with(|value| match value {
+                Some(Err(error)) => ServerErrors::from(error),
+                _ => ServerErrors::default(),
+            })
+        })
+    }
+}
+
+impl From<HashMap<String, Vec<String>>> for ServerErrors {
+    fn from(fields: HashMap<String, Vec<String>>) -> Self {
+        Self {
+            fields,
+            form: Vec::new(),
+        }
+    }
+}
+
+impl From<&ServerFnError> for ServerErrors {
+    /// Reads errors created with `ServerFnError::from(ServerErrors)`. Other errors become a single
+    /// form error.
+    fn from(error: &ServerFnError) -> Self {
+        match error {
+            ServerFnError::ServerError(message) => {
+                serde_json::from_str(message).unwrap_or_else(|_| Self::new().form(message.clone()))
+            }
+            error => Self::new().form(error.to_string()),
+        }
+    }
+}
+
+impl From<ServerErrors> for ServerFnError {
+    fn from(errors: ServerErrors) -> Self {
+        ServerFnError::ServerError(
+            serde_json::to_string(&errors).expect("Server errors should serialize."),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::reactive::owner::Owner;
+
+    use super::*;
+
+    #[server]
+    async fn sign_up(email: String) -> Result<(), ServerFnError> {
+        Err(ServerErrors::new()
+            .field("email", format!("{email} is taken"))
+            .into())
+    }
+
+    #[test]
+    fn builds_field_and_form_errors() {
+        let errors = ServerErrors::new()
+            .field("email", "Email is taken")
+            .field("email", "Email is blocked")
+            .form("Try again later");
+        assert_eq!(
+            errors.fields.get("email"),
+            Some(&vec![
+                "Email is taken".to_string(),
+                "Email is blocked".to_string()
+            ])
+        );
+        assert_eq!(errors.form, vec!["Try again later"]);
+        assert!(!errors.is_empty());
+        assert!(ServerErrors::new().is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_server_fn_error() {
+        let errors = ServerErrors::new()
+            .field("email", "Email is taken")
+            .form("Try again later");
+        let error = ServerFnError::from(errors.clone());
+        assert_eq!(ServerErrors::from(&error), errors);
+    }
+
+    #[test]
+    fn reads_errors_from_server_action() {
+        Owner::new_root(None).with(|| {
+            let action = ServerAction::<SignUp>::new();
+            let errors = ServerErrors::from_action(action);
+            assert!(errors.get_untracked().is_empty());
+
+            action.value().set(Some(Err(ServerErrors::new()
+                .field("email", "Email is taken")
+                .into())));
+            assert_eq!(
+                errors.get_untracked(),
+                ServerErrors::new().field("email", "Email is taken")
+            );
+
+            action.value().set(Some(Ok(())));
+            assert!(errors.get_untracked().is_empty());
+        });
+    }
+
+    #[test]
+    fn other_server_fn_errors_become_form_errors() {
+        let error = ServerFnError::ServerError("Database is down".into());
+        assert_eq!(
+            ServerErrors::from(&error),
+            ServerErrors::new().form("Database is down")
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * ValidationConstraints
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Constraint attributes of a control, read when it validates, for interpolation into
+/// [`ValidationMessages`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationConstraints {
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub min_length: Option<String>,
+    pub max_length: Option<String>,
+    pub step: Option<String>,
+    pub pattern: Option<String>,
+}
+
+impl ValidationConstraints {
+    pub(super) fn from_control(control: &NativeControl) -> Self {
+        let element = control.element();
+        Self {
+            min: element.get_attribute("min"),
+            max: element.get_attribute("max"),
+            min_length: element.get_attribute("minlength"),
+            max_length: element.get_attribute("maxlength"),
+            step: element.get_attribute("step"),
+            pattern: element.get_attribute("pattern"),
+        }
+    }
+
+    /// Replaces `{min}`, `{max}`, `{minlength}`, `{maxlength}`, `{step}` and `{pattern}` in
+    /// `template`. Placeholders of missing attributes become empty.
+    pub fn interpolate(&self, template: &str) -> String {
+        [
+            ("{min}", &self.min),
+            ("{max}", &self.max),
+            ("{minlength}", &self.min_length),
+            ("{maxlength}", &self.max_length),
+            ("{step}", &self.step),
+            ("{pattern}", &self.pattern),
+        ]
+        .into_iter()
+        .fold(template.to_string(), |message, (placeholder, value)| {
+            message.replace(placeholder, value.as_deref().unwrap_or_default())
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ValidationMessages
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Default messages of [`FormMessage`] and [`Validity::message`], e.g. translations.
+///
+/// Templates may contain the placeholders of [`ValidationConstraints::interpolate`]. Matchers
+/// without a template keep the English defaults. Templates are signals, so messages follow a
+/// change of locale.
+///
+/// ```ignore
+/// let messages = ValidationMessages::new()
+///     .message(ValidityMatcher::ValueMissing, "Dieses Feld ist erforderlich")
+///     .message(ValidityMatcher::RangeOverflow, "Höchstens {max}")
+///     .invalid("Dieser Wert ist ungültig");
+///
+/// view! {
+///     <ValidationMessagesProvider messages=messages>
+///         <Form>/* ... */</Form>
+///     </ValidationMessagesProvider>
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ValidationMessages {
+    templates: HashMap<ValidityMatcher, Signal<String>>,
+    invalid: Option<Signal<String>>,
+}
+
+impl ValidationMessages {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the template of the message for `matcher`.
+    pub fn message(
+        mut self,
+        matcher: ValidityMatcher,
+        template: impl Into<Signal<String>>,
+    ) -> Self {
+        self.templates.insert(matcher, template.into());
+        self
+    }
+
+    /// Sets the template of the message of custom matchers and messages without a matcher.
+    pub fn invalid(mut self, template: impl Into<Signal<String>>) -> Self {
+        self.invalid = Some(template.into());
+        self
+    }
+
+    /// Message for `matcher`, or the message of custom matchers when `None`. Tracks the template.
+    pub fn resolve(
+        &self,
+        matcher: Option<ValidityMatcher>,
+        constraints: &ValidationConstraints,
+    ) -> String {
+        let template = match matcher {
+            Some(matcher) => self.templates.get(&matcher),
+            None => self.invalid.as_ref(),
+        };
+        match template {
+            Some(template) => template.with(|template| constraints.interpolate(template)),
+            None => matcher
+                .map_or(DEFAULT_INVALID_MESSAGE, |matcher| matcher.default_message())
+                .to_string(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ValidationMessagesProvider
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Provides [`ValidationMessages`] to the forms inside it.
+#[component]
+pub fn ValidationMessagesProvider(
+    messages: ValidationMessages,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <Provider value=messages>
+            {children()}
+        </Provider>
+    }
+}
+
+/// Returns the [`ValidationMessages`] of the enclosing [`ValidationMessagesProvider`], or the
+/// English defaults.
+pub fn use_validation_messages() -> ValidationMessages {
+    use_context::<ValidationMessages>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use any_spawner::Executor;
+
+    struct NoopExecutor;
+
+    impl any_spawner::CustomExecutor for NoopExecutor {
+        fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+        fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+        fn poll_local(&self) {}
+    }
+
+    fn with_owner<T>(f: impl FnOnce() -> T) -> T {
+        let _ = Executor::init_custom_executor(NoopExecutor);
+        let owner = Owner::new_root(None);
+        owner.with(f)
+    }
+
+    fn constraints() -> ValidationConstraints {
+        ValidationConstraints {
+            min: Some("1".into()),
+            max: Some("10".into()),
+            min_length: Some("2".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn interpolates_constraints() {
+        assert_eq!(
+            constraints().interpolate("Between {min} and {max}, at least {minlength} digits"),
+            "Between 1 and 10, at least 2 digits"
+        );
+        assert_eq!(constraints().interpolate("Step {step}"), "Step ");
+    }
+
+    #[test]
+    fn falls_back_to_english_defaults() {
+        with_owner(|| {
+            let messages = ValidationMessages::new();
+            assert_eq!(
+                messages.resolve(Some(ValidityMatcher::ValueMissing), &constraints()),
+                "This value is missing"
+            );
+            assert_eq!(
+                messages.resolve(None, &constraints()),
+                DEFAULT_INVALID_MESSAGE
+            );
+        });
+    }
+
+    #[test]
+    fn resolves_templates() {
+        with_owner(|| {
+            let messages = ValidationMessages::new()
+                .message(ValidityMatcher::RangeOverflow, "Höchstens {max}")
+                .invalid("Ungültig");
+            assert_eq!(
+                messages.resolve(Some(ValidityMatcher::RangeOverflow), &constraints()),
+                "Höchstens 10"
+            );
+            assert_eq!(
+                messages.resolve(Some(ValidityMatcher::TooShort), &constraints()),
+                "This value is too short"
+            );
+            assert_eq!(messages.resolve(None, &constraints()), "Ungültig");
+        });
+    }
+
+    #[test]
+    fn follows_template_signals() {
+        with_owner(|| {
+            let template = RwSignal::new("Required".to_string());
+            let messages =
+                ValidationMessages::new().message(ValidityMatcher::ValueMissing, template);
+            template.set("Erforderlich".to_string());
+            assert_eq!(
+                messages.resolve(Some(ValidityMatcher::ValueMissing), &constraints()),
+                "Erforderlich"
+            );
+        });
+    }
+}
@@ -185,19 +185,8 @@ pub(super) fn MenuRootContentModal(
     let content_ref = AnyNodeRef::new();
     let composed_refs = use_composed_refs(vec![node_ref, content_ref]);
 
-    // Hide everything from ARIA except the `MenuContent`.
-    let hidden_elements: RwSignal<Vec<SendWrapper<web_sys::Element>>> = RwSignal::new(Vec::new());
-
-    Effect::new(move |_| {
-        if let Some(content) = content_ref.get() {
-            let content: web_sys::HtmlElement = content.unchecked_into();
-            hide_others(&content, hidden_elements);
-        }
-    });
-
-    on_cleanup(move || {
-        unhide_others(hidden_elements);
-    });
+    // Make everything except the `MenuContent` inert.
+    use_inert_others(content_ref, MaybeProp::default());
 
     // Wrap for forwarding through view! macro.
     let on_close_auto_focus = wrap_callback(on_close_auto_focus);
@@ -36,7 +36,6 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 
 use crate::internal::utils::{Point, is_point_in_polygon, wrap_array};
-use crate::support::aria_hidden::{hide_others, unhide_others};
 use crate::support::collection::{
     CollectionItemSlot, CollectionProvider, CollectionSlot, use_collection,
 };
@@ -48,6 +47,7 @@ use crate::support::focus_guards::use_focus_guards;
 use crate::support::focus_scope::FocusScope;
 use crate::support::forwarded_attrs::ForwardedAttrs;
 use crate::support::id::use_id;
+use crate::support::layer_stack::use_inert_others;
 pub use crate::support::popper::{
     Align, ClientRectObject, Padding, PopperVirtualElement, Side as PopperSide, Sticky,
     set_popper_virtual_ref,
@@ -14,6 +14,8 @@ pub mod calendar;
 pub mod checkbox;
 #[cfg(feature = "collapsible")]
 pub mod collapsible;
+#[cfg(feature = "color-picker")]
+pub mod color_picker;
 #[cfg(feature = "combobox")]
 pub mod combobox;
 #[cfg(feature = "context-menu")]
@@ -22,6 +24,8 @@ pub mod context_menu;
 pub mod dialog;
 #[cfg(feature = "dropdown-menu")]
 pub mod dropdown_menu;
+#[cfg(feature = "file-upload")]
+pub mod file_upload;
 #[cfg(feature = "form")]
 pub mod form;
 #[cfg(feature = "hover-card")]
@@ -36,6 +40,8 @@ pub mod menubar;
 pub mod navigation_menu;
 #[cfg(feature = "one-time-password-field")]
 pub mod one_time_password_field;
+#[cfg(feature = "pagination")]
+pub mod pagination;
 #[cfg(feature = "password-toggle-field")]
 pub mod password_toggle_field;
 #[cfg(feature = "popover")]
@@ -44,6 +50,8 @@ pub mod popover;
 pub mod progress;
 #[cfg(feature = "radio-group")]
 pub mod radio_group;
+#[cfg(feature = "rating")]
+pub mod rating;
 #[cfg(feature = "scroll-area")]
 pub mod scroll_area;
 #[cfg(feature = "select")]
@@ -56,6 +64,8 @@ pub mod slider;
 pub mod switch;
 #[cfg(feature = "tabs")]
 pub mod tabs;
+#[cfg(feature = "tags-input")]
+pub mod tags_input;
 #[cfg(feature = "toast")]
 pub mod toast;
 #[cfg(feature = "toggle")]
@@ -24,6 +24,7 @@
 //! - Configurable validation (numeric, alpha, alphanumeric)
 //! - Password or text input type
 //! - Native form participation via hidden `<input>`
+//! - Validation and `aria-describedby` wiring inside a `FormField`
 //! - Roving focus with arrow key navigation
 //!
 //! # Keyboard Interactions
@@ -51,6 +52,7 @@ use crate::support::collection::{
 };
 use crate::support::compose_refs::use_composed_refs;
 use crate::support::direction::{Direction, use_direction};
+use crate::support::form_control::{UseFormControlParams, use_form_control};
 use crate::support::primitive::{
     Primitive, VoidPrimitive, compose_callbacks, data_attr, prop_or, prop_or_default,
 };
@@ -59,6 +61,7 @@ use crate::support::roving_focus::{
     RovingFocusGroupItemContext,
 };
 use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
+use crate::support::use_previous::use_previous;
 use leptos::{context::Provider, ev, html, prelude::*};
 use leptos_node_ref::AnyNodeRef;
 use send_wrapper::SendWrapper;
@@ -231,6 +234,7 @@ struct OneTimePasswordFieldContextValue {
     auto_focus: Signal<bool>,
     disabled: Signal<bool>,
     read_only: Signal<bool>,
+    required: Signal<bool>,
     form: Signal<Option<String>>,
     name: Signal<Option<String>>,
     placeholder: Signal<Option<String>>,
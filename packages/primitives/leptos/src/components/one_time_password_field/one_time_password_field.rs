@@ -23,6 +23,7 @@ pub fn OneTimePasswordField(
     #[prop(into, optional)] on_auto_submit: Option<Callback<String>>,
     #[prop(into, optional)] disabled: MaybeProp<bool>,
     #[prop(into, optional)] read_only: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
     #[prop(into, optional)] auto_complete: MaybeProp<AutoComplete>,
     #[prop(into, optional)] auto_focus: MaybeProp<bool>,
     #[prop(into, optional)] form: MaybeProp<String>,
@@ -56,6 +57,7 @@ pub fn OneTimePasswordField(
                 auto_submit=auto_submit
                 disabled=disabled
                 read_only=read_only
+                required=required
                 auto_complete=auto_complete
                 auto_focus=auto_focus
                 form=form
@@ -81,6 +83,7 @@ fn OneTimePasswordFieldImpl(
     #[prop(into, optional)] auto_submit: MaybeProp<bool>,
     #[prop(into, optional)] disabled: MaybeProp<bool>,
     #[prop(into, optional)] read_only: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
     #[prop(into, optional)] auto_complete: MaybeProp<AutoComplete>,
     #[prop(into, optional)] auto_focus: MaybeProp<bool>,
     #[prop(into, optional)] form: MaybeProp<String>,
@@ -104,10 +107,10 @@ fn OneTimePasswordFieldImpl(
     let direction = use_direction(dir);
     let disabled_sig = prop_or_default(disabled);
     let read_only_sig = prop_or_default(read_only);
+    let required_sig = prop_or_default(required);
     let auto_complete_sig = prop_or_default(auto_complete);
     let auto_focus_sig = prop_or_default(auto_focus);
     let form_sig = Signal::derive(move || form.get());
-    let name_sig = Signal::derive(move || name.get());
     let placeholder_sig = Signal::derive(move || placeholder.get());
     let type_sig = prop_or_default(r#type);
     let orientation_sig = prop_or(orientation, Orientation::Horizontal);
@@ -163,6 +166,12 @@ fn OneTimePasswordFieldImpl(
     let get_items = StoredValue::new(get_items_fn);
     let composed_refs = use_composed_refs(vec![node_ref, root_ref]);
 
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: hidden_input_ref,
+        focus_ref: root_ref,
+    });
+    let name_sig = Signal::derive(move || name.get().or_else(|| form_control.name.get()));
+
     let user_action: RwSignal<Option<KeyboardActionDetails>> = RwSignal::new(None);
 
     let locate_form = move || -> Option<web_sys::HtmlFormElement> {
@@ -391,6 +400,7 @@ fn OneTimePasswordFieldImpl(
         auto_focus: auto_focus_sig,
         disabled: disabled_sig,
         read_only: read_only_sig,
+        required: required_sig,
         form: form_sig,
         name: name_sig,
         placeholder: placeholder_sig,
@@ -418,6 +428,7 @@ fn OneTimePasswordFieldImpl(
                         as_child=as_child
                         node_ref=composed_refs
                         attr:role="group"
+                        {..form_control.into_attrs()}
                         on:paste=compose_callbacks(on_paste, Some(Callback::new(move |event: ev::ClipboardEvent| {
                             event.prevent_default();
                             let clipboard_event: &web_sys::ClipboardEvent = event.unchecked_ref();
@@ -519,17 +519,40 @@ pub fn OneTimePasswordFieldHiddenInput(
 
     let value = Signal::derive(move || context.value.get().join("").trim().to_string());
     let name_sig = Signal::derive(move || name.get().or_else(|| context.name.get()));
+    let prev_value = use_previous(value);
+
+    // Bubble value change to parents, e.g. for form validation
+    Effect::new(move |_| {
+        if let Some(input) = composed_ref.get()
+            && prev_value.get() != value.get()
+        {
+            let input: &web_sys::HtmlInputElement = input.unchecked_ref();
+            input.set_value(&value.get());
+
+            let init = web_sys::EventInit::new();
+            init.set_bubbles(true);
+            let event = web_sys::Event::new_with_event_init_dict("input", &init)
+                .expect("Input event should be instantiated.");
+            input
+                .dispatch_event(&event)
+                .expect("Input event should be dispatched.");
+        }
+    });
 
     view! {
+        // NOTE: Not `type="hidden"` or `readonly`, which are barred from constraint validation,
+        // e.g. `required`. `display: none` keeps it out of reach of the user instead.
         <input
             node_ref=composed_ref
             name=move || name_sig.get().unwrap_or_default()
             value=move || value.get()
+            required=move || context.required.get().then_some("")
             autocomplete="off"
             autocapitalize="off"
             spellcheck="false"
-            type="hidden"
-            readonly
+            aria-hidden="true"
+            tabindex="-1"
+            style:display="none"
         />
     }
 }
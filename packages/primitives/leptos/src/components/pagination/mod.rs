@@ -0,0 +1,337 @@
+//! Pagination for moving between pages of content.
+//!
+//! Renders a `<nav>` with buttons for the pages around the current one, the first and last
+//! pages, and ellipses for the gaps between them. Pages are 1-based.
+//!
+//! Every part supports `as_child`, so it can render a router link instead of a button:
+//!
+//! ```ignore
+//! <PaginationItem page=page as_child=true>
+//!     <A href=format!("/posts?page={page}")>{page}</A>
+//! </PaginationItem>
+//! ```
+//!
+//! # Anatomy
+//!
+//! ```text
+//! <Pagination>
+//!     <PaginationFirst />
+//!     <PaginationPrev />
+//!     <PaginationItem />
+//!     <PaginationEllipsis />
+//!     <PaginationNext />
+//!     <PaginationLast />
+//! </Pagination>
+//! ```
+//!
+//! # Features
+//!
+//! - Controlled and uncontrolled page, with a controlled page count
+//! - Page range with configurable sibling and boundary counts, via [`use_pagination_range`]
+//! - `aria-current="page"` on the item of the current page
+//! - Roving focus with arrow key navigation
+//! - RTL support
+//!
+//! # Keyboard Interactions
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | Tab | Moves focus to the current page (or the last focused control) |
+//! | ArrowRight / ArrowLeft | Focuses the next/previous control (swapped in RTL) |
+//! | Home / End | Focuses the first/last control |
+//! | Enter / Space | Goes to the page of the focused control |
+//!
+//! # Data Attributes
+//!
+//! **PaginationItem:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-selected` | Present for the current page |
+//! | `data-disabled` | Present when disabled |
+//!
+//! **PaginationFirst, PaginationPrev, PaginationNext, PaginationLast:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-disabled` | Present when disabled, e.g. on the first page for `PaginationPrev` |
+
+use crate::support::direction::{Direction, use_direction};
+use crate::support::primitive::{Primitive, data_attr, prop_or, prop_or_default};
+use crate::support::roving_focus::{Orientation, RovingFocusGroup, RovingFocusGroupItem};
+use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
+use leptos::{context::Provider, ev, html, prelude::*};
+use leptos_node_ref::AnyNodeRef;
+use web_sys::wasm_bindgen::JsCast;
+
+mod pagination;
+mod pagination_controls;
+mod pagination_item;
+
+pub use pagination::*;
+pub use pagination_controls::*;
+pub use pagination_item::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Types
+ * -----------------------------------------------------------------------------------------------*/
+
+/// An entry of the page range from [`use_pagination_range`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PaginationRangeItem {
+    /// A page, rendered as a [`PaginationItem`].
+    Page(usize),
+    /// The gap after the first pages, rendered as a [`PaginationEllipsis`].
+    StartEllipsis,
+    /// The gap before the last pages, rendered as a [`PaginationEllipsis`].
+    EndEllipsis,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Context
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct PaginationContextValue {
+    page: Signal<usize>,
+    page_count: Signal<usize>,
+    on_page_change: Callback<usize>,
+    range: Memo<Vec<PaginationRangeItem>>,
+    disabled: Signal<bool>,
+}
+
+impl PaginationContextValue {
+    fn go_to(&self, page: usize) {
+        let page = page.clamp(1, self.page_count.get_untracked().max(1));
+        self.on_page_change.run(page);
+    }
+}
+
+/// The page range of the enclosing [`Pagination`], to render its items and ellipses from.
+pub fn use_pagination_range() -> Signal<Vec<PaginationRangeItem>> {
+    expect_context::<PaginationContextValue>().range.into()
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationButton
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Control going to `target_page`: a `<button>`, or with `as_child` e.g. a router link.
+#[component]
+fn PaginationButton(
+    #[prop(into)] target_page: Signal<usize>,
+    #[prop(into)] disabled: Signal<bool>,
+    #[prop(into)] current: Signal<bool>,
+    #[prop(into)] aria_label: Signal<String>,
+    on_click: Option<Callback<ev::MouseEvent>>,
+    on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<PaginationContextValue>();
+    let disabled = Signal::derive(move || context.disabled.get() || disabled.get());
+    let is_button = move || !as_child.get().unwrap_or(false);
+
+    let on_click_stored = StoredValue::new(on_click);
+    let on_key_down_stored = StoredValue::new(on_key_down);
+
+    // Attributes on this component pass through `RovingFocusGroupItem` onto the control.
+    view! {
+        <RovingFocusGroupItem
+            as_child=true
+            focusable=Signal::derive(move || !disabled.get())
+            active=current
+        >
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=node_ref
+                attr:r#type=move || is_button().then_some("button")
+                attr:aria-label=move || aria_label.get()
+                attr:aria-current=move || current.get().then_some("page")
+                attr:aria-disabled=move || disabled.get().then_some("true")
+                attr:disabled=move || (is_button() && disabled.get()).then_some("")
+                attr:data-selected=data_attr(current)
+                attr:data-disabled=data_attr(disabled)
+                on:click=move |event: ev::MouseEvent| {
+                    if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if disabled.get_untracked() {
+                        // Keeps disabled links from navigating.
+                        event.prevent_default();
+                    } else if !event.default_prevented() {
+                        context.go_to(target_page.get_untracked());
+                    }
+                }
+                on:keydown=move |event: ev::KeyboardEvent| {
+                    if let Some(Some(cb)) = on_key_down_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    // Links activate on Enter only, while buttons also activate on Space.
+                    if !event.default_prevented()
+                        && event.key() == " "
+                        && !is_button()
+                        && let Some(target) = event.current_target()
+                        && let Ok(element) = target.dyn_into::<web_sys::HtmlElement>()
+                    {
+                        event.prevent_default();
+                        element.click();
+                    }
+                }
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+        </RovingFocusGroupItem>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Pages to show for `page` of `page_count`: `boundary_count` pages at each end and
+/// `sibling_count` pages on each side of `page`, with ellipses for the gaps.
+///
+/// The number of entries stays the same while paging, so the controls don't move around. A gap
+/// of a single page shows that page instead of an ellipsis.
+fn pagination_range(
+    page: usize,
+    page_count: usize,
+    sibling_count: usize,
+    boundary_count: usize,
+) -> Vec<PaginationRangeItem> {
+    let [page, count, siblings, boundaries] =
+        [page, page_count, sibling_count, boundary_count].map(|value| value as i64);
+    let pages = |start: i64, end: i64| (start.max(1)..=end).map(|page| page as usize);
+
+    let start_pages = pages(1, boundaries.min(count));
+    let end_pages_start = (count - boundaries + 1).max(boundaries + 1);
+    let end_pages = pages(end_pages_start, count);
+
+    let siblings_start = (page - siblings)
+        .min(count - boundaries - siblings * 2 - 1)
+        .max(boundaries + 2);
+    let siblings_end =
+        (page + siblings)
+            .max(boundaries + siblings * 2 + 2)
+            .min(if end_pages_start <= count {
+                end_pages_start - 2
+            } else {
+                count - 1
+            });
+
+    let mut range: Vec<PaginationRangeItem> = start_pages.map(PaginationRangeItem::Page).collect();
+    if siblings_start > boundaries + 2 {
+        range.push(PaginationRangeItem::StartEllipsis);
+    } else if boundaries + 1 < count - boundaries {
+        range.push(PaginationRangeItem::Page((boundaries + 1) as usize));
+    }
+    range.extend(pages(siblings_start, siblings_end).map(PaginationRangeItem::Page));
+    if siblings_end < count - boundaries - 1 {
+        range.push(PaginationRangeItem::EndEllipsis);
+    } else if count - boundaries > boundaries {
+        range.push(PaginationRangeItem::Page((count - boundaries) as usize));
+    }
+    range.extend(end_pages.map(PaginationRangeItem::Page));
+    range
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PaginationRangeItem::{EndEllipsis, Page, StartEllipsis};
+
+    #[test]
+    fn shows_all_pages_when_they_fit() {
+        assert_eq!(
+            pagination_range(1, 5, 1, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+        assert_eq!(pagination_range(1, 1, 1, 1), vec![Page(1)]);
+        assert_eq!(pagination_range(1, 0, 1, 1), vec![]);
+    }
+
+    #[test]
+    fn collapses_gaps_into_ellipses() {
+        assert_eq!(
+            pagination_range(1, 10, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                EndEllipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            pagination_range(5, 10, 1, 1),
+            vec![
+                Page(1),
+                StartEllipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                EndEllipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            pagination_range(10, 10, 1, 1),
+            vec![
+                Page(1),
+                StartEllipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn shows_a_single_hidden_page_instead_of_an_ellipsis() {
+        assert_eq!(
+            pagination_range(4, 10, 1, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                EndEllipsis,
+                Page(10)
+            ]
+        );
+    }
+
+    #[test]
+    fn honors_sibling_and_boundary_counts() {
+        assert_eq!(
+            pagination_range(10, 20, 2, 2),
+            vec![
+                Page(1),
+                Page(2),
+                StartEllipsis,
+                Page(8),
+                Page(9),
+                Page(10),
+                Page(11),
+                Page(12),
+                EndEllipsis,
+                Page(19),
+                Page(20),
+            ]
+        );
+        assert_eq!(
+            pagination_range(10, 20, 0, 0),
+            vec![StartEllipsis, Page(10), EndEllipsis]
+        );
+    }
+}
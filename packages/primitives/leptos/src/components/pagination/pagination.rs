@@ -0,0 +1,96 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Pagination (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Root pagination component.
+///
+/// Renders as a `<nav>` labelled "Pagination". Manages the current page and provides the page
+/// range to [`use_pagination_range`].
+#[component]
+pub fn Pagination(
+    #[prop(into, optional)] page: MaybeProp<usize>,
+    #[prop(into, optional)] default_page: MaybeProp<usize>,
+    #[prop(into, optional)] on_page_change: Option<Callback<usize>>,
+    /// Number of pages.
+    #[prop(into)]
+    page_count: Signal<usize>,
+    /// Pages on each side of the current page. Defaults to `1`.
+    #[prop(into, optional)]
+    sibling_count: MaybeProp<usize>,
+    /// Pages at the start and at the end. Defaults to `1`.
+    #[prop(into, optional)]
+    boundary_count: MaybeProp<usize>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] dir: MaybeProp<Direction>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let direction = use_direction(dir);
+    let sibling_count = prop_or(sibling_count, 1);
+    let boundary_count = prop_or(boundary_count, 1);
+    let disabled = prop_or_default(disabled);
+
+    let (page_signal, set_page) = use_controllable_state(UseControllableStateParams {
+        prop: page,
+        default_prop: default_page,
+        on_change: on_page_change.map(|cb| {
+            Callback::new(move |value: Option<usize>| {
+                if let Some(value) = value {
+                    cb.run(value);
+                }
+            })
+        }),
+    });
+    let page = Signal::derive(move || {
+        page_signal
+            .get()
+            .unwrap_or(1)
+            .clamp(1, page_count.get().max(1))
+    });
+
+    let range = Memo::new(move |_| {
+        pagination_range(
+            page.get(),
+            page_count.get(),
+            sibling_count.get(),
+            boundary_count.get(),
+        )
+    });
+
+    let context = PaginationContextValue {
+        page,
+        page_count,
+        on_page_change: Callback::new(move |page: usize| {
+            set_page.run(Some(page));
+        }),
+        range,
+        disabled,
+    };
+
+    view! {
+        <Provider value=context>
+            <RovingFocusGroup
+                as_child=true
+                orientation=Orientation::Horizontal
+                dir=direction
+                r#loop=false
+            >
+                <Primitive
+                    element=html::nav
+                    as_child=as_child
+                    node_ref=node_ref
+                    attr:aria-label="Pagination"
+                    attr:data-disabled=data_attr(disabled)
+                    attr:dir=move || direction.get().to_string()
+                >
+                    {children.with_value(|children| children())}
+                </Primitive>
+            </RovingFocusGroup>
+        </Provider>
+    }
+}
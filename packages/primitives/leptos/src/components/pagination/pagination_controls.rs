@@ -0,0 +1,187 @@
+use super::*;
+
+/// Label and page of a [`PaginationButton`] for the first, previous, next or last page.
+#[derive(Clone, Copy)]
+enum PaginationControl {
+    First,
+    Prev,
+    Next,
+    Last,
+}
+
+impl PaginationControl {
+    fn label(self) -> &'static str {
+        match self {
+            PaginationControl::First => "First page",
+            PaginationControl::Prev => "Previous page",
+            PaginationControl::Next => "Next page",
+            PaginationControl::Last => "Last page",
+        }
+    }
+
+    fn default_children(self) -> &'static str {
+        match self {
+            PaginationControl::First => "\u{00ab}",
+            PaginationControl::Prev => "\u{2039}",
+            PaginationControl::Next => "\u{203a}",
+            PaginationControl::Last => "\u{00bb}",
+        }
+    }
+
+    /// Page the control goes to, and whether it is disabled because that is the current page.
+    fn target(self, page: usize, page_count: usize) -> (usize, bool) {
+        let last = page_count.max(1);
+        match self {
+            PaginationControl::First => (1, page <= 1),
+            PaginationControl::Prev => (page.saturating_sub(1).max(1), page <= 1),
+            PaginationControl::Next => ((page + 1).min(last), page >= last),
+            PaginationControl::Last => (last, page >= last),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationFirst
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Control going to the first page, disabled on it.
+#[component]
+pub fn PaginationFirst(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    view! {
+        <PaginationControlButton
+            control=PaginationControl::First
+            disabled=disabled
+            on_click=on_click
+            on_key_down=on_key_down
+            as_child=as_child
+            node_ref=node_ref
+            content=children
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationPrev
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Control going to the previous page, disabled on the first page.
+#[component]
+pub fn PaginationPrev(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    view! {
+        <PaginationControlButton
+            control=PaginationControl::Prev
+            disabled=disabled
+            on_click=on_click
+            on_key_down=on_key_down
+            as_child=as_child
+            node_ref=node_ref
+            content=children
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationNext
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Control going to the next page, disabled on the last page.
+#[component]
+pub fn PaginationNext(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    view! {
+        <PaginationControlButton
+            control=PaginationControl::Next
+            disabled=disabled
+            on_click=on_click
+            on_key_down=on_key_down
+            as_child=as_child
+            node_ref=node_ref
+            content=children
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationLast
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Control going to the last page, disabled on it.
+#[component]
+pub fn PaginationLast(
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    view! {
+        <PaginationControlButton
+            control=PaginationControl::Last
+            disabled=disabled
+            on_click=on_click
+            on_key_down=on_key_down
+            as_child=as_child
+            node_ref=node_ref
+            content=children
+        />
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationControlButton
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+fn PaginationControlButton(
+    control: PaginationControl,
+    disabled: MaybeProp<bool>,
+    on_click: Option<Callback<ev::MouseEvent>>,
+    on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    as_child: MaybeProp<bool>,
+    node_ref: AnyNodeRef,
+    content: Option<ChildrenFn>,
+) -> impl IntoView {
+    let content = StoredValue::new(content);
+
+    let context = expect_context::<PaginationContextValue>();
+    let target = Memo::new(move |_| control.target(context.page.get(), context.page_count.get()));
+
+    view! {
+        <PaginationButton
+            target_page=Signal::derive(move || target.get().0)
+            disabled=Signal::derive(move || target.get().1 || disabled.get().unwrap_or(false))
+            current=false
+            aria_label=control.label().to_string()
+            on_click=on_click
+            on_key_down=on_key_down
+            as_child=as_child
+            node_ref=node_ref
+        >
+            {content.with_value(|content| match content {
+                Some(content) => content().into_any(),
+                None => control.default_children().into_any(),
+            })}
+        </PaginationButton>
+    }
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Control going to `page`, with `aria-current="page"` when it is the current page.
+///
+/// Shows the page number unless given children.
+#[component]
+pub fn PaginationItem(
+    #[prop(into)] page: Signal<usize>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<PaginationContextValue>();
+    let current = Signal::derive(move || context.page.get() == page.get());
+
+    view! {
+        <PaginationButton
+            target_page=page
+            disabled=prop_or_default(disabled)
+            current=current
+            aria_label=Signal::derive(move || format!("Page {}", page.get()))
+            on_click=on_click
+            on_key_down=on_key_down
+            as_child=as_child
+            node_ref=node_ref
+        >
+            {children.with_value(|children| match children {
+                Some(children) => children().into_any(),
+                None => (move || page.get()).into_any(),
+            })}
+        </PaginationButton>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * PaginationEllipsis
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Marks pages left out of the range. Hidden from assistive technology.
+#[component]
+pub fn PaginationEllipsis(
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    view! {
+        <Primitive
+            element=html::span
+            as_child=as_child
+            node_ref=node_ref
+            attr:aria-hidden="true"
+        >
+            {children.with_value(|children| match children {
+                Some(children) => children().into_any(),
+                None => "\u{2026}".into_any(),
+            })}
+        </Primitive>
+    }
+}
@@ -51,12 +51,12 @@
 //! | `data-side` | `top`, `right`, `bottom`, `left` |
 //! | `data-align` | `start`, `center`, `end` |
 
-use crate::support::aria_hidden::{hide_others, unhide_others};
 use crate::support::compose_refs::use_composed_refs;
 use crate::support::dismissable_layer::DismissableLayer;
 use crate::support::focus_guards::use_focus_guards;
-use crate::support::focus_scope::FocusScope;
+use crate::support::focus_scope::{FocusScope, final_focus_target};
 use crate::support::id::use_id;
+use crate::support::layer_stack::use_inert_others;
 use crate::support::popper::{
     Popper, PopperAnchor, PopperArrow, PopperContent, UpdatePositionStrategy, provide_popper_scope,
     use_popper_scope,
@@ -66,7 +66,7 @@ use crate::support::presence::Presence;
 use crate::support::primitive::{
     Primitive, adapt_callback, compose_callbacks, open_closed_state, prop_or_default,
 };
-use crate::support::scroll_lock::use_body_scroll_lock;
+use crate::support::scroll_lock::{UseScrollLockParams, use_scroll_lock};
 use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
 use leptos::{
     attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
@@ -74,12 +74,11 @@ use leptos::{
 use leptos_node_ref::AnyNodeRef;
 use send_wrapper::SendWrapper;
 use wasm_bindgen::JsCast;
-use web_sys::wasm_bindgen::closure::Closure;
 
 mod popover;
 mod popover_content;
 
-pub use crate::support::popper::{Align, Padding, Side, Sticky};
+pub use crate::support::popper::{Align, ClientRectObject, FollowCursor, Padding, Side, Sticky};
 pub use popover::*;
 pub use popover_content::*;
 
@@ -109,3 +108,12 @@ struct ContentCallbacks {
     on_focus_outside: StoredValue<Option<Callback<web_sys::CustomEvent>>>,
     on_interact_outside: StoredValue<Option<Callback<web_sys::CustomEvent>>>,
 }
+
+/// Shared focus options for popover content variants, threaded through the same layers.
+#[derive(Clone, Copy)]
+struct ContentOptions {
+    initial_focus: Option<AnyNodeRef>,
+    final_focus: Option<AnyNodeRef>,
+    external_focus_targets: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+    focus_trap_paused: MaybeProp<bool>,
+}
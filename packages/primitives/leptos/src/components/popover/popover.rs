@@ -56,6 +56,15 @@ pub fn Popover(
 
 #[component]
 pub fn PopoverAnchor(
+    /// Anchors content to a DOM range, e.g. the current text selection.
+    #[prop(into, optional)]
+    range: MaybeProp<SendWrapper<web_sys::Range>>,
+    /// Anchors content to the rect returned by this callback.
+    #[prop(into, optional_no_strip)]
+    virtual_rect: Option<Callback<(), ClientRectObject>>,
+    /// Anchors content to the pointer while it moves over the anchor.
+    #[prop(into, optional)]
+    follow_cursor: MaybeProp<FollowCursor>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
     #[prop(optional)] children: Option<ChildrenFn>,
@@ -72,6 +81,9 @@ pub fn PopoverAnchor(
 
     view! {
         <PopperAnchor
+            range=range
+            virtual_rect=virtual_rect
+            follow_cursor=follow_cursor
             as_child=as_child
             node_ref=node_ref
         >
@@ -40,6 +40,18 @@ pub fn PopoverPortal(
 #[component]
 pub fn PopoverContent(
     #[prop(into, optional)] force_mount: MaybeProp<bool>,
+    /// Element to focus on open instead of the first tabbable element.
+    #[prop(into, optional_no_strip)]
+    initial_focus: Option<AnyNodeRef>,
+    /// Element to focus on close instead of the trigger.
+    #[prop(into, optional_no_strip)]
+    final_focus: Option<AnyNodeRef>,
+    /// Elements outside the popover that focus may move to while it is trapped, e.g. third-party iframes.
+    #[prop(into, optional)]
+    external_focus_targets: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+    /// When `true`, focus trapping is suspended while the popover stays open.
+    #[prop(into, optional)]
+    focus_trap_paused: MaybeProp<bool>,
     #[prop(into, optional)] on_open_auto_focus: Option<Callback<ev::Event>>,
     #[prop(into, optional)] on_close_auto_focus: Option<Callback<ev::Event>>,
     #[prop(into, optional)] on_escape_key_down: Option<Callback<ev::KeyboardEvent>>,
@@ -83,6 +95,13 @@ pub fn PopoverContent(
         on_interact_outside: StoredValue::new(on_interact_outside),
     };
 
+    let options = ContentOptions {
+        initial_focus,
+        final_focus,
+        external_focus_targets,
+        focus_trap_paused,
+    };
+
     let presence_ref = AnyNodeRef::new();
 
     view! {
@@ -92,6 +111,7 @@ pub fn PopoverContent(
                 fallback=move || view! {
                     <PopoverContentNonModal
                         callbacks=callbacks
+                        options=options
                         side=side
                         side_offset=side_offset
                         align=align
@@ -113,6 +133,7 @@ pub fn PopoverContent(
             >
                 <PopoverContentModal
                     callbacks=callbacks
+                    options=options
                     side=side
                     side_offset=side_offset
                     align=align
@@ -142,6 +163,7 @@ pub fn PopoverContent(
 #[component]
 fn PopoverContentModal(
     callbacks: ContentCallbacks,
+    options: ContentOptions,
     // PopperContent forwarded props
     #[prop(into, optional, default = Side::Bottom.into())] side: Signal<Side>,
     #[prop(into, optional, default = 0.0.into())] side_offset: Signal<f64>,
@@ -170,27 +192,8 @@ fn PopoverContentModal(
 
     let is_right_click_outside_ref: StoredValue<bool> = StoredValue::new(false);
 
-    // aria-hide everything except the content (better supported equivalent to setting aria-modal).
-    // Deferred to requestAnimationFrame so that FocusScope's deferred auto-focus moves focus
-    // into the popover content before hide_others sets aria-hidden on outside elements.
-    let hidden_elements: RwSignal<Vec<SendWrapper<web_sys::Element>>> = RwSignal::new(Vec::new());
-
-    Effect::new(move |_| {
-        if let Some(content) = content_ref.get() {
-            let content: web_sys::HtmlElement = content.unchecked_into();
-            let cb = Closure::once_into_js(move || {
-                hide_others(&content, hidden_elements);
-            });
-            web_sys::window()
-                .expect("Window should exist.")
-                .request_animation_frame(cb.unchecked_ref())
-                .ok();
-        }
-    });
-
-    on_cleanup(move || {
-        unhide_others(hidden_elements);
-    });
+    // Make everything except the content inert (better supported equivalent to setting aria-modal).
+    let release_inert = use_inert_others(content_ref, options.external_focus_targets);
 
     let on_close_auto_focus = Callback::new(move |event: web_sys::Event| {
         callbacks.on_close_auto_focus.with_value(|cb| {
@@ -198,14 +201,14 @@ fn PopoverContentModal(
                 cb.run(event.clone());
             }
         });
-        // Remove aria-hidden from outside elements BEFORE focusing the trigger.
-        unhide_others(hidden_elements);
+        // Restore outside elements BEFORE focusing the trigger.
+        release_inert.run(());
         event.prevent_default();
         if !is_right_click_outside_ref.get_value()
-            && let Some(trigger) = context.trigger_ref.get_untracked()
+            && let Some(target) = final_focus_target(options.final_focus, context.trigger_ref)
         {
-            let trigger: &web_sys::HtmlElement = trigger.unchecked_ref();
-            trigger.focus().ok();
+            let target: &web_sys::HtmlElement = target.unchecked_ref();
+            target.focus().ok();
         }
     });
 
@@ -250,10 +253,14 @@ fn PopoverContentModal(
         .unwrap_or(Callback::new(|_| {}));
 
     // Body scroll lock for modal popover
-    use_body_scroll_lock();
+    use_scroll_lock(UseScrollLockParams {
+        layer: content_ref,
+        shards: vec![],
+    });
 
     view! {
         <PopoverContentImpl
+            options=options
             trap_focus=context.open
             disable_outside_pointer_events=true
             on_open_auto_focus=on_open_auto_focus
@@ -288,6 +295,7 @@ fn PopoverContentModal(
 #[component]
 fn PopoverContentNonModal(
     callbacks: ContentCallbacks,
+    options: ContentOptions,
     // PopperContent forwarded props
     #[prop(into, optional, default = Side::Bottom.into())] side: Signal<Side>,
     #[prop(into, optional, default = 0.0.into())] side_offset: Signal<f64>,
@@ -325,10 +333,10 @@ fn PopoverContentNonModal(
 
         if !event.default_prevented() {
             if !has_interacted_outside.get_untracked()
-                && let Some(trigger) = context.trigger_ref.get_untracked()
+                && let Some(target) = final_focus_target(options.final_focus, context.trigger_ref)
             {
-                let trigger: &web_sys::HtmlElement = trigger.unchecked_ref();
-                trigger.focus().ok();
+                let target: &web_sys::HtmlElement = target.unchecked_ref();
+                target.focus().ok();
             }
             // Always prevent auto focus because we either focus manually or want user agent focus
             event.prevent_default();
@@ -405,6 +413,7 @@ fn PopoverContentNonModal(
 
     view! {
         <PopoverContentImpl
+            options=options
             trap_focus=false
             disable_outside_pointer_events=false
             on_open_auto_focus=on_open_auto_focus
@@ -438,6 +447,7 @@ fn PopoverContentNonModal(
 
 #[component]
 fn PopoverContentImpl(
+    options: ContentOptions,
     #[prop(into, optional)] trap_focus: MaybeProp<bool>,
     #[prop(into, optional)] disable_outside_pointer_events: MaybeProp<bool>,
     #[prop(into)] on_open_auto_focus: Callback<web_sys::Event>,
@@ -483,6 +493,10 @@ fn PopoverContentImpl(
             trapped=trapped
             on_mount_auto_focus=on_open_auto_focus
             on_unmount_auto_focus=Some(on_close_auto_focus)
+            initial_focus=options.initial_focus
+            final_focus=options.final_focus
+            external_focus_targets=options.external_focus_targets
+            paused=options.focus_trap_paused
         >
             <DismissableLayer
                 as_child=true
@@ -0,0 +1,219 @@
+//! Rating input, e.g. a row of stars.
+//!
+//! Follows the radio group semantics: the root is a `role="radiogroup"` and each item a
+//! `role="radio"`, with the item of the current value checked. Values can be whole or, with
+//! `allow_half`, half steps picked by the pointer position within an item.
+//!
+//! Implements the [WAI-ARIA Radio Group pattern](https://www.w3.org/WAI/ARIA/apd/patterns/radio/).
+//!
+//! # Anatomy
+//!
+//! ```text
+//! <Rating>
+//!     <RatingItem />
+//! </Rating>
+//! ```
+//!
+//! # Features
+//!
+//! - Controlled and uncontrolled value state
+//! - Half steps with `allow_half`
+//! - Hover preview of the value under the pointer
+//! - RTL support for pointer positions and arrow keys
+//! - Read-only display mode, announced as e.g. "3.5 out of 5"
+//! - Native form participation via a hidden input, with form reset support
+//!
+//! # Keyboard Interactions
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | Tab | Moves focus to the checked item (or first item if none checked) |
+//! | ArrowRight / ArrowUp | Increases the value by one step (ArrowLeft in RTL) |
+//! | ArrowLeft / ArrowDown | Decreases the value by one step (ArrowRight in RTL) |
+//! | Home | Sets the value to the minimum step |
+//! | End | Sets the value to `max` |
+//! | Space | Sets the value to the focused item |
+//!
+//! # Data Attributes
+//!
+//! **Rating:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-disabled` | Present when disabled |
+//! | `data-readonly` | Present when read-only |
+//!
+//! **RatingItem:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-state` | `full`, `half`, `empty` — of the hovered value while hovering |
+//! | `data-highlighted` | Present when filled by the hovered value |
+//! | `data-disabled` | Present when disabled |
+//! | `data-readonly` | Present when read-only |
+
+use crate::support::compose_refs::use_composed_refs;
+use crate::support::direction::{Direction, use_direction};
+use crate::support::form_control::{UseFormControlParams, use_form_control, use_form_reset};
+use crate::support::primitive::{Primitive, data_attr, prop_or, prop_or_default};
+use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
+use leptos::{
+    attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
+};
+use leptos_node_ref::AnyNodeRef;
+use wasm_bindgen::JsCast;
+
+mod rating;
+mod rating_item;
+
+pub use rating::*;
+pub use rating_item::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Context
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct RatingContextValue {
+    value: Signal<f64>,
+    on_value_change: Callback<f64>,
+    hovered_value: RwSignal<Option<f64>>,
+    max: Signal<usize>,
+    allow_half: Signal<bool>,
+    read_only: Signal<bool>,
+    disabled: Signal<bool>,
+    direction: Signal<Direction>,
+    item_refs: StoredValue<Vec<(usize, AnyNodeRef)>>,
+}
+
+impl RatingContextValue {
+    fn is_interactive(&self) -> bool {
+        !self.read_only.get_untracked() && !self.disabled.get_untracked()
+    }
+
+    fn step(&self) -> f64 {
+        if self.allow_half.get_untracked() {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Value shown by the items: the hovered one while hovering, else the current one.
+    fn display_value(&self) -> f64 {
+        self.hovered_value.get().unwrap_or_else(|| self.value.get())
+    }
+
+    /// Sets `value` and moves focus to the item that now holds it.
+    fn select(&self, value: f64) {
+        self.on_value_change.run(value);
+        let index = focus_index(value, self.max.get_untracked());
+        let item_ref = self.item_refs.with_value(|item_refs| {
+            item_refs
+                .iter()
+                .find(|(item_index, _)| *item_index == index)
+                .map(|(_, item_ref)| *item_ref)
+        });
+        if let Some(item) = item_ref.and_then(|item_ref| item_ref.get_untracked()) {
+            let item: &web_sys::HtmlElement = item.unchecked_ref();
+            let _ = item.focus();
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Helpers
+ * -----------------------------------------------------------------------------------------------*/
+
+const VISUALLY_HIDDEN_STYLES_STR: &str = "position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;";
+
+/// Value picked at `fraction` (0 to 1, from the start edge) of the item at the 1-based `index`.
+fn value_at_pointer(index: usize, fraction: f64, allow_half: bool) -> f64 {
+    if allow_half && fraction < 0.5 {
+        index as f64 - 0.5
+    } else {
+        index as f64
+    }
+}
+
+/// `data-state` of the item at the 1-based `index` for `value`.
+fn item_state(index: usize, value: f64) -> &'static str {
+    let index = index as f64;
+    if value >= index {
+        "full"
+    } else if value >= index - 0.5 {
+        "half"
+    } else {
+        "empty"
+    }
+}
+
+/// 1-based index of the item that holds `value`, i.e. the checked and focusable one.
+fn focus_index(value: f64, max: usize) -> usize {
+    (value.ceil() as usize).clamp(1, max.max(1))
+}
+
+/// `value` moved by `steps` steps of `step`, kept between one step and `max`. Values off the
+/// step grid move to the nearest step in that direction, and an empty value can only increase.
+fn step_value(value: f64, steps: i32, step: f64, max: usize) -> f64 {
+    let max = max as f64;
+    let min = step.min(value).min(max);
+    let current = if steps > 0 {
+        (value / step).floor()
+    } else {
+        (value / step).ceil()
+    };
+    ((current + steps as f64) * step).clamp(min, max)
+}
+
+/// Accessible label of `value`, e.g. "3.5 out of 5".
+fn value_label(value: f64, max: usize) -> String {
+    format!("{value} out of {max}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_half_steps_by_pointer_position() {
+        assert_eq!(value_at_pointer(3, 0.2, true), 2.5);
+        assert_eq!(value_at_pointer(3, 0.5, true), 3.0);
+        assert_eq!(value_at_pointer(3, 0.2, false), 3.0);
+    }
+
+    #[test]
+    fn fills_items_up_to_the_value() {
+        assert_eq!(item_state(2, 3.5), "full");
+        assert_eq!(item_state(4, 3.5), "half");
+        assert_eq!(item_state(5, 3.5), "empty");
+        assert_eq!(item_state(1, 0.0), "empty");
+    }
+
+    #[test]
+    fn focuses_the_item_holding_the_value() {
+        assert_eq!(focus_index(0.0, 5), 1);
+        assert_eq!(focus_index(3.5, 5), 4);
+        assert_eq!(focus_index(3.0, 5), 3);
+        assert_eq!(focus_index(7.0, 5), 5);
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        assert_eq!(step_value(3.0, 1, 0.5, 5), 3.5);
+        assert_eq!(step_value(3.0, -1, 1.0, 5), 2.0);
+        assert_eq!(step_value(5.0, 1, 1.0, 5), 5.0);
+        assert_eq!(step_value(0.5, -1, 0.5, 5), 0.5);
+        assert_eq!(step_value(0.0, -1, 0.5, 5), 0.0);
+        assert_eq!(step_value(0.0, 1, 1.0, 5), 1.0);
+        // Whole steps from a half value snap to the next whole one.
+        assert_eq!(step_value(2.5, 1, 1.0, 5), 3.0);
+        assert_eq!(step_value(2.5, -1, 1.0, 5), 2.0);
+    }
+
+    #[test]
+    fn labels_values() {
+        assert_eq!(value_label(3.5, 5), "3.5 out of 5");
+        assert_eq!(value_label(3.0, 5), "3 out of 5");
+    }
+}
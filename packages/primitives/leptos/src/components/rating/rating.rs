@@ -0,0 +1,187 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Rating (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Root rating component.
+///
+/// Renders as a `<div>` with `role="radiogroup"`, or with `role="img"` and a label such as
+/// "3.5 out of 5" when `read_only`. A value of `0` means no rating.
+#[component]
+pub fn Rating(
+    #[prop(into, optional)] value: MaybeProp<f64>,
+    #[prop(into, optional)] default_value: MaybeProp<f64>,
+    #[prop(into, optional)] on_value_change: Option<Callback<f64>>,
+    /// Number of items. Defaults to `5`.
+    #[prop(into, optional)]
+    max: MaybeProp<usize>,
+    /// Allows half steps, picked by the pointer position within an item.
+    #[prop(into, optional)]
+    allow_half: MaybeProp<bool>,
+    /// Only displays the value, without any interaction.
+    #[prop(into, optional)]
+    read_only: MaybeProp<bool>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+    #[prop(into, optional)] dir: MaybeProp<Direction>,
+    #[prop(into, optional)] on_pointer_leave: Option<Callback<ev::PointerEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let root_ref = AnyNodeRef::new();
+    let composed_ref = use_composed_refs(vec![node_ref, root_ref]);
+    let bubble_ref = AnyNodeRef::new();
+
+    let direction = use_direction(dir);
+    let max = prop_or(max, 5);
+    let allow_half = prop_or_default(allow_half);
+    let read_only = prop_or_default(read_only);
+    let disabled = prop_or_default(disabled);
+    let required = prop_or_default(required);
+
+    let (value_signal, set_value) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change.map(|cb| {
+            Callback::new(move |value: Option<f64>| {
+                if let Some(value) = value {
+                    cb.run(value);
+                }
+            })
+        }),
+    });
+    let value = Signal::derive(move || value_signal.get().unwrap_or(0.0));
+
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: root_ref,
+    });
+
+    let initial_value = value.get_untracked();
+    use_form_reset(bubble_ref, move || {
+        set_value.run(Some(initial_value));
+    });
+
+    let hovered_value = RwSignal::new(None);
+    let context = RatingContextValue {
+        value,
+        on_value_change: Callback::new(move |value: f64| {
+            set_value.run(Some(value));
+        }),
+        hovered_value,
+        max,
+        allow_half,
+        read_only,
+        disabled,
+        direction,
+        item_refs: StoredValue::new(Vec::new()),
+    };
+
+    let name = Signal::derive(move || name.get().or_else(|| form_control.name.get()));
+    let form = Signal::derive(move || form.get());
+    let on_pointer_leave_stored = StoredValue::new(on_pointer_leave);
+
+    view! {
+        <Provider value=context>
+            <AttributeInterceptor let:attrs>
+                <Primitive
+                    element=html::div
+                    as_child=as_child
+                    node_ref=composed_ref
+                    attr:id=move || form_control.id.get()
+                    attr:role=move || if read_only.get() { "img" } else { "radiogroup" }
+                    attr:aria-label=move || read_only.get().then(|| value_label(value.get(), max.get()))
+                    attr:aria-required=move || (required.get() && !read_only.get()).then_some("true")
+                    attr:aria-disabled=move || (disabled.get() && !read_only.get()).then_some("true")
+                    {..form_control.into_attrs()}
+                    attr:data-disabled=data_attr(disabled)
+                    attr:data-readonly=data_attr(read_only)
+                    attr:dir=move || direction.get().to_string()
+                    on:pointerleave=move |event: ev::PointerEvent| {
+                        if let Some(Some(cb)) = on_pointer_leave_stored.try_get_value() {
+                            cb.run(event);
+                        }
+                        hovered_value.set(None);
+                    }
+                    {..attrs}
+                >
+                    {children.with_value(|children| children())}
+                </Primitive>
+            </AttributeInterceptor>
+
+            <RatingBubbleInput
+                node_ref=bubble_ref
+                value=value
+                name=name
+                form=form
+                disabled=disabled
+                required=required
+            />
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * RatingBubbleInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Input that holds the value for forms and constraint validation. It is empty without a rating,
+/// so `required` fails until one is picked.
+#[component]
+fn RatingBubbleInput(
+    node_ref: AnyNodeRef,
+    value: Signal<f64>,
+    name: Signal<Option<String>>,
+    form: Signal<Option<String>>,
+    disabled: Signal<bool>,
+    required: Signal<bool>,
+) -> impl IntoView {
+    let input_value = Memo::new(move |_| {
+        let value = value.get();
+        if value > 0.0 {
+            value.to_string()
+        } else {
+            String::new()
+        }
+    });
+    let prev_value = StoredValue::new(input_value.get_untracked());
+
+    // Bubble value change to parent forms
+    Effect::new(move |_| {
+        let current = input_value.get();
+        let previous = prev_value.try_get_value().unwrap_or_default();
+        let _ = prev_value.try_set_value(current.clone());
+
+        if previous != current
+            && let Some(input_el) = node_ref.get()
+        {
+            let input_el: web_sys::HtmlInputElement = (*input_el).clone().unchecked_into();
+            input_el.set_value(&current);
+            let event_init = web_sys::EventInit::new();
+            event_init.set_bubbles(true);
+            let event = web_sys::Event::new_with_event_init_dict("change", &event_init)
+                .expect("Event should be created.");
+            let _ = input_el.dispatch_event(&event);
+        }
+    });
+
+    view! {
+        <input
+            node_ref=node_ref
+            aria-hidden="true"
+            tabindex="-1"
+            name=move || name.get()
+            form=move || form.get()
+            value=move || input_value.get()
+            disabled=move || disabled.get()
+            required=move || required.get()
+            style=VISUALLY_HIDDEN_STYLES_STR
+        />
+    }
+}
@@ -0,0 +1,164 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * RatingItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// An item of the [`Rating`], e.g. a star.
+///
+/// Renders as a `<span>` with `role="radio"`, checked when it holds the value. Must be a
+/// descendant of [`Rating`].
+#[component]
+pub fn RatingItem(
+    /// 1-based position of the item, up to the `max` of the [`Rating`].
+    index: usize,
+    #[prop(into, optional)] on_pointer_move: Option<Callback<ev::PointerEvent>>,
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<RatingContextValue>();
+    let item_ref = AnyNodeRef::new();
+    let composed_ref = use_composed_refs(vec![node_ref, item_ref]);
+
+    context
+        .item_refs
+        .update_value(|item_refs| item_refs.push((index, item_ref)));
+    Owner::on_cleanup(move || {
+        context.item_refs.try_update_value(|item_refs| {
+            item_refs.retain(|(item_index, _)| *item_index != index);
+        });
+    });
+
+    let is_interactive =
+        Signal::derive(move || !context.read_only.get() && !context.disabled.get());
+    let checked = Signal::derive(move || {
+        let value = context.value.get();
+        value > 0.0 && focus_index(value, context.max.get()) == index
+    });
+    let focusable = Signal::derive(move || {
+        is_interactive.get() && focus_index(context.value.get(), context.max.get()) == index
+    });
+    let highlighted = Signal::derive(move || {
+        context
+            .hovered_value
+            .get()
+            .is_some_and(|hovered| item_state(index, hovered) != "empty")
+    });
+    let aria_label = Signal::derive(move || {
+        let value = if checked.get() {
+            context.value.get()
+        } else {
+            index as f64
+        };
+        value_label(value, context.max.get())
+    });
+
+    // Value under the pointer, from the start edge of the item in the reading direction.
+    let value_at = move |event: &ev::MouseEvent| {
+        let target: web_sys::Element = event.current_target()?.dyn_into().ok()?;
+        let rect = target.get_bounding_client_rect();
+        if rect.width() <= 0.0 {
+            return Some(index as f64);
+        }
+        let fraction = (event.client_x() as f64 - rect.left()) / rect.width();
+        let fraction = match context.direction.get_untracked() {
+            Direction::Ltr => fraction,
+            Direction::Rtl => 1.0 - fraction,
+        };
+        Some(value_at_pointer(
+            index,
+            fraction,
+            context.allow_half.get_untracked(),
+        ))
+    };
+
+    let on_pointer_move_stored = StoredValue::new(on_pointer_move);
+    let on_click_stored = StoredValue::new(on_click);
+    let on_key_down_stored = StoredValue::new(on_key_down);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <Primitive
+                element=html::span
+                as_child=as_child
+                node_ref=composed_ref
+                attr:role=move || (!context.read_only.get()).then_some("radio")
+                attr:aria-checked=move || (!context.read_only.get()).then(|| checked.get().to_string())
+                attr:aria-label=move || (!context.read_only.get()).then(|| aria_label.get())
+                attr:aria-setsize=move || (!context.read_only.get()).then(|| context.max.get())
+                attr:aria-posinset=move || (!context.read_only.get()).then_some(index)
+                attr:aria-disabled=move || (context.disabled.get() && !context.read_only.get()).then_some("true")
+                attr:aria-hidden=move || context.read_only.get().then_some("true")
+                attr:tabindex=move || is_interactive.get().then(|| if focusable.get() { "0" } else { "-1" })
+                attr:data-state=move || item_state(index, context.display_value())
+                attr:data-highlighted=data_attr(highlighted)
+                attr:data-disabled=data_attr(context.disabled)
+                attr:data-readonly=data_attr(context.read_only)
+                on:pointermove=move |event: ev::PointerEvent| {
+                    if let Some(Some(cb)) = on_pointer_move_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented()
+                        && context.is_interactive()
+                        && let Some(value) = value_at(&event)
+                    {
+                        context.hovered_value.set(Some(value));
+                    }
+                }
+                on:click=move |event: ev::MouseEvent| {
+                    if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented()
+                        && context.is_interactive()
+                        && let Some(value) = value_at(&event)
+                    {
+                        context.select(value);
+                    }
+                }
+                on:keydown=move |event: ev::KeyboardEvent| {
+                    if let Some(Some(cb)) = on_key_down_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if event.default_prevented() || !context.is_interactive() {
+                        return;
+                    }
+
+                    let value = context.value.get_untracked();
+                    let step = context.step();
+                    let max = context.max.get_untracked();
+                    let forward = match context.direction.get_untracked() {
+                        Direction::Ltr => 1,
+                        Direction::Rtl => -1,
+                    };
+                    let next_value = match event.key().as_str() {
+                        "ArrowRight" => step_value(value, forward, step, max),
+                        "ArrowLeft" => step_value(value, -forward, step, max),
+                        "ArrowUp" => step_value(value, 1, step, max),
+                        "ArrowDown" => step_value(value, -1, step, max),
+                        "Home" => step,
+                        "End" => max as f64,
+                        " " => index as f64,
+                        // According to WAI ARIA, radio groups don't activate items on enter keypress.
+                        "Enter" => {
+                            event.prevent_default();
+                            return;
+                        }
+                        _ => return,
+                    };
+                    event.prevent_default();
+                    context.hovered_value.set(None);
+                    context.select(next_value);
+                }
+                {..attrs}
+            >
+                {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+            </Primitive>
+        </AttributeInterceptor>
+    }
+}
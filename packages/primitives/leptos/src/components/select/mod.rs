@@ -41,7 +41,8 @@
 //! - Grouped items with labels
 //! - Popper or item-aligned positioning
 //! - Scroll buttons for overflow
-//! - Native form participation via hidden `<select>`
+//! - Native form participation via hidden `<select>`, with form reset support
+//! - Validation and `aria-describedby` wiring inside a `FormField`
 //! - Full keyboard navigation
 //!
 //! # Keyboard Interactions
@@ -79,7 +80,6 @@ use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI32, Ordering};
 
-use crate::support::aria_hidden::{hide_others, unhide_others};
 use crate::support::collection::{
     CollectionItemSlot, CollectionItemValue, CollectionProvider, CollectionSlot,
     provide_collection_scope, use_collection, use_collection_scope,
@@ -89,7 +89,11 @@ use crate::support::direction::{Direction, use_direction};
 use crate::support::dismissable_layer::DismissableLayer;
 use crate::support::focus_guards::use_focus_guards;
 use crate::support::focus_scope::FocusScope;
+use crate::support::form_control::{
+    FormControlAttributes, UseFormControlParams, use_form_control, use_form_reset,
+};
 use crate::support::id::use_id;
+use crate::support::layer_stack::use_inert_others;
 use crate::support::popper::{
     Popper, PopperAnchor, PopperArrow, PopperContent, UpdatePositionStrategy, provide_popper_scope,
     use_popper_scope,
@@ -164,6 +168,7 @@ struct SelectContextValue {
     dir: Signal<Direction>,
     trigger_pointer_down_pos_ref: StoredValue<Option<(f64, f64)>>,
     disabled: Signal<bool>,
+    form_control: FormControlAttributes,
 }
 
 #[derive(Clone, Copy)]
@@ -48,6 +48,17 @@ pub fn Select(
     let disabled_state = prop_or_default(disabled);
     let required_state = prop_or_default(required);
 
+    let initial_value = value_state.get_untracked();
+    use_form_reset(trigger_ref, move || {
+        set_value.run(initial_value.clone());
+    });
+
+    let bubble_ref = AnyNodeRef::new();
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: trigger_ref,
+    });
+
     let context = SelectContextValue {
         trigger_ref,
         value_node_ref,
@@ -65,6 +76,7 @@ pub fn Select(
         dir: direction,
         trigger_pointer_down_pos_ref,
         disabled: disabled_state,
+        form_control,
     };
 
     // Native select for form integration
@@ -81,8 +93,13 @@ pub fn Select(
                     </CollectionProvider<SelectItemData>>
 
                     <SelectBubbleInput
+                        node_ref=bubble_ref
                         value=value_state
-                        name=Signal::derive(move || name.try_with_value(|n| n.get()).flatten())
+                        name=Signal::derive(move || {
+                            name.try_with_value(|n| n.get())
+                                .flatten()
+                                .or_else(|| form_control.name.get())
+                        })
                         auto_complete=Signal::derive(move || auto_complete.try_with_value(|a| a.get()).flatten())
                         form=Signal::derive(move || form.try_with_value(|f| f.get()).flatten())
                         disabled=disabled_state
@@ -170,6 +187,8 @@ pub fn SelectTrigger(
                     attr:disabled=data_attr(is_disabled)
                     attr:data-disabled=data_attr(is_disabled)
                     attr:data-placeholder=move || should_show_placeholder(&context.value.get()).then_some("")
+                    attr:id=move || context.form_control.id.get()
+                    {..context.form_control.into_attrs()}
                     on:click=compose_callbacks(
                         on_click_stored.get_value(),
                         Some(Callback::new(move |event: ev::MouseEvent| {
@@ -72,6 +72,7 @@ pub fn SelectArrow(
 /// Hidden native <select> element for form integration.
 #[component]
 pub(super) fn SelectBubbleInput(
+    node_ref: AnyNodeRef,
     value: Signal<Option<String>>,
     name: Signal<Option<String>>,
     auto_complete: Signal<Option<String>>,
@@ -79,7 +80,6 @@ pub(super) fn SelectBubbleInput(
     disabled: Signal<bool>,
     required: Signal<bool>,
 ) -> impl IntoView {
-    let select_ref = AnyNodeRef::new();
     let prev_value: StoredValue<Option<String>> = StoredValue::new(None);
 
     // Bubble value change to parent forms
@@ -89,7 +89,7 @@ pub(super) fn SelectBubbleInput(
         let _ = prev_value.try_set_value(current_value.clone());
 
         if previous != current_value
-            && let Some(select_el) = select_ref.get()
+            && let Some(select_el) = node_ref.get()
         {
             let select_el: web_sys::HtmlSelectElement = (*select_el).clone().unchecked_into();
             select_el.set_value(&current_value.clone().unwrap_or_default());
@@ -103,7 +103,7 @@ pub(super) fn SelectBubbleInput(
 
     view! {
         <select
-            node_ref=select_ref
+            node_ref=node_ref
             aria-hidden="true"
             tabindex="-1"
             name=move || name.get()
@@ -25,6 +25,7 @@
 //! - RTL support
 //! - Min step distance between thumbs
 //! - Native form participation via hidden `<input>`
+//! - Validation and `aria-describedby` wiring inside a `FormField`
 //! - Touch device support with pointer capture
 //!
 //! # Keyboard Interactions
@@ -63,6 +64,7 @@ use crate::support::collection::{
 };
 use crate::support::compose_refs::use_composed_refs;
 use crate::support::direction::{Direction, use_direction};
+use crate::support::form_control::{UseFormControlParams, use_form_control};
 use crate::support::primitive::{
     Primitive, compose_callbacks, data_attr, prop_or, prop_or_default,
 };
@@ -298,6 +300,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn decimal_count_multiple_decimals() {
         assert_eq!(get_decimal_count(0.01), 2);
         assert_eq!(get_decimal_count(0.001), 3);
@@ -313,12 +316,14 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn round_value_one_decimal() {
         assert_eq!(round_value(3.14, 1), 3.1);
         assert_eq!(round_value(3.15, 1), 3.2);
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn round_value_two_decimals() {
         assert_eq!(round_value(3.141, 2), 3.14);
         assert_eq!(round_value(3.145, 2), 3.15);
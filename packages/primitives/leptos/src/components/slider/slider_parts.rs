@@ -245,17 +245,27 @@ fn SliderThumbImpl(
         }
     });
 
+    let bubble_ref = AnyNodeRef::new();
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: thumb_ref,
+    });
+
     let computed_name = Memo::new(move |_| {
         if let Some(thumb_name) = name.get() {
             Some(thumb_name)
         } else {
-            context.name.get().map(|n| {
-                if context.values.get().len() > 1 {
-                    format!("{}[]", n)
-                } else {
-                    n
-                }
-            })
+            context
+                .name
+                .get()
+                .or_else(|| form_control.name.get())
+                .map(|n| {
+                    if context.values.get().len() > 1 {
+                        format!("{}[]", n)
+                    } else {
+                        n
+                    }
+                })
         }
     });
 
@@ -274,6 +284,7 @@ fn SliderThumbImpl(
                 attr:data-orientation=move || context.orientation.get().to_string()
                 attr:data-disabled=data_attr(context.disabled)
                 attr:tabindex=move || if context.disabled.get() { None } else { Some("0") }
+                {..form_control.into_attrs()}
                 style:transform="var(--radix-slider-thumb-transform)"
                 style:position="absolute"
                 style:left=move || (orientation.get().start_edge == "left").then(|| format!("calc({}% + {}px)", percent.get(), thumb_in_bounds_offset.get()))
@@ -294,6 +305,7 @@ fn SliderThumbImpl(
                 {children.with_value(|children| children.as_ref().map(|children| children()))}
                 <Show when=move || is_form_control.get()>
                     <SliderBubbleInput
+                        node_ref=bubble_ref
                         name=Signal::derive(move || computed_name.get())
                         form=Signal::derive(move || context.form.get())
                         value=Signal::derive(move || value.get())
@@ -310,16 +322,16 @@ fn SliderThumbImpl(
 
 #[component]
 fn SliderBubbleInput(
+    #[prop(into)] node_ref: AnyNodeRef,
     #[prop(into)] name: Signal<Option<String>>,
     #[prop(into)] form: Signal<Option<String>>,
     #[prop(into)] value: Signal<Option<f64>>,
 ) -> impl IntoView {
-    let input_ref = AnyNodeRef::new();
     let prev_value = use_previous(value);
 
     // Bubble value change to parents (e.g. form change event)
     Effect::new(move |_| {
-        if let Some(input_node) = input_ref.get() {
+        if let Some(input_node) = node_ref.get() {
             let input: &web_sys::HtmlInputElement = input_node.unchecked_ref();
             let prev = prev_value.get();
             let current = value.get();
@@ -347,7 +359,7 @@ fn SliderBubbleInput(
         // We use display:none instead to hide the input visually while preserving
         // form participation (matching the React reference approach).
         <input
-            node_ref=input_ref
+            node_ref=node_ref
             name=move || name.get()
             form=move || form.get()
             value=move || value.get().map(|v| v.to_string()).unwrap_or_default()
@@ -18,6 +18,8 @@
 //!
 //! - Controlled and uncontrolled checked state
 //! - Native form participation via hidden `<input>`
+//! - Form reset support
+//! - Validation and `aria-describedby` wiring inside a `FormField`
 //! - Keyboard accessible (Space and Enter toggle via native button)
 //!
 //! # Data Attributes
@@ -37,10 +39,11 @@
 //! | `data-disabled` | Present when disabled |
 
 use crate::support::compose_refs::use_composed_refs;
+use crate::support::form_control::{
+    CheckboxBubbleInput, UseFormControlParams, use_form_control, use_form_reset,
+};
 use crate::support::primitive::{Primitive, adapt_callback, compose_callbacks, data_attr, prop_or};
 use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
-use crate::support::use_previous::use_previous;
-use crate::support::use_size::use_size;
 use leptos::{
     attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
 };
@@ -77,7 +80,6 @@ pub fn Switch(
 ) -> impl IntoView {
     let children = StoredValue::new(children);
 
-    let name = Signal::derive(move || name.get());
     let required = prop_or(required, false);
     let disabled = prop_or(disabled, false);
     let value = prop_or(value, "on".into());
@@ -106,6 +108,18 @@ pub fn Switch(
     });
     let checked = Signal::derive(move || checked.get().unwrap_or(false));
 
+    let initial_checked = checked.get_untracked();
+    use_form_reset(button_ref, move || {
+        set_checked.run(Some(initial_checked));
+    });
+
+    let bubble_ref = AnyNodeRef::new();
+    let form_control = use_form_control(UseFormControlParams {
+        native_ref: bubble_ref,
+        focus_ref: button_ref,
+    });
+    let name = Signal::derive(move || name.get().or_else(|| form_control.name.get()));
+
     let context_value = SwitchContextValue { checked, disabled };
 
     view! {
@@ -126,6 +140,8 @@ pub fn Switch(
                     attr:data-disabled=data_attr(disabled)
                     attr:disabled=data_attr(disabled)
                     attr:value=move || value.get()
+                    attr:id=move || form_control.id.get()
+                    {..form_control.into_attrs()}
                     on:click=compose_callbacks(on_click, Some(Callback::new(move |event: ev::MouseEvent| {
                         if !disabled.get() {
                             set_checked.run(Some(!checked.get()));
@@ -144,9 +160,10 @@ pub fn Switch(
                 </Primitive>
             </AttributeInterceptor>
             <Show when=move || is_form_control.get()>
-                <BubbleInput
-                    attr:name=move || name.get()
+                <CheckboxBubbleInput
+                    node_ref=bubble_ref
                     control_ref=button_ref
+                    name=name
                     bubbles=Signal::derive(|| true)
                     value=value
                     checked=checked
@@ -186,64 +203,6 @@ pub fn SwitchThumb(
     }
 }
 
-#[component]
-fn BubbleInput(
-    #[prop(into)] control_ref: AnyNodeRef,
-    #[prop(into)] checked: Signal<bool>,
-    #[prop(into)] bubbles: Signal<bool>,
-    #[prop(into)] required: Signal<bool>,
-    #[prop(into)] disabled: Signal<bool>,
-    #[prop(into)] value: Signal<String>,
-    #[prop(into, optional)] form: Signal<Option<String>>,
-) -> impl IntoView {
-    let node_ref: NodeRef<html::Input> = NodeRef::new();
-    let prev_checked = use_previous(checked);
-    let control_size = use_size(control_ref);
-
-    // Bubble checked change to parents
-    Effect::new(move |_| {
-        if let Some(input) = node_ref.get() {
-            if prev_checked.get() != checked.get() {
-                let init = web_sys::EventInit::new();
-                init.set_bubbles(bubbles.get());
-
-                let event = web_sys::Event::new_with_event_init_dict("click", &init)
-                    .expect("Click event should be instantiated.");
-
-                input.set_checked(checked.get());
-
-                input
-                    .dispatch_event(&event)
-                    .expect("Click event should be dispatched.");
-            }
-        }
-    });
-
-    view! {
-        <input
-            node_ref=node_ref
-            type="checkbox"
-            aria-hidden="true"
-            checked=move || checked.get().then_some("")
-            required=move || required.get().then_some("")
-            disabled=move || disabled.get().then_some("")
-            value=move || value.get()
-            form=move || form.get()
-            tabindex="-1"
-            // We transform because the input is absolutely positioned, but we have
-            // rendered it **after** the button. This pulls it back to sit on top
-            // of the button.
-            style:transform="translateX(-100%)"
-            style:width=move || control_size.get().map(|size| format!("{}px", size.width))
-            style:height=move || control_size.get().map(|size| format!("{}px", size.height))
-            style:position="absolute"
-            style:pointer-events="none"
-            style:opacity="0"
-            style:margin="0px"
-        />
-    }
-}
-
 fn get_state(checked: bool) -> String {
     (match checked {
         true => "checked",
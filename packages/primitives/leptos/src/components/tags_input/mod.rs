@@ -0,0 +1,314 @@
+//! Tags input for free-form token entry.
+//!
+//! A text input that turns typed or pasted text into a list of tags, e.g. email recipients or
+//! labels. Unlike `Combobox` chips, tags are not picked from a list of options.
+//!
+//! # Anatomy
+//!
+//! ```text
+//! <TagsInput>
+//!     <TagsInputItem>
+//!         <TagsInputItemDelete />
+//!     </TagsInputItem>
+//!     <TagsInputInput />
+//! </TagsInput>
+//! ```
+//!
+//! # Features
+//!
+//! - Controlled and uncontrolled values
+//! - Adds tags on Enter, on typed delimiters and, optionally, on blur
+//! - Splits pasted text on delimiters and line breaks
+//! - Maximum tag count, duplicate rejection and per-tag validation, reported via `on_invalid`
+//! - Native form participation via a hidden input per value, with form reset support
+//!
+//! # Keyboard Interactions
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | Enter | Adds the typed text as a tag |
+//! | Delimiter | Adds the typed text as a tag |
+//! | Backspace | At the start of the input, highlights the last tag, then deletes it |
+//! | Delete | Deletes the highlighted tag |
+//! | ArrowLeft | At the start of the input, highlights the previous tag |
+//! | ArrowRight | Highlights the next tag, then returns to the input |
+//! | Escape | Clears the highlight |
+//!
+//! # Data Attributes
+//!
+//! **TagsInput:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-disabled` | Present when disabled |
+//! | `data-invalid` | Present when the last added text was rejected |
+//!
+//! **TagsInputItem:**
+//!
+//! | Attribute | Values |
+//! |-----------|--------|
+//! | `data-highlighted` | Present when highlighted for deletion |
+//! | `data-disabled` | Present when disabled |
+
+use crate::support::compose_refs::use_composed_refs;
+use crate::support::direction::{Direction, use_direction};
+use crate::support::form_control::use_form_reset;
+use crate::support::primitive::{Primitive, VoidPrimitive, data_attr, prop_or, prop_or_default};
+use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
+use leptos::{
+    attribute_interceptor::AttributeInterceptor, context::Provider, ev, html, prelude::*,
+};
+use leptos_node_ref::AnyNodeRef;
+use wasm_bindgen::JsCast;
+
+mod tags_input;
+mod tags_input_input;
+mod tags_input_item;
+
+pub use tags_input::*;
+pub use tags_input_input::*;
+pub use tags_input_item::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * Types
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Why a tag was not added, passed to `on_invalid` of [`TagsInput`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TagRejection {
+    /// The maximum number of tags is reached.
+    TooMany,
+    /// The tag is already present and duplicates are not allowed.
+    Duplicate,
+    /// `validate` returned `false` for the tag.
+    Invalid,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Contexts
+ * -----------------------------------------------------------------------------------------------*/
+
+#[derive(Clone, Copy)]
+struct TagsInputContextValue {
+    input_ref: AnyNodeRef,
+    values: Signal<Vec<String>>,
+    on_values_change: Callback<Vec<String>>,
+    input_value: RwSignal<String>,
+    /// Index of the tag highlighted for deletion. `None` means the input is active.
+    highlighted_index: RwSignal<Option<usize>>,
+    /// Whether the last added text was rejected.
+    invalid: RwSignal<bool>,
+    delimiters: Signal<Vec<char>>,
+    add_on_paste: Signal<bool>,
+    add_on_blur: Signal<bool>,
+    max: Signal<Option<usize>>,
+    allow_duplicates: Signal<bool>,
+    validate: Option<Callback<String, bool>>,
+    on_invalid: Option<Callback<(String, TagRejection)>>,
+    disabled: Signal<bool>,
+    required: Signal<bool>,
+    dir: Signal<Direction>,
+}
+
+impl TagsInputContextValue {
+    /// Move focus to the tags input element.
+    fn focus_input(&self) {
+        if let Some(input_el) = self.input_ref.get_untracked() {
+            let el: &web_sys::HtmlElement = (*input_el).unchecked_ref();
+            let _ = el.focus();
+        }
+    }
+
+    /// Adds `candidates` that pass the rules and reports the others through `on_invalid`.
+    /// Returns whether all candidates were added.
+    fn add(&self, candidates: Vec<String>) -> bool {
+        let rules = TagRules {
+            max: self.max.get_untracked(),
+            allow_duplicates: self.allow_duplicates.get_untracked(),
+            validate: &|tag| {
+                self.validate
+                    .is_none_or(|validate| validate.run(tag.to_string()))
+            },
+        };
+        let current_values = self.values.get_untracked();
+        let (values, rejections) = add_tags(&current_values, candidates, &rules);
+
+        if values.len() != current_values.len() {
+            self.on_values_change.run(values);
+        }
+        self.invalid.set(!rejections.is_empty());
+        if let Some(on_invalid) = self.on_invalid {
+            for rejection in rejections.iter().cloned() {
+                on_invalid.run(rejection);
+            }
+        }
+        rejections.is_empty()
+    }
+
+    /// Adds the text of the input, clearing it when all its tags were added.
+    fn commit_input(&self) {
+        let text = self.input_value.get_untracked();
+        if text.trim().is_empty() {
+            return;
+        }
+        if self.add(split_tags(&text, &self.delimiters.get_untracked())) {
+            self.input_value.set(String::new());
+        }
+    }
+
+    /// Removes the tag at `index` and moves the highlight to its neighbour.
+    fn remove_at(&self, index: usize) {
+        let mut values = self.values.get_untracked();
+        if index >= values.len() {
+            return;
+        }
+        values.remove(index);
+        if self.highlighted_index.get_untracked().is_some() {
+            self.highlighted_index
+                .set(highlight_after_removal(index, values.len()));
+        }
+        self.on_values_change.run(values);
+    }
+}
+
+#[derive(Clone)]
+struct TagsInputItemContextValue {
+    value: String,
+    index: Signal<Option<usize>>,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Utilities
+ * -----------------------------------------------------------------------------------------------*/
+
+const DEFAULT_DELIMITERS: [char; 1] = [','];
+
+struct TagRules<'a> {
+    max: Option<usize>,
+    allow_duplicates: bool,
+    validate: &'a dyn Fn(&str) -> bool,
+}
+
+/// Splits `text` into trimmed, non-empty tags on `delimiters` and line breaks.
+fn split_tags(text: &str, delimiters: &[char]) -> Vec<String> {
+    text.split(|c: char| delimiters.contains(&c) || c == '\n' || c == '\r')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Appends the `candidates` that pass `rules` to `values`. Returns the new values and the
+/// rejected candidates.
+fn add_tags(
+    values: &[String],
+    candidates: Vec<String>,
+    rules: &TagRules,
+) -> (Vec<String>, Vec<(String, TagRejection)>) {
+    let mut values = values.to_vec();
+    let mut rejections = Vec::new();
+
+    for candidate in candidates {
+        let candidate = candidate.trim().to_string();
+        if candidate.is_empty() {
+            continue;
+        }
+        let rejection = if rules.max.is_some_and(|max| values.len() >= max) {
+            Some(TagRejection::TooMany)
+        } else if !rules.allow_duplicates && values.contains(&candidate) {
+            Some(TagRejection::Duplicate)
+        } else if !(rules.validate)(&candidate) {
+            Some(TagRejection::Invalid)
+        } else {
+            None
+        };
+        match rejection {
+            Some(rejection) => rejections.push((candidate, rejection)),
+            None => values.push(candidate),
+        }
+    }
+
+    (values, rejections)
+}
+
+/// Highlight after removing the tag at `index`, with `len` tags left: the tag that took its
+/// place, else the new last tag.
+fn highlight_after_removal(index: usize, len: usize) -> Option<usize> {
+    if len == 0 {
+        None
+    } else {
+        Some(index.min(len - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn rules(max: Option<usize>, allow_duplicates: bool) -> TagRules<'static> {
+        TagRules {
+            max,
+            allow_duplicates,
+            validate: &|tag| tag.contains('@'),
+        }
+    }
+
+    #[test]
+    fn splits_on_delimiters_and_line_breaks() {
+        assert_eq!(
+            split_tags(" a@x.com, b@x.com;c@x.com\r\n\nd@x.com ,", &[',', ';']),
+            tags(&["a@x.com", "b@x.com", "c@x.com", "d@x.com"])
+        );
+        assert_eq!(split_tags(" , ", &DEFAULT_DELIMITERS), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_duplicates_and_invalid_tags() {
+        let (values, rejections) = add_tags(
+            &tags(&["a@x.com"]),
+            tags(&["a@x.com", "nope", " b@x.com "]),
+            &rules(None, false),
+        );
+        assert_eq!(values, tags(&["a@x.com", "b@x.com"]));
+        assert_eq!(
+            rejections,
+            vec![
+                ("a@x.com".to_string(), TagRejection::Duplicate),
+                ("nope".to_string(), TagRejection::Invalid),
+            ]
+        );
+    }
+
+    #[test]
+    fn allows_duplicates_when_enabled() {
+        let (values, rejections) =
+            add_tags(&tags(&["a@x.com"]), tags(&["a@x.com"]), &rules(None, true));
+        assert_eq!(values, tags(&["a@x.com", "a@x.com"]));
+        assert!(rejections.is_empty());
+    }
+
+    #[test]
+    fn rejects_tags_over_max() {
+        let (values, rejections) = add_tags(
+            &tags(&["a@x.com"]),
+            tags(&["b@x.com", "c@x.com"]),
+            &rules(Some(2), false),
+        );
+        assert_eq!(values, tags(&["a@x.com", "b@x.com"]));
+        assert_eq!(
+            rejections,
+            vec![("c@x.com".to_string(), TagRejection::TooMany)]
+        );
+    }
+
+    #[test]
+    fn moves_highlight_after_removal() {
+        assert_eq!(highlight_after_removal(1, 3), Some(1));
+        assert_eq!(highlight_after_removal(2, 2), Some(1));
+        assert_eq!(highlight_after_removal(0, 0), None);
+    }
+}
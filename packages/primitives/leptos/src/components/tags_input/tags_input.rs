@@ -0,0 +1,126 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * TagsInput (Root)
+ * -----------------------------------------------------------------------------------------------*/
+
+#[component]
+pub fn TagsInput(
+    #[prop(into, optional)] value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] default_value: MaybeProp<Vec<String>>,
+    #[prop(into, optional)] on_value_change: Option<Callback<Vec<String>>>,
+    /// Characters that add the typed text as a tag. Defaults to `,`.
+    #[prop(into, optional)]
+    delimiters: MaybeProp<Vec<char>>,
+    /// Whether pasted text is split into tags. Defaults to `true`.
+    #[prop(into, optional)]
+    add_on_paste: MaybeProp<bool>,
+    /// Whether the typed text is added as a tag when the input loses focus.
+    #[prop(into, optional)]
+    add_on_blur: MaybeProp<bool>,
+    /// Maximum number of tags.
+    #[prop(into, optional)]
+    max: MaybeProp<usize>,
+    #[prop(into, optional)] allow_duplicates: MaybeProp<bool>,
+    /// Returns whether a tag may be added.
+    #[prop(into, optional)]
+    validate: Option<Callback<String, bool>>,
+    /// Called for each tag that was not added.
+    #[prop(into, optional)]
+    on_invalid: Option<Callback<(String, TagRejection)>>,
+    #[prop(into, optional)] disabled: MaybeProp<bool>,
+    #[prop(into, optional)] required: MaybeProp<bool>,
+    #[prop(into, optional)] name: MaybeProp<String>,
+    #[prop(into, optional)] form: MaybeProp<String>,
+    #[prop(into, optional)] dir: MaybeProp<Direction>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    children: ChildrenFn,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let input_ref = AnyNodeRef::new();
+    let direction = use_direction(dir);
+
+    let (values_signal, set_values) = use_controllable_state(UseControllableStateParams {
+        prop: value,
+        default_prop: default_value,
+        on_change: on_value_change.map(|cb| {
+            Callback::new(move |value: Option<Vec<String>>| {
+                if let Some(value) = value {
+                    cb.run(value);
+                }
+            })
+        }),
+    });
+    let values = Signal::derive(move || values_signal.get().unwrap_or_default());
+
+    let disabled = prop_or_default(disabled);
+    let highlighted_index = RwSignal::new(None);
+    let input_value = RwSignal::new(String::new());
+    let invalid = RwSignal::new(false);
+
+    let initial_values = values.get_untracked();
+    use_form_reset(input_ref, move || {
+        set_values.run(Some(initial_values.clone()));
+        input_value.set(String::new());
+        highlighted_index.set(None);
+        invalid.set(false);
+    });
+
+    let context = TagsInputContextValue {
+        input_ref,
+        values,
+        on_values_change: Callback::new(move |values: Vec<String>| {
+            set_values.run(Some(values));
+        }),
+        input_value,
+        highlighted_index,
+        invalid,
+        delimiters: prop_or(delimiters, DEFAULT_DELIMITERS.to_vec()),
+        add_on_paste: prop_or(add_on_paste, true),
+        add_on_blur: prop_or_default(add_on_blur),
+        max: Signal::derive(move || max.get()),
+        allow_duplicates: prop_or_default(allow_duplicates),
+        validate,
+        on_invalid,
+        disabled,
+        required: prop_or_default(required),
+        dir: direction,
+    };
+
+    let name = Signal::derive(move || name.get());
+    let form = Signal::derive(move || form.get());
+
+    view! {
+        <Provider value=context>
+            <Primitive
+                element=html::div
+                as_child=as_child
+                node_ref=node_ref
+                attr:dir=move || direction.get().to_string()
+                attr:data-disabled=data_attr(disabled)
+                attr:data-invalid=move || invalid.get().then_some("")
+            >
+                {children.with_value(|children| children())}
+            </Primitive>
+
+            // One input per value, so the form data holds each tag under `name`.
+            <Show when=move || name.get().is_some()>
+                <For
+                    each=move || values.get().into_iter().enumerate()
+                    key=|value| value.clone()
+                    let:value
+                >
+                    <input
+                        r#type="hidden"
+                        name=move || name.get()
+                        form=move || form.get()
+                        disabled=move || disabled.get()
+                        value=value.1
+                    />
+                </For>
+            </Show>
+        </Provider>
+    }
+}
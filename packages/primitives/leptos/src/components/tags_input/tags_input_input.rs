@@ -0,0 +1,219 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * TagsInputInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Text input for new tags. Focus stays here while tags are highlighted.
+#[component]
+pub fn TagsInputInput(
+    #[prop(into, optional)] on_input: Option<Callback<ev::Event>>,
+    #[prop(into, optional)] on_key_down: Option<Callback<ev::KeyboardEvent>>,
+    #[prop(into, optional)] on_paste: Option<Callback<ev::ClipboardEvent>>,
+    #[prop(into, optional)] on_blur: Option<Callback<ev::FocusEvent>>,
+    #[prop(into, optional)] placeholder: MaybeProp<String>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let context = expect_context::<TagsInputContextValue>();
+    let composed_ref = use_composed_refs(vec![node_ref, context.input_ref]);
+
+    let on_input_stored = StoredValue::new(on_input);
+    let on_key_down_stored = StoredValue::new(on_key_down);
+    let on_paste_stored = StoredValue::new(on_paste);
+    let on_blur_stored = StoredValue::new(on_blur);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <VoidPrimitive
+                element=html::input
+                as_child=as_child
+                node_ref=composed_ref
+                attr:r#type="text"
+                attr:autocomplete="off"
+                attr:aria-invalid=move || context.invalid.get().then_some("true")
+                attr:data-disabled=data_attr(context.disabled)
+                attr:data-tag-highlighted=move || context.highlighted_index.get().is_some().then_some("")
+                attr:disabled=data_attr(context.disabled)
+                // The hidden inputs can't be required, so the input is required while there are no tags.
+                attr:required=move || (context.required.get() && context.values.get().is_empty()).then_some("")
+                attr:placeholder=move || placeholder.get()
+                style:caret-color=move || {
+                    context.highlighted_index.get().is_some().then_some("transparent")
+                }
+                prop:value=move || context.input_value.get()
+                on:input=move |event: ev::Event| {
+                    if let Some(Some(cb)) = on_input_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented() {
+                        let target: web_sys::HtmlInputElement = event.target().unwrap().unchecked_into();
+                        handle_input(target.value(), &context);
+                    }
+                }
+                on:keydown=move |event: ev::KeyboardEvent| {
+                    if let Some(Some(cb)) = on_key_down_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented() && !context.disabled.get_untracked() {
+                        handle_input_keydown(&event, &context);
+                    }
+                }
+                on:paste=move |event: ev::ClipboardEvent| {
+                    if let Some(Some(cb)) = on_paste_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    if !event.default_prevented() && context.add_on_paste.get_untracked() {
+                        handle_paste(&event, &context);
+                    }
+                }
+                on:blur=move |event: ev::FocusEvent| {
+                    if let Some(Some(cb)) = on_blur_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    context.highlighted_index.set(None);
+                    if context.add_on_blur.get_untracked() {
+                        context.commit_input();
+                    }
+                }
+                {..attrs}
+            >
+                {()}
+            </VoidPrimitive>
+        </AttributeInterceptor>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Input handling
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Stores the typed text, adding the text before the last delimiter as tags. Delimiters are
+/// usually caught on keydown, but not e.g. for IME or autofill input.
+fn handle_input(text: String, context: &TagsInputContextValue) {
+    context.highlighted_index.set(None);
+    context.invalid.set(false);
+
+    let delimiters = context.delimiters.get_untracked();
+    match text.rfind(|c: char| delimiters.contains(&c)) {
+        Some(position) => {
+            let (complete, rest) = text.split_at(position);
+            let rest = &rest[rest.chars().next().map_or(0, char::len_utf8)..];
+            if context.add(split_tags(complete, &delimiters)) {
+                context.input_value.set(rest.to_string());
+            } else {
+                context.input_value.set(text);
+            }
+        }
+        None => context.input_value.set(text),
+    }
+}
+
+/// Splits pasted text into tags when it contains a delimiter or line break. Text without either
+/// is pasted as usual.
+fn handle_paste(event: &ev::ClipboardEvent, context: &TagsInputContextValue) {
+    let Some(pasted) = event
+        .clipboard_data()
+        .and_then(|data| data.get_data("text").ok())
+    else {
+        return;
+    };
+    let delimiters = context.delimiters.get_untracked();
+    if !pasted.contains(|c: char| delimiters.contains(&c) || c == '\n' || c == '\r') {
+        return;
+    }
+
+    event.prevent_default();
+    let text = format!("{}{}", context.input_value.get_untracked(), pasted);
+    if context.add(split_tags(&text, &delimiters)) {
+        context.input_value.set(String::new());
+    } else {
+        context.input_value.set(text);
+    }
+}
+
+/// Handles keydown events on the tags input element.
+fn handle_input_keydown(event: &ev::KeyboardEvent, context: &TagsInputContextValue) {
+    let key = event.key();
+    let is_rtl = context.dir.get_untracked() == Direction::Rtl;
+
+    match key.as_str() {
+        "Enter" => {
+            // Only keep Enter from submitting the form when there is text to add.
+            if !context.input_value.get_untracked().trim().is_empty() {
+                event.prevent_default();
+                context.commit_input();
+            }
+        }
+        "Backspace" => handle_backspace(event, context),
+        "Delete" => {
+            if let Some(index) = context.highlighted_index.get_untracked() {
+                event.prevent_default();
+                context.remove_at(index);
+            }
+        }
+        "ArrowLeft" if !is_rtl => handle_arrow_previous(event, context),
+        "ArrowRight" if is_rtl => handle_arrow_previous(event, context),
+        "ArrowLeft" | "ArrowRight" => handle_arrow_next(event, context),
+        "Escape" => {
+            if context.highlighted_index.get_untracked().is_some() {
+                event.prevent_default();
+                context.highlighted_index.set(None);
+            }
+        }
+        _ => {
+            let mut chars = key.chars();
+            if let (Some(c), None) = (chars.next(), chars.next())
+                && context.delimiters.get_untracked().contains(&c)
+            {
+                event.prevent_default();
+                context.commit_input();
+            }
+        }
+    }
+}
+
+/// Whether the caret is at the start of the input with nothing selected.
+fn is_caret_at_start(event: &ev::KeyboardEvent) -> bool {
+    let target: web_sys::HtmlInputElement = event.target().unwrap().unchecked_into();
+    target.selection_start().ok().flatten().unwrap_or(0) == 0
+        && target.selection_end().ok().flatten().unwrap_or(0) == 0
+}
+
+/// Handle Backspace: highlight the last tag, then delete the highlighted tag.
+fn handle_backspace(event: &ev::KeyboardEvent, context: &TagsInputContextValue) {
+    if let Some(index) = context.highlighted_index.get_untracked() {
+        event.prevent_default();
+        context.remove_at(index);
+    } else if is_caret_at_start(event) {
+        let len = context.values.get_untracked().len();
+        if len > 0 {
+            event.prevent_default();
+            context.highlighted_index.set(Some(len - 1));
+        }
+    }
+}
+
+/// Handle the arrow towards the tags: move from the input into the tags, or to the previous tag.
+fn handle_arrow_previous(event: &ev::KeyboardEvent, context: &TagsInputContextValue) {
+    let len = context.values.get_untracked().len();
+    let next = match context.highlighted_index.get_untracked() {
+        Some(index) => Some(index.saturating_sub(1)),
+        None if len > 0 && is_caret_at_start(event) => Some(len - 1),
+        None => return,
+    };
+    event.prevent_default();
+    context.highlighted_index.set(next);
+}
+
+/// Handle the arrow away from the tags: move to the next tag, or back to the input.
+fn handle_arrow_next(event: &ev::KeyboardEvent, context: &TagsInputContextValue) {
+    let Some(index) = context.highlighted_index.get_untracked() else {
+        return;
+    };
+    event.prevent_default();
+    let len = context.values.get_untracked().len();
+    context
+        .highlighted_index
+        .set((index + 1 < len).then_some(index + 1));
+}
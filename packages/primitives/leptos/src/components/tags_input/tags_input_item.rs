@@ -0,0 +1,122 @@
+use super::*;
+
+/* -------------------------------------------------------------------------------------------------
+ * TagsInputItem
+ * -----------------------------------------------------------------------------------------------*/
+
+/// A tag of the [`TagsInput`].
+///
+/// The tag's position is derived from its `value` prop's position in the values. An explicit
+/// `index` prop can be provided when values repeat, e.g. with `allow_duplicates`.
+#[component]
+pub fn TagsInputItem(
+    #[prop(into)] value: String,
+    /// Optional explicit index override. When omitted, the index is derived from
+    /// the tag's value position in the values.
+    #[prop(into, optional)]
+    index: MaybeProp<usize>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<TagsInputContextValue>();
+    let item_value = StoredValue::new(value.clone());
+
+    let resolved_index = Signal::derive(move || {
+        index.get().or_else(|| {
+            item_value
+                .try_get_value()
+                .and_then(|value| context.values.get().iter().position(|v| v == &value))
+        })
+    });
+
+    let is_highlighted = Signal::derive(move || {
+        resolved_index
+            .get()
+            .is_some_and(|index| context.highlighted_index.get() == Some(index))
+    });
+
+    let item_context = TagsInputItemContextValue {
+        value,
+        index: resolved_index,
+    };
+
+    view! {
+        <Provider value=item_context>
+            <AttributeInterceptor let:attrs>
+                <Primitive
+                    element=html::span
+                    as_child=as_child
+                    node_ref=node_ref
+                    attr:data-disabled=data_attr(context.disabled)
+                    attr:data-highlighted=move || is_highlighted.get().then_some("")
+                    on:click=move |_: ev::MouseEvent| {
+                        if !context.disabled.get_untracked() {
+                            context.highlighted_index.set(resolved_index.get_untracked());
+                            context.focus_input();
+                        }
+                    }
+                    {..attrs}
+                >
+                    {children.try_with_value(|children| children.as_ref().map(|c| c()))}
+                </Primitive>
+            </AttributeInterceptor>
+        </Provider>
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * TagsInputItemDelete
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Button to delete the enclosing [`TagsInputItem`].
+#[component]
+pub fn TagsInputItemDelete(
+    #[prop(into, optional)] on_click: Option<Callback<ev::MouseEvent>>,
+    #[prop(into, optional)] as_child: MaybeProp<bool>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let children = StoredValue::new(children);
+
+    let context = expect_context::<TagsInputContextValue>();
+    let item_context = expect_context::<TagsInputItemContextValue>();
+    let aria_label = format!("Remove {}", item_context.value);
+    let item_index = item_context.index;
+    let on_click_stored = StoredValue::new(on_click);
+
+    view! {
+        <AttributeInterceptor let:attrs>
+            <Primitive
+                element=html::button
+                as_child=as_child
+                node_ref=node_ref
+                attr:r#type="button"
+                attr:tabindex="-1"
+                attr:aria-label=aria_label.clone()
+                attr:data-disabled=data_attr(context.disabled)
+                attr:disabled=data_attr(context.disabled)
+                on:click=move |event: ev::MouseEvent| {
+                    if let Some(Some(cb)) = on_click_stored.try_get_value() {
+                        cb.run(event.clone());
+                    }
+                    // Don't let the item highlight itself.
+                    event.stop_propagation();
+                    if !event.default_prevented() && !context.disabled.get_untracked() {
+                        if let Some(index) = item_index.get_untracked() {
+                            context.remove_at(index);
+                        }
+                        context.focus_input();
+                    }
+                }
+                {..attrs}
+            >
+                {children.try_with_value(|children| {
+                    children.as_ref().map(|c| c()).unwrap_or_else(|| "\u{2715}".into_any())
+                })}
+            </Primitive>
+        </AttributeInterceptor>
+    }
+}
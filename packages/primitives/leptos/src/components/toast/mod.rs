@@ -56,6 +56,7 @@ use crate::support::forwarded_attrs::ForwardedAttrs;
 use crate::support::portal::Portal;
 use crate::support::presence::Presence;
 use crate::support::primitive::{Primitive, adapt_callback, compose_callbacks};
+use crate::support::scroll_lock::use_scroll_lock_shard;
 use crate::support::use_controllable_state::{UseControllableStateParams, use_controllable_state};
 use crate::support::visually_hidden::VisuallyHidden;
 use leptos::attribute_interceptor::AttributeInterceptor;
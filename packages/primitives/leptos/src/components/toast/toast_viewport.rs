@@ -44,6 +44,9 @@ pub fn ToastViewport(
     let viewport_ref = AnyNodeRef::new();
     let composed_refs = use_composed_refs(vec![node_ref, viewport_ref]);
 
+    // Keep the viewport scrollable while a modal overlay locks body scroll.
+    use_scroll_lock_shard(viewport_ref);
+
     let has_toasts = Signal::derive(move || context.toast_count.get() > 0);
 
     // F8 hotkey to focus the viewport
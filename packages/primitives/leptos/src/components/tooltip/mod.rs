@@ -81,7 +81,7 @@ mod tooltip;
 mod tooltip_content;
 mod tooltip_provider;
 
-pub use crate::support::popper::{Align, Padding, Side, Sticky};
+pub use crate::support::popper::{Align, ClientRectObject, FollowCursor, Padding, Side, Sticky};
 pub use tooltip::*;
 pub use tooltip_content::*;
 pub use tooltip_provider::*;
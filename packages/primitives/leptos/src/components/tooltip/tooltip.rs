@@ -143,6 +143,15 @@ pub fn Tooltip(
 
 #[component]
 pub fn TooltipTrigger(
+    /// Anchors content to a DOM range, e.g. the current text selection.
+    #[prop(into, optional)]
+    range: MaybeProp<SendWrapper<web_sys::Range>>,
+    /// Anchors content to the rect returned by this callback.
+    #[prop(into, optional_no_strip)]
+    virtual_rect: Option<Callback<(), ClientRectObject>>,
+    /// Anchors content to the pointer while it moves over the trigger.
+    #[prop(into, optional)]
+    follow_cursor: MaybeProp<FollowCursor>,
     #[prop(into, optional)] on_pointer_move: Option<Callback<ev::PointerEvent>>,
     #[prop(into, optional)] on_pointer_leave: Option<Callback<ev::PointerEvent>>,
     #[prop(into, optional)] on_pointer_down: Option<Callback<ev::PointerEvent>>,
@@ -179,7 +188,12 @@ pub fn TooltipTrigger(
     // while the document listener still references it.
 
     view! {
-        <PopperAnchor as_child=true>
+        <PopperAnchor
+            range=range
+            virtual_rect=virtual_rect
+            follow_cursor=follow_cursor
+            as_child=true
+        >
             <AttributeInterceptor let:attrs>
                 <Primitive
                     element=html::button
@@ -1,26 +0,0 @@
-use leptos::{prelude::*, svg::Svg};
-#[component]
-pub fn AlignBottomIcon(
-    #[prop(default = 15.into(), into)] width: Signal<usize>,
-    #[prop(default = 15.into(), into)] height: Signal<usize>,
-    #[prop(default = "currentColor".into(), into)] color: Signal<String>,
-    #[prop(optional)] node_ref: NodeRef<Svg>,
-) -> impl IntoView {
-    view! {
-        <svg
-            node_ref=node_ref
-            width=width
-            height=height
-            viewBox="0 0 15 15"
-            fill="none"
-            xmlns="http://www.w3.org/2000/svg"
-        >
-            <path
-                fill-rule="evenodd"
-                clip-rule="evenodd"
-                d="M9 3C9 2.44772 8.55229 2 8 2H7C6.44772 2 6 2.44772 6 3L6 14H1.5C1.22386 14 1 14.2239 1 14.5C1 14.7761 1.22386 15 1.5 15L6 15H9H13.5C13.7761 15 14 14.7761 14 14.5C14 14.2239 13.7761 14 13.5 14H9V3Z"
-                fill=color
-            ></path>
-        </svg>
-    }
-}
@@ -178,8 +178,8 @@ mod tests {
 
         let handler = compose_event_handlers(Some(preventing_handler), Some(our_handler), None);
 
-        let mut init = web_sys::EventInit::new();
-        init.cancelable(true);
+        let init = web_sys::EventInit::new();
+        init.set_cancelable(true);
         let event = Event::new_with_event_init_dict("click", &init).unwrap();
         handler(event);
 
@@ -13,12 +13,18 @@
 //! cardo-ui = { version = "0.0.2", features = ["checkbox", "dialog", "tabs"] }
 //! ```
 //!
+//! The `colors` and `icons` features re-export the `cardo-ui-colors` and
+//! `cardo-ui-icons` crates as `colors` and `icons`; `icons-sprite` renders the
+//! icons from an SVG sprite sheet.
+//!
 //! # Public Support Modules
 //!
 //! These modules are always available (no feature flag required):
 //!
 //! - [`arrow`] — SVG arrow for floating UI components
 //! - [`direction`] — RTL/LTR direction context
+//! - [`id`] — Hydration-stable ID generation and [`id::IdProvider`]
+//! - [`layer_stack`] — Overlay stacking order via [`layer_stack::use_layer_stack`]
 //! - [`portal`] — Render children into a different DOM node
 //! - [`primitive`] — Base rendering primitive with `as_child` support
 //! - [`visually_hidden`] — Screen-reader-only content
@@ -41,15 +47,17 @@ mod internal;
 mod support;
 
 #[cfg(feature = "colors")]
-pub mod colors;
+pub use cardo_ui_colors as colors;
 
 #[cfg(feature = "icons")]
-pub mod icons;
+pub use cardo_ui_icons as icons;
 
 // ── Re-export public support modules at crate level ──
+pub use cardo_ui_class as class;
 pub use support::arrow;
-pub use support::class;
 pub use support::direction;
+pub use support::id;
+pub use support::layer_stack;
 pub use support::portal;
 pub use support::primitive;
 pub use support::visually_hidden;
@@ -72,7 +80,7 @@ pub use support::focus_guards;
 #[cfg(feature = "internals")]
 pub use support::focus_scope;
 #[cfg(feature = "internals")]
-pub use support::id;
+pub use support::form_control;
 #[cfg(feature = "internals")]
 pub use support::popper;
 #[cfg(feature = "internals")]
@@ -109,6 +117,8 @@ pub use components::calendar;
 pub use components::checkbox;
 #[cfg(feature = "collapsible")]
 pub use components::collapsible;
+#[cfg(feature = "color-picker")]
+pub use components::color_picker;
 #[cfg(feature = "combobox")]
 pub use components::combobox;
 #[cfg(feature = "context-menu")]
@@ -117,6 +127,8 @@ pub use components::context_menu;
 pub use components::dialog;
 #[cfg(feature = "dropdown-menu")]
 pub use components::dropdown_menu;
+#[cfg(feature = "file-upload")]
+pub use components::file_upload;
 #[cfg(feature = "form")]
 pub use components::form;
 #[cfg(feature = "hover-card")]
@@ -131,6 +143,8 @@ pub use components::menubar;
 pub use components::navigation_menu;
 #[cfg(feature = "one-time-password-field")]
 pub use components::one_time_password_field;
+#[cfg(feature = "pagination")]
+pub use components::pagination;
 #[cfg(feature = "password-toggle-field")]
 pub use components::password_toggle_field;
 #[cfg(feature = "popover")]
@@ -139,6 +153,8 @@ pub use components::popover;
 pub use components::progress;
 #[cfg(feature = "radio-group")]
 pub use components::radio_group;
+#[cfg(feature = "rating")]
+pub use components::rating;
 #[cfg(feature = "scroll-area")]
 pub use components::scroll_area;
 #[cfg(feature = "select")]
@@ -151,6 +167,8 @@ pub use components::slider;
 pub use components::switch;
 #[cfg(feature = "tabs")]
 pub use components::tabs;
+#[cfg(feature = "tags-input")]
+pub use components::tags_input;
 #[cfg(feature = "toast")]
 pub use components::toast;
 #[cfg(feature = "toggle")]
@@ -1,47 +1,160 @@
 //! Utility for hiding sibling DOM elements from assistive technology.
 //!
-//! Sets `aria-hidden="true"` on body's direct children that don't contain
-//! the specified content element. Used by modal components (dialog, popover)
-//! to isolate focus to the overlay content.
+//! Sets `aria-hidden="true"` and, where supported, `inert` on body's direct
+//! children that don't contain any of the kept elements. Used by the
+//! [`layer_stack`](crate::support::layer_stack) service to isolate the
+//! topmost modal overlay.
 
 use leptos::prelude::*;
 use send_wrapper::SendWrapper;
 
-/// Sets `aria-hidden="true"` on body's direct children that don't contain `content`,
-/// storing the affected elements in the provided signal for later cleanup via [`unhide_others`].
+/// How outside elements are hidden.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HideMode {
+    /// `inert` plus `aria-hidden="true"`: hidden from assistive technology and
+    /// removed from focus and pointer interaction.
+    Inert,
+    /// `aria-hidden="true"` only, for browsers without `inert` support.
+    AriaHidden,
+}
+
+impl HideMode {
+    /// Returns [`HideMode::Inert`] if the browser supports the `inert` attribute.
+    pub fn detect() -> Self {
+        let supports_inert = document()
+            .create_element("div")
+            .ok()
+            .is_some_and(|element| {
+                js_sys::Reflect::has(&element, &"inert".into()).unwrap_or(false)
+            });
+
+        if supports_inert {
+            HideMode::Inert
+        } else {
+            HideMode::AriaHidden
+        }
+    }
+}
+
+/// Hides body's direct children that don't contain any element in `keep`,
+/// returning the affected elements for later cleanup via [`unhide_others`].
+///
+/// Elements that are already hidden, scripts, live regions and focus guards
+/// are left untouched.
 pub fn hide_others(
-    content: &web_sys::HtmlElement,
-    hidden_elements: RwSignal<Vec<SendWrapper<web_sys::Element>>>,
-) {
+    keep: &[web_sys::Element],
+    mode: HideMode,
+) -> Vec<SendWrapper<web_sys::Element>> {
     let Some(body) = document().body() else {
-        return;
+        return Vec::new();
     };
     let children = body.children();
     let mut hidden = Vec::new();
 
     for i in 0..children.length() {
         if let Some(child) = children.item(i) {
-            // Skip elements that contain the content or are already hidden
-            let contains_content = child.contains(Some(content));
+            let contains_kept = keep.iter().any(|element| child.contains(Some(element)));
             let already_hidden = child
                 .get_attribute("aria-hidden")
-                .is_some_and(|v| v == "true");
+                .is_some_and(|v| v == "true")
+                || child.has_attribute("inert");
             let is_script = child.tag_name().eq_ignore_ascii_case("SCRIPT");
+            let is_exempt = child
+                .matches("[aria-live], [data-radix-focus-guard]")
+                .unwrap_or(false);
 
-            if !contains_content && !already_hidden && !is_script {
+            if !contains_kept && !already_hidden && !is_script && !is_exempt {
                 let _ = child.set_attribute("aria-hidden", "true");
+                if mode == HideMode::Inert {
+                    let _ = child.set_attribute("inert", "");
+                }
                 hidden.push(SendWrapper::new(child));
             }
         }
     }
 
-    hidden_elements.set(hidden);
+    hidden
 }
 
-/// Removes `aria-hidden` from all elements previously hidden by [`hide_others`].
-pub fn unhide_others(hidden_elements: RwSignal<Vec<SendWrapper<web_sys::Element>>>) {
-    for element in hidden_elements.get_untracked() {
+/// Unhides all elements previously hidden by [`hide_others`].
+pub fn unhide_others(hidden: &[SendWrapper<web_sys::Element>], mode: HideMode) {
+    for element in hidden {
         let _ = element.remove_attribute("aria-hidden");
+        if mode == HideMode::Inert {
+            let _ = element.remove_attribute("inert");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Appends a `tag` element to the document body and returns it. The
+    /// caller should remove it when done.
+    fn append_to_body(tag: &str) -> web_sys::HtmlElement {
+        let element: web_sys::HtmlElement =
+            document().create_element(tag).unwrap().unchecked_into();
+        document().body().unwrap().append_child(&element).unwrap();
+        element
+    }
+
+    fn is_hidden(element: &web_sys::Element) -> bool {
+        element.get_attribute("aria-hidden").as_deref() == Some("true")
+    }
+
+    #[wasm_bindgen_test]
+    fn hides_siblings_of_every_kept_element() {
+        let first_container = append_to_body("div");
+        let first: web_sys::Element = document().create_element("div").unwrap();
+        first_container.append_child(&first).unwrap();
+        let second = append_to_body("div");
+        let sibling = append_to_body("div");
+
+        let hidden = hide_others(&[first, second.clone().into()], HideMode::Inert);
+
+        assert!(hidden.iter().any(|element| **element == *sibling));
+        assert!(!is_hidden(&first_container));
+        assert!(!is_hidden(&second));
+        assert!(is_hidden(&sibling));
+        assert!(sibling.has_attribute("inert"));
+
+        unhide_others(&hidden, HideMode::Inert);
+        assert!(!is_hidden(&sibling));
+        assert!(!sibling.has_attribute("inert"));
+
+        for element in [&first_container, &second, &sibling] {
+            element.remove();
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn leaves_exempt_and_already_hidden_elements_alone() {
+        let kept = append_to_body("div");
+        let script = append_to_body("script");
+        let live_region = append_to_body("div");
+        live_region.set_attribute("aria-live", "polite").unwrap();
+        let already_hidden = append_to_body("div");
+        already_hidden.set_attribute("aria-hidden", "true").unwrap();
+
+        let hidden = hide_others(&[kept.clone().into()], HideMode::AriaHidden);
+
+        for element in [&kept, &script, &live_region, &already_hidden] {
+            let element: &web_sys::Element = element;
+            assert!(!hidden.iter().any(|hidden| **hidden == *element));
+        }
+        assert!(!is_hidden(&script));
+        assert!(!is_hidden(&live_region));
+
+        unhide_others(&hidden, HideMode::AriaHidden);
+        assert!(is_hidden(&already_hidden));
+
+        for element in [&kept, &script, &live_region, &already_hidden] {
+            element.remove();
+        }
     }
-    hidden_elements.set(Vec::new());
 }
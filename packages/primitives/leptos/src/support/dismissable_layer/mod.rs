@@ -4,12 +4,17 @@
 //! outside, or presses Escape. Supports nested layers (e.g., a dropdown
 //! inside a dialog) with correct event delegation. Implements the "two
 //! escapes" pattern for text inputs.
+//!
+//! Each layer exposes its position in the stack as the `--layer-index` CSS
+//! variable, so overlays can be stacked with e.g.
+//! `z-index: calc(50 + var(--layer-index))` instead of hand-tuned values.
 
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Mutex;
 
 use crate::support::compose_refs::use_composed_refs;
+use crate::support::layer_stack::{LayerNodeContextValue, refresh_inert};
 use crate::support::primitive::{Primitive, prop_or_default};
 use crate::support::use_escape_keydown::use_escape_keydown;
 use leptos::{attribute_interceptor::AttributeInterceptor, html, prelude::*};
@@ -25,7 +30,7 @@ use web_sys::{
 /// See tooltip.rs for detailed rationale.
 type ClosureCell<T> = SendWrapper<Rc<RefCell<Option<Closure<T>>>>>;
 
-const CONTEXT_UPDATE: &str = "dismissableLayer.update";
+pub(crate) const CONTEXT_UPDATE: &str = "dismissableLayer.update";
 const POINTER_DOWN_OUTSIDE: &str = "dismissableLayer.pointerDownOutside";
 const FOCUS_OUTSIDE: &str = "dismissableLayer.focusOutside";
 
@@ -99,6 +104,20 @@ impl DismissableLayerContextValue {
         self.layers.len()
     }
 
+    /// Layers registered after the layer containing `node`.
+    fn layers_above(&self, node: &web_sys::Node) -> Vec<web_sys::HtmlElement> {
+        let index = self.layers.iter().rposition(|l| l.contains(Some(node)));
+        index
+            .map(|index| {
+                self.layers
+                    .iter()
+                    .skip(index + 1)
+                    .map(|l| (**l).clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     fn layers_with_outside_pointer_events_disabled_count(&self) -> usize {
         self.layers_with_outside_pointer_events_disabled.len()
     }
@@ -133,9 +152,34 @@ impl DismissableLayerContextValue {
     }
 }
 
+// -- Stack queries ----------------------------------------------------------
+
+pub(crate) fn layers_count() -> usize {
+    DISMISSABLE_LAYER_CONTEXT
+        .lock()
+        .expect("Context mutex should lock.")
+        .layers_count()
+}
+
+pub(crate) fn layer_index(node: &web_sys::HtmlElement) -> Option<usize> {
+    DISMISSABLE_LAYER_CONTEXT
+        .lock()
+        .expect("Context mutex should lock.")
+        .layer_index(node)
+}
+
+pub(crate) fn layers_above(node: &web_sys::Node) -> Vec<web_sys::HtmlElement> {
+    DISMISSABLE_LAYER_CONTEXT
+        .lock()
+        .expect("Context mutex should lock.")
+        .layers_above(node)
+}
+
 // -- Update broadcasting ----------------------------------------------------
 
+/// Must be called without holding the context lock.
 fn dispatch_update() {
+    refresh_inert();
     let event = CustomEvent::new(CONTEXT_UPDATE).expect("CustomEvent should be instantiated.");
     document().dispatch_event(&event).ok();
 }
@@ -472,6 +516,9 @@ pub fn DismissableLayer(
 
     let owner_document = document();
 
+    // Expose this layer's position to descendants via `use_layer_stack`.
+    provide_context(LayerNodeContextValue(container_ref));
+
     // Derived computations that depend on force_update and container_ref
     let is_body_pointer_events_disabled = Signal::derive(move || {
         let _ = force_update.get();
@@ -737,11 +784,13 @@ pub fn DismissableLayer(
             let node: web_sys::HtmlElement = node.unchecked_into();
             let _ = unmount_cleanup.try_with_value(|f| {
                 f.borrow_mut().replace(Box::new(move || {
-                    let mut ctx = DISMISSABLE_LAYER_CONTEXT
-                        .lock()
-                        .expect("Context mutex should lock.");
-                    ctx.remove_layer(&node);
-                    ctx.remove_layer_with_outside_pointer_events_disabled(&node);
+                    {
+                        let mut ctx = DISMISSABLE_LAYER_CONTEXT
+                            .lock()
+                            .expect("Context mutex should lock.");
+                        ctx.remove_layer(&node);
+                        ctx.remove_layer_with_outside_pointer_events_disabled(&node);
+                    }
                     dispatch_update();
                 }));
             });
@@ -850,6 +899,21 @@ pub fn DismissableLayer(
         }
     });
 
+    let layer_index_style = Memo::new(move |_| {
+        let _ = force_update.get();
+        container_ref
+            .get()
+            .and_then(|node| {
+                let node: web_sys::HtmlElement = node.unchecked_into();
+                DISMISSABLE_LAYER_CONTEXT
+                    .lock()
+                    .expect("Context mutex should lock.")
+                    .layer_index(&node)
+            })
+            .map(|index| index.to_string())
+            .unwrap_or_default()
+    });
+
     let pointer_events_style = Memo::new(move |_| {
         let is_disabled = is_body_pointer_events_disabled.get();
         let is_enabled = is_pointer_events_enabled.get();
@@ -871,6 +935,7 @@ pub fn DismissableLayer(
                 as_child=as_child
                 node_ref=composed_refs
                 style:pointer-events=move || pointer_events_style.get()
+                style:--layer-index=move || layer_index_style.get()
                 {..attrs}
             >
                 {children.with_value(|children| children())}
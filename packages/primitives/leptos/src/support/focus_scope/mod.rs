@@ -4,6 +4,14 @@
 //! trapped, Tab and Shift+Tab cycle through focusable elements inside the
 //! scope without escaping. Supports auto-focus on mount and restore-focus
 //! on unmount.
+//!
+//! On mount, focus moves to `initial_focus` if given, otherwise to the first
+//! descendant marked with `data-autofocus`, otherwise to the first tabbable
+//! element. On unmount, focus returns to `final_focus` if given, otherwise to
+//! the element that was focused before the scope mounted.
+//!
+//! Only the most recently mounted scope traps focus: activating a nested scope
+//! pauses the one below it, which resumes once the nested scope unmounts.
 
 use std::cell::RefCell;
 use std::ops::Deref;
@@ -27,6 +35,9 @@ use web_sys::{
 const AUTOFOCUS_ON_MOUNT: &str = "focusScope.autoFocusOnMount";
 const AUTOFOCUS_ON_UNMOUNT: &str = "focusScope.autoFocusOnUnmount";
 
+/// Marks the element a [`FocusScope`] should focus on mount when no `initial_focus` is given.
+pub const AUTOFOCUS_ATTRIBUTE: &str = "data-autofocus";
+
 type FocusEventClosure = Arc<SendWrapper<Closure<dyn Fn(FocusEvent)>>>;
 
 #[component]
@@ -40,6 +51,18 @@ pub fn FocusScope(
     #[prop(into, optional)] on_mount_auto_focus: Option<Callback<Event>>,
     // TODO: hopefully remove the double option
     #[prop(into, optional)] on_unmount_auto_focus: Option<Option<Callback<Event>>>,
+    /// Element to focus on mount instead of the first tabbable element.
+    #[prop(into, optional_no_strip)]
+    initial_focus: Option<AnyNodeRef>,
+    /// Element to focus on unmount instead of the element focused before mount.
+    #[prop(into, optional_no_strip)]
+    final_focus: Option<AnyNodeRef>,
+    /// Elements outside the scope that focus may move to while trapped, e.g. third-party iframes.
+    #[prop(into, optional)]
+    external_focus_targets: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+    /// When `true`, trapping and looping are suspended without unmounting the scope. Defaults to `false`.
+    #[prop(into, optional)]
+    paused: MaybeProp<bool>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(into, optional)] node_ref: AnyNodeRef,
     children: ChildrenFn,
@@ -48,6 +71,7 @@ pub fn FocusScope(
 
     let r#loop = prop_or_default(r#loop);
     let trapped = prop_or_default(trapped);
+    let paused = prop_or_default(paused);
 
     let container_ref: AnyNodeRef = AnyNodeRef::new();
     let composed_refs = use_composed_refs(vec![node_ref, container_ref]);
@@ -55,13 +79,23 @@ pub fn FocusScope(
         RwSignal::new(None);
     let focus_scope = RwSignal::new(FocusScopeAPI::new());
 
-    let handle_focus_in: FocusEventClosure =
-        Arc::new(SendWrapper::new(Closure::new(move |event: FocusEvent| {
-            if focus_scope
+    let is_paused = move || {
+        paused.try_get_untracked().unwrap_or(true)
+            || focus_scope
                 .try_get_untracked()
                 .map(|s| s.paused())
                 .unwrap_or(true)
-            {
+    };
+    let is_external_focus_target = move |target: Option<&web_sys::HtmlElement>| {
+        external_focus_targets
+            .try_get_untracked()
+            .flatten()
+            .is_some_and(|targets| contains_target(&targets, target))
+    };
+
+    let handle_focus_in: FocusEventClosure =
+        Arc::new(SendWrapper::new(Closure::new(move |event: FocusEvent| {
+            if is_paused() {
                 return;
             }
 
@@ -73,7 +107,7 @@ pub fn FocusScope(
 
                 if container.contains(target.as_ref().map(|e| e.unchecked_ref())) {
                     let _ = last_focused_element.try_set(target.map(SendWrapper::new));
-                } else {
+                } else if !is_external_focus_target(target.as_ref()) {
                     focus(
                         last_focused_element
                             .try_get_untracked()
@@ -88,11 +122,7 @@ pub fn FocusScope(
 
     let handle_focus_out: FocusEventClosure =
         Arc::new(SendWrapper::new(Closure::new(move |event: FocusEvent| {
-            if focus_scope
-                .try_get_untracked()
-                .map(|s| s.paused())
-                .unwrap_or(true)
-            {
+            if is_paused() {
                 return;
             }
 
@@ -117,8 +147,11 @@ pub fn FocusScope(
                 }
 
                 // If the focus has moved to an actual legitimate element (`related_target != None`)
-                // that is outside the container, we move focus to the last valid focused element inside.
-                if !container.contains(related_target.as_ref().map(|e| e.unchecked_ref())) {
+                // that is outside the container, we move focus to the last valid focused element inside,
+                // unless it is one of the allow-listed external targets.
+                if !container.contains(related_target.as_ref().map(|e| e.unchecked_ref()))
+                    && !is_external_focus_target(related_target.as_ref())
+                {
                     focus(
                         last_focused_element
                             .try_get_untracked()
@@ -269,6 +302,14 @@ pub fn FocusScope(
                 focus_scope_stack.add(focus_scope.get());
             }
 
+            let scope = focus_scope.get_untracked();
+            let remove_from_stack = move || {
+                FOCUS_SCOPE_STACK
+                    .lock()
+                    .expect("Focus scope stack mutex should lock.")
+                    .remove(&scope);
+            };
+
             let previously_focused_element = document()
                 .active_element()
                 .map(|element| element.unchecked_into::<web_sys::HtmlElement>());
@@ -314,10 +355,19 @@ pub fn FocusScope(
                     let container_for_raf = container.clone();
                     let prev_for_raf = previously_focused_element.clone();
                     let cb = Closure::once_into_js(move || {
-                        focus_first(
-                            remove_links(get_tabbable_candidates(&container_for_raf)),
-                            Some(FocusOptions { select: true }),
-                        );
+                        let preferred = initial_focus
+                            .and_then(|initial_focus| initial_focus.try_get_untracked().flatten())
+                            .map(|element| element.unchecked_into::<web_sys::HtmlElement>())
+                            .or_else(|| find_autofocus_target(&container_for_raf));
+                        if preferred.is_some() {
+                            focus(preferred, Some(FocusOptions { select: true }));
+                        }
+                        if document().active_element().as_ref() == prev_for_raf.as_deref() {
+                            focus_first(
+                                remove_links(get_tabbable_candidates(&container_for_raf)),
+                                Some(FocusOptions { select: true }),
+                            );
+                        }
                         if document().active_element().as_ref() == prev_for_raf.as_deref() {
                             focus(Some(container_for_raf), None);
                         }
@@ -363,8 +413,13 @@ pub fn FocusScope(
                             .expect("Auto focus on unmount event should be dispatched.");
 
                         if !unmount_event.default_prevented() {
+                            let final_focus = final_focus
+                                .and_then(|final_focus| final_focus.try_get_untracked().flatten())
+                                .map(|element| element.unchecked_into::<web_sys::HtmlElement>());
                             focus(
-                                previously_focused_element.clone().or(document().body()),
+                                final_focus
+                                    .or_else(|| previously_focused_element.clone())
+                                    .or(document().body()),
                                 Some(FocusOptions { select: true }),
                             );
                         }
@@ -376,16 +431,15 @@ pub fn FocusScope(
                             )
                             .expect("Auto focus on unmount event listener should be removed.");
 
-                        {
-                            let mut focus_scope_stack = FOCUS_SCOPE_STACK
-                                .lock()
-                                .expect("Focus scope stack mutex should lock.");
-                            if let Some(scope) = focus_scope.try_get_untracked() {
-                                focus_scope_stack.remove(&scope);
-                            }
-                        }
+                        remove_from_stack();
                     }));
                 });
+            } else {
+                // Focus was already inside, so there is nothing to restore, but the scope
+                // must still leave the stack so that the scope below it resumes.
+                let _ = auto_focus_end.try_with_value(|end| {
+                    end.borrow_mut().replace(Box::new(remove_from_stack));
+                });
             }
         }
     });
@@ -409,7 +463,7 @@ pub fn FocusScope(
         if !r#loop && !trapped.get_untracked() {
             return;
         }
-        if focus_scope.get_untracked().paused() {
+        if is_paused() {
             return;
         }
 
@@ -666,6 +720,41 @@ fn focus(element: Option<web_sys::HtmlElement>, options: Option<FocusOptions>) {
     }
 }
 
+/// Returns the first visible descendant marked with [`AUTOFOCUS_ATTRIBUTE`].
+fn find_autofocus_target(container: &web_sys::HtmlElement) -> Option<web_sys::HtmlElement> {
+    let elements = container
+        .query_selector_all(&format!("[{AUTOFOCUS_ATTRIBUTE}]"))
+        .ok()?;
+
+    (0..elements.length())
+        .filter_map(|i| elements.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .find(|element| {
+            !is_hidden(
+                element,
+                Some(IsHiddenOptions {
+                    up_to: Some(container),
+                }),
+            )
+        })
+}
+
+/// Whether `target` is, or is inside, one of the allow-listed external elements.
+fn contains_target(targets: &[web_sys::Element], target: Option<&web_sys::HtmlElement>) -> bool {
+    target.is_some_and(|target| targets.iter().any(|element| element.contains(Some(target))))
+}
+
+/// Resolves where focus goes when an overlay closes: `final_focus` if set and
+/// mounted, otherwise `fallback` (typically the trigger).
+pub(crate) fn final_focus_target(
+    final_focus: Option<AnyNodeRef>,
+    fallback: AnyNodeRef,
+) -> Option<web_sys::Element> {
+    final_focus
+        .and_then(|final_focus| final_focus.get_untracked())
+        .or_else(|| fallback.get_untracked())
+}
+
 fn remove_links(items: Vec<web_sys::HtmlElement>) -> Vec<web_sys::HtmlElement> {
     items
         .into_iter()
@@ -1122,5 +1211,52 @@ mod tests {
 
             cleanup(&container);
         }
+
+        // ── find_autofocus_target ────────────────────────────
+
+        #[wasm_bindgen_test]
+        fn autofocus_target_returns_first_marked_element() {
+            let container = append_container();
+            let first = create_element("button");
+            let marked = create_element("input");
+            marked.set_attribute(AUTOFOCUS_ATTRIBUTE, "").unwrap();
+            container.append_child(&first).unwrap();
+            container.append_child(&marked).unwrap();
+
+            assert_eq!(find_autofocus_target(&container), Some(marked));
+
+            cleanup(&container);
+        }
+
+        #[wasm_bindgen_test]
+        fn autofocus_target_skips_hidden_elements() {
+            let container = append_container();
+            let hidden = create_element("input");
+            hidden.set_attribute(AUTOFOCUS_ATTRIBUTE, "").unwrap();
+            hidden.style().set_property("display", "none").unwrap();
+            container.append_child(&hidden).unwrap();
+
+            assert!(find_autofocus_target(&container).is_none());
+
+            cleanup(&container);
+        }
+
+        // ── contains_target ──────────────────────────────────
+
+        #[wasm_bindgen_test]
+        fn contains_target_matches_descendants_of_external_targets() {
+            let external = append_container();
+            let inner = create_element("button");
+            external.append_child(&inner).unwrap();
+            let unrelated = append_container();
+
+            let targets: Vec<web_sys::Element> = vec![external.clone().into()];
+            assert!(contains_target(&targets, Some(&inner)));
+            assert!(!contains_target(&targets, Some(&unrelated)));
+            assert!(!contains_target(&targets, None));
+
+            cleanup(&external);
+            cleanup(&unrelated);
+        }
     }
 }
@@ -0,0 +1,487 @@
+//! Form participation for controls that aren't native form elements.
+//!
+//! Primitives such as `Checkbox`, `Select` and `Slider` render a visible control and a hidden
+//! native element (a "bubble input") that carries their value, so that they submit with forms and
+//! take part in constraint validation. [`use_form_control`] connects that native element to the
+//! enclosing form field, which provides a [`FormControlContextValue`]:
+//!
+//! - The field validates on the native element's `change`, `click` (for checkboxes), `input`
+//!   and `invalid` events, when focus leaves the visible control, and clears on form `reset`.
+//! - The visible control is focused instead of the native element when it is the first invalid
+//!   control of a submitted form.
+//! - The returned [`FormControlAttributes`] carry the field's `id`, `name` and ARIA and data
+//!   attributes for the visible control.
+//!
+//! Outside of a form field, the attributes are empty and no listeners are added.
+//!
+//! [`CheckboxBubbleInput`] is the shared bubble input of checkable controls,
+//! [`use_form_control_error`] reports errors that constraint validation can't detect, and
+//! [`use_form_reset`] restores a control's state when its form is reset.
+
+use std::rc::Rc;
+
+use crate::support::use_previous::use_previous;
+use crate::support::use_size::use_size;
+use leptos::{
+    attr::{Attribute, custom::custom_attribute},
+    prelude::*,
+};
+use leptos_node_ref::AnyNodeRef;
+use send_wrapper::SendWrapper;
+use web_sys::wasm_bindgen::{JsCast, closure::Closure};
+
+/* -------------------------------------------------------------------------------------------------
+ * NativeControl
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Native form element with constraint validation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NativeControl {
+    Input(web_sys::HtmlInputElement),
+    Select(web_sys::HtmlSelectElement),
+    TextArea(web_sys::HtmlTextAreaElement),
+}
+
+impl NativeControl {
+    pub fn from_element(element: &web_sys::Element) -> Option<Self> {
+        if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+            Some(Self::Input(input.clone()))
+        } else if let Some(select) = element.dyn_ref::<web_sys::HtmlSelectElement>() {
+            Some(Self::Select(select.clone()))
+        } else {
+            element
+                .dyn_ref::<web_sys::HtmlTextAreaElement>()
+                .map(|text_area| Self::TextArea(text_area.clone()))
+        }
+    }
+
+    pub fn element(&self) -> &web_sys::HtmlElement {
+        match self {
+            Self::Input(input) => input.as_ref(),
+            Self::Select(select) => select.as_ref(),
+            Self::TextArea(text_area) => text_area.as_ref(),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        match self {
+            Self::Input(input) => input.value(),
+            Self::Select(select) => select.value(),
+            Self::TextArea(text_area) => text_area.value(),
+        }
+    }
+
+    pub fn validity(&self) -> web_sys::ValidityState {
+        match self {
+            Self::Input(input) => input.validity(),
+            Self::Select(select) => select.validity(),
+            Self::TextArea(text_area) => text_area.validity(),
+        }
+    }
+
+    pub fn set_custom_validity(&self, message: &str) {
+        match self {
+            Self::Input(input) => input.set_custom_validity(message),
+            Self::Select(select) => select.set_custom_validity(message),
+            Self::TextArea(text_area) => text_area.set_custom_validity(message),
+        }
+    }
+
+    pub fn form(&self) -> Option<web_sys::HtmlFormElement> {
+        match self {
+            Self::Input(input) => input.form(),
+            Self::Select(select) => select.form(),
+            Self::TextArea(text_area) => text_area.form(),
+        }
+    }
+}
+
+/// First control of `form` that fails constraint validation or is marked `aria-invalid`.
+pub fn first_invalid_control(form: &web_sys::HtmlFormElement) -> Option<NativeControl> {
+    let elements = form.elements();
+    (0..elements.length())
+        .filter_map(|index| elements.item(index))
+        .filter_map(|element| NativeControl::from_element(&element))
+        .find(|control| {
+            !control.validity().valid()
+                || control.element().get_attribute("aria-invalid").as_deref() == Some("true")
+        })
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * FormControlContextValue
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Event of a control that its form field validates on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormControlEvent {
+    /// The value was committed.
+    Change,
+    /// The value was edited.
+    Input,
+    /// Focus left the control.
+    Blur,
+    /// The form was submitted with the control failing constraint validation.
+    Invalid,
+    /// The form was reset.
+    Reset,
+}
+
+/// Attributes of a control from its form field. Empty outside of a form field.
+#[derive(Clone, Copy, Default)]
+pub struct FormControlAttributes {
+    pub id: Signal<Option<String>>,
+    pub name: Signal<Option<String>>,
+    pub aria_describedby: Signal<Option<String>>,
+    pub aria_invalid: Signal<Option<&'static str>>,
+    pub data_valid: Signal<Option<&'static str>>,
+    pub data_invalid: Signal<Option<&'static str>>,
+    pub data_validating: Signal<Option<&'static str>>,
+}
+
+impl FormControlAttributes {
+    /// The ARIA and data attributes, to spread onto the visible control with `{..attrs}`.
+    ///
+    /// `id` is left out, since controls with several parts, such as `Slider` with several thumbs,
+    /// set it on one of them only.
+    pub fn into_attrs(self) -> impl Attribute {
+        (
+            custom_attribute("aria-describedby", move || self.aria_describedby.get()),
+            custom_attribute("aria-invalid", move || self.aria_invalid.get()),
+            custom_attribute("data-valid", move || self.data_valid.get()),
+            custom_attribute("data-invalid", move || self.data_invalid.get()),
+            custom_attribute("data-validating", move || self.data_validating.get()),
+        )
+    }
+}
+
+/// Form field of the controls inside it.
+#[derive(Clone, Copy)]
+pub struct FormControlContextValue {
+    pub attributes: FormControlAttributes,
+    pub on_event: Callback<(FormControlEvent, NativeControl)>,
+    /// Sets or, with `None`, clears the error of a control that constraint validation can't
+    /// detect. The field keeps it until the control clears it.
+    pub on_error: Callback<(Option<String>, NativeControl)>,
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_form_control
+ * -----------------------------------------------------------------------------------------------*/
+
+pub struct UseFormControlParams {
+    /// Hidden native element that carries the value of the control.
+    pub native_ref: AnyNodeRef,
+    /// Visible control, or a group of them, focused when the native element is the first invalid
+    /// control.
+    pub focus_ref: AnyNodeRef,
+}
+
+/// Connects a control to the enclosing form field, if any. See the [module docs](self).
+pub fn use_form_control(params: UseFormControlParams) -> FormControlAttributes {
+    let UseFormControlParams {
+        native_ref,
+        focus_ref,
+    } = params;
+
+    let Some(context) = use_context::<FormControlContextValue>() else {
+        return FormControlAttributes::default();
+    };
+    let on_event = context.on_event;
+
+    Effect::new(move |_| {
+        if let Some(element) = native_ref.get()
+            && let Some(control) = NativeControl::from_element(&element)
+        {
+            let target: &web_sys::EventTarget = control.element().as_ref();
+            for (event_name, event) in [
+                ("change", FormControlEvent::Change),
+                // Checkbox and switch bubble inputs dispatch `click` instead of `change`.
+                ("click", FormControlEvent::Change),
+                ("input", FormControlEvent::Input),
+            ] {
+                let control = control.clone();
+                add_event_listener(target, event_name, move |_| {
+                    on_event.run((event, control.clone()));
+                });
+            }
+
+            let invalid_control = control.clone();
+            add_event_listener(target, "invalid", move |_| {
+                on_event.run((FormControlEvent::Invalid, invalid_control.clone()));
+
+                // The native element is hidden, so focus the visible control instead.
+                if let Some(form) = invalid_control.form()
+                    && first_invalid_control(&form).as_ref() == Some(&invalid_control)
+                    && let Some(focus_element) = focus_ref.get_untracked()
+                    && let Some(focus_element) = get_focusable(&focus_element)
+                {
+                    focus_element.focus().ok();
+                }
+            });
+
+            if let Some(form) = control.form() {
+                let reset_control = control.clone();
+                add_event_listener(form.as_ref(), "reset", move |_| {
+                    on_event.run((FormControlEvent::Reset, reset_control.clone()));
+                });
+            }
+
+            if let Some(focus_element) = focus_ref.get() {
+                let blur_control = control.clone();
+                let container = focus_element.clone();
+                add_event_listener(focus_element.as_ref(), "focusout", move |event| {
+                    // Moving focus within the control, e.g. between its inputs, is no blur.
+                    let related_target = event
+                        .dyn_ref::<web_sys::FocusEvent>()
+                        .and_then(|event| event.related_target())
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    if !container.contains(related_target.as_ref()) {
+                        on_event.run((FormControlEvent::Blur, blur_control.clone()));
+                    }
+                });
+            }
+        }
+    });
+
+    context.attributes
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_form_control_error
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Returns a callback that sets or, with `None`, clears an error of the control in `native_ref`
+/// that constraint validation can't detect, e.g. a rejected file.
+///
+/// Inside a form field, the field keeps the error when it validates. Elsewhere, the error becomes
+/// the custom validity of the native element.
+pub fn use_form_control_error(native_ref: AnyNodeRef) -> Callback<Option<String>> {
+    let context = use_context::<FormControlContextValue>();
+
+    Callback::new(move |message: Option<String>| {
+        let Some(control) = native_ref
+            .get_untracked()
+            .and_then(|element| NativeControl::from_element(&element))
+        else {
+            return;
+        };
+        match context {
+            Some(context) => context.on_error.run((message, control)),
+            None => control.set_custom_validity(message.as_deref().unwrap_or_default()),
+        }
+    })
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_form_reset
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Calls `on_reset` when the form containing `control_ref` is reset.
+pub fn use_form_reset(control_ref: AnyNodeRef, on_reset: impl Fn() + 'static) {
+    let on_reset = Rc::new(on_reset);
+
+    Effect::new(move |_| {
+        if let Some(form) = control_ref
+            .get()
+            .and_then(|control| control.closest("form").ok().flatten())
+        {
+            let on_reset = on_reset.clone();
+            add_event_listener(form.as_ref(), "reset", move |_| on_reset());
+        }
+    });
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * CheckboxBubbleInput
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Hidden checkbox input of a checkable control, e.g. `Checkbox` or `Switch`, rendered after the
+/// control in `control_ref`.
+///
+/// Dispatches a `click` event when `checked` changes, so that native form validation works and
+/// form events reflect the control's updates.
+#[component]
+pub fn CheckboxBubbleInput(
+    #[prop(into)] control_ref: AnyNodeRef,
+    #[prop(into)] checked: Signal<bool>,
+    #[prop(into, optional)] indeterminate: Signal<bool>,
+    #[prop(into)] bubbles: Signal<bool>,
+    #[prop(into)] name: Signal<Option<String>>,
+    #[prop(into)] required: Signal<bool>,
+    #[prop(into)] disabled: Signal<bool>,
+    #[prop(into)] value: Signal<String>,
+    #[prop(into, optional)] form: Signal<Option<String>>,
+    #[prop(into, optional)] node_ref: AnyNodeRef,
+) -> impl IntoView {
+    let state = Signal::derive(move || (checked.get(), indeterminate.get()));
+    let prev_state = use_previous(state);
+    let control_size = use_size(control_ref);
+
+    // Bubble checked change to parents
+    Effect::new(move |_| {
+        if let Some(input) = node_ref
+            .get()
+            .and_then(|input| input.dyn_into::<web_sys::HtmlInputElement>().ok())
+        {
+            if prev_state.get() != state.get() {
+                let init = web_sys::EventInit::new();
+                init.set_bubbles(bubbles.get());
+
+                let event = web_sys::Event::new_with_event_init_dict("click", &init)
+                    .expect("Click event should be instantiated.");
+
+                input.set_indeterminate(indeterminate.get());
+                input.set_checked(checked.get());
+
+                input
+                    .dispatch_event(&event)
+                    .expect("Click event should be dispatched.");
+            }
+        }
+    });
+
+    view! {
+        <input
+            node_ref=node_ref
+            type="checkbox"
+            aria-hidden="true"
+            name=move || name.get()
+            checked=move || checked.get().then_some("")
+            required=move || required.get().then_some("")
+            disabled=move || disabled.get().then_some("")
+            value=move || value.get()
+            form=move || form.get()
+            tabindex="-1"
+            // We transform because the input is absolutely positioned, but we have
+            // rendered it **after** the control. This pulls it back to sit on top
+            // of the control.
+            style:transform="translateX(-100%)"
+            style:width=move || control_size.get().map(|size| format!("{}px", size.width))
+            style:height=move || control_size.get().map(|size| format!("{}px", size.height))
+            style:position="absolute"
+            style:pointer-events="none"
+            style:opacity="0"
+            style:margin="0px"
+        />
+    }
+}
+
+const FOCUSABLE_SELECTOR: &str = "input, select, textarea, button, [tabindex]";
+
+/// `element` when it is focusable, otherwise its first focusable descendant, e.g. the first input
+/// of a group.
+fn get_focusable(element: &web_sys::Element) -> Option<web_sys::HtmlElement> {
+    let focusable = if element.matches(FOCUSABLE_SELECTOR).unwrap_or(false) {
+        Some(element.clone())
+    } else {
+        element.query_selector(FOCUSABLE_SELECTOR).ok().flatten()
+    };
+    focusable.and_then(|focusable| focusable.dyn_into::<web_sys::HtmlElement>().ok())
+}
+
+/// Adds an event listener that is removed when the current owner is cleaned up.
+fn add_event_listener(
+    target: &web_sys::EventTarget,
+    event_name: &'static str,
+    listener: impl Fn(web_sys::Event) + 'static,
+) {
+    let closure = Closure::<dyn Fn(web_sys::Event)>::new(listener);
+    target
+        .add_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())
+        .ok();
+
+    let target = SendWrapper::new(target.clone());
+    let closure = SendWrapper::new(closure);
+    Owner::on_cleanup(move || {
+        target
+            .remove_event_listener_with_callback(event_name, closure.as_ref().unchecked_ref())
+            .ok();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use any_spawner::Executor;
+
+    struct NoopExecutor;
+
+    impl any_spawner::CustomExecutor for NoopExecutor {
+        fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+        fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+        fn poll_local(&self) {}
+    }
+
+    fn with_owner<T>(f: impl FnOnce() -> T) -> T {
+        let _ = Executor::init_custom_executor(NoopExecutor);
+        let owner = Owner::new_root(None);
+        owner.with(f)
+    }
+
+    fn use_test_form_control() -> FormControlAttributes {
+        use_form_control(UseFormControlParams {
+            native_ref: AnyNodeRef::new(),
+            focus_ref: AnyNodeRef::new(),
+        })
+    }
+
+    #[test]
+    fn attributes_are_empty_outside_of_a_form_field() {
+        with_owner(|| {
+            let attributes = use_test_form_control();
+            assert_eq!(attributes.id.get_untracked(), None);
+            assert_eq!(attributes.name.get_untracked(), None);
+            assert_eq!(attributes.aria_describedby.get_untracked(), None);
+            assert_eq!(attributes.aria_invalid.get_untracked(), None);
+        });
+    }
+
+    #[test]
+    fn attributes_come_from_the_form_field() {
+        with_owner(|| {
+            provide_context(FormControlContextValue {
+                attributes: FormControlAttributes {
+                    id: Signal::stored(Some("field-id".into())),
+                    name: Signal::stored(Some("terms".into())),
+                    aria_invalid: Signal::stored(Some("true")),
+                    ..Default::default()
+                },
+                on_event: Callback::new(|_| {}),
+                on_error: Callback::new(|_| {}),
+            });
+
+            let attributes = use_test_form_control();
+            assert_eq!(attributes.id.get_untracked().as_deref(), Some("field-id"));
+            assert_eq!(attributes.name.get_untracked().as_deref(), Some("terms"));
+            assert_eq!(attributes.aria_invalid.get_untracked(), Some("true"));
+            assert_eq!(attributes.data_valid.get_untracked(), None);
+        });
+    }
+
+    #[test]
+    fn into_attrs_renders_aria_and_data_attributes() {
+        with_owner(|| {
+            let attributes = FormControlAttributes {
+                id: Signal::stored(Some("field-id".into())),
+                aria_describedby: Signal::stored(Some("field-message".into())),
+                aria_invalid: Signal::stored(Some("true")),
+                data_invalid: Signal::stored(Some("")),
+                ..Default::default()
+            };
+
+            let mut html = String::new();
+            attributes.into_attrs().to_html(
+                &mut html,
+                &mut String::new(),
+                &mut String::new(),
+                &mut String::new(),
+            );
+            assert!(html.contains(r#"aria-describedby="field-message""#));
+            assert!(html.contains(r#"aria-invalid="true""#));
+            assert!(html.contains(r#"data-invalid"#));
+            assert!(!html.contains("data-valid="));
+            assert!(!html.contains("field-id"));
+        });
+    }
+}
@@ -2,18 +2,194 @@
 //!
 //! Provides [`use_id`] which returns a stable, unique ID signal for
 //! associating ARIA attributes (e.g., `aria-controls`, `aria-labelledby`).
+//!
+//! IDs are allocated from the nearest [`IdProvider`]. Because the provider is
+//! created as part of the component tree, every SSR request gets its own
+//! counter and the client replays the same sequence during hydration, so
+//! `aria-*` links rendered on the server still match after hydration. Without
+//! a provider, IDs fall back to a process-wide counter, which is only stable
+//! for client-side rendering.
+//!
+//! A nested [`IdProvider`] shares its parent's counter unless it is given a
+//! different prefix, so IDs stay unique across the whole tree.
 
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
 
-use leptos::prelude::*;
+use leptos::{context::Provider, prelude::*};
+
+const DEFAULT_PREFIX: &str = "radix";
 
 static COUNT: AtomicUsize = AtomicUsize::new(0);
 
+/// ID allocator provided by [`IdProvider`].
+#[derive(Clone, Debug)]
+pub struct IdContextValue {
+    prefix: Arc<str>,
+    count: Arc<AtomicUsize>,
+}
+
+impl IdContextValue {
+    pub fn new(prefix: Option<String>) -> Self {
+        Self {
+            prefix: prefix.as_deref().unwrap_or(DEFAULT_PREFIX).into(),
+            count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// The prefix prepended to every ID allocated by this provider.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The allocator for a provider with `prefix`: the parent provider's, if
+    /// there is one and `prefix` doesn't differ from it, otherwise a new one.
+    fn scoped(prefix: Option<String>) -> Self {
+        match (use_context::<IdContextValue>(), prefix) {
+            (Some(parent), None) => parent,
+            (Some(parent), Some(prefix)) if *parent.prefix == prefix => parent,
+            (_, prefix) => Self::new(prefix),
+        }
+    }
+
+    fn next_id(&self) -> String {
+        format!(
+            "{}-{}",
+            self.prefix,
+            self.count.fetch_add(1, Ordering::Relaxed)
+        )
+    }
+}
+
+/// Scopes ID generation to a subtree.
+///
+/// Render one at the root of the app so that each SSR request starts counting
+/// from zero. The root provider replaces the process-wide counter, so render
+/// every component that uses [`use_id`] inside it: IDs allocated outside of it
+/// count separately and may collide with the provider's.
+///
+/// A nested provider without a `prefix` (or with its parent's) continues the
+/// parent's counter. Use a different `prefix` to keep IDs unique when several
+/// independently rendered apps (e.g. micro-frontends) share one document.
+#[component]
+pub fn IdProvider(
+    /// Prefix for generated IDs. Defaults to `"radix"`.
+    #[prop(into, optional)]
+    prefix: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <Provider value={IdContextValue::scoped(prefix)}>{children()}</Provider>
+    }
+}
+
 pub fn use_id(deterministic_id: Option<String>) -> ReadSignal<String> {
-    let (id, _) = signal(
-        deterministic_id
-            .unwrap_or_else(|| format!("radix-{}", COUNT.fetch_add(1, Ordering::Relaxed))),
-    );
+    let (id, _) = signal(deterministic_id.unwrap_or_else(|| {
+        use_context::<IdContextValue>()
+            .map(|context| context.next_id())
+            .unwrap_or_else(|| {
+                format!("{DEFAULT_PREFIX}-{}", COUNT.fetch_add(1, Ordering::Relaxed))
+            })
+    }));
 
     id
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use any_spawner::Executor;
+    use leptos::reactive::owner::Owner;
+
+    struct NoopExecutor;
+
+    impl any_spawner::CustomExecutor for NoopExecutor {
+        fn spawn(&self, _fut: any_spawner::PinnedFuture<()>) {}
+        fn spawn_local(&self, _fut: any_spawner::PinnedLocalFuture<()>) {}
+        fn poll_local(&self) {}
+    }
+
+    fn with_owner<T>(f: impl FnOnce() -> T) -> T {
+        let _ = Executor::init_custom_executor(NoopExecutor);
+        let owner = Owner::new_root(None);
+        owner.with(f)
+    }
+
+    #[test]
+    fn deterministic_id_takes_precedence() {
+        with_owner(|| {
+            provide_context(IdContextValue::new(None));
+            assert_eq!(use_id(Some("custom".into())).get(), "custom");
+        });
+    }
+
+    #[test]
+    fn provider_ids_are_sequential() {
+        with_owner(|| {
+            provide_context(IdContextValue::new(None));
+            assert_eq!(use_id(None).get(), "radix-0");
+            assert_eq!(use_id(None).get(), "radix-1");
+        });
+    }
+
+    #[test]
+    fn separate_providers_produce_identical_sequences() {
+        // Simulates two SSR requests (or server + hydrating client).
+        let first = with_owner(|| {
+            provide_context(IdContextValue::new(None));
+            [use_id(None).get(), use_id(None).get()]
+        });
+        let second = with_owner(|| {
+            provide_context(IdContextValue::new(None));
+            [use_id(None).get(), use_id(None).get()]
+        });
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn provider_prefix_is_applied() {
+        with_owner(|| {
+            provide_context(IdContextValue::new(Some("checkout".into())));
+            assert_eq!(use_id(None).get(), "checkout-0");
+        });
+    }
+
+    #[test]
+    fn nested_provider_continues_parent_counter() {
+        with_owner(|| {
+            provide_context(IdContextValue::scoped(None));
+            assert_eq!(use_id(None).get(), "radix-0");
+
+            Owner::new().with(|| {
+                provide_context(IdContextValue::scoped(None));
+                assert_eq!(use_id(None).get(), "radix-1");
+
+                Owner::new().with(|| {
+                    provide_context(IdContextValue::scoped(Some("radix".into())));
+                    assert_eq!(use_id(None).get(), "radix-2");
+                });
+            });
+
+            assert_eq!(use_id(None).get(), "radix-3");
+        });
+    }
+
+    #[test]
+    fn nested_provider_with_new_prefix_starts_own_counter() {
+        with_owner(|| {
+            provide_context(IdContextValue::scoped(None));
+            assert_eq!(use_id(None).get(), "radix-0");
+
+            Owner::new().with(|| {
+                provide_context(IdContextValue::scoped(Some("widget".into())));
+                assert_eq!(use_id(None).get(), "widget-0");
+                assert_eq!(use_id(None).get(), "widget-1");
+            });
+
+            assert_eq!(use_id(None).get(), "radix-1");
+        });
+    }
+}
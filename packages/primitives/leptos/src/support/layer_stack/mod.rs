@@ -0,0 +1,329 @@
+//! Global stack of open overlay layers.
+//!
+//! Every [`DismissableLayer`](crate::support::dismissable_layer::DismissableLayer)
+//! registers itself in a global stack in mount order. This module builds on
+//! that stack to:
+//!
+//! - Expose the stack to consumers via [`use_layer_stack`], e.g. to check
+//!   whether the surrounding overlay is the topmost one.
+//! - Make everything outside the topmost modal overlay `inert` (falling back
+//!   to `aria-hidden` where `inert` is unsupported). Layers opened on top of
+//!   the modal (such as a tooltip inside a dialog) stay interactive, as do
+//!   any external elements the modal allows focus to move to, and closing a
+//!   nested modal re-applies isolation for the one below it.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+use once_cell::sync::Lazy;
+use send_wrapper::SendWrapper;
+use web_sys::wasm_bindgen::{JsCast, closure::Closure};
+
+use crate::support::aria_hidden::{HideMode, hide_others, unhide_others};
+use crate::support::dismissable_layer::{CONTEXT_UPDATE, layer_index, layers_above, layers_count};
+
+static MODAL_STACK: Lazy<Mutex<ModalStack>> = Lazy::new(|| Mutex::new(ModalStack::default()));
+
+struct Modal {
+    content: SendWrapper<web_sys::HtmlElement>,
+    external: Vec<SendWrapper<web_sys::Element>>,
+}
+
+#[derive(Default)]
+struct ModalStack {
+    modals: Vec<Modal>,
+    hidden: Vec<SendWrapper<web_sys::Element>>,
+    mode: Option<HideMode>,
+}
+
+impl ModalStack {
+    fn apply(&mut self) {
+        let mode = *self.mode.get_or_insert_with(HideMode::detect);
+
+        unhide_others(&self.hidden, mode);
+        self.hidden.clear();
+
+        if let Some(top) = self.modals.last() {
+            let content: &web_sys::HtmlElement = &top.content;
+            let mut keep: Vec<web_sys::Element> = vec![content.clone().into()];
+            keep.extend(layers_above(content).into_iter().map(Into::into));
+            keep.extend(top.external.iter().map(|element| (**element).clone()));
+            self.hidden = hide_others(&keep, mode);
+        }
+    }
+}
+
+/// Makes everything outside `content_ref` and `external` inert while the
+/// calling component is mounted.
+///
+/// Isolation is deferred to the next animation frame so that focus scopes can
+/// move focus into the content first; hiding the still-focused trigger would
+/// otherwise blur it and trigger a "Blocked aria-hidden" browser warning.
+///
+/// Returns a callback that releases isolation early, e.g. right before focus
+/// returns to a trigger outside the layer.
+pub(crate) fn use_inert_others(
+    content_ref: AnyNodeRef,
+    external: MaybeProp<SendWrapper<Vec<web_sys::Element>>>,
+) -> Callback<()> {
+    // Shared storage that outlives StoredValue disposal, so cleanup can still release.
+    let content: SendWrapper<Rc<RefCell<Option<web_sys::HtmlElement>>>> =
+        SendWrapper::new(Rc::new(RefCell::new(None)));
+
+    Effect::new({
+        let content = content.clone();
+        move |_| {
+            if let Some(node) = content_ref.get() {
+                let node: web_sys::HtmlElement = node.unchecked_into();
+                *content.borrow_mut() = Some(node.clone());
+                let external = external.get().map(|external| (*external).clone());
+                let cb = Closure::once_into_js(move || {
+                    // Skip if the content unmounted before the frame fired.
+                    if node.is_connected() {
+                        inert_others(&node, external.unwrap_or_default());
+                    }
+                });
+                window().request_animation_frame(cb.unchecked_ref()).ok();
+            }
+        }
+    });
+
+    let release = Callback::new(move |_: ()| {
+        if let Some(node) = content.borrow().as_ref() {
+            release_inert(node);
+        }
+    });
+
+    on_cleanup(move || release.run(()));
+
+    release
+}
+
+/// Registers `content` as a modal layer and makes everything outside the
+/// topmost modal inert.
+fn inert_others(content: &web_sys::HtmlElement, external: Vec<web_sys::Element>) {
+    let mut stack = MODAL_STACK.lock().expect("Modal stack mutex should lock.");
+    let external = external.into_iter().map(SendWrapper::new).collect();
+    match stack
+        .modals
+        .iter_mut()
+        .find(|modal| *modal.content == *content)
+    {
+        Some(modal) => modal.external = external,
+        None => stack.modals.push(Modal {
+            content: SendWrapper::new(content.clone()),
+            external,
+        }),
+    }
+    stack.apply();
+}
+
+/// Unregisters `content` and restores elements hidden on its behalf. Safe to
+/// call more than once.
+fn release_inert(content: &web_sys::HtmlElement) {
+    let mut stack = MODAL_STACK.lock().expect("Modal stack mutex should lock.");
+    let count = stack.modals.len();
+    stack.modals.retain(|modal| *modal.content != *content);
+    if stack.modals.len() != count {
+        stack.apply();
+    }
+}
+
+/// Re-applies isolation after layers above the top modal changed.
+pub(crate) fn refresh_inert() {
+    let mut stack = MODAL_STACK.lock().expect("Modal stack mutex should lock.");
+    if !stack.modals.is_empty() {
+        stack.apply();
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * use_layer_stack
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Provided by each `DismissableLayer` so descendants can locate it.
+#[derive(Clone, Copy)]
+pub(crate) struct LayerNodeContextValue(pub(crate) AnyNodeRef);
+
+/// Reactive view of the overlay layer stack, returned by [`use_layer_stack`].
+#[derive(Clone, Copy, Debug)]
+pub struct LayerStack {
+    /// Position of the enclosing layer in the stack (`0` is the bottom), or
+    /// `None` when called outside a layer.
+    pub index: Signal<Option<usize>>,
+    /// Number of open layers.
+    pub count: Signal<usize>,
+    /// Whether the enclosing layer is the topmost one. Outside a layer, this
+    /// is `true` when no layers are open.
+    pub is_topmost: Signal<bool>,
+}
+
+/// Returns the overlay layer stack as seen from the calling component.
+pub fn use_layer_stack() -> LayerStack {
+    let layer = use_context::<LayerNodeContextValue>();
+
+    let version = RwSignal::new(0u64);
+    let update_closure: SendWrapper<Closure<dyn Fn(web_sys::Event)>> =
+        SendWrapper::new(Closure::new(move |_event: web_sys::Event| {
+            version.try_update(|v| *v += 1);
+        }));
+    document()
+        .add_event_listener_with_callback(CONTEXT_UPDATE, update_closure.as_ref().unchecked_ref())
+        .expect("Layer stack update listener should be added.");
+    on_cleanup(move || {
+        document()
+            .remove_event_listener_with_callback(
+                CONTEXT_UPDATE,
+                update_closure.as_ref().unchecked_ref(),
+            )
+            .ok();
+    });
+
+    let index = Signal::derive(move || {
+        version.track();
+        layer
+            .and_then(|layer| layer.0.get())
+            .and_then(|node| layer_index(node.unchecked_ref()))
+    });
+    let count = Signal::derive(move || {
+        version.track();
+        layers_count()
+    });
+    let is_topmost = Signal::derive(move || is_topmost(index.get(), count.get()));
+
+    LayerStack {
+        index,
+        count,
+        is_topmost,
+    }
+}
+
+/// Whether the layer at `index` is the topmost of `count` layers. Outside a
+/// layer (`index` is `None`), whether no layers are open.
+fn is_topmost(index: Option<usize>, count: usize) -> bool {
+    match index {
+        Some(index) => index + 1 == count,
+        None => count == 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topmost_is_the_last_layer() {
+        assert!(is_topmost(Some(2), 3));
+        assert!(!is_topmost(Some(0), 3));
+        assert!(!is_topmost(Some(1), 3));
+    }
+
+    #[test]
+    fn topmost_outside_a_layer_only_without_open_layers() {
+        assert!(is_topmost(None, 0));
+        assert!(!is_topmost(None, 1));
+    }
+
+    // ── WASM browser tests ───────────────────────────────────
+
+    mod wasm {
+        use super::super::*;
+        use wasm_bindgen_test::*;
+
+        wasm_bindgen_test_configure!(run_in_browser);
+
+        /// Appends a div to the document body and returns it. The caller
+        /// should remove it when done.
+        fn append_to_body() -> web_sys::HtmlElement {
+            let element: web_sys::HtmlElement =
+                document().create_element("div").unwrap().unchecked_into();
+            document().body().unwrap().append_child(&element).unwrap();
+            element
+        }
+
+        fn is_hidden(element: &web_sys::HtmlElement) -> bool {
+            element.get_attribute("aria-hidden").as_deref() == Some("true")
+        }
+
+        #[wasm_bindgen_test]
+        fn nested_modal_isolates_the_topmost_and_restores_on_release() {
+            let app = append_to_body();
+            let first = append_to_body();
+            let second = append_to_body();
+
+            inert_others(&first, vec![]);
+            assert!(is_hidden(&app));
+            assert!(!is_hidden(&first));
+            assert!(is_hidden(&second));
+
+            inert_others(&second, vec![]);
+            assert!(is_hidden(&app));
+            assert!(is_hidden(&first));
+            assert!(!is_hidden(&second));
+
+            // Popping the top modal re-applies isolation for the one below it.
+            release_inert(&second);
+            assert!(is_hidden(&app));
+            assert!(!is_hidden(&first));
+            assert!(is_hidden(&second));
+
+            release_inert(&first);
+            for element in [&app, &first, &second] {
+                assert!(!is_hidden(element));
+                assert!(!element.has_attribute("inert"));
+                element.remove();
+            }
+        }
+
+        #[wasm_bindgen_test]
+        fn releasing_a_lower_modal_keeps_the_topmost_isolated() {
+            let app = append_to_body();
+            let first = append_to_body();
+            let second = append_to_body();
+
+            inert_others(&first, vec![]);
+            inert_others(&second, vec![]);
+
+            release_inert(&first);
+            assert!(is_hidden(&app));
+            assert!(is_hidden(&first));
+            assert!(!is_hidden(&second));
+
+            release_inert(&second);
+            for element in [&app, &first, &second] {
+                assert!(!is_hidden(element));
+                element.remove();
+            }
+        }
+
+        #[wasm_bindgen_test]
+        fn release_restores_only_elements_hidden_by_the_stack() {
+            let app = append_to_body();
+            let hidden_by_app = append_to_body();
+            hidden_by_app.set_attribute("aria-hidden", "true").unwrap();
+            let external = append_to_body();
+            let modal = append_to_body();
+
+            inert_others(&modal, vec![external.clone().into()]);
+            assert!(is_hidden(&app));
+            assert!(!is_hidden(&external));
+
+            // Registering the same content again updates it in place.
+            inert_others(&modal, vec![]);
+            assert!(is_hidden(&external));
+
+            release_inert(&modal);
+            release_inert(&modal);
+            assert!(!is_hidden(&app));
+            assert!(!is_hidden(&external));
+            assert!(is_hidden(&hidden_by_app));
+
+            for element in [&app, &hidden_by_app, &external, &modal] {
+                element.remove();
+            }
+        }
+    }
+}
@@ -1,5 +1,4 @@
 pub mod announce;
-pub mod class;
 pub mod aria_hidden;
 pub mod arrow;
 pub mod collection;
@@ -8,8 +7,10 @@ pub mod direction;
 pub mod dismissable_layer;
 pub mod focus_guards;
 pub mod focus_scope;
+pub mod form_control;
 pub(crate) mod forwarded_attrs;
 pub mod id;
+pub mod layer_stack;
 pub mod popper;
 pub mod portal;
 pub mod presence;
@@ -5,10 +5,15 @@
 //! avoidance (flip, shift, hide), offset, and arrow placement. Used by
 //! popover, tooltip, hover card, dropdown menu, select, and other
 //! floating components.
+//!
+//! Besides its own DOM element, [`PopperAnchor`] can anchor content to a
+//! virtual rect, to the pointer position ([`FollowCursor`]) or to a DOM
+//! `Range` such as the current text selection.
 
 use crate::support::arrow::Arrow as ArrowPrimitive;
 use crate::support::compose_refs::use_composed_refs;
 use crate::support::primitive::Primitive;
+use crate::support::scroll_lock::use_scroll_lock_shard;
 use crate::support::use_size::use_size;
 use floating_ui_leptos::{
     ARROW_NAME, Alignment, ApplyState, Arrow, ArrowData, ArrowOptions, AutoUpdateOptions, Boundary,
@@ -66,6 +71,17 @@ pub enum UpdatePositionStrategy {
     Always,
 }
 
+/// Axes along which content follows the pointer over a [`PopperAnchor`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FollowCursor {
+    /// Follow horizontally, keeping the anchor's vertical edges.
+    X,
+    /// Follow vertically, keeping the anchor's horizontal edges.
+    Y,
+    /// Follow in both axes, anchoring to the pointer itself.
+    Both,
+}
+
 #[derive(Clone, Copy)]
 struct PopperContextValue {
     pub anchor_ref: AnyNodeRef,
@@ -132,8 +148,25 @@ pub fn set_popper_virtual_ref(virtual_el: Box<dyn VirtualElement<web_sys::Elemen
         .set(Some(SendWrapper::new(virtual_el)));
 }
 
+/// Marks the element content is positioned against.
+///
+/// The anchoring mode props take precedence over the element, in the order
+/// `range`, `virtual_rect`, `follow_cursor`. Content is repositioned whenever
+/// `range` changes, a signal read by `virtual_rect` changes or the pointer
+/// moves. With `UpdatePositionStrategy::Always`, the rects are also re-read
+/// every animation frame, for anchors that move without a signal changing.
 #[component]
 pub fn PopperAnchor(
+    /// Anchors content to a DOM range, e.g. the current text selection.
+    #[prop(into, optional)]
+    range: MaybeProp<SendWrapper<web_sys::Range>>,
+    /// Anchors content to the rect returned by this callback.
+    #[prop(into, optional_no_strip)]
+    virtual_rect: Option<Callback<(), ClientRectObject>>,
+    /// Anchors content to the pointer while it moves over the anchor element.
+    /// Until the pointer moves, content is anchored to the element.
+    #[prop(into, optional)]
+    follow_cursor: MaybeProp<FollowCursor>,
     #[prop(into, optional)] as_child: MaybeProp<bool>,
     #[prop(optional)] node_ref: AnyNodeRef,
     children: ChildrenFn,
@@ -141,17 +174,194 @@ pub fn PopperAnchor(
     let context: PopperContextValue = expect_context();
     let composed_refs = use_composed_refs(vec![node_ref, context.anchor_ref]);
 
+    let cursor = RwSignal::new(None::<(f64, f64)>);
+    // Whether this anchor set the virtual reference, so it only clears its own
+    // (e.g. not one set through `set_popper_virtual_ref`).
+    let owns_virtual = StoredValue::new(false);
+
+    // Setting the virtual reference, even to an equal one, makes `PopperContent`
+    // recompute its position.
+    Effect::new(move |_| {
+        let cursor = match (follow_cursor.get(), cursor.get(), context.anchor_ref.get()) {
+            (Some(axis), Some(position), Some(anchor)) => Some((axis, position, anchor)),
+            _ => None,
+        };
+
+        if let Some(virtual_el) = virtual_anchor(
+            range.get().map(|range| (*range).clone()),
+            virtual_rect,
+            cursor,
+        ) {
+            owns_virtual.set_value(true);
+            context
+                .anchor_virtual
+                .set(Some(SendWrapper::new(virtual_el)));
+        } else if owns_virtual.get_value() {
+            owns_virtual.set_value(false);
+            context.anchor_virtual.set(None);
+        }
+    });
+
+    on_cleanup(move || {
+        if owns_virtual.try_get_value().unwrap_or(false) {
+            context.anchor_virtual.try_set(None);
+        }
+    });
+
     view! {
         <Primitive
             element=html::div
             as_child=as_child
             node_ref=composed_refs
+            on:pointermove=move |event: web_sys::PointerEvent| {
+                if follow_cursor.get_untracked().is_some() {
+                    cursor.set(Some((event.client_x() as f64, event.client_y() as f64)));
+                }
+            }
         >
             {children()}
         </Primitive>
     }
 }
 
+/// The virtual element content is anchored to: `range`, then `virtual_rect`,
+/// then the pointer over `anchor` as `(axis, (x, y), anchor)`, if any.
+///
+/// Reads `virtual_rect` once, so that a calling effect tracks the signals it
+/// depends on.
+fn virtual_anchor(
+    range: Option<web_sys::Range>,
+    virtual_rect: Option<Callback<(), ClientRectObject>>,
+    cursor: Option<(FollowCursor, (f64, f64), web_sys::Element)>,
+) -> Option<Box<dyn VirtualElement<web_sys::Element>>> {
+    if let Some(range) = range {
+        Some(Box::new(RangeVirtualElement(range)))
+    } else if let Some(get_rect) = virtual_rect {
+        get_rect.run(());
+        Some(Box::new(RectVirtualElement(get_rect)))
+    } else if let Some((axis, (x, y), anchor)) = cursor {
+        Some(Box::new(CursorVirtualElement { anchor, axis, x, y }))
+    } else {
+        None
+    }
+}
+
+/// Builds a [`ClientRectObject`] from an origin and a size.
+fn client_rect(x: f64, y: f64, width: f64, height: f64) -> ClientRectObject {
+    ClientRectObject {
+        x,
+        y,
+        width,
+        height,
+        top: y,
+        right: x + width,
+        bottom: y + height,
+        left: x,
+    }
+}
+
+fn dom_rect_to_client_rect(rect: &web_sys::DomRect) -> ClientRectObject {
+    client_rect(rect.x(), rect.y(), rect.width(), rect.height())
+}
+
+/// Virtual anchor backed by a rect callback.
+#[derive(Clone)]
+struct RectVirtualElement(Callback<(), ClientRectObject>);
+
+impl PartialEq for RectVirtualElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_bounding_client_rect() == other.get_bounding_client_rect()
+    }
+}
+
+impl VirtualElement<web_sys::Element> for RectVirtualElement {
+    fn get_bounding_client_rect(&self) -> ClientRectObject {
+        // The callback may be disposed while a final animation frame update is pending.
+        self.0
+            .try_run(())
+            .unwrap_or_else(|| client_rect(0.0, 0.0, 0.0, 0.0))
+    }
+
+    fn get_client_rects(&self) -> Option<Vec<ClientRectObject>> {
+        None
+    }
+
+    fn context_element(&self) -> Option<web_sys::Element> {
+        None
+    }
+}
+
+/// Virtual anchor backed by a DOM range.
+#[derive(Clone, PartialEq)]
+struct RangeVirtualElement(web_sys::Range);
+
+impl VirtualElement<web_sys::Element> for RangeVirtualElement {
+    fn get_bounding_client_rect(&self) -> ClientRectObject {
+        dom_rect_to_client_rect(&self.0.get_bounding_client_rect())
+    }
+
+    fn get_client_rects(&self) -> Option<Vec<ClientRectObject>> {
+        let rects = self.0.get_client_rects()?;
+        Some(
+            (0..rects.length())
+                .filter_map(|i| rects.item(i))
+                .map(|rect| dom_rect_to_client_rect(&rect))
+                .collect(),
+        )
+    }
+
+    fn context_element(&self) -> Option<web_sys::Element> {
+        // Lets auto update observe the scroll ancestors of the selected content.
+        let container = self.0.common_ancestor_container().ok()?;
+        match container.dyn_ref::<web_sys::Element>() {
+            Some(element) => Some(element.clone()),
+            None => container.parent_element(),
+        }
+    }
+}
+
+/// Virtual anchor following the pointer over an anchor element.
+#[derive(Clone, PartialEq)]
+struct CursorVirtualElement {
+    anchor: web_sys::Element,
+    axis: FollowCursor,
+    x: f64,
+    y: f64,
+}
+
+impl VirtualElement<web_sys::Element> for CursorVirtualElement {
+    fn get_bounding_client_rect(&self) -> ClientRectObject {
+        cursor_rect(
+            &dom_rect_to_client_rect(&self.anchor.get_bounding_client_rect()),
+            self.axis,
+            self.x,
+            self.y,
+        )
+    }
+
+    fn get_client_rects(&self) -> Option<Vec<ClientRectObject>> {
+        None
+    }
+
+    fn context_element(&self) -> Option<web_sys::Element> {
+        Some(self.anchor.clone())
+    }
+}
+
+/// The rect of the pointer at `(x, y)` over an anchor with rect `anchor`,
+/// collapsed to the pointer along the followed `axis`.
+fn cursor_rect(anchor: &ClientRectObject, axis: FollowCursor, x: f64, y: f64) -> ClientRectObject {
+    let (x, width) = match axis {
+        FollowCursor::Y => (anchor.x, anchor.width),
+        FollowCursor::X | FollowCursor::Both => (x, 0.0),
+    };
+    let (y, height) = match axis {
+        FollowCursor::X => (anchor.y, anchor.height),
+        FollowCursor::Y | FollowCursor::Both => (y, 0.0),
+    };
+    client_rect(x, y, width, height)
+}
+
 #[derive(Clone)]
 struct PopperContentContextValue {
     pub placed_side: Signal<Side>,
@@ -209,6 +419,9 @@ pub fn PopperContent(
         Signal::derive(move || Placement::from((side.get(), align.get().alignment())));
 
     let floating_ref = AnyNodeRef::new();
+    // Content is usually portaled out of the layer that locked scrolling, e.g. a select in a
+    // dialog, so it's registered to stay scrollable.
+    use_scroll_lock_shard(floating_ref);
 
     let anchor_virtual = context.anchor_virtual;
     let anchor_node = context.anchor_ref;
@@ -359,6 +572,18 @@ pub fn PopperContent(
         }
     });
 
+    // Recompute when a virtual anchor is set, e.g. because the range, the
+    // signals read by the virtual rect or the pointer position changed.
+    Effect::new({
+        let update_floating_position = update_floating_position.clone();
+
+        move |_| {
+            if anchor_virtual.with(Option::is_some) {
+                update_floating_position();
+            }
+        }
+    });
+
     let placed_side = Signal::derive(move || placement.get().side());
     let placed_align = move || Align::from(placement.get().alignment());
 
@@ -767,3 +992,168 @@ impl Middleware<web_sys::Element, web_sys::Window> for TransformOrigin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use leptos::reactive::owner::Owner;
+
+    fn rect_callback(rect: ClientRectObject) -> Callback<(), ClientRectObject> {
+        Callback::new(move |_| rect.clone())
+    }
+
+    #[test]
+    fn cursor_rect_follows_both_axes() {
+        let anchor = client_rect(10.0, 20.0, 100.0, 50.0);
+        assert_eq!(
+            cursor_rect(&anchor, FollowCursor::Both, 40.0, 30.0),
+            client_rect(40.0, 30.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn cursor_rect_keeps_the_anchor_edges_of_the_other_axis() {
+        let anchor = client_rect(10.0, 20.0, 100.0, 50.0);
+        assert_eq!(
+            cursor_rect(&anchor, FollowCursor::X, 40.0, 30.0),
+            client_rect(40.0, 20.0, 0.0, 50.0)
+        );
+        assert_eq!(
+            cursor_rect(&anchor, FollowCursor::Y, 40.0, 30.0),
+            client_rect(10.0, 30.0, 100.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn virtual_rect_anchor_reads_the_callback() {
+        Owner::new_root(None).with(|| {
+            let rect = client_rect(5.0, 6.0, 7.0, 8.0);
+            let anchor = virtual_anchor(None, Some(rect_callback(rect.clone())), None)
+                .expect("Virtual rect should anchor.");
+
+            assert_eq!(anchor.get_bounding_client_rect(), rect);
+            assert!(anchor.context_element().is_none());
+        });
+    }
+
+    #[test]
+    fn disposed_virtual_rect_falls_back_to_an_empty_rect() {
+        Owner::new_root(None).with(|| {
+            let get_rect = rect_callback(client_rect(5.0, 6.0, 7.0, 8.0));
+            let anchor = RectVirtualElement(get_rect);
+            get_rect.dispose();
+
+            assert_eq!(
+                anchor.get_bounding_client_rect(),
+                client_rect(0.0, 0.0, 0.0, 0.0)
+            );
+        });
+    }
+
+    #[test]
+    fn no_anchoring_mode_anchors_to_the_element() {
+        assert!(virtual_anchor(None, None, None).is_none());
+    }
+
+    // ── WASM browser tests ───────────────────────────────────
+
+    mod wasm {
+        use super::super::*;
+        use super::rect_callback;
+        use leptos::reactive::owner::Owner;
+        use wasm_bindgen_test::*;
+
+        wasm_bindgen_test_configure!(run_in_browser);
+
+        /// Appends a fixed-size block with some text to the document body. The
+        /// caller should remove it when done.
+        fn append_anchor() -> web_sys::HtmlElement {
+            let element: web_sys::HtmlElement =
+                document().create_element("div").unwrap().unchecked_into();
+            element
+                .set_attribute(
+                    "style",
+                    "position: fixed; left: 10px; top: 20px; width: 100px; height: 50px",
+                )
+                .unwrap();
+            element.set_text_content(Some("Selected text"));
+            document().body().unwrap().append_child(&element).unwrap();
+            element
+        }
+
+        fn text_range(element: &web_sys::HtmlElement) -> web_sys::Range {
+            let range = document().create_range().unwrap();
+            range
+                .select_node_contents(&element.first_child().unwrap())
+                .unwrap();
+            range
+        }
+
+        #[wasm_bindgen_test]
+        fn range_anchor_uses_the_range_rects() {
+            let element = append_anchor();
+            let range = text_range(&element);
+
+            let anchor = virtual_anchor(Some(range.clone()), None, None).unwrap();
+
+            assert_eq!(
+                anchor.get_bounding_client_rect(),
+                dom_rect_to_client_rect(&range.get_bounding_client_rect())
+            );
+            assert!(!anchor.get_client_rects().unwrap().is_empty());
+            // The common ancestor is the text node, so its parent is observed.
+            assert_eq!(anchor.context_element(), Some(element.clone().into()));
+
+            element.remove();
+        }
+
+        #[wasm_bindgen_test]
+        fn range_takes_precedence_over_virtual_rect_and_cursor() {
+            Owner::new_root(None).with(|| {
+                let element = append_anchor();
+                let range = text_range(&element);
+                let virtual_rect = client_rect(1.0, 2.0, 3.0, 4.0);
+                let cursor = Some((FollowCursor::Both, (40.0, 30.0), element.clone().into()));
+
+                let anchor = virtual_anchor(
+                    Some(range.clone()),
+                    Some(rect_callback(virtual_rect.clone())),
+                    cursor.clone(),
+                )
+                .unwrap();
+                assert_eq!(
+                    anchor.get_bounding_client_rect(),
+                    dom_rect_to_client_rect(&range.get_bounding_client_rect())
+                );
+
+                let anchor =
+                    virtual_anchor(None, Some(rect_callback(virtual_rect.clone())), cursor)
+                        .unwrap();
+                assert_eq!(anchor.get_bounding_client_rect(), virtual_rect);
+
+                element.remove();
+            });
+        }
+
+        #[wasm_bindgen_test]
+        fn cursor_anchor_collapses_the_anchor_rect_to_the_pointer() {
+            let element = append_anchor();
+
+            let anchor = virtual_anchor(
+                None,
+                None,
+                Some((FollowCursor::X, (40.0, 30.0), element.clone().into())),
+            )
+            .unwrap();
+
+            assert_eq!(
+                anchor.get_bounding_client_rect(),
+                client_rect(40.0, 20.0, 0.0, 50.0)
+            );
+            assert_eq!(anchor.context_element(), Some(element.clone().into()));
+
+            element.remove();
+        }
+    }
+}
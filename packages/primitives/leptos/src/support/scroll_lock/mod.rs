@@ -3,71 +3,548 @@
 //! Prevents background page scrolling while a modal component (dialog,
 //! popover, select) is open. Compensates for scrollbar width to avoid
 //! layout shift.
+//!
+//! Locks are reference-counted in a global stack: the `<body>` styles are
+//! applied when the first lock is taken and restored when the last one is
+//! released, regardless of the order in which overlapping overlays close.
+//! While locked, `wheel` and `touchmove` events outside the topmost lock's
+//! layer, its shards, popper content and dismissable layers opened above it
+//! are cancelled, which also stops touch scrolling on iOS Safari where
+//! `overflow: hidden` alone is ignored.
+//!
+//! The removed scrollbar width is exposed on `<body>` as the
+//! `--removed-body-scroll-bar-size` CSS variable so fixed-position elements
+//! can compensate for it too.
 
+use std::cell::Cell;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::support::dismissable_layer::layers_above;
 use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+use once_cell::sync::Lazy;
+use send_wrapper::SendWrapper;
+use web_sys::{
+    AddEventListenerOptions, EventListenerOptions,
+    wasm_bindgen::{JsCast, closure::Closure},
+};
 
-/// Sets `overflow: hidden` on `<body>` while the calling component is mounted,
-/// compensating for scrollbar width to prevent layout shift, then restores the
-/// original values on cleanup.
-pub fn use_body_scroll_lock() {
-    let original_overflow: RwSignal<Option<String>> = RwSignal::new(None);
-    let original_padding_right: RwSignal<Option<String>> = RwSignal::new(None);
+/// CSS variable set on `<body>` with the compensated scrollbar width.
+pub const REMOVED_SCROLL_BAR_SIZE_VAR: &str = "--removed-body-scroll-bar-size";
+
+static NEXT_LOCK_ID: AtomicU64 = AtomicU64::new(0);
+
+static SCROLL_LOCK_STACK: Lazy<Mutex<ScrollLockStack>> =
+    Lazy::new(|| Mutex::new(ScrollLockStack::default()));
+
+thread_local! {
+    static TOUCH_START: Cell<(f64, f64)> = const { Cell::new((0.0, 0.0)) };
+}
+
+struct ScrollLock {
+    id: u64,
+    layer: AnyNodeRef,
+    shards: Vec<AnyNodeRef>,
+}
+
+#[derive(Default)]
+struct SavedBodyStyle {
+    overflow: String,
+    padding_right: String,
+}
+
+#[derive(Default)]
+struct ScrollLockStack {
+    locks: Vec<ScrollLock>,
+    global_shards: Vec<(u64, AnyNodeRef)>,
+    saved: Option<SavedBodyStyle>,
+    listeners: Option<SendWrapper<ScrollListeners>>,
+}
+
+struct ScrollListeners {
+    wheel: Closure<dyn Fn(web_sys::WheelEvent)>,
+    touch_start: Closure<dyn Fn(web_sys::TouchEvent)>,
+    touch_move: Closure<dyn Fn(web_sys::TouchEvent)>,
+}
+
+/// Parameters for [`use_scroll_lock`].
+#[derive(Clone, Default)]
+pub struct UseScrollLockParams {
+    /// Element that remains scrollable while locked. When unset, only shards
+    /// can scroll.
+    pub layer: AnyNodeRef,
+    /// Additional elements that remain scrollable while this lock is on top.
+    pub shards: Vec<AnyNodeRef>,
+}
+
+/// Locks body scroll while the calling component is mounted.
+///
+/// Nested locks stack: only the topmost lock's layer and shards accept wheel
+/// and touch scrolling, and the `<body>` styles are restored once every lock
+/// has been released.
+pub fn use_scroll_lock(UseScrollLockParams { layer, shards }: UseScrollLockParams) {
+    let id = NEXT_LOCK_ID.fetch_add(1, Ordering::Relaxed);
 
     Effect::new(move |_| {
-        if let Some(body) = document().body() {
-            let style = body.style();
-            let prev_overflow = style.get_property_value("overflow").unwrap_or_default();
-            let prev_padding_right = style
-                .get_property_value("padding-right")
-                .unwrap_or_default();
-            original_overflow.set(Some(prev_overflow));
-
-            // Measure scrollbar width: difference between window inner width and document client width.
-            let scrollbar_width = window()
-                .inner_width()
-                .ok()
-                .and_then(|v| v.as_f64())
-                .unwrap_or(0.0)
-                - document()
-                    .document_element()
-                    .map(|el| el.client_width() as f64)
-                    .unwrap_or(0.0);
-
-            // Parse any existing padding-right value and add the scrollbar width.
-            let existing_padding: f64 = prev_padding_right
-                .trim_end_matches("px")
-                .parse()
-                .unwrap_or(0.0);
-
-            original_padding_right.set(Some(prev_padding_right));
-
-            let _ = style.set_property(
-                "padding-right",
-                &format!("{}px", existing_padding + scrollbar_width),
-            );
-            let _ = style.set_property("overflow", "hidden");
+        let mut stack = SCROLL_LOCK_STACK
+            .lock()
+            .expect("Scroll lock mutex should lock.");
+        if stack.locks.iter().any(|lock| lock.id == id) {
+            return;
+        }
+        if stack.locks.is_empty() {
+            stack.saved = lock_body();
+            stack.listeners = Some(SendWrapper::new(add_listeners()));
         }
+        stack.locks.push(ScrollLock {
+            id,
+            layer,
+            shards: shards.clone(),
+        });
     });
 
     on_cleanup(move || {
-        if let Some(body) = document().body() {
-            let style = body.style();
-
-            if let Some(original) = original_overflow.get_untracked() {
-                if original.is_empty() {
-                    let _ = style.remove_property("overflow");
-                } else {
-                    let _ = style.set_property("overflow", &original);
-                }
+        let mut stack = SCROLL_LOCK_STACK
+            .lock()
+            .expect("Scroll lock mutex should lock.");
+        let was_locked = !stack.locks.is_empty();
+        stack.locks.retain(|lock| lock.id != id);
+        if was_locked && stack.locks.is_empty() {
+            if let Some(listeners) = stack.listeners.take() {
+                remove_listeners(&listeners);
             }
-
-            if let Some(original) = original_padding_right.get_untracked() {
-                if original.is_empty() {
-                    let _ = style.remove_property("padding-right");
-                } else {
-                    let _ = style.set_property("padding-right", &original);
-                }
+            if let Some(saved) = stack.saved.take() {
+                unlock_body(saved);
             }
         }
     });
 }
+
+/// Locks body scroll with no scrollable layer other than registered shards.
+pub fn use_body_scroll_lock() {
+    use_scroll_lock(UseScrollLockParams::default());
+}
+
+/// Registers an element that stays scrollable under every scroll lock (e.g. a
+/// toast viewport) for as long as the calling component is mounted.
+pub fn use_scroll_lock_shard(node_ref: AnyNodeRef) {
+    let id = NEXT_LOCK_ID.fetch_add(1, Ordering::Relaxed);
+
+    SCROLL_LOCK_STACK
+        .lock()
+        .expect("Scroll lock mutex should lock.")
+        .global_shards
+        .push((id, node_ref));
+
+    on_cleanup(move || {
+        SCROLL_LOCK_STACK
+            .lock()
+            .expect("Scroll lock mutex should lock.")
+            .global_shards
+            .retain(|(shard_id, _)| *shard_id != id);
+    });
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Body styles
+ * -----------------------------------------------------------------------------------------------*/
+
+fn lock_body() -> Option<SavedBodyStyle> {
+    let body = document().body()?;
+    let style = body.style();
+    let saved = SavedBodyStyle {
+        overflow: style.get_property_value("overflow").unwrap_or_default(),
+        padding_right: style
+            .get_property_value("padding-right")
+            .unwrap_or_default(),
+    };
+
+    // Measure scrollbar width: difference between window inner width and document client width.
+    let document_element = document().document_element();
+    let scrollbar_width = window()
+        .inner_width()
+        .ok()
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0)
+        - document_element
+            .as_ref()
+            .map(|el| el.client_width() as f64)
+            .unwrap_or(0.0);
+    // `scrollbar-gutter: stable` already reserves the space, so hiding the
+    // scrollbar causes no layout shift.
+    let gutter = document_element
+        .and_then(|el| window().get_computed_style(&el).ok().flatten())
+        .and_then(|computed| computed.get_property_value("scrollbar-gutter").ok())
+        .unwrap_or_default();
+    let gap = scroll_bar_compensation(scrollbar_width, &gutter);
+
+    let _ = style.set_property(
+        "padding-right",
+        &format!("{}px", parse_px(&saved.padding_right) + gap),
+    );
+    let _ = style.set_property(REMOVED_SCROLL_BAR_SIZE_VAR, &format!("{gap}px"));
+    let _ = style.set_property("overflow", "hidden");
+
+    Some(saved)
+}
+
+fn unlock_body(saved: SavedBodyStyle) {
+    let Some(body) = document().body() else {
+        return;
+    };
+    let style = body.style();
+
+    for (property, original) in [
+        ("overflow", saved.overflow),
+        ("padding-right", saved.padding_right),
+    ] {
+        if original.is_empty() {
+            let _ = style.remove_property(property);
+        } else {
+            let _ = style.set_property(property, &original);
+        }
+    }
+    let _ = style.remove_property(REMOVED_SCROLL_BAR_SIZE_VAR);
+}
+
+/// Width to add as body padding when the scrollbar is hidden.
+fn scroll_bar_compensation(scrollbar_width: f64, scrollbar_gutter: &str) -> f64 {
+    if scrollbar_gutter.trim().starts_with("stable") {
+        0.0
+    } else {
+        scrollbar_width.max(0.0)
+    }
+}
+
+fn parse_px(value: &str) -> f64 {
+    value.trim().trim_end_matches("px").parse().unwrap_or(0.0)
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Wheel / touch prevention
+ * -----------------------------------------------------------------------------------------------*/
+
+fn add_listeners() -> ScrollListeners {
+    let wheel = Closure::<dyn Fn(web_sys::WheelEvent)>::new(|event: web_sys::WheelEvent| {
+        handle_scroll(&event, event.delta_x(), event.delta_y());
+    });
+    let touch_start = Closure::<dyn Fn(web_sys::TouchEvent)>::new(|event: web_sys::TouchEvent| {
+        if let Some(touch) = event.changed_touches().get(0) {
+            TOUCH_START.set((touch.client_x() as f64, touch.client_y() as f64));
+        }
+    });
+    let touch_move = Closure::<dyn Fn(web_sys::TouchEvent)>::new(|event: web_sys::TouchEvent| {
+        // Allow pinch zoom.
+        if event.touches().length() > 1 {
+            return;
+        }
+        let Some(touch) = event.changed_touches().get(0) else {
+            return;
+        };
+        let (start_x, start_y) = TOUCH_START.get();
+        handle_scroll(
+            &event,
+            start_x - touch.client_x() as f64,
+            start_y - touch.client_y() as f64,
+        );
+    });
+
+    let options = AddEventListenerOptions::new();
+    options.set_passive(false);
+    options.set_capture(true);
+    let document = document();
+    for (name, listener) in [
+        ("wheel", wheel.as_ref()),
+        ("touchstart", touch_start.as_ref()),
+        ("touchmove", touch_move.as_ref()),
+    ] {
+        document
+            .add_event_listener_with_callback_and_add_event_listener_options(
+                name,
+                listener.unchecked_ref(),
+                &options,
+            )
+            .expect("Scroll lock event listener should be added.");
+    }
+
+    ScrollListeners {
+        wheel,
+        touch_start,
+        touch_move,
+    }
+}
+
+fn remove_listeners(listeners: &ScrollListeners) {
+    let options = EventListenerOptions::new();
+    options.set_capture(true);
+    let document = document();
+    for (name, listener) in [
+        ("wheel", listeners.wheel.as_ref()),
+        ("touchstart", listeners.touch_start.as_ref()),
+        ("touchmove", listeners.touch_move.as_ref()),
+    ] {
+        document
+            .remove_event_listener_with_callback_and_event_listener_options(
+                name,
+                listener.unchecked_ref(),
+                &options,
+            )
+            .ok();
+    }
+}
+
+/// Cancels `event` unless it targets a scrollable area of the topmost lock, or
+/// of a dismissable layer above it, that can still scroll in the direction of `(delta_x, delta_y)`. Cancelling at the
+/// scroll edges prevents scroll chaining to the body.
+fn handle_scroll(event: &web_sys::Event, delta_x: f64, delta_y: f64) {
+    if !event.cancelable() {
+        return;
+    }
+    let Some(target) = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+    else {
+        return;
+    };
+
+    let (lock_roots, allowed_root) = {
+        let stack = SCROLL_LOCK_STACK
+            .lock()
+            .expect("Scroll lock mutex should lock.");
+        let Some(top) = stack.locks.last() else {
+            return;
+        };
+        let element = |node_ref: &AnyNodeRef| -> Option<web_sys::Element> {
+            node_ref.get_untracked().map(|node| node.unchecked_into())
+        };
+        let lock_roots: Vec<web_sys::Element> = std::iter::once(&top.layer)
+            .chain(top.shards.iter())
+            .filter_map(element)
+            .collect();
+        let allowed_root = lock_roots
+            .iter()
+            .cloned()
+            .chain(
+                stack
+                    .global_shards
+                    .iter()
+                    .filter_map(|(_, shard)| element(shard)),
+            )
+            .find(|root| root.contains(Some(&target)));
+        (lock_roots, allowed_root)
+    };
+    // Dismissable layers opened from within the lock, such as a menu portaled out of a dialog,
+    // scroll too.
+    let allowed_root = allowed_root.or_else(|| {
+        lock_roots
+            .iter()
+            .flat_map(|root| layers_above(root))
+            .map(web_sys::Element::from)
+            .find(|layer| layer.contains(Some(&target)))
+    });
+
+    let can_scroll =
+        allowed_root.is_some_and(|root| can_scroll_within(&target, &root, delta_x, delta_y));
+    if !can_scroll {
+        event.prevent_default();
+    }
+}
+
+/// Walks from `target` up to (and including) `root`, returning `true` if any
+/// scroll container on the way can scroll further in the given direction.
+fn can_scroll_within(
+    target: &web_sys::Node,
+    root: &web_sys::Element,
+    delta_x: f64,
+    delta_y: f64,
+) -> bool {
+    let mut current = target
+        .clone()
+        .dyn_into::<web_sys::Element>()
+        .ok()
+        .or_else(|| target.parent_element());
+
+    while let Some(element) = current {
+        if let Some(style) = window().get_computed_style(&element).ok().flatten() {
+            let overflow_x = style.get_property_value("overflow-x").unwrap_or_default();
+            let overflow_y = style.get_property_value("overflow-y").unwrap_or_default();
+
+            if is_scrollable_overflow(&overflow_y)
+                && can_scroll_axis(
+                    delta_y,
+                    element.scroll_top() as f64,
+                    element.scroll_height() as f64,
+                    element.client_height() as f64,
+                )
+            {
+                return true;
+            }
+            if is_scrollable_overflow(&overflow_x)
+                && can_scroll_axis(
+                    delta_x,
+                    element.scroll_left() as f64,
+                    element.scroll_width() as f64,
+                    element.client_width() as f64,
+                )
+            {
+                return true;
+            }
+        }
+
+        if element == *root {
+            break;
+        }
+        current = element.parent_element();
+    }
+
+    false
+}
+
+fn is_scrollable_overflow(overflow: &str) -> bool {
+    matches!(overflow, "auto" | "scroll" | "overlay")
+}
+
+fn can_scroll_axis(delta: f64, position: f64, scroll_size: f64, client_size: f64) -> bool {
+    if delta < 0.0 {
+        position > 0.0
+    } else if delta > 0.0 {
+        // Round up: fractional scroll positions can leave a sub-pixel remainder.
+        (position + client_size).ceil() < scroll_size
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── Scrollbar compensation ────────────────────────────────
+
+    #[test]
+    fn compensation_uses_scrollbar_width() {
+        assert_eq!(scroll_bar_compensation(15.0, "auto"), 15.0);
+    }
+
+    #[test]
+    fn compensation_is_zero_with_stable_gutter() {
+        assert_eq!(scroll_bar_compensation(15.0, "stable"), 0.0);
+        assert_eq!(scroll_bar_compensation(15.0, "stable both-edges"), 0.0);
+    }
+
+    #[test]
+    fn compensation_is_never_negative() {
+        assert_eq!(scroll_bar_compensation(-2.0, ""), 0.0);
+    }
+
+    #[test]
+    fn parse_px_handles_units_and_empty_values() {
+        assert_eq!(parse_px("12px"), 12.0);
+        assert_eq!(parse_px(" 4.5px "), 4.5);
+        assert_eq!(parse_px(""), 0.0);
+    }
+
+    // ── Scroll edges ──────────────────────────────────────────
+
+    #[test]
+    fn cannot_scroll_up_at_top() {
+        assert!(!can_scroll_axis(-10.0, 0.0, 500.0, 100.0));
+        assert!(can_scroll_axis(10.0, 0.0, 500.0, 100.0));
+    }
+
+    #[test]
+    fn cannot_scroll_down_at_bottom() {
+        assert!(!can_scroll_axis(10.0, 400.0, 500.0, 100.0));
+        assert!(can_scroll_axis(-10.0, 400.0, 500.0, 100.0));
+    }
+
+    #[test]
+    fn zero_delta_does_not_scroll() {
+        assert!(!can_scroll_axis(0.0, 200.0, 500.0, 100.0));
+    }
+
+    // ── WASM browser tests ───────────────────────────────────
+
+    #[cfg(all(feature = "dialog", feature = "select"))]
+    mod wasm {
+        use crate::dialog::{Dialog, DialogContent, DialogOverlay, DialogPortal};
+        use crate::select::{
+            Select, SelectContent, SelectItem, SelectItemText, SelectPortal, SelectTrigger,
+            SelectValue, SelectViewport,
+        };
+        use any_spawner::Executor;
+        use leptos::{mount::mount_to, prelude::*};
+        use wasm_bindgen_test::*;
+        use web_sys::wasm_bindgen::JsCast;
+
+        wasm_bindgen_test_configure!(run_in_browser);
+
+        /// Lets effects, such as portals and scroll locks, run.
+        async fn settle() {
+            for _ in 0..10 {
+                Executor::tick().await;
+            }
+        }
+
+        #[wasm_bindgen_test]
+        async fn select_in_a_modal_dialog_scrolls() {
+            let container: web_sys::HtmlElement =
+                document().create_element("div").unwrap().unchecked_into();
+            document().body().unwrap().append_child(&container).unwrap();
+
+            let handle = mount_to(container.clone(), || {
+                view! {
+                    <Dialog default_open=true>
+                        <DialogPortal>
+                            <DialogOverlay />
+                            <DialogContent>
+                                <Select default_open=true>
+                                    <SelectTrigger>
+                                        <SelectValue />
+                                    </SelectTrigger>
+                                    <SelectPortal>
+                                        <SelectContent position="popper">
+                                            <SelectViewport>
+                                                {(0..50)
+                                                    .map(|index| {
+                                                        view! {
+                                                            <SelectItem value=index.to_string()>
+                                                                <SelectItemText>{index}</SelectItemText>
+                                                            </SelectItem>
+                                                        }
+                                                    })
+                                                    .collect_view()}
+                                            </SelectViewport>
+                                        </SelectContent>
+                                    </SelectPortal>
+                                </Select>
+                            </DialogContent>
+                        </DialogPortal>
+                    </Dialog>
+                }
+            });
+            settle().await;
+
+            let viewport: web_sys::HtmlElement = document()
+                .query_selector("[data-radix-select-viewport]")
+                .unwrap()
+                .expect("Select viewport should be rendered.")
+                .unchecked_into();
+            let _ = viewport.style().set_property("height", "40px");
+            let _ = viewport.style().set_property("max-height", "40px");
+
+            let init = web_sys::WheelEventInit::new();
+            init.set_bubbles(true);
+            init.set_cancelable(true);
+            init.set_delta_y(10.0);
+            let event = web_sys::WheelEvent::new_with_event_init_dict("wheel", &init).unwrap();
+            let target = viewport.first_element_child().unwrap_or(viewport.into());
+            target.dispatch_event(&event).unwrap();
+            assert!(!event.default_prevented());
+
+            drop(handle);
+            container.remove();
+        }
+    }
+}
@@ -16,7 +16,7 @@ pub enum BadgeVariant {
     #[tw(class = "border border-border text-foreground hover:bg-muted hover:text-muted-foreground")]
     Outline,
 }
-cardo_ui_themes::impl_cardo_tw_variant!(BadgeVariant);
+crate::impl_cardo_tw_variant!(BadgeVariant);
 
 #[derive(TwClass)]
 #[tw(
@@ -25,7 +25,7 @@ cardo_ui_themes::impl_cardo_tw_variant!(BadgeVariant);
 pub struct BadgeClass {
     pub variant: BadgeVariant,
 }
-cardo_ui_themes::impl_cardo_tw_class!(BadgeClass);
+crate::impl_cardo_tw_class!(BadgeClass);
 
 // ---------------------------------------------------------------------------
 // Component
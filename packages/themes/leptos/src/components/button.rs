@@ -20,7 +20,7 @@ pub enum ButtonVariant {
     #[tw(class = "text-primary underline-offset-4 hover:underline")]
     Link,
 }
-cardo_ui_themes::impl_cardo_tw_variant!(ButtonVariant);
+crate::impl_cardo_tw_variant!(ButtonVariant);
 
 #[derive(TwVariant)]
 pub enum ButtonSize {
@@ -33,7 +33,7 @@ pub enum ButtonSize {
     #[tw(class = "size-9")]
     Icon,
 }
-cardo_ui_themes::impl_cardo_tw_variant!(ButtonSize);
+crate::impl_cardo_tw_variant!(ButtonSize);
 
 #[derive(TwClass)]
 #[tw(
@@ -43,7 +43,7 @@ pub struct ButtonClass {
     pub variant: ButtonVariant,
     pub size: ButtonSize,
 }
-cardo_ui_themes::impl_cardo_tw_class!(ButtonClass);
+crate::impl_cardo_tw_class!(ButtonClass);
 
 // ---------------------------------------------------------------------------
 // Component
@@ -0,0 +1,74 @@
+#[cfg(feature = "accordion")]
+pub mod accordion;
+#[cfg(feature = "alert-dialog")]
+pub mod alert_dialog;
+#[cfg(feature = "aspect-ratio")]
+pub mod aspect_ratio;
+#[cfg(feature = "avatar")]
+pub mod avatar;
+#[cfg(feature = "badge")]
+pub mod badge;
+#[cfg(feature = "button")]
+pub mod button;
+#[cfg(feature = "calendar")]
+pub mod calendar;
+#[cfg(feature = "card")]
+pub mod card;
+#[cfg(feature = "checkbox")]
+pub mod checkbox;
+#[cfg(feature = "collapsible")]
+pub mod collapsible;
+#[cfg(feature = "combobox")]
+pub mod combobox;
+#[cfg(feature = "context-menu")]
+pub mod context_menu;
+#[cfg(feature = "dialog")]
+pub mod dialog;
+#[cfg(feature = "dropdown-menu")]
+pub mod dropdown_menu;
+#[cfg(feature = "form")]
+pub mod form;
+#[cfg(feature = "hover-card")]
+pub mod hover_card;
+#[cfg(feature = "input")]
+pub mod input;
+#[cfg(feature = "label")]
+pub mod label;
+#[cfg(feature = "menubar")]
+pub mod menubar;
+#[cfg(feature = "navigation-menu")]
+pub mod navigation_menu;
+#[cfg(feature = "one-time-password-field")]
+pub mod one_time_password_field;
+#[cfg(feature = "password-toggle-field")]
+pub mod password_toggle_field;
+#[cfg(feature = "popover")]
+pub mod popover;
+#[cfg(feature = "progress")]
+pub mod progress;
+#[cfg(feature = "radio-group")]
+pub mod radio_group;
+#[cfg(feature = "scroll-area")]
+pub mod scroll_area;
+#[cfg(feature = "select")]
+pub mod select;
+#[cfg(feature = "separator")]
+pub mod separator;
+#[cfg(feature = "slider")]
+pub mod slider;
+#[cfg(feature = "switch")]
+pub mod switch;
+#[cfg(feature = "tabs")]
+pub mod tabs;
+#[cfg(feature = "textarea")]
+pub mod textarea;
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toggle")]
+pub mod toggle;
+#[cfg(feature = "toggle-group")]
+pub mod toggle_group;
+#[cfg(feature = "toolbar")]
+pub mod toolbar;
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
@@ -13,7 +13,7 @@ pub enum ToggleVariant {
     #[tw(class = "border border-input bg-transparent shadow-xs hover:bg-accent hover:text-accent-foreground")]
     Outline,
 }
-cardo_ui_themes::impl_cardo_tw_variant!(ToggleVariant);
+crate::impl_cardo_tw_variant!(ToggleVariant);
 
 #[derive(TwVariant)]
 pub enum ToggleSize {
@@ -24,7 +24,7 @@ pub enum ToggleSize {
     #[tw(class = "h-10 min-w-10 px-2.5")]
     Lg,
 }
-cardo_ui_themes::impl_cardo_tw_variant!(ToggleSize);
+crate::impl_cardo_tw_variant!(ToggleSize);
 
 #[derive(TwClass)]
 #[tw(
@@ -34,7 +34,7 @@ pub struct ToggleClass {
     pub variant: ToggleVariant,
     pub size: ToggleSize,
 }
-cardo_ui_themes::impl_cardo_tw_class!(ToggleClass);
+crate::impl_cardo_tw_class!(ToggleClass);
 
 // ---------------------------------------------------------------------------
 // Component
@@ -31,6 +31,108 @@
 //!
 //! // Now usable: <Button class=BtnSize::Sm />
 //! ```
+//!
+//! # Components
+//!
+//! Styled [shadcn/ui](https://ui.shadcn.com/) (new-york) components built on
+//! Cardo UI primitives. Each component lives in its own module behind a cargo
+//! feature of the same name (e.g. `dialog`, `dropdown-menu`), or enable `all`.
+//! Feature flags for primitive-backed components also enable the matching
+//! `cardo-ui` feature.
+//!
+//! Variants and sizes are `TwVariant` enums such as `button::ButtonVariant`
+//! and `button::ButtonSize`, which are bridged to
+//! [`cardo_ui::class::IntoCardoClass`] and can also be used on their own.
+//!
+//! The components are styled with Tailwind utility classes and expect the
+//! design tokens (`bg-primary`, `text-muted-foreground`, ...) and utilities
+//! (`focus-ring`, `disabled-base`, ...) defined in the theme stylesheet.
+//!
+//! # Theme
+//!
+//! With the `theme` feature, [`theme::Theme`] provides appearance
+//! (light/dark/system), accent and gray color scales, radius and scaling as
+//! CSS custom properties, and [`theme::use_theme`] reads and changes them.
+
+mod components;
+#[cfg(feature = "theme")]
+pub mod theme;
+
+// ── Re-export feature-gated components at crate level ──
+#[cfg(feature = "accordion")]
+pub use components::accordion;
+#[cfg(feature = "alert-dialog")]
+pub use components::alert_dialog;
+#[cfg(feature = "aspect-ratio")]
+pub use components::aspect_ratio;
+#[cfg(feature = "avatar")]
+pub use components::avatar;
+#[cfg(feature = "badge")]
+pub use components::badge;
+#[cfg(feature = "button")]
+pub use components::button;
+#[cfg(feature = "calendar")]
+pub use components::calendar;
+#[cfg(feature = "card")]
+pub use components::card;
+#[cfg(feature = "checkbox")]
+pub use components::checkbox;
+#[cfg(feature = "collapsible")]
+pub use components::collapsible;
+#[cfg(feature = "combobox")]
+pub use components::combobox;
+#[cfg(feature = "context-menu")]
+pub use components::context_menu;
+#[cfg(feature = "dialog")]
+pub use components::dialog;
+#[cfg(feature = "dropdown-menu")]
+pub use components::dropdown_menu;
+#[cfg(feature = "form")]
+pub use components::form;
+#[cfg(feature = "hover-card")]
+pub use components::hover_card;
+#[cfg(feature = "input")]
+pub use components::input;
+#[cfg(feature = "label")]
+pub use components::label;
+#[cfg(feature = "menubar")]
+pub use components::menubar;
+#[cfg(feature = "navigation-menu")]
+pub use components::navigation_menu;
+#[cfg(feature = "one-time-password-field")]
+pub use components::one_time_password_field;
+#[cfg(feature = "password-toggle-field")]
+pub use components::password_toggle_field;
+#[cfg(feature = "popover")]
+pub use components::popover;
+#[cfg(feature = "progress")]
+pub use components::progress;
+#[cfg(feature = "radio-group")]
+pub use components::radio_group;
+#[cfg(feature = "scroll-area")]
+pub use components::scroll_area;
+#[cfg(feature = "select")]
+pub use components::select;
+#[cfg(feature = "separator")]
+pub use components::separator;
+#[cfg(feature = "slider")]
+pub use components::slider;
+#[cfg(feature = "switch")]
+pub use components::switch;
+#[cfg(feature = "tabs")]
+pub use components::tabs;
+#[cfg(feature = "textarea")]
+pub use components::textarea;
+#[cfg(feature = "toast")]
+pub use components::toast;
+#[cfg(feature = "toggle")]
+pub use components::toggle;
+#[cfg(feature = "toggle-group")]
+pub use components::toggle_group;
+#[cfg(feature = "toolbar")]
+pub use components::toolbar;
+#[cfg(feature = "tooltip")]
+pub use components::tooltip;
 
 /// Bridge one or more `TwClass` structs (which implement
 /// [`tailwind_fuse::IntoTailwindClass`]) to
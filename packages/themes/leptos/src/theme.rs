@@ -0,0 +1,726 @@
+//! Theme root providing appearance, colors, radius and scaling.
+//!
+//! [`Theme`] renders a `.radix-themes` container that defines the accent and
+//! gray color scales as CSS custom properties (`--accent-1` … `--accent-12`,
+//! `--accent-a1` … `--accent-a12`, `--gray-*`), radius and scaling factors,
+//! and provides them to descendants through [`use_theme`].
+//!
+//! Colors are emitted as `light-dark()` pairs, so the active scale follows the
+//! element's `color-scheme` without re-rendering:
+//!
+//! - The root theme controls `color-scheme` and the `light`/`dark` class on
+//!   `<html>`, tracks `prefers-color-scheme` for [`Appearance::System`] and
+//!   persists the appearance chosen through [`ThemeContextValue::set_appearance`]
+//!   to `localStorage`.
+//! - Nested themes inherit every unset prop from their parent and set their
+//!   own `color-scheme` when given an explicit appearance.
+//!
+//! Render [`ThemeScript`] in `<head>` to apply the persisted appearance before
+//! first paint, so server-rendered pages don't flash the wrong appearance.
+
+use std::fmt;
+
+use cardo_ui::colors::ScaleName;
+use leptos::{context::Provider, prelude::*};
+use send_wrapper::SendWrapper;
+use web_sys::wasm_bindgen::{JsCast, closure::Closure};
+
+/// Default `localStorage` key for the persisted appearance.
+pub const DEFAULT_STORAGE_KEY: &str = "cardo-ui-appearance";
+
+const PREFERS_DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Base radii in pixels for `--radius-1` … `--radius-6`.
+const BASE_RADII: [f64; 6] = [3.0, 4.0, 6.0, 8.0, 12.0, 16.0];
+
+/* -------------------------------------------------------------------------------------------------
+ * Props
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Color scheme setting of a [`Theme`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Appearance {
+    Light,
+    Dark,
+    /// Follows the operating system's `prefers-color-scheme`.
+    #[default]
+    System,
+}
+
+impl Appearance {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Appearance::Light => "light",
+            Appearance::Dark => "dark",
+            Appearance::System => "system",
+        }
+    }
+
+    /// Parses the value stored by [`Appearance::as_str`].
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "light" => Some(Appearance::Light),
+            "dark" => Some(Appearance::Dark),
+            "system" => Some(Appearance::System),
+            _ => None,
+        }
+    }
+
+    /// Resolves [`Appearance::System`] against the current system preference.
+    pub fn resolve(self, prefers_dark: bool) -> ResolvedAppearance {
+        match self {
+            Appearance::Light => ResolvedAppearance::Light,
+            Appearance::Dark => ResolvedAppearance::Dark,
+            Appearance::System if prefers_dark => ResolvedAppearance::Dark,
+            Appearance::System => ResolvedAppearance::Light,
+        }
+    }
+
+    fn color_scheme(self) -> &'static str {
+        match self {
+            Appearance::Light => "light",
+            Appearance::Dark => "dark",
+            Appearance::System => "light dark",
+        }
+    }
+}
+
+impl fmt::Display for Appearance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Appearance actually in effect, with [`Appearance::System`] resolved.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ResolvedAppearance {
+    #[default]
+    Light,
+    Dark,
+}
+
+impl ResolvedAppearance {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ResolvedAppearance::Light => "light",
+            ResolvedAppearance::Dark => "dark",
+        }
+    }
+}
+
+/// Accent color of a [`Theme`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum AccentColor {
+    Gray,
+    Gold,
+    Bronze,
+    Brown,
+    Yellow,
+    Amber,
+    Orange,
+    Tomato,
+    Red,
+    Ruby,
+    Crimson,
+    Pink,
+    Plum,
+    Purple,
+    Violet,
+    Iris,
+    #[default]
+    Indigo,
+    Blue,
+    Cyan,
+    Teal,
+    Jade,
+    Green,
+    Grass,
+    Lime,
+    Mint,
+    Sky,
+}
+
+impl AccentColor {
+    pub fn as_str(self) -> &'static str {
+        self.scale().as_str()
+    }
+
+    /// The color scale this accent uses.
+    pub fn scale(self) -> ScaleName {
+        match self {
+            AccentColor::Gray => ScaleName::Gray,
+            AccentColor::Gold => ScaleName::Gold,
+            AccentColor::Bronze => ScaleName::Bronze,
+            AccentColor::Brown => ScaleName::Brown,
+            AccentColor::Yellow => ScaleName::Yellow,
+            AccentColor::Amber => ScaleName::Amber,
+            AccentColor::Orange => ScaleName::Orange,
+            AccentColor::Tomato => ScaleName::Tomato,
+            AccentColor::Red => ScaleName::Red,
+            AccentColor::Ruby => ScaleName::Ruby,
+            AccentColor::Crimson => ScaleName::Crimson,
+            AccentColor::Pink => ScaleName::Pink,
+            AccentColor::Plum => ScaleName::Plum,
+            AccentColor::Purple => ScaleName::Purple,
+            AccentColor::Violet => ScaleName::Violet,
+            AccentColor::Iris => ScaleName::Iris,
+            AccentColor::Indigo => ScaleName::Indigo,
+            AccentColor::Blue => ScaleName::Blue,
+            AccentColor::Cyan => ScaleName::Cyan,
+            AccentColor::Teal => ScaleName::Teal,
+            AccentColor::Jade => ScaleName::Jade,
+            AccentColor::Green => ScaleName::Green,
+            AccentColor::Grass => ScaleName::Grass,
+            AccentColor::Lime => ScaleName::Lime,
+            AccentColor::Mint => ScaleName::Mint,
+            AccentColor::Sky => ScaleName::Sky,
+        }
+    }
+
+    /// The gray that pairs naturally with this accent, used by [`GrayColor::Auto`].
+    pub fn natural_gray(self) -> GrayColor {
+        match self {
+            AccentColor::Tomato
+            | AccentColor::Red
+            | AccentColor::Ruby
+            | AccentColor::Crimson
+            | AccentColor::Pink
+            | AccentColor::Plum
+            | AccentColor::Purple
+            | AccentColor::Violet => GrayColor::Mauve,
+            AccentColor::Iris
+            | AccentColor::Indigo
+            | AccentColor::Blue
+            | AccentColor::Sky
+            | AccentColor::Cyan => GrayColor::Slate,
+            AccentColor::Teal | AccentColor::Jade | AccentColor::Mint | AccentColor::Green => {
+                GrayColor::Sage
+            }
+            AccentColor::Grass | AccentColor::Lime => GrayColor::Olive,
+            AccentColor::Yellow
+            | AccentColor::Amber
+            | AccentColor::Orange
+            | AccentColor::Brown
+            | AccentColor::Gold
+            | AccentColor::Bronze => GrayColor::Sand,
+            AccentColor::Gray => GrayColor::Gray,
+        }
+    }
+
+    /// Text color for use on step 9 backgrounds. Bright accents need dark text.
+    pub fn contrast(self) -> &'static str {
+        match self {
+            AccentColor::Yellow | AccentColor::Amber | AccentColor::Lime | AccentColor::Mint => {
+                "#21201c"
+            }
+            AccentColor::Sky => "#1c2024",
+            _ => "white",
+        }
+    }
+}
+
+/// Gray color of a [`Theme`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum GrayColor {
+    /// Picks the gray that matches the accent color.
+    #[default]
+    Auto,
+    Gray,
+    Mauve,
+    Slate,
+    Sage,
+    Olive,
+    Sand,
+}
+
+impl GrayColor {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GrayColor::Auto => "auto",
+            gray => gray.scale().as_str(),
+        }
+    }
+
+    /// The color scale this gray uses. [`GrayColor::Auto`] falls back to
+    /// [`ScaleName::Gray`]; [`GrayColor::resolve`] it against the accent first.
+    pub fn scale(self) -> ScaleName {
+        match self {
+            GrayColor::Auto | GrayColor::Gray => ScaleName::Gray,
+            GrayColor::Mauve => ScaleName::Mauve,
+            GrayColor::Slate => ScaleName::Slate,
+            GrayColor::Sage => ScaleName::Sage,
+            GrayColor::Olive => ScaleName::Olive,
+            GrayColor::Sand => ScaleName::Sand,
+        }
+    }
+
+    /// Resolves [`GrayColor::Auto`] against `accent`.
+    pub fn resolve(self, accent: AccentColor) -> GrayColor {
+        match self {
+            GrayColor::Auto => accent.natural_gray(),
+            gray => gray,
+        }
+    }
+}
+
+/// Corner radius of a [`Theme`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Radius {
+    None,
+    Small,
+    #[default]
+    Medium,
+    Large,
+    Full,
+}
+
+impl Radius {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Radius::None => "none",
+            Radius::Small => "small",
+            Radius::Medium => "medium",
+            Radius::Large => "large",
+            Radius::Full => "full",
+        }
+    }
+
+    /// Multiplier applied to the base radii.
+    pub fn factor(self) -> f64 {
+        match self {
+            Radius::None => 0.0,
+            Radius::Small => 0.75,
+            Radius::Medium => 1.0,
+            Radius::Large | Radius::Full => 1.5,
+        }
+    }
+}
+
+/// Size scaling of a [`Theme`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Scaling {
+    S90,
+    S95,
+    #[default]
+    S100,
+    S105,
+    S110,
+}
+
+impl Scaling {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Scaling::S90 => "90%",
+            Scaling::S95 => "95%",
+            Scaling::S100 => "100%",
+            Scaling::S105 => "105%",
+            Scaling::S110 => "110%",
+        }
+    }
+
+    pub fn factor(self) -> f64 {
+        match self {
+            Scaling::S90 => 0.9,
+            Scaling::S95 => 0.95,
+            Scaling::S100 => 1.0,
+            Scaling::S105 => 1.05,
+            Scaling::S110 => 1.1,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * Theme
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Theme settings in effect for a subtree, returned by [`use_theme`].
+#[derive(Clone, Copy)]
+pub struct ThemeContextValue {
+    /// The appearance setting, which may be [`Appearance::System`].
+    pub appearance: Signal<Appearance>,
+    /// The appearance in effect.
+    pub resolved_appearance: Signal<ResolvedAppearance>,
+    pub accent_color: Signal<AccentColor>,
+    /// The gray in effect, with [`GrayColor::Auto`] resolved.
+    pub gray_color: Signal<GrayColor>,
+    pub radius: Signal<Radius>,
+    pub scaling: Signal<Scaling>,
+    prefers_dark: Signal<bool>,
+    on_appearance_change: Callback<Appearance>,
+}
+
+impl ThemeContextValue {
+    /// Changes the appearance of the nearest theme. On the root theme, the
+    /// choice is persisted to `localStorage`.
+    pub fn set_appearance(&self, appearance: Appearance) {
+        self.on_appearance_change.run(appearance);
+    }
+}
+
+/// Returns the settings of the nearest [`Theme`].
+pub fn use_theme() -> ThemeContextValue {
+    expect_context::<ThemeContextValue>()
+}
+
+#[component]
+pub fn Theme(
+    /// Defaults to the parent theme's appearance, or [`Appearance::System`] at the root.
+    /// At the root, a persisted appearance takes precedence once hydrated.
+    #[prop(into, optional)]
+    appearance: MaybeProp<Appearance>,
+    #[prop(into, optional)] accent_color: MaybeProp<AccentColor>,
+    #[prop(into, optional)] gray_color: MaybeProp<GrayColor>,
+    #[prop(into, optional)] radius: MaybeProp<Radius>,
+    #[prop(into, optional)] scaling: MaybeProp<Scaling>,
+    /// `localStorage` key for the persisted appearance. Only used by the root theme.
+    /// Defaults to [`DEFAULT_STORAGE_KEY`].
+    #[prop(into, optional)]
+    storage_key: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let parent = use_context::<ThemeContextValue>();
+    let is_root = parent.is_none();
+    let storage_key = StoredValue::new(storage_key.unwrap_or_else(|| DEFAULT_STORAGE_KEY.into()));
+
+    let local_appearance = RwSignal::new(appearance.get_untracked());
+    Effect::watch(
+        move || appearance.get(),
+        move |appearance, _, _| local_appearance.set(*appearance),
+        false,
+    );
+
+    let system_prefers_dark = RwSignal::new(false);
+    let mounted = RwSignal::new(false);
+
+    if is_root {
+        // Only runs on the client, after hydration, so the first client render
+        // matches the server's.
+        Effect::new(move |_| {
+            if let Some(stored) = storage_key.with_value(|key| read_appearance(key)) {
+                local_appearance.set(Some(stored));
+            }
+            track_prefers_dark(system_prefers_dark);
+            mounted.set(true);
+        });
+    } else {
+        Effect::new(move |_| mounted.set(true));
+    }
+
+    let appearance = Signal::derive(move || {
+        local_appearance
+            .get()
+            .or_else(|| parent.map(|parent| parent.appearance.get()))
+            .unwrap_or_default()
+    });
+    let prefers_dark = match parent {
+        Some(parent) => parent.prefers_dark,
+        None => system_prefers_dark.into(),
+    };
+    let resolved_appearance = Signal::derive(move || appearance.get().resolve(prefers_dark.get()));
+    let accent_color = Signal::derive(move || {
+        accent_color
+            .get()
+            .or_else(|| parent.map(|parent| parent.accent_color.get()))
+            .unwrap_or_default()
+    });
+    let gray_color = Signal::derive(move || match gray_color.get() {
+        Some(gray_color) => gray_color.resolve(accent_color.get()),
+        None => match parent {
+            // Keep following the accent when only the accent changes in a nested theme.
+            Some(parent) if parent.accent_color.get() == accent_color.get() => {
+                parent.gray_color.get()
+            }
+            _ => GrayColor::Auto.resolve(accent_color.get()),
+        },
+    });
+    let radius = Signal::derive(move || {
+        radius
+            .get()
+            .or_else(|| parent.map(|parent| parent.radius.get()))
+            .unwrap_or_default()
+    });
+    let scaling = Signal::derive(move || {
+        scaling
+            .get()
+            .or_else(|| parent.map(|parent| parent.scaling.get()))
+            .unwrap_or_default()
+    });
+
+    let on_appearance_change = Callback::new(move |appearance: Appearance| {
+        local_appearance.set(Some(appearance));
+        if is_root {
+            storage_key.with_value(|key| write_appearance(key, appearance));
+        }
+    });
+
+    // The root theme owns the document's color scheme, which `ThemeScript` sets before paint.
+    if is_root {
+        Effect::new(move |_| {
+            apply_to_document(appearance.get(), resolved_appearance.get());
+        });
+    }
+
+    let context = ThemeContextValue {
+        appearance,
+        resolved_appearance,
+        accent_color,
+        gray_color,
+        radius,
+        scaling,
+        prefers_dark,
+        on_appearance_change,
+    };
+
+    view! {
+        <Provider value=context>
+            <div
+                class=move || {
+                    // The system preference is only known on the client.
+                    if mounted.get() || appearance.get() != Appearance::System {
+                        format!("radix-themes {}", resolved_appearance.get().as_str())
+                    } else {
+                        "radix-themes".into()
+                    }
+                }
+                data-accent-color=move || accent_color.get().as_str()
+                data-gray-color=move || gray_color.get().as_str()
+                data-radius=move || radius.get().as_str()
+                data-scaling=move || scaling.get().as_str()
+                style=move || {
+                    let mut style = theme_style(
+                        accent_color.get(),
+                        gray_color.get(),
+                        radius.get(),
+                        scaling.get(),
+                    );
+                    // Nested themes scope their own color scheme; the root uses the document's.
+                    if !is_root && local_appearance.get().is_some() {
+                        style.push_str(&format!(
+                            "color-scheme: {};",
+                            appearance.get().color_scheme()
+                        ));
+                    }
+                    style
+                }
+            >
+                {children()}
+            </div>
+        </Provider>
+    }
+}
+
+/// Returns the CSS custom properties for the given theme settings.
+///
+/// Colors are `light-dark()` pairs that follow the element's `color-scheme`.
+pub fn theme_style(
+    accent_color: AccentColor,
+    gray_color: GrayColor,
+    radius: Radius,
+    scaling: Scaling,
+) -> String {
+    let mut style = String::new();
+
+    push_scale(&mut style, "accent", accent_color.scale());
+    push_scale(&mut style, "gray", gray_color.resolve(accent_color).scale());
+    style.push_str(&format!("--accent-contrast: {};", accent_color.contrast()));
+
+    style.push_str(&format!("--scaling: {};", scaling.factor()));
+    style.push_str(&format!("--radius-factor: {};", radius.factor()));
+    for (index, base) in BASE_RADII.iter().enumerate() {
+        style.push_str(&format!(
+            "--radius-{}: calc({base}px * var(--scaling) * var(--radius-factor));",
+            index + 1
+        ));
+    }
+    style.push_str(match radius {
+        Radius::Full => "--radius-full: 9999px;",
+        _ => "--radius-full: 0px;",
+    });
+
+    style
+}
+
+fn push_scale(style: &mut String, name: &str, scale: ScaleName) {
+    for step in 1..=12 {
+        style.push_str(&format!(
+            "--{name}-{step}: light-dark({}, {});",
+            scale.light().step(step),
+            scale.dark().step(step)
+        ));
+    }
+    for step in 1..=12 {
+        style.push_str(&format!(
+            "--{name}-a{step}: light-dark({}, {});",
+            scale.light_alpha().step(step),
+            scale.dark_alpha().step(step)
+        ));
+    }
+}
+
+fn read_appearance(storage_key: &str) -> Option<Appearance> {
+    let storage = window().local_storage().ok().flatten()?;
+    let value = storage.get_item(storage_key).ok().flatten()?;
+    Appearance::parse(&value)
+}
+
+fn write_appearance(storage_key: &str, appearance: Appearance) {
+    if let Some(storage) = window().local_storage().ok().flatten() {
+        // Storage may be full or disabled; the in-memory setting still applies.
+        let _ = storage.set_item(storage_key, appearance.as_str());
+    }
+}
+
+/// Keeps `prefers_dark` in sync with the `prefers-color-scheme` media query.
+fn track_prefers_dark(prefers_dark: RwSignal<bool>) {
+    let Some(query) = window().match_media(PREFERS_DARK_QUERY).ok().flatten() else {
+        return;
+    };
+    prefers_dark.set(query.matches());
+
+    let listener: SendWrapper<Closure<dyn Fn(web_sys::MediaQueryListEvent)>> =
+        SendWrapper::new(Closure::new(move |event: web_sys::MediaQueryListEvent| {
+            prefers_dark.try_set(event.matches());
+        }));
+    query
+        .add_event_listener_with_callback("change", listener.as_ref().unchecked_ref())
+        .expect("Media query listener should be added.");
+
+    let query = SendWrapper::new(query);
+    on_cleanup(move || {
+        query
+            .remove_event_listener_with_callback("change", listener.as_ref().unchecked_ref())
+            .ok();
+    });
+}
+
+fn apply_to_document(appearance: Appearance, resolved: ResolvedAppearance) {
+    let Some(root) = document().document_element() else {
+        return;
+    };
+    let class_list = root.class_list();
+    let _ = class_list.remove_2("light", "dark");
+    let _ = class_list.add_1(resolved.as_str());
+    let _ = root
+        .unchecked_ref::<web_sys::HtmlElement>()
+        .style()
+        .set_property("color-scheme", appearance.color_scheme());
+}
+
+/* -------------------------------------------------------------------------------------------------
+ * ThemeScript
+ * -----------------------------------------------------------------------------------------------*/
+
+/// Applies the persisted appearance to `<html>` before first paint.
+///
+/// Render it in `<head>` of server-rendered pages. `appearance` is used when
+/// nothing is persisted and should match the root [`Theme`]'s `appearance`.
+#[component]
+pub fn ThemeScript(
+    #[prop(optional)] appearance: Appearance,
+    #[prop(into, optional)] storage_key: Option<String>,
+) -> impl IntoView {
+    let script = theme_script(
+        storage_key.as_deref().unwrap_or(DEFAULT_STORAGE_KEY),
+        appearance,
+    );
+
+    view! { <script inner_html=script></script> }
+}
+
+/// Returns the inline script rendered by [`ThemeScript`].
+pub fn theme_script(storage_key: &str, default_appearance: Appearance) -> String {
+    format!(
+        "(function(){{try{{\
+         var a=null;try{{a=localStorage.getItem({storage_key});}}catch(_){{}}\
+         if(a!==\"light\"&&a!==\"dark\"&&a!==\"system\")a={default};\
+         var d=a===\"dark\"||(a===\"system\"&&matchMedia({query}).matches);\
+         var e=document.documentElement;\
+         e.classList.remove(\"light\",\"dark\");e.classList.add(d?\"dark\":\"light\");\
+         e.style.colorScheme=a===\"system\"?\"light dark\":a;\
+         }}catch(_){{}}}})();",
+        storage_key = js_string(storage_key),
+        default = js_string(default_appearance.as_str()),
+        query = js_string(PREFERS_DARK_QUERY),
+    )
+}
+
+/// Quotes `value` as a JavaScript string literal that is safe to inline in a
+/// `<script>` element.
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value)
+        .expect("String should serialize.")
+        .replace("</", "<\\/")
+}
+
+#[cfg(test)]
+mod tests {
+    use cardo_ui::colors::*;
+
+    use super::*;
+
+    #[test]
+    fn appearance_round_trips_through_storage_value() {
+        for appearance in [Appearance::Light, Appearance::Dark, Appearance::System] {
+            assert_eq!(Appearance::parse(appearance.as_str()), Some(appearance));
+        }
+        assert_eq!(Appearance::parse("sepia"), None);
+    }
+
+    #[test]
+    fn system_appearance_resolves_against_preference() {
+        assert_eq!(Appearance::System.resolve(true), ResolvedAppearance::Dark);
+        assert_eq!(Appearance::System.resolve(false), ResolvedAppearance::Light);
+        assert_eq!(Appearance::Light.resolve(true), ResolvedAppearance::Light);
+    }
+
+    #[test]
+    fn auto_gray_follows_accent() {
+        assert_eq!(GrayColor::Auto.resolve(AccentColor::Blue), GrayColor::Slate);
+        assert_eq!(
+            GrayColor::Auto.resolve(AccentColor::Crimson),
+            GrayColor::Mauve
+        );
+        assert_eq!(GrayColor::Sand.resolve(AccentColor::Blue), GrayColor::Sand);
+    }
+
+    #[test]
+    fn theme_style_pairs_light_and_dark_steps() {
+        let style = theme_style(
+            AccentColor::Blue,
+            GrayColor::Auto,
+            Radius::Medium,
+            Scaling::S100,
+        );
+
+        assert!(style.contains(&format!(
+            "--accent-9: light-dark({}, {});",
+            BLUE[8], BLUE_DARK[8]
+        )));
+        assert!(style.contains(&format!(
+            "--accent-a3: light-dark({}, {});",
+            BLUE_A[2], BLUE_DARK_A[2]
+        )));
+        assert!(style.contains(&format!(
+            "--gray-12: light-dark({}, {});",
+            SLATE[11], SLATE_DARK[11]
+        )));
+        assert!(style.contains("--accent-contrast: white;"));
+        assert!(style.contains("--radius-factor: 1;"));
+    }
+
+    #[test]
+    fn theme_script_uses_storage_key_and_default() {
+        let script = theme_script("my-app", Appearance::Dark);
+
+        assert!(script.contains("localStorage.getItem(\"my-app\")"));
+        assert!(script.contains("a=\"dark\""));
+    }
+
+    #[test]
+    fn theme_script_escapes_storage_key() {
+        let script = theme_script("</script>\"key", Appearance::Light);
+
+        assert!(!script.contains("</script>"));
+        assert!(script.contains(r#"getItem("<\/script>\"key")"#));
+    }
+}
@@ -1,3 +1,5 @@
+const skipReact = it.skipForFramework('react', 'Radix does not set inert');
+
 describe('Dialog', () => {
     // ── Helpers ──────────────────────────────────────────────
 
@@ -78,6 +80,15 @@ describe('Dialog', () => {
             shouldBeClosed();
             cy.findByText('open').closest('[aria-hidden]').should('not.exist');
         });
+
+        skipReact('outside elements are inert while modal dialog is open', () => {
+            cy.findByText('open').click();
+            shouldBeOpen();
+            cy.findByText('open').closest('[inert]').should('exist');
+            cy.realPress('Escape');
+            shouldBeClosed();
+            cy.findByText('open').closest('[inert]').should('not.exist');
+        });
     });
 
     // ── 2. Data Attributes ──────────────────────────────────
@@ -14,7 +14,7 @@ describe('OneTimePasswordField', () => {
     }
 
     function getHiddenInput() {
-        return getRoot().find('input[type="hidden"][name="code"]');
+        return getRoot().find('input[aria-hidden="true"][name="code"]');
     }
 
     function shouldHaveValue(expected) {
@@ -11,23 +11,8 @@
 
 use crate::support::use_forwarded_attrs::ForwardedAttrs;
 use leptos::attribute_interceptor::AttributeInterceptor;
-use leptos::html;
 use leptos::prelude::*;
 
-// ── Helper: A "Primitive-like" component that renders a div ─────────────────
-
-#[component]
-fn InnerDiv(
-    #[prop(into, optional)] node_ref: leptos_node_ref::AnyNodeRef,
-    children: ChildrenFn,
-) -> impl IntoView {
-    view! {
-        <div node_ref=node_ref>
-            {children()}
-        </div>
-    }
-}
-
 // ── Fixture 1: style: directives on the innermost element ───────────────────
 //
 // The component has a wrapper that captures user attrs, and an inner
@@ -12,7 +12,6 @@
 use crate::support::use_forwarded_attrs::ForwardedAttrs;
 use leptos::attribute_interceptor::AttributeInterceptor;
 use leptos::prelude::*;
-use leptos::{html, tachys::html::element::ElementType};
 
 // ── Fixture 1: style: directive vs attr:style spread ────────────────────────
 //
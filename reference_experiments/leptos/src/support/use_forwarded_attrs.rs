@@ -8,7 +8,6 @@
 // independent reactive subscriptions (built internally by `RenderEffect`
 // when the attribute contains signal-backed closures).
 
-use leptos::attr::Attribute as _;
 use leptos::attr::any_attribute::{AnyAttribute, IntoAnyAttribute};
 use leptos::prelude::*;
 use send_wrapper::SendWrapper;
@@ -65,7 +64,7 @@ impl ForwardedAttrs {
     /// Can be called multiple times — each call produces an independent clone.
     pub fn spread(&self) -> AnyAttribute {
         self.attrs
-            .with_value(|a| a.as_ref().map(|a| AnyAttribute::clone(a)))
+            .with_value(|a| a.as_deref().cloned())
             .unwrap_or_else(|| ().into_any_attr())
     }
 }
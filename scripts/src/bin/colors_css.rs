@@ -0,0 +1,78 @@
+//! Generates a CSS custom property sheet and a Tailwind colors config from the
+//! Cardo UI color scales.
+//!
+//! ```sh
+//! cargo run -p scripts --bin colors_css -- --accent blue --gray slate --out target/colors
+//! ```
+//!
+//! Options:
+//!
+//! - `--accent <scale>`, `--gray <scale>`: emit `--accent-*` / `--gray-*` aliases.
+//! - `--scales <a,b,...>`: only emit these scales (all by default).
+//! - `--no-alpha`, `--no-p3`, `--no-overlays`: skip those variants.
+//! - `--light-selector <selector>`, `--dark-selector <selector>`: override the
+//!   light and dark selectors.
+//! - `--out <dir>`: output directory for `colors.css` and
+//!   `tailwind.colors.json` (default `target/colors`).
+
+use std::{env, error::Error, fs, path::PathBuf};
+
+use cardo_ui_colors::{
+    ScaleName,
+    css::{CssOptions, ScaleAlias, css, tailwind_colors_json},
+};
+use log::info;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    env_logger::init();
+
+    let mut options = CssOptions::default();
+    let mut output_path = PathBuf::from("target/colors");
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for \"{arg}\"."))
+        };
+
+        match arg.as_str() {
+            "--accent" => options
+                .aliases
+                .push(ScaleAlias::new("accent", scale(&value()?)?)),
+            "--gray" => options
+                .aliases
+                .push(ScaleAlias::new("gray", scale(&value()?)?)),
+            "--scales" => {
+                options.scales = value()?
+                    .split(',')
+                    .map(|name| scale(name.trim()))
+                    .collect::<Result<_, _>>()?;
+            }
+            "--no-alpha" => options.alpha = false,
+            "--no-p3" => options.p3 = false,
+            "--no-overlays" => options.overlays = false,
+            "--light-selector" => options.light_selector = value()?,
+            "--dark-selector" => options.dark_selector = value()?,
+            "--out" => output_path = PathBuf::from(value()?),
+            _ => return Err(format!("Unknown argument \"{arg}\".").into()),
+        }
+    }
+
+    fs::create_dir_all(&output_path)?;
+
+    let css_path = output_path.join("colors.css");
+    info!("Writing \"{}\".", css_path.display());
+    fs::write(&css_path, css(&options))?;
+
+    let tailwind_path = output_path.join("tailwind.colors.json");
+    info!("Writing \"{}\".", tailwind_path.display());
+    fs::write(&tailwind_path, tailwind_colors_json(&options))?;
+
+    Ok(())
+}
+
+fn scale(name: &str) -> Result<ScaleName, String> {
+    name.parse()
+        .map_err(|_| format!("Unknown color scale \"{name}\"."))
+}
@@ -1,35 +1,82 @@
-use std::{error::Error, fs, path::Path};
+//! Generates icon crates from a set of SVG files.
+//!
+//! Without arguments, generates `cardo-ui-icons` from Radix Icons:
+//!
+//! ```sh
+//! cargo run -p scripts --bin icons
+//! ```
+//!
+//! Other icon sets are generated into `packages/icons-<name>` as
+//! `cardo-ui-icons-<name>`, e.g. from a local checkout of Lucide:
+//!
+//! ```sh
+//! cargo run -p scripts --bin icons -- --name lucide --source ../lucide/icons --url https://lucide.dev
+//! ```
+//!
+//! Options:
+//!
+//! - `--name <name>`: generate a third-party icon set instead of Radix Icons.
+//!   Defaults to 24x24 outline icons.
+//! - `--source <dir>`: read SVG files from a local directory, for generating
+//!   offline.
+//! - `--git <url>`, `--ref <ref>`, `--path <dir>`: read SVG files from directory
+//!   `<dir>` of a Git repository checked out at `<ref>`.
+//! - `--title <title>`, `--url <url>`: name and home page for the docs.
+//! - `--prefix <prefix>`, `--suffix <suffix>`: component naming, e.g.
+//!   `--prefix Lucide` for `LucideCheckIcon` (suffix `Icon` by default).
+//! - `--size <size>`: default `width` and `height`.
+//! - `--view-box <view box>`: `viewBox` for icons that don't declare one.
+//! - `--fill`, `--stroke`: whether the `color` prop sets `fill` or `stroke`.
+
+use std::{
+    env,
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use convert_case::{Case, Casing};
 use git2::Repository;
 use log::info;
-use scripts::{framework::Framework, frameworks::leptos::Leptos};
+use scripts::{
+    framework::Framework,
+    frameworks::leptos::Leptos,
+    icon_set::{IconSet, IconSource, IconSvg, Paint},
+};
 use tempfile::tempdir;
 
-const GIT_URL: &str = "https://github.com/radix-ui/icons.git";
-const GIT_REF: &str = "@radix-ui/react-icons@1.3.2";
-
 fn main() -> Result<(), Box<dyn Error>> {
     env_logger::init();
 
+    let icon_set = parse_args(env::args().skip(1))?;
+
     let frameworks: [Box<dyn Framework>; 1] = [Box::new(Leptos)];
 
     let repository_path = tempdir()?;
-    let repository_icons_path = repository_path.path().join("packages/radix-icons/icons");
-
-    info!(
-        "Cloning \"{}\" ref \"{}\" into \"{}\".",
-        GIT_URL,
-        GIT_REF,
-        repository_path.path().display()
-    );
-
-    git_checkout(&repository_path)?;
+    let icons_path = match &icon_set.source {
+        IconSource::Git {
+            url,
+            reference,
+            path,
+        } => {
+            info!(
+                "Cloning \"{}\" ref \"{}\" into \"{}\".",
+                url,
+                reference,
+                repository_path.path().display()
+            );
+
+            git_checkout(url, reference, &repository_path)?;
+
+            repository_path.path().join(path)
+        }
+        IconSource::Local(path) => path.clone(),
+    };
 
     info!("Generating icons.");
 
     let mut paths = vec![];
-    for entry in fs::read_dir(repository_icons_path)? {
+    for entry in fs::read_dir(icons_path)? {
         let path = entry?.path();
 
         if !path.is_file() || path.extension().is_none_or(|extension| extension != "svg") {
@@ -48,57 +95,169 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     paths.sort_by_key(|(_, file_stem)| file_stem.clone());
 
+    for framework in &frameworks {
+        let crate_path = icon_set.crate_path(framework.name());
+        fs::create_dir_all(crate_path.join("src"))?;
+
+        let manifest_path = crate_path.join("Cargo.toml");
+        if !manifest_path.exists() {
+            info!("Writing \"{}\".", manifest_path.display());
+            fs::write(manifest_path, framework.generate_manifest(&icon_set))?;
+        }
+    }
+
     let mut modules = vec![];
     let mut component_names = vec![];
+    let mut icons = vec![];
+    let mut symbols = vec![];
 
     for (path, file_stem) in paths {
         let file_contents = fs::read_to_string(path)?;
+        let svg = IconSvg::parse(&icon_set, &file_contents)?;
 
         let module = format!("{file_stem}-icon").to_case(Case::Snake);
         modules.push(module.clone());
 
-        let component_name = format!("{file_stem}-icon").to_case(Case::Pascal);
+        let component_name = icon_set.component_name(&file_stem);
         component_names.push(component_name.clone());
+        icons.push((file_stem.clone(), component_name.clone()));
+        symbols.push(svg.sprite_symbol(&icon_set, &file_stem));
 
         info!("{module} - {component_name}");
 
         for framework in &frameworks {
             generate_icon(
                 &**framework,
+                &icon_set,
+                file_stem.clone(),
                 module.clone(),
                 component_name.clone(),
-                file_contents.clone(),
+                &svg,
             )?;
         }
     }
 
+    modules.push("icon".into());
+    modules.push("sprite".into());
+
     for framework in &frameworks {
-        generate_lib(&**framework, &modules)?;
-        generate_example(&**framework, &component_names)?;
+        generate_icon_lookup(&**framework, &icon_set, &icons)?;
+        generate_sprite(&**framework, &icon_set, &symbols)?;
+        generate_lib(&**framework, &icon_set, &modules)?;
 
         framework.format(
-            format!("radix-{}-icons", framework.name()),
-            Path::new("packages")
-                .join("icons")
-                .join(framework.name())
-                .join("src"),
+            icon_set.package.clone(),
+            icon_set.crate_path(framework.name()).join("src"),
         )?;
 
-        framework.format(
-            format!("radix-{}-book-icons", framework.name()),
-            Path::new("book-examples")
-                .join(framework.name())
-                .join("icons")
-                .join("src"),
-        )?;
+        if let Some(package) = &icon_set.book_examples {
+            generate_example(&**framework, &component_names)?;
+
+            framework.format(
+                package.clone(),
+                Path::new("book-examples")
+                    .join(framework.name())
+                    .join("icons")
+                    .join("src"),
+            )?;
+        }
     }
 
     Ok(())
 }
 
-fn git_checkout<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
-    let repository = Repository::clone(GIT_URL, path)?;
-    let (object, reference) = repository.revparse_ext(GIT_REF)?;
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<IconSet, Box<dyn Error>> {
+    let mut name = None;
+    let mut source = None;
+    let mut git = None;
+    let mut reference = None;
+    let mut git_path = None;
+    let mut title = None;
+    let mut url = None;
+    let mut prefix = None;
+    let mut suffix = None;
+    let mut size = None;
+    let mut view_box = None;
+    let mut paint = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for \"{arg}\"."))
+        };
+
+        match arg.as_str() {
+            "--name" => name = Some(value()?),
+            "--source" => source = Some(PathBuf::from(value()?)),
+            "--git" => git = Some(value()?),
+            "--ref" => reference = Some(value()?),
+            "--path" => git_path = Some(PathBuf::from(value()?)),
+            "--title" => title = Some(value()?),
+            "--url" => url = Some(value()?),
+            "--prefix" => prefix = Some(value()?),
+            "--suffix" => suffix = Some(value()?),
+            "--size" => size = Some(value()?.parse()?),
+            "--view-box" => view_box = Some(value()?),
+            "--fill" => paint = Some(Paint::Fill),
+            "--stroke" => paint = Some(Paint::Stroke),
+            _ => return Err(format!("Unknown argument \"{arg}\".").into()),
+        }
+    }
+
+    let source = match (source, git) {
+        (Some(_), Some(_)) => return Err("Use either \"--source\" or \"--git\".".into()),
+        (Some(source), None) => Some(IconSource::Local(source)),
+        (None, Some(url)) => Some(IconSource::Git {
+            url,
+            reference: reference.ok_or("Missing \"--ref\" for \"--git\".")?,
+            path: git_path.unwrap_or_default(),
+        }),
+        (None, None) => None,
+    };
+
+    let mut icon_set = match name {
+        Some(name) => IconSet::third_party(
+            &name,
+            source.ok_or("Missing \"--source\" or \"--git\" for \"--name\".")?,
+        ),
+        None => {
+            let mut icon_set = IconSet::radix();
+            if let Some(source) = source {
+                icon_set.source = source;
+            }
+            icon_set
+        }
+    };
+
+    if let Some(title) = title {
+        icon_set.title = title;
+    }
+    if url.is_some() {
+        icon_set.url = url;
+    }
+    if let Some(prefix) = prefix {
+        icon_set.component_prefix = prefix;
+    }
+    if let Some(suffix) = suffix {
+        icon_set.component_suffix = suffix;
+    }
+    if let Some(size) = size {
+        icon_set.size = size;
+    }
+    if let Some(view_box) = view_box {
+        icon_set.view_box = view_box;
+    }
+    if let Some(paint) = paint {
+        icon_set.paint = paint;
+    }
+
+    Ok(icon_set)
+}
+
+fn git_checkout<P: AsRef<Path>>(url: &str, reference: &str, path: P) -> Result<(), Box<dyn Error>> {
+    let repository = Repository::clone(url, path)?;
+    let (object, reference) = repository.revparse_ext(reference)?;
 
     repository.checkout_tree(&object, None)?;
 
@@ -114,17 +273,18 @@ fn git_checkout<P: AsRef<Path>>(path: P) -> Result<(), Box<dyn Error>> {
 
 fn generate_icon(
     framework: &dyn Framework,
+    icon_set: &IconSet,
+    icon_name: String,
     module: String,
     component_name: String,
-    input: String,
+    svg: &IconSvg,
 ) -> Result<(), Box<dyn Error>> {
-    let output_path = Path::new("packages")
-        .join("icons")
-        .join(framework.name())
+    let output_path = icon_set
+        .crate_path(framework.name())
         .join("src")
         .join(format!("{module}.rs"));
 
-    let output_tokens = framework.generate(component_name, input)?;
+    let output_tokens = framework.generate(icon_set, icon_name, component_name, svg)?;
     let output = prettyplease::unparse(&syn::parse2(output_tokens)?);
 
     fs::write(output_path, output)?;
@@ -132,6 +292,46 @@ fn generate_icon(
     Ok(())
 }
 
+fn generate_icon_lookup(
+    framework: &dyn Framework,
+    icon_set: &IconSet,
+    icons: &[(String, String)],
+) -> Result<(), Box<dyn Error>> {
+    let output_path = icon_set
+        .crate_path(framework.name())
+        .join("src")
+        .join("icon.rs");
+
+    let output_tokens = framework.generate_icon_lookup(icon_set, icons)?;
+    let output = prettyplease::unparse(&syn::parse2(output_tokens)?);
+
+    fs::write(output_path, output)?;
+
+    Ok(())
+}
+
+fn generate_sprite(
+    framework: &dyn Framework,
+    icon_set: &IconSet,
+    symbols: &[String],
+) -> Result<(), Box<dyn Error>> {
+    let output_path = icon_set.crate_path(framework.name()).join("src");
+
+    let output_tokens = framework.generate_sprite(icon_set)?;
+    let output = prettyplease::unparse(&syn::parse2(output_tokens)?);
+
+    fs::write(output_path.join("sprite.rs"), output)?;
+
+    let sprite = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\">\n{}\n</svg>\n",
+        symbols.join("\n")
+    );
+
+    fs::write(output_path.join("sprite.svg"), sprite)?;
+
+    Ok(())
+}
+
 fn generate_example(
     framework: &dyn Framework,
     component_names: &[String],
@@ -150,10 +350,13 @@ fn generate_example(
     Ok(())
 }
 
-fn generate_lib(framework: &dyn Framework, modules: &[String]) -> Result<(), Box<dyn Error>> {
-    let output_path = Path::new("packages")
-        .join("icons")
-        .join(framework.name())
+fn generate_lib(
+    framework: &dyn Framework,
+    icon_set: &IconSet,
+    modules: &[String],
+) -> Result<(), Box<dyn Error>> {
+    let output_path = icon_set
+        .crate_path(framework.name())
         .join("src")
         .join("lib.rs");
 
@@ -171,7 +374,7 @@ fn generate_lib(framework: &dyn Framework, modules: &[String]) -> Result<(), Box
 
     let output = format!(
         "{}{}\n\n{}\n",
-        match framework.lib_header() {
+        match framework.lib_header(icon_set) {
             Some(header) => format!("{header}\n\n"),
             None => "".into(),
         },
@@ -192,3 +395,106 @@ fn sanitize_identifier(identifier: &str) -> &str {
         identifier => identifier,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<IconSet, Box<dyn Error>> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn defaults_to_radix_icons() {
+        let icon_set = parse(&[]).unwrap();
+
+        assert_eq!(icon_set.package, "cardo-ui-icons");
+        assert_eq!(icon_set.paint, Paint::Fill);
+        assert!(matches!(icon_set.source, IconSource::Git { .. }));
+    }
+
+    #[test]
+    fn radix_icons_can_be_read_from_a_local_directory() {
+        let icon_set = parse(&["--source", "../icons"]).unwrap();
+
+        assert_eq!(icon_set.package, "cardo-ui-icons");
+        assert!(
+            matches!(icon_set.source, IconSource::Local(path) if path == Path::new("../icons"))
+        );
+    }
+
+    #[test]
+    fn parses_third_party_icon_set() {
+        let icon_set = parse(&[
+            "--name",
+            "lucide",
+            "--source",
+            "../lucide/icons",
+            "--url",
+            "https://lucide.dev",
+            "--prefix",
+            "Lucide",
+            "--size",
+            "20",
+            "--view-box",
+            "0 0 20 20",
+            "--fill",
+        ])
+        .unwrap();
+
+        assert_eq!(icon_set.package, "cardo-ui-icons-lucide");
+        assert_eq!(icon_set.title, "Lucide");
+        assert_eq!(icon_set.url.as_deref(), Some("https://lucide.dev"));
+        assert_eq!(icon_set.component_name("check"), "LucideCheckIcon");
+        assert_eq!(icon_set.size, 20);
+        assert_eq!(icon_set.view_box, "0 0 20 20");
+        assert_eq!(icon_set.paint, Paint::Fill);
+    }
+
+    #[test]
+    fn parses_git_source() {
+        let icon_set = parse(&[
+            "--name",
+            "lucide",
+            "--git",
+            "https://example.com/lucide.git",
+            "--ref",
+            "v1",
+            "--path",
+            "icons",
+        ])
+        .unwrap();
+
+        match icon_set.source {
+            IconSource::Git {
+                url,
+                reference,
+                path,
+            } => {
+                assert_eq!(url, "https://example.com/lucide.git");
+                assert_eq!(reference, "v1");
+                assert_eq!(path, PathBuf::from("icons"));
+            }
+            IconSource::Local(_) => panic!("Expected a Git source."),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        for args in [
+            &["--name", "lucide"][..],
+            &[
+                "--source",
+                "icons",
+                "--git",
+                "https://example.com/icons.git",
+            ],
+            &["--git", "https://example.com/icons.git"],
+            &["--size"],
+            &["--size", "large"],
+            &["--sprite"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?} should be rejected");
+        }
+    }
+}
@@ -2,12 +2,35 @@ use std::{error::Error, path::PathBuf};
 
 use proc_macro2::TokenStream;
 
+use crate::icon_set::{IconSet, IconSvg};
+
 pub trait Framework {
     fn name(&self) -> &'static str;
 
-    fn lib_header(&self) -> Option<String>;
+    fn lib_header(&self, icon_set: &IconSet) -> Option<String>;
+
+    /// Generates the `Cargo.toml` of a new icon crate for `icon_set`.
+    fn generate_manifest(&self, icon_set: &IconSet) -> String;
+
+    fn generate(
+        &self,
+        icon_set: &IconSet,
+        icon_name: String,
+        component_name: String,
+        svg: &IconSvg,
+    ) -> Result<TokenStream, Box<dyn Error>>;
+
+    /// Generates the `icon` module for picking icons by name at runtime, from
+    /// `(icon name, component name)` pairs such as `("arrow-up", "ArrowUpIcon")`.
+    fn generate_icon_lookup(
+        &self,
+        icon_set: &IconSet,
+        icons: &[(String, String)],
+    ) -> Result<TokenStream, Box<dyn Error>>;
 
-    fn generate(&self, component_name: String, svg: String) -> Result<TokenStream, Box<dyn Error>>;
+    /// Generates the `sprite` module, which renders icons from the SVG sprite
+    /// sheet (`sprite.svg`) instead of inline paths.
+    fn generate_sprite(&self, icon_set: &IconSet) -> Result<TokenStream, Box<dyn Error>>;
 
     fn generate_example(&self, component_names: &[String]) -> Result<TokenStream, Box<dyn Error>>;
 
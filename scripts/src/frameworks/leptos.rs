@@ -1,10 +1,14 @@
 use std::{error::Error, path::PathBuf, process::Command};
 
 use convert_case::{Case, Casing};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{ToTokens, quote};
 
-use crate::{framework::Framework, status::ExitStatusExt};
+use crate::{
+    framework::Framework,
+    icon_set::{IconSet, IconSvg},
+    status::ExitStatusExt,
+};
 
 pub struct Leptos;
 
@@ -13,40 +17,378 @@ impl Framework for Leptos {
         "leptos"
     }
 
-    fn lib_header(&self) -> Option<String> {
-        Some(
+    fn lib_header(&self, icon_set: &IconSet) -> Option<String> {
+        let title = match &icon_set.url {
+            Some(url) => format!("[{}]({url})", icon_set.title),
+            None => icon_set.title.clone(),
+        };
+        let description = icon_set
+            .description
+            .iter()
+            .flat_map(|description| description.lines().chain([""]))
+            .map(|line| format!("//!{}{line}\n", if line.is_empty() { "" } else { " " }))
+            .collect::<String>();
+        let check_icon = icon_set.component_name("check");
+        let check_id = icon_set.sprite_id("check");
+        let gear_icon = icon_set.component_name("gear");
+
+        Some(format!(
             "\
-            //! Leptos port of [Radix Icons](https://www.radix-ui.com/icons).\n\
+            //! Leptos port of {title}.\n\
+            //!\n\
+            {description}\
+            //! Each icon is a component (`{check_icon}`, `{gear_icon}`, ...). To pick icons at runtime,\n\
+            //! use [`Icon`] with an [`IconName`], which parses from and displays as the kebab-case\n\
+            //! icon name (`\"arrow-up\"`); [`ALL_ICONS`] lists every name.\n\
             //!\n\
-            //! Radix Icons is a crisp set of 15x15 icons.\n\
+            //! Icons are decorative (`aria-hidden=\"true\"`) unless given a `label`, which adds\n\
+            //! `role=\"img\"` and a `<title>`. They accept a `class` (`ClassProp`) and any other\n\
+            //! attribute, e.g. `<{check_icon} class=\"size-4\" attr:data-state=\"on\" />`.\n\
             //!\n\
-            //! See [the Cardo UI book](https://cardo-ui.dev/icons/index.html) for more documenation.\n\
+            //! With the `sprite` feature, icons render as `<svg><use href=\"#{check_id}\" /></svg>`\n\
+            //! instead of inline paths, which keeps the WASM bundle small. Render [`IconSprite`] once to\n\
+            //! inline the sprite sheet ([`SPRITE`]) the icons refer to.\n\
             "
-            .to_owned()
+        ))
+    }
+
+    fn generate_manifest(&self, icon_set: &IconSet) -> String {
+        format!(
+            "\
+            [package]\n\
+            name = \"{}\"\n\
+            description = \"Leptos port of {}.\"\n\
+            \n\
+            authors.workspace = true\n\
+            edition.workspace = true\n\
+            license.workspace = true\n\
+            repository.workspace = true\n\
+            version.workspace = true\n\
+            \n\
+            [features]\n\
+            default = []\n\
+            # Render icons from an SVG sprite sheet instead of inline paths.\n\
+            sprite = []\n\
+            \n\
+            [dependencies]\n\
+            cardo-ui-class.workspace = true\n\
+            leptos.workspace = true\n\
+            ",
+            icon_set.package, icon_set.title
         )
     }
 
-    fn generate(&self, component_name: String, svg: String) -> Result<TokenStream, Box<dyn Error>> {
+    fn generate(
+        &self,
+        icon_set: &IconSet,
+        icon_name: String,
+        component_name: String,
+        svg: &IconSvg,
+    ) -> Result<TokenStream, Box<dyn Error>> {
         let component_name: TokenStream = component_name.parse()?;
-        let svg: TokenStream = svg
-            .replacen("<svg", "<svg node_ref=node_ref ", 1)
-            .replacen("width=\"15\"", "width=width", 1)
-            .replacen("height=\"15\"", "height=height", 1)
-            .replace("fill=\"currentColor\"", "fill=color")
-            .parse()?;
+        let size = Literal::usize_unsuffixed(icon_set.size);
+
+        // The `color` prop replaces `currentColor` for the icon set's paint.
+        let paint = icon_set.paint.attribute();
+        let attributes = svg
+            .attributes
+            .iter()
+            .map(|(name, value)| {
+                if name == paint && value == "currentColor" {
+                    format!("{name}=color")
+                } else {
+                    format!("{name}={value:?}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let children = svg.children.replace(
+            &format!("{paint}=\"currentColor\""),
+            &format!("{paint}=color"),
+        );
+        // `class` is a `Signal` derived from the `ClassProp`, which isn't `Copy` and so can't be
+        // moved into the interceptor's children.
+        let svg: TokenStream = format!(
+            "<svg node_ref=node_ref \
+            class=class \
+            role=move || label.get().map(|_| \"img\") \
+            aria-hidden=move || label.get().is_none().then_some(\"true\") \
+            {{..attrs}} \
+            width=width height=height {attributes} xmlns=\"http://www.w3.org/2000/svg\">\
+            {{move || label.get().map(|label| view! {{ <title>{{label}}</title> }})}}\
+            {children}\
+            </svg>"
+        )
+        .parse()?;
 
         Ok(quote! {
+            #[cfg(feature = "sprite")]
+            use super::SpriteIcon;
+            use cardo_ui_class::ClassProp;
+            #[cfg(not(feature = "sprite"))]
+            use leptos::attribute_interceptor::AttributeInterceptor;
             use leptos::{prelude::*, svg::Svg};
 
+            #[cfg(not(feature = "sprite"))]
+            #[component]
+            pub fn #component_name(
+                #[prop(default = #size.into(), into)] width: Signal<usize>,
+                #[prop(default = #size.into(), into)] height: Signal<usize>,
+                #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+                #[prop(into, optional)] class: ClassProp,
+                #[prop(into, optional)] label: MaybeProp<String>,
+                #[prop(optional)] node_ref: NodeRef<Svg>,
+            ) -> impl IntoView {
+                let class = Signal::derive(move || class.get().map(|class| class.0));
+                view! {
+                    <AttributeInterceptor let:attrs>
+                        #svg
+                    </AttributeInterceptor>
+                }
+            }
+
+            #[cfg(feature = "sprite")]
             #[component]
             pub fn #component_name(
-                #[prop(default = 15.into(), into)] width: Signal<usize>,
-                #[prop(default = 15.into(), into)] height: Signal<usize>,
+                #[prop(default = #size.into(), into)] width: Signal<usize>,
+                #[prop(default = #size.into(), into)] height: Signal<usize>,
+                #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+                #[prop(into, optional)] class: ClassProp,
+                #[prop(into, optional)] label: MaybeProp<String>,
+                #[prop(optional)] node_ref: NodeRef<Svg>,
+            ) -> impl IntoView {
+                view! {
+                    <SpriteIcon
+                        id=#icon_name
+                        width=width
+                        height=height
+                        color=color
+                        class=class
+                        label=label
+                        node_ref=node_ref
+                    />
+                }
+            }
+        })
+    }
+
+    fn generate_sprite(&self, icon_set: &IconSet) -> Result<TokenStream, Box<dyn Error>> {
+        let size = Literal::usize_unsuffixed(icon_set.size);
+        let view_box = &icon_set.view_box;
+        let sprite_id_prefix = &icon_set.sprite_id_prefix;
+        let sprite_doc = format!(
+            " The SVG sprite sheet, with a `<symbol>` per icon whose `id` is `{sprite_id_prefix}<name>`."
+        );
+        let href = format!("#{sprite_id_prefix}{{}}");
+
+        Ok(quote! {
+            use cardo_ui_class::ClassProp;
+            use leptos::{attribute_interceptor::AttributeInterceptor, prelude::*, svg::Svg};
+
+            #[doc = #sprite_doc]
+            pub const SPRITE: &str = include_str!("sprite.svg");
+
+            /// Inlines the sprite sheet once, for icons rendered with [`SpriteIcon`].
+            ///
+            /// Render it once near the root of the app, e.g. at the top of `<body>`.
+            #[component]
+            pub fn IconSprite() -> impl IntoView {
+                view! {
+                    <div style="display: none" aria-hidden="true" inner_html=SPRITE></div>
+                }
+            }
+
+            /// Renders the icon named `id` (e.g. `"check"`) from the sprite sheet inlined by
+            /// [`IconSprite`].
+            #[component]
+            pub fn SpriteIcon(
+                #[prop(into)] id: Signal<&'static str>,
+                #[prop(default = #size.into(), into)] width: Signal<usize>,
+                #[prop(default = #size.into(), into)] height: Signal<usize>,
                 #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+                #[prop(into, optional)] class: ClassProp,
+                #[prop(into, optional)] label: MaybeProp<String>,
                 #[prop(optional)] node_ref: NodeRef<Svg>,
             ) -> impl IntoView {
+                let class = Signal::derive(move || class.get().map(|class| class.0));
                 view! {
-                    #svg
+                    <AttributeInterceptor let:attrs>
+                        <svg
+                            node_ref=node_ref
+                            class=class
+                            role=move || label.get().map(|_| "img")
+                            aria-hidden=move || label.get().is_none().then_some("true")
+                            {..attrs}
+                            width=width
+                            height=height
+                            viewBox=#view_box
+                            color=color
+                            xmlns="http://www.w3.org/2000/svg"
+                        >
+                            {move || label.get().map(|label| view! { <title>{label}</title> })}
+                            <use href=move || format!(#href, id.get()) />
+                        </svg>
+                    </AttributeInterceptor>
+                }
+            }
+        })
+    }
+
+    fn generate_icon_lookup(
+        &self,
+        icon_set: &IconSet,
+        icons: &[(String, String)],
+    ) -> Result<TokenStream, Box<dyn Error>> {
+        let size = Literal::usize_unsuffixed(icon_set.size);
+        let count = Literal::usize_unsuffixed(icons.len());
+        let mut variant: Vec<TokenStream> = vec![];
+        let mut name: Vec<String> = vec![];
+        let mut component_name: Vec<TokenStream> = vec![];
+
+        for (icon_name, icon_component_name) in icons {
+            variant.push(icon_name.to_case(Case::Pascal).parse()?);
+            name.push(icon_name.clone());
+            component_name.push(icon_component_name.parse()?);
+        }
+
+        Ok(quote! {
+            use std::{fmt, str::FromStr};
+
+            use cardo_ui_class::ClassProp;
+            use leptos::{prelude::*, svg::Svg};
+
+            use super::*;
+
+            /// Name of an icon, for picking icons at runtime (e.g. from configuration).
+            #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub enum IconName {
+                #(#variant,)*
+            }
+
+            /// All icon names, in alphabetical order.
+            pub const ALL_ICONS: [IconName; #count] = [
+                #(IconName::#variant,)*
+            ];
+
+            impl IconName {
+                /// Iterates over all icon names, in alphabetical order.
+                pub fn iter() -> impl Iterator<Item = IconName> {
+                    ALL_ICONS.into_iter()
+                }
+
+                /// The kebab-case name of the icon, e.g. `"arrow-up"`.
+                pub const fn as_str(self) -> &'static str {
+                    match self {
+                        #(IconName::#variant => #name,)*
+                    }
+                }
+            }
+
+            impl fmt::Display for IconName {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(self.as_str())
+                }
+            }
+
+            /// Error returned when parsing an unknown [`IconName`].
+            #[derive(Clone, Debug, Eq, PartialEq)]
+            pub struct ParseIconNameError {
+                name: String,
+            }
+
+            impl fmt::Display for ParseIconNameError {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "unknown icon `{}`", self.name)
+                }
+            }
+
+            impl std::error::Error for ParseIconNameError {}
+
+            impl FromStr for IconName {
+                type Err = ParseIconNameError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    match s {
+                        #(#name => Ok(IconName::#variant),)*
+                        _ => Err(ParseIconNameError { name: s.into() }),
+                    }
+                }
+            }
+
+            /// Renders the icon named `name`.
+            #[cfg(not(feature = "sprite"))]
+            #[component]
+            pub fn Icon(
+                #[prop(into)] name: Signal<IconName>,
+                #[prop(default = #size.into(), into)] width: Signal<usize>,
+                #[prop(default = #size.into(), into)] height: Signal<usize>,
+                #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+                #[prop(into, optional)] class: ClassProp,
+                #[prop(into, optional)] label: MaybeProp<String>,
+                #[prop(optional)] node_ref: NodeRef<Svg>,
+            ) -> impl IntoView {
+                move || match name.get() {
+                    #(IconName::#variant => view! {
+                        <#component_name
+                            width=width
+                            height=height
+                            color=color
+                            class=class.clone()
+                            label=label
+                            node_ref=node_ref
+                        />
+                    }.into_any(),)*
+                }
+            }
+
+            /// Renders the icon named `name`.
+            #[cfg(feature = "sprite")]
+            #[component]
+            pub fn Icon(
+                #[prop(into)] name: Signal<IconName>,
+                #[prop(default = #size.into(), into)] width: Signal<usize>,
+                #[prop(default = #size.into(), into)] height: Signal<usize>,
+                #[prop(default = "currentColor".into(), into)] color: Signal<String>,
+                #[prop(into, optional)] class: ClassProp,
+                #[prop(into, optional)] label: MaybeProp<String>,
+                #[prop(optional)] node_ref: NodeRef<Svg>,
+            ) -> impl IntoView {
+                view! {
+                    <SpriteIcon
+                        id=Signal::derive(move || name.get().as_str())
+                        width=width
+                        height=height
+                        color=color
+                        class=class
+                        label=label
+                        node_ref=node_ref
+                    />
+                }
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                #[test]
+                fn names_round_trip() {
+                    for name in ALL_ICONS {
+                        assert_eq!(name.as_str().parse(), Ok(name));
+                        assert_eq!(name.to_string(), name.as_str());
+                    }
+                }
+
+                #[test]
+                fn unknown_name_fails_to_parse() {
+                    let error = "not-an-icon".parse::<IconName>().unwrap_err();
+
+                    assert_eq!(error.to_string(), "unknown icon `not-an-icon`");
+                }
+
+                #[test]
+                fn all_icons_are_sorted_and_unique() {
+                    assert!(ALL_ICONS.windows(2).all(|pair| pair[0] < pair[1]));
+                    assert!(IconName::iter().eq(ALL_ICONS));
                 }
             }
         })
@@ -107,7 +449,7 @@ impl Framework for Leptos {
 
         Ok(quote! {
             use leptos::prelude::*;
-            use cardo_ui_icons::*;
+            use cardo_ui::icons::*;
 
             #[component]
             pub fn Icons() -> impl IntoView {
@@ -0,0 +1,331 @@
+//! Configuration of an icon set for the icon generator (`src/bin/icons.rs`).
+
+use std::{error::Error, path::PathBuf};
+
+use convert_case::{Case, Casing};
+
+const RADIX_GIT_URL: &str = "https://github.com/radix-ui/icons.git";
+const RADIX_GIT_REF: &str = "@radix-ui/react-icons@1.3.2";
+
+/// Where the SVG files of an icon set are read from.
+#[derive(Clone, Debug)]
+pub enum IconSource {
+    /// Directory `path` of a Git repository checked out at `reference`.
+    Git {
+        url: String,
+        reference: String,
+        path: PathBuf,
+    },
+    /// Local directory, for generating offline.
+    Local(PathBuf),
+}
+
+/// Which paint the `color` prop of the generated icons sets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Paint {
+    /// Solid icons, drawn with `fill="currentColor"`.
+    Fill,
+    /// Outline icons, drawn with `stroke="currentColor"`.
+    Stroke,
+}
+
+impl Paint {
+    pub fn attribute(self) -> &'static str {
+        match self {
+            Paint::Fill => "fill",
+            Paint::Stroke => "stroke",
+        }
+    }
+}
+
+/// A set of SVG icons and how to turn it into an icon crate.
+#[derive(Clone, Debug)]
+pub struct IconSet {
+    /// Human-readable name, e.g. `"Radix Icons"`.
+    pub title: String,
+    /// Home page of the icon set.
+    pub url: Option<String>,
+    /// Paragraphs for the crate documentation, after the title.
+    pub description: Option<String>,
+    /// Package of the generated crate, e.g. `"cardo-ui-icons"`.
+    pub package: String,
+    /// Directory of the generated crates, with one subdirectory per framework.
+    pub output: PathBuf,
+    pub source: IconSource,
+    /// Prepended to component names, e.g. `"Lucide"` for `LucideCheckIcon`.
+    pub component_prefix: String,
+    /// Appended to component names, e.g. `"Icon"` for `CheckIcon`.
+    pub component_suffix: String,
+    /// Default `width` and `height` of the icons.
+    pub size: usize,
+    /// `viewBox` for icons that don't declare one.
+    pub view_box: String,
+    pub paint: Paint,
+    /// Prepended to icon names for the `id` of their sprite `<symbol>`, e.g.
+    /// `"cardo-icon-"` for `cardo-icon-check`, so they don't clash with other IDs in the
+    /// document.
+    pub sprite_id_prefix: String,
+    /// Package of the book examples listing every icon, if any.
+    pub book_examples: Option<String>,
+}
+
+impl IconSet {
+    /// [Radix Icons](https://www.radix-ui.com/icons), the icons of `cardo-ui-icons`.
+    pub fn radix() -> Self {
+        IconSet {
+            title: "Radix Icons".into(),
+            url: Some("https://www.radix-ui.com/icons".into()),
+            description: Some(
+                "Radix Icons is a crisp set of 15x15 icons.\n\n\
+                See [the Cardo UI book](https://cardo-ui.dev/icons/index.html) for more documenation."
+                    .into(),
+            ),
+            package: "cardo-ui-icons".into(),
+            output: PathBuf::from("packages/icons"),
+            source: IconSource::Git {
+                url: RADIX_GIT_URL.into(),
+                reference: RADIX_GIT_REF.into(),
+                path: PathBuf::from("packages/radix-icons/icons"),
+            },
+            component_prefix: "".into(),
+            component_suffix: "Icon".into(),
+            size: 15,
+            view_box: "0 0 15 15".into(),
+            paint: Paint::Fill,
+            sprite_id_prefix: "cardo-icon-".into(),
+            book_examples: Some("cardo-ui-book-icons".into()),
+        }
+    }
+
+    /// A third-party icon set named `name` (e.g. `"lucide"`), generated into
+    /// `packages/icons-<name>` as `cardo-ui-icons-<name>`.
+    ///
+    /// Defaults to 24x24 outline icons, like Lucide and Heroicons.
+    pub fn third_party(name: &str, source: IconSource) -> Self {
+        IconSet {
+            title: name.to_case(Case::Title),
+            url: None,
+            description: None,
+            package: format!("cardo-ui-icons-{name}"),
+            output: PathBuf::from(format!("packages/icons-{name}")),
+            source,
+            component_prefix: "".into(),
+            component_suffix: "Icon".into(),
+            size: 24,
+            view_box: "0 0 24 24".into(),
+            paint: Paint::Stroke,
+            sprite_id_prefix: format!("cardo-icon-{name}-"),
+            book_examples: None,
+        }
+    }
+
+    /// Component name of the icon in `file_stem.svg`, e.g. `CheckIcon`.
+    pub fn component_name(&self, file_stem: &str) -> String {
+        format!(
+            "{}{}{}",
+            self.component_prefix,
+            file_stem.to_case(Case::Pascal),
+            self.component_suffix
+        )
+    }
+
+    /// `id` of the sprite `<symbol>` of the icon in `file_stem.svg`, e.g. `cardo-icon-check`.
+    pub fn sprite_id(&self, file_stem: &str) -> String {
+        format!("{}{file_stem}", self.sprite_id_prefix)
+    }
+
+    /// Directory of the generated crate for `framework`.
+    pub fn crate_path(&self, framework: &str) -> PathBuf {
+        self.output.join(framework)
+    }
+}
+
+/// An icon SVG, split into the attributes of its `<svg>` element and its
+/// children.
+#[derive(Clone, Debug)]
+pub struct IconSvg {
+    /// Attributes of the `<svg>` element, without `width`, `height`, `class` and
+    /// `xmlns`, which the generated components set themselves. Includes
+    /// `viewBox`, from the icon set if the SVG has none.
+    pub attributes: Vec<(String, String)>,
+    /// Source of the children of the `<svg>` element.
+    pub children: String,
+}
+
+impl IconSvg {
+    pub fn parse(icon_set: &IconSet, svg: &str) -> Result<Self, Box<dyn Error>> {
+        let document = roxmltree::Document::parse(svg)?;
+        let root = document.root_element();
+        if root.tag_name().name() != "svg" {
+            return Err("Expected an `<svg>` element.".into());
+        }
+
+        let mut attributes: Vec<(String, String)> = root
+            .attributes()
+            .filter(|attribute| !matches!(attribute.name(), "width" | "height" | "class"))
+            .map(|attribute| (attribute.name().to_owned(), attribute.value().to_owned()))
+            .collect();
+        if !attributes.iter().any(|(name, _)| name == "viewBox") {
+            attributes.insert(0, ("viewBox".into(), icon_set.view_box.clone()));
+        }
+
+        let children = match (root.first_child(), root.last_child()) {
+            (Some(first), Some(last)) => svg[first.range().start..last.range().end].trim(),
+            _ => "",
+        };
+
+        Ok(IconSvg {
+            attributes,
+            children: children.to_owned(),
+        })
+    }
+
+    /// The icon in `file_stem.svg` as a `<symbol>` for the sprite sheet, with
+    /// [`IconSet::sprite_id`] as its `id`.
+    ///
+    /// Presentation attributes of the `<svg>` element (`fill`, `stroke`, ...)
+    /// move to the `<symbol>`, which passes them on to its children.
+    pub fn sprite_symbol(&self, icon_set: &IconSet, file_stem: &str) -> String {
+        let id = icon_set.sprite_id(file_stem);
+        let attributes = self
+            .attributes
+            .iter()
+            .map(|(name, value)| {
+                let value = value.replace('&', "&amp;").replace('"', "&quot;");
+                format!(" {name}=\"{value}\"")
+            })
+            .collect::<String>();
+        let mut children = String::new();
+        for line in self.children.lines().map(str::trim) {
+            // Keep lines apart when a tag spans several lines.
+            if !children.is_empty() && !children.ends_with('>') {
+                children.push(' ');
+            }
+            children.push_str(line);
+        }
+
+        format!("<symbol id=\"{id}\"{attributes}>{children}</symbol>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LUCIDE_CHECK: &str = r#"<svg
+  xmlns="http://www.w3.org/2000/svg"
+  width="24"
+  height="24"
+  viewBox="0 0 24 24"
+  fill="none"
+  stroke="currentColor"
+  stroke-width="2"
+  stroke-linecap="round"
+  stroke-linejoin="round"
+  class="lucide lucide-check"
+>
+  <path d="M20 6 9 17l-5-5" />
+</svg>
+"#;
+
+    const NO_VIEW_BOX: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" fill="none" stroke="currentColor">
+  <circle
+    cx="12"
+    cy="12"
+    r="10"
+  />
+  <path d="M12 8v8" />
+</svg>
+"#;
+
+    fn lucide() -> IconSet {
+        IconSet::third_party("lucide", IconSource::Local(PathBuf::from("icons")))
+    }
+
+    fn attributes(svg: &IconSvg) -> Vec<(&str, &str)> {
+        svg.attributes
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn parse_keeps_presentation_attributes_and_children() {
+        let svg = IconSvg::parse(&lucide(), LUCIDE_CHECK).unwrap();
+
+        assert_eq!(
+            attributes(&svg),
+            [
+                ("viewBox", "0 0 24 24"),
+                ("fill", "none"),
+                ("stroke", "currentColor"),
+                ("stroke-width", "2"),
+                ("stroke-linecap", "round"),
+                ("stroke-linejoin", "round"),
+            ]
+        );
+        assert_eq!(svg.children, r#"<path d="M20 6 9 17l-5-5" />"#);
+    }
+
+    #[test]
+    fn parse_adds_view_box_of_icon_set() {
+        let mut icon_set = lucide();
+        icon_set.view_box = "0 0 32 32".into();
+
+        let svg = IconSvg::parse(&icon_set, NO_VIEW_BOX).unwrap();
+
+        assert_eq!(
+            attributes(&svg),
+            [
+                ("viewBox", "0 0 32 32"),
+                ("fill", "none"),
+                ("stroke", "currentColor"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rejects_other_elements() {
+        assert!(IconSvg::parse(&lucide(), "<g><path d=\"M0 0\" /></g>").is_err());
+        assert!(IconSvg::parse(&lucide(), "<svg>").is_err());
+    }
+
+    #[test]
+    fn sprite_symbol_joins_multi_line_tags() {
+        let svg = IconSvg::parse(&lucide(), NO_VIEW_BOX).unwrap();
+
+        assert_eq!(
+            svg.sprite_symbol(&lucide(), "circle-plus"),
+            "<symbol id=\"cardo-icon-lucide-circle-plus\" viewBox=\"0 0 24 24\" fill=\"none\" \
+            stroke=\"currentColor\"><circle cx=\"12\" cy=\"12\" r=\"10\" /><path d=\"M12 8v8\" \
+            /></symbol>"
+        );
+    }
+
+    #[test]
+    fn sprite_symbol_escapes_attributes() {
+        let svg = IconSvg {
+            attributes: vec![("aria-label".into(), "\"Fish\" & chips".into())],
+            children: String::new(),
+        };
+
+        assert_eq!(
+            svg.sprite_symbol(&IconSet::radix(), "fish"),
+            "<symbol id=\"cardo-icon-fish\" aria-label=\"&quot;Fish&quot; &amp; chips\"></symbol>"
+        );
+    }
+
+    #[test]
+    fn component_name_adds_prefix_and_suffix() {
+        let mut icon_set = IconSet::radix();
+        assert_eq!(icon_set.component_name("check"), "CheckIcon");
+        assert_eq!(
+            icon_set.component_name("arrow-top-right"),
+            "ArrowTopRightIcon"
+        );
+
+        icon_set.component_prefix = "Lucide".into();
+        icon_set.component_suffix = "".into();
+        assert_eq!(icon_set.component_name("circle-plus"), "LucideCirclePlus");
+    }
+}
@@ -1,3 +1,4 @@
 pub mod framework;
 pub mod frameworks;
+pub mod icon_set;
 pub mod status;